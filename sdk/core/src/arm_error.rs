@@ -0,0 +1,117 @@
+/// The error payload of Azure Resource Manager, as returned by the failed
+/// operations and by the status monitors of the long running operations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArmError {
+    /// The error code, e.g. `StorageAccountAlreadyTaken`.
+    #[serde(default)]
+    pub code: String,
+    #[serde(default)]
+    pub message: String,
+    /// The resource or property the error is about.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<ArmError>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_info: Vec<ArmErrorAdditionalInfo>,
+    /// The more specific error some services, like Key Vault, nest in it.
+    #[serde(
+        default,
+        rename = "innererror",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub inner_error: Option<ArmInnerError>,
+}
+
+/// Service specific information attached to an [`ArmError`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArmErrorAdditionalInfo {
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub info: serde_json::Value,
+}
+
+/// The `innererror` of an [`ArmError`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ArmInnerError {
+    /// The error code, e.g. `ForbiddenByPolicy`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ArmErrorPayload {
+    Wrapped { error: ArmError },
+    Flat(ArmError),
+}
+
+impl ArmError {
+    pub fn new<C, M>(code: C, message: M) -> Self
+    where
+        C: Into<String>,
+        M: Into<String>,
+    {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Parses either `{"error": {"code": .., ..}}` (a `CloudError`) or the
+    /// bare `{"code": .., ..}`. `None` if the body is neither or carries no
+    /// code.
+    pub fn from_body(body: &[u8]) -> Option<Self> {
+        Self::parse(body).filter(|error| !error.code.is_empty())
+    }
+
+    /// Parses either form, also without a code: the `HttpError` of every
+    /// JSON service goes through it.
+    pub(crate) fn parse(body: &[u8]) -> Option<Self> {
+        match serde_json::from_slice(body).ok()? {
+            ArmErrorPayload::Wrapped { error } => Some(error),
+            ArmErrorPayload::Flat(error) => Some(error),
+        }
+    }
+}
+
+impl std::fmt::Display for ArmError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)?;
+        if let Some(target) = &self.target {
+            write!(f, " (target: {})", target)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ArmError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_cloud_error() {
+        let body = br#"{"error": {"code": "StorageAccountAlreadyTaken", "message": "The storage account named foo is already taken.", "target": "foo", "details": [{"code": "Inner", "message": "inner"}], "additionalInfo": [{"type": "PolicyViolation", "info": {"policy": "p"}}]}}"#;
+
+        let error = ArmError::from_body(body).unwrap();
+
+        assert_eq!(error.code, "StorageAccountAlreadyTaken");
+        assert_eq!(error.target.as_deref(), Some("foo"));
+        assert_eq!(error.details, vec![ArmError::new("Inner", "inner")]);
+        assert_eq!(error.additional_info[0].type_, "PolicyViolation");
+        assert_eq!(error.additional_info[0].info["policy"], "p");
+    }
+
+    #[test]
+    fn parses_a_bare_error() {
+        let error = ArmError::from_body(br#"{"code": "Conflict", "message": "busy"}"#).unwrap();
+        assert_eq!(error, ArmError::new("Conflict", "busy"));
+        assert_eq!(ArmError::from_body(b"{}"), None);
+        assert_eq!(ArmError::from_body(b"<html/>"), None);
+    }
+}
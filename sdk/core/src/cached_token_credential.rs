@@ -0,0 +1,205 @@
+use crate::errors::AzureError;
+use crate::{TokenCredential, TokenResponse};
+use chrono::{Duration, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+type TokenSlot = Arc<futures::lock::Mutex<Option<TokenResponse>>>;
+
+/// Wraps a [`TokenCredential`] and hands out its tokens until they are about
+/// to expire.
+///
+/// Tokens are cached per resource and requested again `refresh_margin`
+/// (5 minutes by default) before their `expires_on`. Concurrent callers
+/// asking for the same resource wait for a single refresh instead of each
+/// hitting the identity provider (or spawning `az` for the
+/// `AzureCliCredential`).
+///
+/// ```no_run
+/// # use azure_core::{CachedTokenCredential, TokenCredential};
+/// # async fn example<C: TokenCredential + Send + Sync>(credential: C) {
+/// let credential = CachedTokenCredential::new(credential);
+/// // only the first call reaches the wrapped credential
+/// let token = credential.get_token("https://management.azure.com/").await;
+/// let token = credential.get_token("https://management.azure.com/").await;
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CachedTokenCredential<C> {
+    credential: C,
+    refresh_margin: Duration,
+    tokens: Mutex<HashMap<String, TokenSlot>>,
+}
+
+impl<C> CachedTokenCredential<C> {
+    pub fn new(credential: C) -> Self {
+        Self {
+            credential,
+            refresh_margin: Duration::minutes(5),
+            tokens: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets how long before `expires_on` a cached token is refreshed.
+    pub fn with_refresh_margin(self, refresh_margin: Duration) -> Self {
+        Self {
+            refresh_margin,
+            ..self
+        }
+    }
+
+    pub fn refresh_margin(&self) -> Duration {
+        self.refresh_margin
+    }
+
+    pub fn credential(&self) -> &C {
+        &self.credential
+    }
+
+    /// Forgets every cached token so the next calls get fresh ones.
+    pub fn clear(&self) {
+        self.tokens.lock().unwrap().clear();
+    }
+
+    fn slot(&self, resource: &str) -> TokenSlot {
+        self.tokens
+            .lock()
+            .unwrap()
+            .entry(resource.to_owned())
+            .or_default()
+            .clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> TokenCredential for CachedTokenCredential<C>
+where
+    C: TokenCredential + Send + Sync,
+{
+    async fn get_token(&self, resource: &str) -> Result<TokenResponse, AzureError> {
+        let slot = self.slot(resource);
+        // holding the lock of the resource while refreshing makes the
+        // concurrent callers wait for this refresh and then reuse its token
+        let mut token = slot.lock().await;
+        if let Some(token) = token.as_ref() {
+            if token.expires_on - self.refresh_margin > Utc::now() {
+                return Ok(token.clone());
+            }
+        }
+
+        debug!("refreshing the token of {}", resource);
+        let fresh = self.credential.get_token(resource).await?;
+        *token = Some(fresh.clone());
+        Ok(fresh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oauth2::AccessToken;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(Debug)]
+    struct CountingCredential {
+        calls: AtomicU32,
+        lifetime: Duration,
+    }
+
+    impl CountingCredential {
+        fn new(lifetime: Duration) -> Self {
+            Self {
+                calls: AtomicU32::new(0),
+                lifetime,
+            }
+        }
+
+        fn calls(&self) -> u32 {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl TokenCredential for CountingCredential {
+        async fn get_token(&self, resource: &str) -> Result<TokenResponse, AzureError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            // give the concurrent callers a chance to pile up
+            tokio::time::delay_for(std::time::Duration::from_millis(10)).await;
+            Ok(TokenResponse::new(
+                AccessToken::new(format!("{}#{}", resource, call)),
+                Utc::now() + self.lifetime,
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn reuses_tokens_per_resource() {
+        let credential = CachedTokenCredential::new(CountingCredential::new(Duration::hours(1)));
+
+        let first = credential
+            .get_token("https://vault.azure.net")
+            .await
+            .unwrap();
+        let second = credential
+            .get_token("https://vault.azure.net")
+            .await
+            .unwrap();
+        let other = credential
+            .get_token("https://management.azure.com/")
+            .await
+            .unwrap();
+
+        assert_eq!(first.token.secret(), "https://vault.azure.net#1");
+        assert_eq!(second.token.secret(), "https://vault.azure.net#1");
+        assert_eq!(other.token.secret(), "https://management.azure.com/#2");
+        assert_eq!(credential.credential().calls(), 2);
+
+        credential.clear();
+        credential
+            .get_token("https://vault.azure.net")
+            .await
+            .unwrap();
+        assert_eq!(credential.credential().calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn refreshes_within_the_margin() {
+        let credential = CachedTokenCredential::new(CountingCredential::new(Duration::minutes(4)));
+
+        credential
+            .get_token("https://vault.azure.net")
+            .await
+            .unwrap();
+        credential
+            .get_token("https://vault.azure.net")
+            .await
+            .unwrap();
+        assert_eq!(credential.credential().calls(), 2);
+
+        let credential = credential.with_refresh_margin(Duration::minutes(1));
+        credential
+            .get_token("https://vault.azure.net")
+            .await
+            .unwrap();
+        credential
+            .get_token("https://vault.azure.net")
+            .await
+            .unwrap();
+        assert_eq!(credential.credential().calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn concurrent_callers_share_one_refresh() {
+        let credential = CachedTokenCredential::new(CountingCredential::new(Duration::hours(1)));
+
+        let tokens = futures::future::join_all(
+            (0..5).map(|_| credential.get_token("https://vault.azure.net")),
+        )
+        .await;
+
+        assert_eq!(credential.credential().calls(), 1);
+        for token in tokens {
+            assert_eq!(token.unwrap().token.secret(), "https://vault.azure.net#1");
+        }
+    }
+}
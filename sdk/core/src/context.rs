@@ -0,0 +1,367 @@
+use crate::errors::AzureError;
+use futures::future::{self, Either};
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Poll, Waker};
+use std::time::{Duration, Instant};
+
+tokio::task_local! {
+    static CURRENT: Context;
+}
+
+/// The key of the trace id shared by the requests of an operation.
+const TRACE_ID: &str = "azure_core::trace_id";
+
+/// Cancels the operations whose [`Context`] carries it (or one of its
+/// clones).
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<CancellationInner>,
+}
+
+#[derive(Debug, Default)]
+struct CancellationInner {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        for waker in self.inner.wakers.lock().unwrap().drain(..) {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Completes once `cancel` has been called.
+    pub async fn cancelled(&self) {
+        future::poll_fn(|cx| {
+            if self.is_cancelled() {
+                return Poll::Ready(());
+            }
+            let mut wakers = self.inner.wakers.lock().unwrap();
+            // checked again under the lock so a concurrent `cancel` cannot
+            // drain the wakers before ours is registered
+            if self.is_cancelled() {
+                return Poll::Ready(());
+            }
+            if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+            Poll::Pending
+        })
+        .await
+    }
+}
+
+/// Per-call settings of an operation: a deadline, a cancellation signal
+/// and arbitrary values for the pipeline policies.
+///
+/// Any operation can be run in a context with [`Context::run`]:
+///
+/// ```no_run
+/// # use azure_core::{CancellationToken, Context};
+/// # use std::time::Duration;
+/// # async fn example(operation: impl std::future::Future<Output = Result<(), azure_core::errors::AzureError>>) {
+/// let cancellation = CancellationToken::new();
+/// let context = Context::new()
+///     .with_timeout(Duration::from_secs(30))
+///     .with_cancellation_token(cancellation.clone());
+/// // `cancellation.cancel()` from another task stops the operation
+/// let result = context.run(operation).await;
+/// # }
+/// ```
+///
+/// Every request the operation sends through a
+/// [`Pipeline`](crate::pipeline::Pipeline) gets the context, so the policies
+/// can read its values, and the storage clients send the remaining time as
+/// the `timeout` query parameter. When the deadline passes or the token is
+/// cancelled the operation is dropped, which aborts the request in flight,
+/// and fails with `AzureError::DeadlineExceeded` or `AzureError::Cancelled`,
+/// converted into the error type of the operation.
+#[derive(Clone, Default)]
+pub struct Context {
+    deadline: Option<Instant>,
+    cancellation_token: Option<CancellationToken>,
+    values: HashMap<String, Arc<dyn Any + Send + Sync>>,
+}
+
+impl std::fmt::Debug for Context {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Context")
+            .field("deadline", &self.deadline)
+            .field("cancellation_token", &self.cancellation_token)
+            .field("values", &self.values.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The context of the operation being run by [`Context::run`], if any.
+    pub fn current() -> Option<Self> {
+        CURRENT.try_with(Clone::clone).ok()
+    }
+
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// The time left before the deadline, zero if it has passed.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    pub fn cancellation_token(&self) -> Option<&CancellationToken> {
+        self.cancellation_token.as_ref()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        matches!(&self.cancellation_token, Some(token) if token.is_cancelled())
+    }
+
+    /// The W3C trace id the requests run in this context send in their
+    /// `traceparent` header, so the service logs of all of them (retries,
+    /// pages, polls of a long running operation...) share one trace.
+    pub fn trace_id(&self) -> Option<&str> {
+        self.value::<String>(TRACE_ID).map(String::as_str)
+    }
+
+    pub fn value<V: Any + Send + Sync>(&self, key: &str) -> Option<&V> {
+        self.values.get(key)?.downcast_ref()
+    }
+
+    pub fn insert_value<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<String>,
+        V: Any + Send + Sync,
+    {
+        self.values.insert(key.into(), Arc::new(value));
+    }
+
+    /// Keeps the earliest of `deadline` and the current deadline.
+    pub fn with_deadline(self, deadline: Instant) -> Self {
+        let deadline = match self.deadline {
+            Some(current) if current < deadline => current,
+            _ => deadline,
+        };
+        Self {
+            deadline: Some(deadline),
+            ..self
+        }
+    }
+
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        self.with_deadline(Instant::now() + timeout)
+    }
+
+    pub fn with_cancellation_token(self, cancellation_token: CancellationToken) -> Self {
+        Self {
+            cancellation_token: Some(cancellation_token),
+            ..self
+        }
+    }
+
+    /// Sends the requests under the trace `trace_id`, e.g. the trace id of
+    /// the caller's span. It must be 32 lowercase hex digits, not all zero:
+    /// any other value is ignored.
+    pub fn with_trace_id<S: Into<String>>(self, trace_id: S) -> Self {
+        let trace_id = trace_id.into();
+        if is_valid_trace_id(&trace_id) {
+            self.with_value(TRACE_ID, trace_id)
+        } else {
+            self
+        }
+    }
+
+    /// Gives the context a new trace id unless it has one already.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn with_default_trace_id(self) -> Self {
+        if self.trace_id().is_some() {
+            self
+        } else {
+            self.with_value(TRACE_ID, new_trace_id())
+        }
+    }
+
+    pub fn with_value<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Any + Send + Sync,
+    {
+        self.insert_value(key, value);
+        self
+    }
+
+    /// Runs `future` in this context: the requests it sends get the context
+    /// and it is stopped when the deadline passes or the token is cancelled.
+    /// With the `tracing` feature they also share a trace id.
+    ///
+    /// `future` may fail with any error that an `AzureError` converts into,
+    /// such as the `Error` of the management operations, so the deadline
+    /// and the cancellation surface in the error type of the operation.
+    pub async fn run<F, T, E>(self, future: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: From<AzureError>,
+    {
+        #[cfg(feature = "tracing")]
+        let context = self.with_default_trace_id();
+        #[cfg(not(feature = "tracing"))]
+        let context = self;
+        let guarded = context.guard(future);
+        CURRENT.scope(context, guarded).await
+    }
+
+    /// Makes this context the current one while `future` runs, without
+    /// guarding it.
+    #[cfg(feature = "tracing")]
+    pub(crate) async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT.scope(self, future).await
+    }
+
+    /// Runs `future` until the deadline passes or the token is cancelled.
+    pub(crate) fn guard<F, T, E>(&self, future: F) -> impl Future<Output = Result<T, E>>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: From<AzureError>,
+    {
+        let deadline = self.deadline;
+        let cancellation_token = self.cancellation_token.clone();
+        async move {
+            if matches!(&cancellation_token, Some(token) if token.is_cancelled()) {
+                return Err(AzureError::Cancelled.into());
+            }
+
+            let expired = async {
+                match deadline {
+                    Some(deadline) => tokio::time::delay_until(deadline.into()).await,
+                    None => future::pending().await,
+                }
+            };
+            let cancelled = async {
+                match &cancellation_token {
+                    Some(cancellation_token) => cancellation_token.cancelled().await,
+                    None => future::pending().await,
+                }
+            };
+            futures::pin_mut!(future, expired, cancelled);
+
+            match future::select(future, future::select(expired, cancelled)).await {
+                Either::Left((result, _)) => result,
+                Either::Right((Either::Left(_), _)) => Err(AzureError::DeadlineExceeded.into()),
+                Either::Right((Either::Right(_), _)) => Err(AzureError::Cancelled.into()),
+            }
+        }
+    }
+}
+
+fn is_valid_trace_id(trace_id: &str) -> bool {
+    trace_id.len() == 32
+        && trace_id
+            .bytes()
+            .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+        && trace_id.bytes().any(|b| b != b'0')
+}
+
+/// A random W3C trace id.
+#[cfg(any(feature = "tracing", test))]
+pub(crate) fn new_trace_id() -> String {
+    format!("{:032x}", rand::random::<u128>().max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn deadline_stops_the_operation() {
+        let context = Context::new().with_timeout(Duration::from_millis(10));
+        let result: Result<(), AzureError> = context
+            .run(async {
+                tokio::time::delay_for(Duration::from_secs(10)).await;
+                Ok(())
+            })
+            .await;
+        assert!(matches!(result, Err(AzureError::DeadlineExceeded)));
+    }
+
+    #[tokio::test]
+    async fn cancellation_stops_the_operation() {
+        let cancellation_token = CancellationToken::new();
+        let context = Context::new().with_cancellation_token(cancellation_token.clone());
+        let cancel = async {
+            tokio::time::delay_for(Duration::from_millis(10)).await;
+            cancellation_token.cancel();
+        };
+        let operation = context.run(async {
+            tokio::time::delay_for(Duration::from_secs(10)).await;
+            Ok::<_, AzureError>(())
+        });
+
+        let (result, _) = futures::join!(operation, cancel);
+        assert!(matches!(result, Err(AzureError::Cancelled)));
+    }
+
+    #[tokio::test]
+    async fn the_operation_sees_the_context() {
+        let context = Context::new()
+            .with_value("tenant", "contoso".to_owned())
+            .with_timeout(Duration::from_secs(60))
+            .with_timeout(Duration::from_secs(3600));
+        assert!(Context::current().is_none());
+
+        let remaining = context
+            .run(async {
+                let current = Context::current().unwrap();
+                assert_eq!(
+                    current.value::<String>("tenant").map(String::as_str),
+                    Some("contoso")
+                );
+                assert_eq!(current.value::<u32>("tenant"), None);
+                Ok::<_, AzureError>(current.remaining().unwrap())
+            })
+            .await
+            .unwrap();
+        assert!(remaining <= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn keeps_only_valid_trace_ids() {
+        let trace_id = "0af7651916cd43dd8448eb211c80319c";
+        assert_eq!(
+            Context::new().with_trace_id(trace_id).trace_id(),
+            Some(trace_id)
+        );
+        assert_eq!(
+            Context::new().with_trace_id("0af7651916cd43dd").trace_id(),
+            None
+        );
+        assert_eq!(
+            Context::new()
+                .with_trace_id("0AF7651916CD43DD8448EB211C80319C")
+                .trace_id(),
+            None
+        );
+        assert_eq!(
+            Context::new().with_trace_id("0".repeat(32)).trace_id(),
+            None
+        );
+        assert!(is_valid_trace_id(&new_trace_id()));
+    }
+}
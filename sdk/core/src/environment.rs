@@ -0,0 +1,272 @@
+/// The endpoints of an Azure cloud.
+///
+/// The presets cover the public cloud (the default), Azure China, Azure US
+/// Government and Azure Germany. A custom environment (e.g. Azure Stack) can
+/// be derived from a preset with the `with_` methods:
+///
+/// ```
+/// use azure_core::AzureEnvironment;
+///
+/// let environment = AzureEnvironment::public()
+///     .with_name("AzureStack")
+///     .with_resource_manager_endpoint("https://management.local.azurestack.external")
+///     .with_storage_endpoint_suffix("local.azurestack.external");
+/// assert_eq!(environment.key_vault_endpoint_suffix(), "vault.azure.net");
+/// ```
+///
+/// Every client has a `with_environment` method (mgmt `OperationConfig`
+/// included), and the `azure_identity` credentials and flows use its
+/// authority host, so the same environment switches the whole stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AzureEnvironment {
+    name: String,
+    authority_host: String,
+    resource_manager_endpoint: String,
+    resource_manager_resource: String,
+    storage_endpoint_suffix: String,
+    key_vault_endpoint_suffix: String,
+    cosmos_endpoint_suffix: String,
+    service_bus_endpoint_suffix: String,
+}
+
+impl Default for AzureEnvironment {
+    fn default() -> Self {
+        Self::public()
+    }
+}
+
+impl AzureEnvironment {
+    #[allow(clippy::too_many_arguments)]
+    fn preset(
+        name: &str,
+        authority_host: &str,
+        resource_manager_endpoint: &str,
+        resource_manager_resource: &str,
+        storage_endpoint_suffix: &str,
+        key_vault_endpoint_suffix: &str,
+        cosmos_endpoint_suffix: &str,
+        service_bus_endpoint_suffix: &str,
+    ) -> Self {
+        Self {
+            name: name.to_owned(),
+            authority_host: authority_host.to_owned(),
+            resource_manager_endpoint: resource_manager_endpoint.to_owned(),
+            resource_manager_resource: resource_manager_resource.to_owned(),
+            storage_endpoint_suffix: storage_endpoint_suffix.to_owned(),
+            key_vault_endpoint_suffix: key_vault_endpoint_suffix.to_owned(),
+            cosmos_endpoint_suffix: cosmos_endpoint_suffix.to_owned(),
+            service_bus_endpoint_suffix: service_bus_endpoint_suffix.to_owned(),
+        }
+    }
+
+    /// The public Azure cloud.
+    pub fn public() -> Self {
+        Self::preset(
+            "AzureCloud",
+            "https://login.microsoftonline.com",
+            "https://management.azure.com",
+            "https://management.azure.com/",
+            "core.windows.net",
+            "vault.azure.net",
+            "documents.azure.com",
+            "servicebus.windows.net",
+        )
+    }
+
+    /// Azure China, operated by 21Vianet.
+    pub fn china() -> Self {
+        Self::preset(
+            "AzureChinaCloud",
+            "https://login.chinacloudapi.cn",
+            "https://management.chinacloudapi.cn",
+            "https://management.core.chinacloudapi.cn/",
+            "core.chinacloudapi.cn",
+            "vault.azure.cn",
+            "documents.azure.cn",
+            "servicebus.chinacloudapi.cn",
+        )
+    }
+
+    /// Azure US Government.
+    pub fn us_government() -> Self {
+        Self::preset(
+            "AzureUSGovernment",
+            "https://login.microsoftonline.us",
+            "https://management.usgovcloudapi.net",
+            "https://management.core.usgovcloudapi.net/",
+            "core.usgovcloudapi.net",
+            "vault.usgovcloudapi.net",
+            "documents.azure.us",
+            "servicebus.usgovcloudapi.net",
+        )
+    }
+
+    /// Azure Germany.
+    pub fn germany() -> Self {
+        Self::preset(
+            "AzureGermanCloud",
+            "https://login.microsoftonline.de",
+            "https://management.microsoftazure.de",
+            "https://management.core.cloudapi.de/",
+            "core.cloudapi.de",
+            "vault.microsoftazure.de",
+            "documents.microsoftazure.de",
+            "servicebus.cloudapi.de",
+        )
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The Azure Active Directory host tokens are requested from, e.g.
+    /// `https://login.microsoftonline.com`.
+    pub fn authority_host(&self) -> &str {
+        &self.authority_host
+    }
+
+    /// The Azure Resource Manager endpoint, e.g.
+    /// `https://management.azure.com`.
+    pub fn resource_manager_endpoint(&self) -> &str {
+        &self.resource_manager_endpoint
+    }
+
+    /// The resource to request Azure Resource Manager tokens for, e.g.
+    /// `https://management.azure.com/`.
+    pub fn resource_manager_resource(&self) -> &str {
+        &self.resource_manager_resource
+    }
+
+    /// The suffix of the storage accounts endpoints, e.g. `core.windows.net`
+    /// as in `https://{account}.blob.core.windows.net`.
+    pub fn storage_endpoint_suffix(&self) -> &str {
+        &self.storage_endpoint_suffix
+    }
+
+    /// The suffix of the Key Vault endpoints, e.g. `vault.azure.net` as in
+    /// `https://{vault}.vault.azure.net`.
+    pub fn key_vault_endpoint_suffix(&self) -> &str {
+        &self.key_vault_endpoint_suffix
+    }
+
+    /// The suffix of the Cosmos accounts endpoints, e.g.
+    /// `documents.azure.com` as in `https://{account}.documents.azure.com`.
+    pub fn cosmos_endpoint_suffix(&self) -> &str {
+        &self.cosmos_endpoint_suffix
+    }
+
+    /// The suffix of the Service Bus and Event Hubs namespaces endpoints,
+    /// e.g. `servicebus.windows.net` as in
+    /// `https://{namespace}.servicebus.windows.net`.
+    pub fn service_bus_endpoint_suffix(&self) -> &str {
+        &self.service_bus_endpoint_suffix
+    }
+
+    pub fn with_name<N: Into<String>>(self, name: N) -> Self {
+        Self {
+            name: name.into(),
+            ..self
+        }
+    }
+
+    pub fn with_authority_host<A: Into<String>>(self, authority_host: A) -> Self {
+        Self {
+            authority_host: authority_host.into(),
+            ..self
+        }
+    }
+
+    /// Sets the Azure Resource Manager endpoint. The resource tokens are
+    /// requested for becomes the endpoint followed by `/`; use
+    /// `with_resource_manager_resource` afterwards if it differs.
+    pub fn with_resource_manager_endpoint<E: Into<String>>(
+        self,
+        resource_manager_endpoint: E,
+    ) -> Self {
+        let resource_manager_endpoint = resource_manager_endpoint.into();
+        let resource_manager_resource =
+            format!("{}/", resource_manager_endpoint.trim_end_matches('/'));
+        Self {
+            resource_manager_endpoint,
+            resource_manager_resource,
+            ..self
+        }
+    }
+
+    pub fn with_resource_manager_resource<R: Into<String>>(
+        self,
+        resource_manager_resource: R,
+    ) -> Self {
+        Self {
+            resource_manager_resource: resource_manager_resource.into(),
+            ..self
+        }
+    }
+
+    pub fn with_storage_endpoint_suffix<S: Into<String>>(self, storage_endpoint_suffix: S) -> Self {
+        Self {
+            storage_endpoint_suffix: storage_endpoint_suffix.into(),
+            ..self
+        }
+    }
+
+    pub fn with_key_vault_endpoint_suffix<S: Into<String>>(
+        self,
+        key_vault_endpoint_suffix: S,
+    ) -> Self {
+        Self {
+            key_vault_endpoint_suffix: key_vault_endpoint_suffix.into(),
+            ..self
+        }
+    }
+
+    pub fn with_cosmos_endpoint_suffix<S: Into<String>>(self, cosmos_endpoint_suffix: S) -> Self {
+        Self {
+            cosmos_endpoint_suffix: cosmos_endpoint_suffix.into(),
+            ..self
+        }
+    }
+
+    pub fn with_service_bus_endpoint_suffix<S: Into<String>>(
+        self,
+        service_bus_endpoint_suffix: S,
+    ) -> Self {
+        Self {
+            service_bus_endpoint_suffix: service_bus_endpoint_suffix.into(),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn public_is_the_default() {
+        let environment = AzureEnvironment::default();
+        assert_eq!(environment, AzureEnvironment::public());
+        assert_eq!(
+            environment.authority_host(),
+            "https://login.microsoftonline.com"
+        );
+        assert_eq!(
+            environment.resource_manager_resource(),
+            "https://management.azure.com/"
+        );
+    }
+
+    #[test]
+    fn custom_resource_manager_endpoint() {
+        let environment = AzureEnvironment::china()
+            .with_resource_manager_endpoint("https://management.local.azurestack.external/");
+        assert_eq!(
+            environment.resource_manager_resource(),
+            "https://management.local.azurestack.external/"
+        );
+        assert_eq!(
+            environment.storage_endpoint_suffix(),
+            "core.chinacloudapi.cn"
+        );
+    }
+}
@@ -1,6 +1,11 @@
-use crate::{enumerations::ParsingError, range::ParseError};
+pub use crate::http_error::{ErrorKind, HttpError, HttpErrorDetail};
+use crate::lro::LroStatus;
+use crate::{collect_pinned_stream, ArmError, Response};
+use crate::{enumerations::ParsingError, range::ParseError, range::Range};
+use bytes::Bytes;
+use futures::Future;
 use http::header::ToStrError;
-use hyper::{self, body, Body, StatusCode};
+use http::{HeaderMap, StatusCode};
 use std::io::Error as IOError;
 use std::num;
 use std::num::ParseIntError;
@@ -28,6 +33,27 @@ quick_error! {
     }
 }
 
+quick_error! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum ResourceIdParseError {
+        MissingLeadingSlash {
+            display("A resource ID starts with a /")
+        }
+        EmptySegment {
+            display("Empty segment")
+        }
+        MissingValue(key: String) {
+            display("Missing value after {}", key)
+        }
+        UnexpectedSegment(segment: String) {
+            display("Unexpected segment {}", segment)
+        }
+        MissingSegment(key: &'static str) {
+            display("The resource ID has no {}", key)
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnexpectedValue {
     expected: Vec<String>,
@@ -50,6 +76,8 @@ impl UnexpectedValue {
     }
 }
 
+/// An unexpected status with the raw body. The helpers of this module now
+/// return the richer [`HttpError`] instead.
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnexpectedHTTPResult {
     expected: Vec<StatusCode>,
@@ -153,6 +181,13 @@ quick_error! {
         }
         CRC64Not8BytesLong(len : u64) {
             display("CRC64 length {} bytes instead of 8", len)
+        }
+        ContentMD5Mismatch(expected: String, computed: String) {
+            display("content MD5 mismatch: expected {}, computed {}", expected, computed)
+        }
+        DownloadInterrupted(remaining: Range, etag: String, err: Box<AzureError>) {
+            display("download of the blob with ETag {} interrupted, bytes {} to {} left: {}", etag, remaining.start, remaining.end, err)
+            cause(&**err)
         }
          ParseBoolError(err: ParseBoolError) {
             from()
@@ -169,10 +204,13 @@ quick_error! {
             display("json error: {}", err)
             cause(err)
         }
-        HyperError(err: hyper::error::Error){
-            from()
+        HyperError(err: Box<dyn std::error::Error + Send + Sync>){
             display("Hyper error: {}", err)
-            cause(err)
+            cause(&**err)
+        }
+        TransportError(err: Box<dyn std::error::Error + Send + Sync>){
+            display("Transport error: {}", err)
+            cause(&**err)
         }
         IOError(err: IOError){
             from()
@@ -192,10 +230,19 @@ quick_error! {
             display("Azure Path parse error: {}", err)
             cause(err)
         }
+        ResourceIdParseError(err: ResourceIdParseError){
+            from()
+            display("Resource ID parse error: {}", err)
+            cause(err)
+        }
         UnexpectedHTTPResult(err: UnexpectedHTTPResult){
             from()
             display("UnexpectedHTTPResult error: {}", err)
         }
+        HttpError(err: Box<HttpError>){
+            display("HTTP error: {}", err)
+            cause(err)
+        }
         UnexpectedValue(err: UnexpectedValue){
             from()
             display("UnexpectedValue error: {:?}", err)
@@ -226,6 +273,15 @@ quick_error! {
             display("Parse error")
         }
         GenericError
+        Cancelled {
+            display("The operation was cancelled")
+        }
+        DeadlineExceeded {
+            display("The deadline of the operation passed")
+        }
+        LongRunningOperationFailed(status: LroStatus, error: Box<ArmError>) {
+            display("Long running operation {:?}: {}", status, error)
+        }
         GenericErrorWithText(err: String) {
             display("Generic error: {}", err)
         }
@@ -328,6 +384,31 @@ impl From<()> for AzureError {
     }
 }
 
+impl From<HttpError> for AzureError {
+    fn from(error: HttpError) -> AzureError {
+        AzureError::HttpError(Box::new(error))
+    }
+}
+
+#[cfg(feature = "enable_hyper")]
+impl From<hyper::Error> for AzureError {
+    fn from(error: hyper::Error) -> AzureError {
+        AzureError::HyperError(Box::new(error))
+    }
+}
+
+impl AzureError {
+    /// Whether the request failed on its way to or from the service (the
+    /// connection, a timeout, the body stream) rather than being refused by
+    /// it. Such requests may succeed when sent again.
+    pub fn is_transport_error(&self) -> bool {
+        matches!(
+            self,
+            AzureError::HyperError(_) | AzureError::TransportError(_) | AzureError::IOError(_)
+        )
+    }
+}
+
 impl From<failure::Error> for AzureError {
     fn from(error: failure::Error) -> AzureError {
         AzureError::FailureError(error)
@@ -335,101 +416,113 @@ impl From<failure::Error> for AzureError {
 }
 
 #[inline]
-pub async fn extract_status_headers_and_body(
-    resp: hyper::client::ResponseFuture,
-) -> Result<(hyper::StatusCode, hyper::HeaderMap, body::Bytes), AzureError> {
-    let res = resp.await?;
-    let (head, body) = res.into_parts();
-    let status = head.status;
-    let headers = head.headers;
-    let body = body::to_bytes(body).await?;
+pub async fn extract_status_headers_and_body<F, R, E>(
+    resp: F,
+) -> Result<(StatusCode, HeaderMap, Bytes), AzureError>
+where
+    F: Future<Output = Result<R, E>>,
+    R: Into<Response>,
+    E: Into<AzureError>,
+{
+    let res: Response = resp.await.map_err(Into::into)?.into();
+    let (status, headers, body) = res.deconstruct();
+    let body = collect_pinned_stream(body).await?;
 
     Ok((status, headers, body))
 }
 
 #[inline]
-pub async fn check_status_extract_headers_and_body(
-    resp: hyper::client::ResponseFuture,
-    expected_status_code: hyper::StatusCode,
-) -> Result<(hyper::HeaderMap, body::Bytes), AzureError> {
+pub async fn check_status_extract_headers_and_body<F, R, E>(
+    resp: F,
+    expected_status_code: StatusCode,
+) -> Result<(HeaderMap, Bytes), AzureError>
+where
+    F: Future<Output = Result<R, E>>,
+    R: Into<Response>,
+    E: Into<AzureError>,
+{
     let (status, headers, body) = extract_status_headers_and_body(resp).await?;
     if status == expected_status_code {
         Ok((headers, body))
     } else {
-        Err(AzureError::UnexpectedHTTPResult(UnexpectedHTTPResult::new(
-            expected_status_code,
-            status,
-            str::from_utf8(&body)?,
-        )))
+        Err(HttpError::new(expected_status_code, status, &headers, &body).into())
     }
 }
 
 #[inline]
-pub async fn check_status_extract_headers_and_body_as_string(
-    resp: hyper::client::ResponseFuture,
-    expected_status_code: hyper::StatusCode,
-) -> Result<(hyper::HeaderMap, String), AzureError> {
+pub async fn check_status_extract_headers_and_body_as_string<F, R, E>(
+    resp: F,
+    expected_status_code: StatusCode,
+) -> Result<(HeaderMap, String), AzureError>
+where
+    F: Future<Output = Result<R, E>>,
+    R: Into<Response>,
+    E: Into<AzureError>,
+{
     let (headers, body) = check_status_extract_headers_and_body(resp, expected_status_code).await?;
     let body = str::from_utf8(&body)?.to_owned();
     Ok((headers, body))
 }
 
 #[inline]
-pub async fn extract_status_and_body(
-    resp: hyper::client::ResponseFuture,
-) -> Result<(StatusCode, String), AzureError> {
-    let res = resp.await?;
-    let status = res.status();
-    let body = body::to_bytes(res.into_body()).await?;
+pub async fn extract_status_and_body<F, R, E>(resp: F) -> Result<(StatusCode, String), AzureError>
+where
+    F: Future<Output = Result<R, E>>,
+    R: Into<Response>,
+    E: Into<AzureError>,
+{
+    let (status, _, body) = extract_status_headers_and_body(resp).await?;
     Ok((status, str::from_utf8(&body)?.to_owned()))
 }
 
 #[inline]
-pub async fn extract_location_status_and_body(
-    resp: hyper::client::ResponseFuture,
-) -> Result<(http::StatusCode, String, String), AzureError> {
-    let res = resp.await?;
-    let status = res.status();
-    let location: String = match res.headers().get("Location") {
+pub async fn extract_location_status_and_body<F, R, E>(
+    resp: F,
+) -> Result<(http::StatusCode, String, String), AzureError>
+where
+    F: Future<Output = Result<R, E>>,
+    R: Into<Response>,
+    E: Into<AzureError>,
+{
+    let (status, headers, body) = extract_status_headers_and_body(resp).await?;
+    let location: String = match headers.get("Location") {
         Some(header_value) => header_value.to_str()?.to_owned(),
         _ => "".to_owned(),
     };
-    let body = body::to_bytes(res.into_body()).await?;
     Ok((status, location, str::from_utf8(&body)?.to_owned()))
 }
 
 #[inline]
-pub async fn check_status_extract_body(
-    resp: hyper::client::ResponseFuture,
-    expected_status_code: hyper::StatusCode,
-) -> Result<String, AzureError> {
-    let (status, body) = extract_status_and_body(resp).await?;
+pub async fn check_status_extract_body<F, R, E>(
+    resp: F,
+    expected_status_code: StatusCode,
+) -> Result<String, AzureError>
+where
+    F: Future<Output = Result<R, E>>,
+    R: Into<Response>,
+    E: Into<AzureError>,
+{
+    let (status, headers, body) = extract_status_headers_and_body(resp).await?;
     if status == expected_status_code {
-        Ok(body)
+        Ok(str::from_utf8(&body)?.to_owned())
     } else {
-        Err(AzureError::UnexpectedHTTPResult(UnexpectedHTTPResult::new(
-            expected_status_code,
-            status,
-            &body,
-        )))
+        Err(HttpError::new(expected_status_code, status, &headers, &body).into())
     }
 }
 
+#[cfg(feature = "enable_hyper")]
 pub async fn check_status_extract_body_2(
-    resp: hyper::Response<Body>,
+    resp: hyper::Response<hyper::Body>,
     expected_status: StatusCode,
 ) -> Result<String, AzureError> {
     let received_status = resp.status();
+    let headers = resp.headers().clone();
 
-    let body = body::to_bytes(resp.into_body()).await?;
+    let body = hyper::body::to_bytes(resp.into_body()).await?;
     let s = String::from_utf8(body.to_vec())?;
     debug!("body: {}", s);
     if received_status != expected_status {
-        Err(AzureError::UnexpectedHTTPResult(UnexpectedHTTPResult::new(
-            expected_status,
-            received_status,
-            &s,
-        )))
+        Err(HttpError::new(expected_status, received_status, &headers, &body).into())
     } else {
         Ok(s)
     }
@@ -57,3 +57,7 @@ pub const REQUIRES_SYNC: &str = "x-ms-requires-sync";
 pub const VERSION: &str = "x-ms-version";
 pub const PROPERTIES: &str = "x-ms-properties"; //=> [String] }
 pub const NAMESPACE_ENABLED: &str = "x-ms-namespace-enabled"; //=> [String] }
+pub const RETRY_AFTER_MS: &str = "retry-after-ms"; //=> [u64] }
+pub const MS_RETRY_AFTER_MS: &str = "x-ms-retry-after-ms"; //=> [u64] }
+pub const TRACEPARENT: &str = "traceparent"; //=> [String] }
+pub const AZURE_ASYNCOPERATION: &str = "azure-asyncoperation"; //=> [String] }
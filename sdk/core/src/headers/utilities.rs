@@ -1,14 +1,12 @@
 use super::*;
-use crate::errors::{check_status_extract_body_2, AzureError};
+use crate::errors::AzureError;
 use crate::lease::LeaseId;
 use crate::util::HeaderMapExt;
 use crate::{Consistency, RequestId, SessionToken};
 
 use chrono::{DateTime, Utc};
-use http::status::StatusCode;
+use http::header::{HeaderName, DATE, ETAG, LAST_MODIFIED};
 use http::HeaderMap;
-use hyper::header::{HeaderName, DATE, ETAG, LAST_MODIFIED};
-use hyper::{Body, Client, Request};
 use uuid::Uuid;
 
 use std::convert::TryFrom;
@@ -303,12 +301,13 @@ pub fn content_type_from_headers(headers: &HeaderMap) -> Result<&str, AzureError
         .to_str()?)
 }
 
+#[cfg(feature = "enable_hyper")]
 pub async fn perform_http_request(
-    client: &Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>>,
-    req: Request<Body>,
-    expected_status: StatusCode,
+    client: &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>>,
+    req: hyper::Request<hyper::Body>,
+    expected_status: http::StatusCode,
 ) -> Result<String, AzureError> {
     debug!("req == {:?}", req);
     let res = client.request(req).await?;
-    check_status_extract_body_2(res, expected_status).await
+    crate::errors::check_status_extract_body_2(res, expected_status).await
 }
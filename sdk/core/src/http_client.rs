@@ -0,0 +1,95 @@
+use crate::errors::AzureError;
+use crate::PinnedStream;
+use bytes::Bytes;
+#[cfg(any(feature = "enable_hyper", feature = "enable_reqwest"))]
+use futures::StreamExt;
+#[cfg(any(feature = "enable_hyper", feature = "enable_reqwest"))]
+use std::sync::Arc;
+
+/// The HTTP stack a [`TransportPolicy`](crate::policies::TransportPolicy)
+/// sends requests with.
+///
+/// Implementations for `hyper` and `reqwest` are available behind the
+/// `enable_hyper` (default) and `enable_reqwest` features. Since clients only
+/// hold an `Arc<dyn HttpClient>`, the same connection pool can be shared by
+/// every client of the process, and tests can plug in a mock.
+#[async_trait::async_trait]
+pub trait HttpClient: Send + Sync + std::fmt::Debug {
+    async fn execute_request(
+        &self,
+        request: http::Request<Bytes>,
+    ) -> Result<http::Response<PinnedStream>, AzureError>;
+}
+
+/// Creates the default `HttpClient`: hyper if the `enable_hyper` feature is
+/// active, reqwest otherwise.
+#[cfg(feature = "enable_hyper")]
+pub fn new_http_client() -> Arc<dyn HttpClient> {
+    Arc::new(hyper::Client::builder().build(hyper_rustls::HttpsConnector::new()))
+}
+
+/// Creates the default `HttpClient`: hyper if the `enable_hyper` feature is
+/// active, reqwest otherwise.
+#[cfg(all(feature = "enable_reqwest", not(feature = "enable_hyper")))]
+pub fn new_http_client() -> Arc<dyn HttpClient> {
+    Arc::new(reqwest::Client::new())
+}
+
+#[cfg(feature = "enable_hyper")]
+#[async_trait::async_trait]
+impl HttpClient for hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
+    async fn execute_request(
+        &self,
+        request: http::Request<Bytes>,
+    ) -> Result<http::Response<PinnedStream>, AzureError> {
+        let (parts, body) = request.into_parts();
+        let request = hyper::Request::from_parts(parts, hyper::Body::from(body));
+
+        let response = self.request(request).await?;
+        let (parts, body) = response.into_parts();
+        let body: PinnedStream = Box::pin(body.map(|chunk| chunk.map_err(AzureError::from)));
+        Ok(http::Response::from_parts(parts, body))
+    }
+}
+
+#[cfg(feature = "enable_reqwest")]
+#[async_trait::async_trait]
+impl HttpClient for reqwest::Client {
+    async fn execute_request(
+        &self,
+        request: http::Request<Bytes>,
+    ) -> Result<http::Response<PinnedStream>, AzureError> {
+        let url = url::Url::parse(&request.uri().to_string())?;
+        let mut builder = self.request(request.method().clone(), url);
+        for (name, value) in request.headers() {
+            builder = builder.header(name, value);
+        }
+        let response = builder
+            .body(request.into_body())
+            .send()
+            .await
+            .map_err(reqwest_error)?;
+
+        let mut builder = http::Response::builder().status(response.status());
+        for (name, value) in response.headers() {
+            builder = builder.header(name, value);
+        }
+        let body: PinnedStream = Box::pin(
+            response
+                .bytes_stream()
+                .map(|chunk| chunk.map_err(reqwest_error)),
+        );
+        Ok(builder.body(body)?)
+    }
+}
+
+/// Maps the failures of sending a request or of reading its body to
+/// `TransportError`, like the errors of hyper end up as `HyperError`.
+#[cfg(feature = "enable_reqwest")]
+fn reqwest_error(err: reqwest::Error) -> AzureError {
+    if err.is_builder() {
+        AzureError::GenericErrorWithText(err.to_string())
+    } else {
+        AzureError::TransportError(Box::new(err))
+    }
+}
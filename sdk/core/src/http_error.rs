@@ -0,0 +1,331 @@
+use crate::headers::{ACTIVITY_ID, REQUEST_ID};
+use crate::ArmError;
+use bytes::Bytes;
+use http::{HeaderMap, StatusCode};
+
+/// The header storage uses to report the error code, also when the response
+/// has no body (e.g. `HEAD` requests).
+const ERROR_CODE: &str = "x-ms-error-code";
+
+/// A coarse classification of an [`HttpError`], useful to decide what to do
+/// without knowing every service specific error code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// 400: the request is malformed or has invalid parameters.
+    BadRequest,
+    /// 401 or 403: the credentials are missing, invalid or not allowed.
+    Auth,
+    /// 404: the resource (blob, container, document, secret...) does not
+    /// exist.
+    NotFound,
+    /// 409: the resource already exists or is in a conflicting state (e.g. a
+    /// lease is already present).
+    Conflict,
+    /// 412: a condition (`If-Match`, lease id...) did not hold.
+    PreconditionFailed,
+    /// 429, or 503 `ServerBusy`: the service is throttling the caller.
+    Throttled,
+    /// Any other 5xx.
+    ServerError,
+    /// Anything else.
+    Other,
+}
+
+impl ErrorKind {
+    fn new(status: StatusCode, error_code: Option<&str>) -> Self {
+        match status.as_u16() {
+            400 => ErrorKind::BadRequest,
+            401 | 403 => ErrorKind::Auth,
+            404 => ErrorKind::NotFound,
+            409 => ErrorKind::Conflict,
+            412 => ErrorKind::PreconditionFailed,
+            429 => ErrorKind::Throttled,
+            503 if error_code == Some("ServerBusy") => ErrorKind::Throttled,
+            500..=599 => ErrorKind::ServerError,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+/// A code/message pair of the `details` of an ARM error.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct HttpErrorDetail {
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+impl From<ArmError> for HttpErrorDetail {
+    fn from(error: ArmError) -> Self {
+        Self {
+            code: Some(error.code).filter(|code| !code.is_empty()),
+            message: Some(error.message).filter(|message| !message.is_empty()),
+        }
+    }
+}
+
+/// A response whose status was not the one expected.
+///
+/// The service error payload is parsed when it is recognized:
+///
+/// * storage: `<Error><Code>..</Code><Message>..</Message></Error>` (or the
+///   `x-ms-error-code` header),
+/// * Cosmos: `{"code": .., "message": ..}`,
+/// * ARM and Key Vault: `{"error": {"code": .., "message": .., "details":
+///   [..], "innererror": {"code": ..}}}`.
+#[derive(Debug, Clone)]
+pub struct HttpError {
+    status: StatusCode,
+    expected: Vec<StatusCode>,
+    kind: ErrorKind,
+    error_code: Option<String>,
+    inner_error_code: Option<String>,
+    message: Option<String>,
+    details: Vec<HttpErrorDetail>,
+    request_id: Option<String>,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+#[derive(Debug, Deserialize)]
+struct StorageErrorPayload {
+    #[serde(rename = "Code")]
+    code: Option<String>,
+    #[serde(rename = "Message")]
+    message: Option<String>,
+}
+
+impl HttpError {
+    pub fn new(expected: StatusCode, status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Self {
+        Self::new_multiple(vec![expected], status, headers, body)
+    }
+
+    pub fn new_multiple(
+        expected: Vec<StatusCode>,
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let mut error = Self {
+            status,
+            expected,
+            kind: ErrorKind::Other,
+            error_code: None,
+            inner_error_code: None,
+            message: None,
+            details: Vec::new(),
+            request_id: header(headers, REQUEST_ID).or_else(|| header(headers, ACTIVITY_ID)),
+            headers: headers.clone(),
+            body: Bytes::copy_from_slice(body),
+        };
+        error.parse_body();
+        if error.error_code.is_none() {
+            error.error_code = header(headers, ERROR_CODE);
+        }
+        error.kind = ErrorKind::new(status, error.error_code.as_deref());
+        error
+    }
+
+    fn parse_body(&mut self) {
+        let body = match std::str::from_utf8(&self.body) {
+            Ok(body) => body.trim_start_matches('\u{feff}').trim(),
+            Err(_) => return,
+        };
+
+        if body.starts_with('<') {
+            if let Ok(payload) = serde_xml_rs::from_str::<StorageErrorPayload>(body) {
+                self.error_code = payload.code;
+                self.message = payload.message;
+            }
+        } else if let Some(error) = ArmError::parse(body.as_bytes()) {
+            self.error_code = Some(error.code).filter(|code| !code.is_empty());
+            self.message = Some(error.message).filter(|message| !message.is_empty());
+            self.details = error.details.into_iter().map(Into::into).collect();
+            self.inner_error_code = error.inner_error.and_then(|inner| inner.code);
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn expected(&self) -> &[StatusCode] {
+        &self.expected
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The service error code, e.g. `BlobNotFound` or `ResourceGroupNotFound`.
+    pub fn error_code(&self) -> Option<&str> {
+        self.error_code.as_deref()
+    }
+
+    /// The code of the Key Vault `innererror`, e.g. `ForbiddenByPolicy`.
+    pub fn inner_error_code(&self) -> Option<&str> {
+        self.inner_error_code.as_deref()
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn details(&self) -> &[HttpErrorDetail] {
+        &self.details
+    }
+
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+}
+
+fn header(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(ToOwned::to_owned)
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unexpected HTTP status {} (expected: {:?})",
+            self.status, self.expected
+        )?;
+        if let Some(error_code) = &self.error_code {
+            write!(f, ", error code: {}", error_code)?;
+        }
+        if let Some(message) = &self.message {
+            write!(f, ", message: {}", message)?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, ", request id: {}", request_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    #[test]
+    fn storage_error() {
+        let mut headers = HeaderMap::new();
+        headers.insert(REQUEST_ID, HeaderValue::from_static("abc"));
+        let body = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?><Error><Code>LeaseAlreadyPresent</Code><Message>There is already a lease present.</Message></Error>";
+
+        let error = HttpError::new(
+            StatusCode::CREATED,
+            StatusCode::CONFLICT,
+            &headers,
+            body.as_bytes(),
+        );
+
+        assert_eq!(error.kind(), ErrorKind::Conflict);
+        assert_eq!(error.error_code(), Some("LeaseAlreadyPresent"));
+        assert_eq!(error.message(), Some("There is already a lease present."));
+        assert_eq!(error.request_id(), Some("abc"));
+    }
+
+    #[test]
+    fn storage_error_without_body() {
+        let mut headers = HeaderMap::new();
+        headers.insert(ERROR_CODE, HeaderValue::from_static("ServerBusy"));
+
+        let error = HttpError::new(
+            StatusCode::OK,
+            StatusCode::SERVICE_UNAVAILABLE,
+            &headers,
+            b"",
+        );
+
+        assert_eq!(error.kind(), ErrorKind::Throttled);
+        assert_eq!(error.error_code(), Some("ServerBusy"));
+        assert_eq!(error.message(), None);
+    }
+
+    #[test]
+    fn cosmos_error() {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACTIVITY_ID, HeaderValue::from_static("def"));
+        let body = r#"{"code":"NotFound","message":"Entity with the specified id does not exist in the system."}"#;
+
+        let error = HttpError::new(
+            StatusCode::OK,
+            StatusCode::NOT_FOUND,
+            &headers,
+            body.as_bytes(),
+        );
+
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+        assert_eq!(error.error_code(), Some("NotFound"));
+        assert_eq!(
+            error.message(),
+            Some("Entity with the specified id does not exist in the system.")
+        );
+        assert_eq!(error.request_id(), Some("def"));
+    }
+
+    #[test]
+    fn arm_error() {
+        let body = r#"{"error":{"code":"InvalidTemplateDeployment","message":"The template deployment failed.","details":[{"code":"QuotaExceeded","message":"Not enough cores."}]}}"#;
+
+        let error = HttpError::new(
+            StatusCode::OK,
+            StatusCode::BAD_REQUEST,
+            &HeaderMap::new(),
+            body.as_bytes(),
+        );
+
+        assert_eq!(error.kind(), ErrorKind::BadRequest);
+        assert_eq!(error.error_code(), Some("InvalidTemplateDeployment"));
+        assert_eq!(error.details().len(), 1);
+        assert_eq!(error.details()[0].code.as_deref(), Some("QuotaExceeded"));
+    }
+
+    #[test]
+    fn key_vault_error() {
+        let body = r#"{"error":{"code":"Forbidden","message":"Operation get is not allowed by vault policy.","innererror":{"code":"ForbiddenByPolicy"}}}"#;
+
+        let error = HttpError::new(
+            StatusCode::OK,
+            StatusCode::FORBIDDEN,
+            &HeaderMap::new(),
+            body.as_bytes(),
+        );
+
+        assert_eq!(error.kind(), ErrorKind::Auth);
+        assert_eq!(error.error_code(), Some("Forbidden"));
+        assert_eq!(error.inner_error_code(), Some("ForbiddenByPolicy"));
+    }
+
+    #[test]
+    fn unknown_payload() {
+        let error = HttpError::new(
+            StatusCode::OK,
+            StatusCode::BAD_GATEWAY,
+            &HeaderMap::new(),
+            b"<html>bad gateway</html>",
+        );
+
+        assert_eq!(error.kind(), ErrorKind::ServerError);
+        assert_eq!(error.error_code(), None);
+        assert_eq!(
+            error.body(),
+            &Bytes::from_static(b"<html>bad gateway</html>")
+        );
+    }
+}
@@ -0,0 +1,89 @@
+//! Spans for the calls made through the SDK.
+//!
+//! When the `tracing` feature is active every logical operation (e.g.
+//! `PutBlockBlobBuilder::finalize`) runs in an `azure_operation` span, and
+//! every request sent through a [`Pipeline`](crate::pipeline::Pipeline) in an
+//! `azure_request` span. Both record:
+//!
+//! * `status`: the HTTP status of the (last) response,
+//! * `request_id`: the `x-ms-request-id` (or `x-ms-activity-id`) the service
+//!   returned,
+//! * `client_request_id`: the `x-ms-client-request-id` sent,
+//! * `trace_id`: the trace id of the W3C `traceparent` header sent to the
+//!   service, shared by every request of the operation (see
+//!   [`Context::trace_id`](crate::Context::trace_id)),
+//! * `duration_ms`: how long the call took, retries included,
+//! * `error`: why the call failed, if it did.
+//!
+//! Cosmos operations also record their `request_charge`.
+//!
+//! Without the feature these functions do nothing.
+use crate::errors::AzureError;
+use std::future::Future;
+
+/// Runs `future`, the body of the logical operation `operation`, in an
+/// `azure_operation` span.
+pub async fn instrument<F, T>(operation: &'static str, future: F) -> Result<T, AzureError>
+where
+    F: Future<Output = Result<T, AzureError>>,
+{
+    #[cfg(feature = "tracing")]
+    {
+        use tracing::Instrument;
+
+        let span = tracing::info_span!(
+            "azure_operation",
+            operation,
+            status = tracing::field::Empty,
+            request_id = tracing::field::Empty,
+            client_request_id = tracing::field::Empty,
+            trace_id = tracing::field::Empty,
+            request_charge = tracing::field::Empty,
+            duration_ms = tracing::field::Empty,
+            error = tracing::field::Empty,
+        );
+        // the requests of the operation share the trace of the context it
+        // runs in, or a new one
+        let context = crate::Context::current()
+            .unwrap_or_default()
+            .with_default_trace_id();
+        let start = std::time::Instant::now();
+        let result = context.scope(future).instrument(span.clone()).await;
+        span.record("duration_ms", start.elapsed().as_millis() as u64);
+        if let Err(err) = &result {
+            span.record("error", tracing::field::display(err));
+        }
+        result
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = operation;
+        future.await
+    }
+}
+
+/// Records the request units consumed by the current Cosmos operation.
+pub fn record_request_charge(request_charge: f64) {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("request_charge", request_charge);
+    #[cfg(not(feature = "tracing"))]
+    let _ = request_charge;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_the_result_of_the_operation() {
+        let ok = instrument("Builder::ok", async { Ok(42) }).await;
+        assert_eq!(ok.unwrap(), 42);
+
+        let err: Result<(), _> = instrument("Builder::err", async {
+            record_request_charge(1.5);
+            Err(AzureError::GenericErrorWithText("boom".to_owned()))
+        })
+        .await;
+        assert!(err.is_err());
+    }
+}
@@ -13,29 +13,56 @@ pub mod errors;
 pub mod parsing;
 #[macro_use]
 pub mod enumerations;
+mod arm_error;
 pub mod ba512_range;
+mod cached_token_credential;
+mod context;
+mod environment;
 pub mod headers;
+mod http_client;
+mod http_error;
 pub mod incompletevector;
+pub mod instrumentation;
 pub mod lease;
+pub mod lro;
+#[cfg(any(test, feature = "mock_transport"))]
+pub mod mock_transport;
 pub mod modify_conditions;
+mod pageable;
+pub mod pipeline;
+pub mod policies;
 pub mod prelude;
 pub mod range;
+mod request;
+mod resource_id;
+mod response;
 mod stored_access_policy;
 pub mod util;
 
+pub use arm_error::{ArmError, ArmErrorAdditionalInfo, ArmInnerError};
+pub use cached_token_credential::CachedTokenCredential;
+pub use context::{CancellationToken, Context};
+pub use environment::AzureEnvironment;
 use errors::AzureError;
 use headers::*;
+#[cfg(any(feature = "enable_hyper", feature = "enable_reqwest"))]
+pub use http_client::new_http_client;
+pub use http_client::HttpClient;
 use lease::LeaseId;
 use modify_conditions::{IfMatchCondition, IfSinceCondition, SequenceNumberCondition};
+pub use pageable::{Page, Pageable};
+pub use request::Request;
+pub use resource_id::ResourceId;
+pub use response::{collect_pinned_stream, PinnedStream, Response, ResponseFuture};
 pub use stored_access_policy::{StoredAccessPolicy, StoredAccessPolicyList};
 
 use base64::encode;
 use chrono::{DateTime, Utc};
-use http::request::Builder;
-use hyper::header::{
+use http::header::{
     CONTENT_ENCODING, CONTENT_LANGUAGE, CONTENT_LENGTH, CONTENT_TYPE, IF_MODIFIED_SINCE, RANGE,
     USER_AGENT,
 };
+use http::request::Builder;
 use oauth2::AccessToken;
 use uuid::Uuid;
 
@@ -62,12 +89,35 @@ impl TokenResponse {
 }
 
 /// Represents a credential capable of providing an OAuth token.
+///
+/// Credentials are shared by the clients and the tasks using them, hence
+/// `Send + Sync`.
 #[async_trait::async_trait]
-pub trait TokenCredential {
+pub trait TokenCredential: Send + Sync {
     /// Gets a `TokenResponse` for the specified resource
     async fn get_token(&self, resource: &str) -> Result<TokenResponse, AzureError>;
 }
 
+#[async_trait::async_trait]
+impl<T> TokenCredential for &T
+where
+    T: TokenCredential + Sync + ?Sized,
+{
+    async fn get_token(&self, resource: &str) -> Result<TokenResponse, AzureError> {
+        (**self).get_token(resource).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> TokenCredential for Box<T>
+where
+    T: TokenCredential + ?Sized,
+{
+    async fn get_token(&self, resource: &str) -> Result<TokenResponse, AzureError> {
+        (**self).get_token(resource).await
+    }
+}
+
 #[macro_export]
 macro_rules! response_from_headers {
     ($cn:ident, $($fh:path => $na:ident: $typ:ty),+) => {
@@ -383,6 +433,15 @@ pub trait AccessTierOption {
     }
 }
 
+pub trait AccessTierRequired {
+    fn access_tier(&self) -> AccessTier;
+
+    #[must_use]
+    fn add_header(&self, builder: Builder) -> Builder {
+        builder.header(BLOB_ACCESS_TIER, self.access_tier().as_ref())
+    }
+}
+
 pub trait DeleteSnapshotsMethodSupport {
     type O;
     fn with_delete_snapshots_method(
@@ -436,15 +495,21 @@ pub trait SnapshotSupport {
     fn with_snapshot(self, snapshot: DateTime<Utc>) -> Self::O;
 }
 
+// The service identifies a snapshot by its exact timestamp, as returned
+// in the x-ms-snapshot header (ie 2011-03-09T01:42:34.9360000Z).
+fn snapshot_to_uri_parameter(snapshot: &DateTime<Utc>) -> String {
+    format!(
+        "snapshot={}.{:07}Z",
+        snapshot.format("%Y-%m-%dT%H:%M:%S"),
+        snapshot.timestamp_subsec_nanos() / 100
+    )
+}
+
 pub trait SnapshotOption {
     fn snapshot(&self) -> Option<DateTime<Utc>>;
 
     fn to_uri_parameter(&self) -> Option<String> {
-        if let Some(ref nm) = self.snapshot() {
-            Some(format!("snapshot={}", nm.to_rfc2822()))
-        } else {
-            None
-        }
+        self.snapshot().as_ref().map(snapshot_to_uri_parameter)
     }
 }
 
@@ -452,7 +517,21 @@ pub trait SnapshotRequired {
     fn snapshot(&self) -> DateTime<Utc>;
 
     fn to_uri_parameter(&self) -> String {
-        format!("snapshot={}", self.snapshot().to_rfc2822())
+        snapshot_to_uri_parameter(&self.snapshot())
+    }
+}
+
+pub trait VersionIdSupport<'a> {
+    type O;
+    fn with_version_id(self, version_id: &'a str) -> Self::O;
+}
+
+pub trait VersionIdOption<'a> {
+    fn version_id(&self) -> Option<&'a str>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.version_id()
+            .map(|version_id| format!("versionid={}", version_id))
     }
 }
 
@@ -543,12 +622,22 @@ pub trait IncludeDeletedOption {
     fn include_deleted(&self) -> bool;
 }
 
+pub trait IncludeVersionsSupport {
+    type O;
+    fn with_include_versions(self) -> Self::O;
+}
+
+pub trait IncludeVersionsOption {
+    fn include_versions(&self) -> bool;
+}
+
 pub trait IncludeListOptions:
     IncludeSnapshotsOption
     + IncludeMetadataOption
     + IncludeUncommittedBlobsOption
     + IncludeCopyOption
     + IncludeDeletedOption
+    + IncludeVersionsOption
 {
     fn to_uri_parameter(&self) -> Option<String> {
         let mut s = String::new();
@@ -588,6 +677,14 @@ pub trait IncludeListOptions:
                 s.push(',');
             }
             s.push_str("deleted");
+            f_first = false;
+        }
+
+        if self.include_versions() {
+            if !f_first {
+                s.push(',');
+            }
+            s.push_str("versions");
         }
 
         if !s.is_empty() {
@@ -833,6 +930,25 @@ pub trait ContentMD5Option<'a> {
     }
 }
 
+pub trait ContentCRC64Support<'a> {
+    type O;
+    fn with_content_crc64(self, _: &'a [u8; 8]) -> Self::O;
+}
+
+pub trait ContentCRC64Option<'a> {
+    fn content_crc64(&self) -> Option<&'a [u8; 8]>;
+
+    #[must_use]
+    fn add_header(&self, builder: Builder) -> Builder {
+        if let Some(content_crc64) = self.content_crc64() {
+            let s = encode(content_crc64);
+            builder.header(CONTENT_CRC64, &s as &str)
+        } else {
+            builder
+        }
+    }
+}
+
 pub trait SourceContentMD5Support<'a> {
     type O;
     fn with_source_content_md5(self, _: &'a [u8]) -> Self::O;
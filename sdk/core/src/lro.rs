@@ -0,0 +1,688 @@
+//! Long running operations of Azure Resource Manager.
+//!
+//! An operation that takes a while (creating a storage account, a virtual
+//! machine or an AKS cluster...) answers `202 Accepted`, or `201 Created`
+//! with the resource being provisioned, and tells where to follow its
+//! progress: the `Azure-AsyncOperation` status monitor, the `Location` URI,
+//! or the resource itself through its `provisioningState`. A [`Poller`]
+//! follows it and resolves to the final resource.
+use crate::errors::{AzureError, HttpError};
+use crate::headers::AZURE_ASYNCOPERATION;
+use crate::pipeline::Pipeline;
+use crate::policies::retry_after;
+use crate::{collect_pinned_stream, ArmError, Request, Response, TokenCredential};
+use bytes::Bytes;
+use http::header::{AUTHORIZATION, LOCATION};
+use http::{HeaderMap, HeaderValue, Method, StatusCode};
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// How long to wait between two polls when the service does not say
+/// (`Retry-After`).
+const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Where the result of an operation is read once it succeeded, the
+/// `final-state-via` of `x-ms-long-running-operation-options`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinalStateVia {
+    /// The body of the `Azure-AsyncOperation` status monitor.
+    AzureAsyncOperation,
+    /// The `Location` URI of the first response.
+    Location,
+    /// The URI of the operation, i.e. the resource created or updated by a
+    /// `PUT` or `PATCH`.
+    OriginalUri,
+}
+
+impl FinalStateVia {
+    /// The ARM default: the resource itself for `PUT` and `PATCH`, the
+    /// `Location` URI otherwise.
+    pub fn default_for(method: &Method) -> Self {
+        if method == Method::PUT || method == Method::PATCH {
+            FinalStateVia::OriginalUri
+        } else {
+            FinalStateVia::Location
+        }
+    }
+}
+
+/// The state of a long running operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LroStatus {
+    InProgress,
+    Succeeded,
+    Failed,
+    Canceled,
+}
+
+impl LroStatus {
+    /// Parses the `status` of a status monitor or the `provisioningState` of
+    /// a resource. Any state but the terminal ones (`Accepted`, `Creating`,
+    /// `Updating`...) means the operation is in progress.
+    pub fn parse(status: &str) -> Self {
+        if status.eq_ignore_ascii_case("succeeded") {
+            LroStatus::Succeeded
+        } else if status.eq_ignore_ascii_case("failed") {
+            LroStatus::Failed
+        } else if status.eq_ignore_ascii_case("canceled")
+            || status.eq_ignore_ascii_case("cancelled")
+        {
+            LroStatus::Canceled
+        } else {
+            LroStatus::InProgress
+        }
+    }
+
+    pub fn is_terminal(self) -> bool {
+        self != LroStatus::InProgress
+    }
+}
+
+/// What a [`Poller`] needs to send its requests: the pipeline and the
+/// credentials of the client that started the operation.
+pub trait PollingClient {
+    fn pipeline(&self) -> &Pipeline;
+
+    fn token_credential(&self) -> Option<&dyn TokenCredential> {
+        None
+    }
+
+    fn token_credential_resource(&self) -> &str {
+        ""
+    }
+
+    /// A fixed bearer token, for the clients configured with one instead of
+    /// a credential.
+    fn bearer_access_token(&self) -> Option<&str> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Monitor {
+    AzureAsyncOperation,
+    Location,
+    Resource,
+}
+
+#[derive(Deserialize)]
+struct OperationStatus {
+    status: String,
+    #[serde(default)]
+    error: Option<ArmError>,
+}
+
+/// Follows a long running operation until it completes, resolving to its
+/// result `T` (`()` for the operations without one).
+///
+/// The generated management operations return it for their long running
+/// operations, whatever the status of their first response:
+///
+/// ```no_run
+/// # use azure_core::lro::{Poller, PollingClient};
+/// # async fn example<C: PollingClient>(client: &C, poller: Poller<serde_json::Value>) -> Result<(), azure_core::errors::AzureError> {
+/// let account = poller.wait(client).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`wait`](Poller::wait) polls until the operation completes, waiting what
+/// the service asks in `Retry-After` between two polls. A failed or canceled
+/// operation ends in `AzureError::LongRunningOperationFailed` with the
+/// [`ArmError`] reported by the service.
+pub struct Poller<T> {
+    method: Method,
+    resource_uri: String,
+    async_operation_uri: Option<String>,
+    location_uri: Option<String>,
+    final_state_via: FinalStateVia,
+    polling_interval: Duration,
+    retry_after: Option<Duration>,
+    status: LroStatus,
+    error: Option<ArmError>,
+    final_body: Option<(Monitor, Bytes)>,
+    /// The body of the first response, when it completed the operation.
+    first_body: Option<Bytes>,
+    result: PhantomData<fn() -> T>,
+}
+
+impl<T> std::fmt::Debug for Poller<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Poller")
+            .field("method", &self.method)
+            .field("resource_uri", &self.resource_uri)
+            .field("async_operation_uri", &self.async_operation_uri)
+            .field("location_uri", &self.location_uri)
+            .field("final_state_via", &self.final_state_via)
+            .field("status", &self.status)
+            .finish()
+    }
+}
+
+impl<T> Poller<T> {
+    /// Starts following the operation sent with `method` to `uri`, whose
+    /// first response had `headers`.
+    pub fn new(method: Method, uri: &str, api_version: &str, headers: &HeaderMap) -> Self {
+        Self {
+            final_state_via: FinalStateVia::default_for(&method),
+            method,
+            resource_uri: with_api_version(uri, api_version),
+            async_operation_uri: header(headers, AZURE_ASYNCOPERATION),
+            location_uri: header(headers, LOCATION.as_str()),
+            polling_interval: DEFAULT_POLLING_INTERVAL,
+            retry_after: retry_after(headers),
+            status: LroStatus::InProgress,
+            error: None,
+            final_body: None,
+            first_body: None,
+            result: PhantomData,
+        }
+    }
+
+    /// Starts following the operation sent with `method` to `uri` from its
+    /// first response.
+    ///
+    /// A `200 OK`, `201 Created` or `204 No Content` without a status monitor
+    /// (`Azure-AsyncOperation` or `Location`) either completed the operation,
+    /// its body being the result, or returned the resource in a non terminal
+    /// `provisioningState` that the poller then follows.
+    pub fn from_response(
+        method: Method,
+        uri: &str,
+        api_version: &str,
+        status_code: StatusCode,
+        headers: &HeaderMap,
+        body: Bytes,
+    ) -> Self {
+        let mut poller = Self::new(method, uri, api_version, headers);
+        if status_code == StatusCode::ACCEPTED
+            || poller.async_operation_uri.is_some()
+            || poller.location_uri.is_some()
+        {
+            return poller;
+        }
+
+        poller.status = if poller.method == Method::DELETE {
+            LroStatus::Succeeded
+        } else {
+            provisioning_state(&body)
+                .map(|state| LroStatus::parse(&state))
+                .unwrap_or(LroStatus::Succeeded)
+        };
+        match poller.status {
+            LroStatus::Succeeded => poller.first_body = Some(body),
+            LroStatus::Failed => poller.error = ArmError::from_body(&body),
+            _ => {}
+        }
+        poller
+    }
+
+    pub fn with_final_state_via(self, final_state_via: FinalStateVia) -> Self {
+        Self {
+            final_state_via,
+            ..self
+        }
+    }
+
+    /// The delay between two polls when the service does not send
+    /// `Retry-After`, 30 seconds by default.
+    pub fn with_polling_interval(self, polling_interval: Duration) -> Self {
+        Self {
+            polling_interval,
+            ..self
+        }
+    }
+
+    /// The status as of the last poll.
+    pub fn status(&self) -> LroStatus {
+        self.status
+    }
+
+    /// The error reported by the service if the operation failed.
+    pub fn error(&self) -> Option<&ArmError> {
+        self.error.as_ref()
+    }
+
+    /// How long to wait before the next poll.
+    pub fn delay(&self) -> Duration {
+        self.retry_after.unwrap_or(self.polling_interval)
+    }
+
+    fn monitor(&self) -> (Monitor, &str) {
+        if let Some(uri) = &self.async_operation_uri {
+            (Monitor::AzureAsyncOperation, uri)
+        } else if let Some(uri) = &self.location_uri {
+            (Monitor::Location, uri)
+        } else {
+            (Monitor::Resource, &self.resource_uri)
+        }
+    }
+
+    /// Asks the service for the status of the operation, once.
+    pub async fn poll<C>(&mut self, client: &C) -> Result<LroStatus, AzureError>
+    where
+        C: PollingClient + ?Sized,
+    {
+        if self.status.is_terminal() {
+            return Ok(self.status);
+        }
+
+        let (monitor, uri) = self.monitor();
+        let (status_code, headers, body) = get(client, uri).await?;
+        self.retry_after = retry_after(&headers);
+
+        if !status_code.is_success() {
+            if monitor == Monitor::Resource
+                && self.method == Method::DELETE
+                && status_code == StatusCode::NOT_FOUND
+            {
+                self.status = LroStatus::Succeeded;
+                return Ok(self.status);
+            }
+            return Err(HttpError::new_multiple(
+                vec![StatusCode::OK, StatusCode::ACCEPTED],
+                status_code,
+                &headers,
+                &body,
+            )
+            .into());
+        }
+
+        match monitor {
+            Monitor::AzureAsyncOperation => {
+                let operation: OperationStatus = serde_json::from_slice(&body)?;
+                self.status = LroStatus::parse(&operation.status);
+                self.error = operation.error;
+            }
+            Monitor::Location if status_code == StatusCode::ACCEPTED => {
+                if let Some(location) = header(&headers, LOCATION.as_str()) {
+                    self.location_uri = Some(location);
+                }
+            }
+            Monitor::Location => self.status = LroStatus::Succeeded,
+            Monitor::Resource if status_code == StatusCode::ACCEPTED => {}
+            Monitor::Resource => {
+                self.status = provisioning_state(&body)
+                    .map(|state| LroStatus::parse(&state))
+                    .unwrap_or(LroStatus::Succeeded);
+                if self.status == LroStatus::Failed {
+                    self.error = ArmError::from_body(&body);
+                }
+            }
+        }
+        if self.status == LroStatus::Succeeded {
+            self.final_body = Some((monitor, body));
+        }
+        Ok(self.status)
+    }
+
+    /// Polls until the operation completes and returns its result.
+    pub async fn wait<C>(mut self, client: &C) -> Result<T, AzureError>
+    where
+        C: PollingClient + ?Sized,
+        T: DeserializeOwned,
+    {
+        while !self.status.is_terminal() {
+            tokio::time::delay_for(self.delay()).await;
+            self.poll(client).await?;
+        }
+        self.result(client).await
+    }
+
+    /// The result of a completed operation, read as its `final-state-via`
+    /// says, or the body of the first response if the operation completed
+    /// right away. When it is read from the `Azure-AsyncOperation` status
+    /// monitor (also the case of a `POST` without `Location`) or from the
+    /// first response, the body is the result only if it deserializes to
+    /// `T`: otherwise the result is `null`, i.e. `()` for the operations
+    /// without one.
+    pub async fn result<C>(self, client: &C) -> Result<T, AzureError>
+    where
+        C: PollingClient + ?Sized,
+        T: DeserializeOwned,
+    {
+        match self.status {
+            LroStatus::Succeeded => {}
+            LroStatus::InProgress => {
+                return Err(AzureError::GenericErrorWithText(
+                    "the long running operation is still in progress".to_owned(),
+                ))
+            }
+            status => {
+                let error = self.error.unwrap_or_else(|| {
+                    ArmError::new(format!("{:?}", status), "the operation did not succeed")
+                });
+                return Err(AzureError::LongRunningOperationFailed(
+                    status,
+                    Box::new(error),
+                ));
+            }
+        }
+
+        let source = match self.final_state_via {
+            FinalStateVia::AzureAsyncOperation => Monitor::AzureAsyncOperation,
+            FinalStateVia::Location if self.location_uri.is_some() => Monitor::Location,
+            FinalStateVia::Location => Monitor::AzureAsyncOperation,
+            FinalStateVia::OriginalUri => Monitor::Resource,
+        };
+        let lenient = self.first_body.is_some() || source == Monitor::AzureAsyncOperation;
+        let body = match (self.first_body, self.final_body) {
+            _ if self.method == Method::DELETE => Bytes::new(),
+            (Some(body), _) => body,
+            (None, Some((monitor, body))) if monitor == source => body,
+            _ => match source {
+                Monitor::Location => get_ok(client, self.location_uri.as_deref().unwrap()).await?,
+                Monitor::Resource => get_ok(client, &self.resource_uri).await?,
+                Monitor::AzureAsyncOperation => Bytes::new(),
+            },
+        };
+
+        if body.iter().all(u8::is_ascii_whitespace) {
+            Ok(serde_json::from_slice(b"null")?)
+        } else if lenient {
+            // the status monitor or the response of an operation without a
+            // result (`()`) may have a body all the same
+            serde_json::from_slice(&body)
+                .or_else(|err| serde_json::from_slice(b"null").map_err(|_| err.into()))
+        } else {
+            Ok(serde_json::from_slice(&body)?)
+        }
+    }
+}
+
+fn header(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(ToOwned::to_owned)
+}
+
+fn with_api_version(uri: &str, api_version: &str) -> String {
+    if uri.contains("api-version=") {
+        uri.to_owned()
+    } else if uri.contains('?') {
+        format!("{}&api-version={}", uri, api_version)
+    } else {
+        format!("{}?api-version={}", uri, api_version)
+    }
+}
+
+fn provisioning_state(body: &[u8]) -> Option<String> {
+    let resource: serde_json::Value = serde_json::from_slice(body).ok()?;
+    resource["properties"]["provisioningState"]
+        .as_str()
+        .map(ToOwned::to_owned)
+}
+
+async fn get<C>(client: &C, uri: &str) -> Result<(StatusCode, HeaderMap, Bytes), AzureError>
+where
+    C: PollingClient + ?Sized,
+{
+    let mut request = Request::new(uri.parse().map_err(http::Error::from)?, Method::GET);
+    let token = match client.token_credential() {
+        Some(token_credential) => Some(
+            token_credential
+                .get_token(client.token_credential_resource())
+                .await?
+                .token
+                .secret()
+                .to_owned(),
+        ),
+        None => client.bearer_access_token().map(ToOwned::to_owned),
+    };
+    if let Some(token) = token {
+        let authorization =
+            HeaderValue::from_str(&format!("Bearer {}", token)).map_err(http::Error::from)?;
+        request.headers_mut().insert(AUTHORIZATION, authorization);
+    }
+
+    let response: Response = client.pipeline().execute(request).await?;
+    let (status_code, headers, body) = response.deconstruct();
+    Ok((status_code, headers, collect_pinned_stream(body).await?))
+}
+
+async fn get_ok<C>(client: &C, uri: &str) -> Result<Bytes, AzureError>
+where
+    C: PollingClient + ?Sized,
+{
+    let (status_code, headers, body) = get(client, uri).await?;
+    if status_code.is_success() {
+        Ok(body)
+    } else {
+        Err(HttpError::new(StatusCode::OK, status_code, &headers, &body).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_transport::{ScriptedResponse, ScriptedTransport};
+    use std::sync::Arc;
+
+    struct TestClient {
+        pipeline: Pipeline,
+        transport: Arc<ScriptedTransport>,
+    }
+
+    impl TestClient {
+        fn new(responses: Vec<ScriptedResponse>) -> Self {
+            let transport = Arc::new(ScriptedTransport::new(responses));
+            Self {
+                pipeline: ScriptedTransport::pipeline(transport.clone()),
+                transport,
+            }
+        }
+
+        fn uris(&self) -> Vec<String> {
+            self.transport
+                .requests()
+                .iter()
+                .map(|request| request.uri().to_string())
+                .collect()
+        }
+    }
+
+    impl PollingClient for TestClient {
+        fn pipeline(&self) -> &Pipeline {
+            &self.pipeline
+        }
+    }
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[tokio::test]
+    async fn put_resolves_to_the_resource() {
+        let client = TestClient::new(vec![
+            ScriptedResponse::new(StatusCode::OK, r#"{"status": "InProgress"}"#)
+                .with_header("retry-after", "0"),
+            ScriptedResponse::new(StatusCode::OK, r#"{"status": "Succeeded"}"#),
+            ScriptedResponse::new(StatusCode::OK, r#"{"name": "account"}"#),
+        ]);
+        let poller: Poller<serde_json::Value> = Poller::new(
+            Method::PUT,
+            "https://management.azure.com/account",
+            "2019-06-01",
+            &headers(&[
+                ("azure-asyncoperation", "https://management.azure.com/op"),
+                ("retry-after", "0"),
+            ]),
+        );
+
+        let account = poller.wait(&client).await.unwrap();
+
+        assert_eq!(account["name"], "account");
+        assert_eq!(
+            client.uris(),
+            vec![
+                "https://management.azure.com/op",
+                "https://management.azure.com/op",
+                "https://management.azure.com/account?api-version=2019-06-01",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn post_resolves_to_the_location() {
+        let client = TestClient::new(vec![
+            ScriptedResponse::new(StatusCode::ACCEPTED, "")
+                .with_header("location", "https://management.azure.com/loc2"),
+            ScriptedResponse::new(StatusCode::OK, r#"{"keys": []}"#),
+        ]);
+        let poller: Poller<serde_json::Value> = Poller::new(
+            Method::POST,
+            "https://management.azure.com/account/failover",
+            "2019-06-01",
+            &headers(&[("location", "https://management.azure.com/loc1")]),
+        )
+        .with_polling_interval(Duration::from_millis(1));
+
+        let result = poller.wait(&client).await.unwrap();
+
+        assert_eq!(result["keys"], serde_json::json!([]));
+        assert_eq!(
+            client.uris(),
+            vec![
+                "https://management.azure.com/loc1",
+                "https://management.azure.com/loc2",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn post_without_result_resolves_to_unit() {
+        let client = TestClient::new(vec![
+            ScriptedResponse::new(StatusCode::OK, r#"{"status": "InProgress"}"#),
+            ScriptedResponse::new(StatusCode::OK, r#"{"status": "Succeeded"}"#),
+        ]);
+        let poller: Poller<()> = Poller::new(
+            Method::POST,
+            "https://management.azure.com/account/failover",
+            "2019-06-01",
+            &headers(&[("azure-asyncoperation", "https://management.azure.com/op")]),
+        )
+        .with_polling_interval(Duration::from_millis(1));
+
+        poller.wait(&client).await.unwrap();
+
+        assert_eq!(
+            client.uris(),
+            vec![
+                "https://management.azure.com/op",
+                "https://management.azure.com/op",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn follows_a_created_resource() {
+        let client = TestClient::new(vec![ScriptedResponse::new(
+            StatusCode::OK,
+            r#"{"name": "vm", "properties": {"provisioningState": "Succeeded"}}"#,
+        )]);
+        let poller: Poller<serde_json::Value> = Poller::from_response(
+            Method::PUT,
+            "https://management.azure.com/vm",
+            "2020-06-01",
+            StatusCode::CREATED,
+            &headers(&[("retry-after", "0")]),
+            Bytes::from_static(
+                br#"{"name": "vm", "properties": {"provisioningState": "Creating"}}"#,
+            ),
+        );
+        assert_eq!(poller.status(), LroStatus::InProgress);
+
+        let vm = poller.wait(&client).await.unwrap();
+
+        assert_eq!(vm["properties"]["provisioningState"], "Succeeded");
+        assert_eq!(
+            client.uris(),
+            vec!["https://management.azure.com/vm?api-version=2020-06-01"]
+        );
+    }
+
+    #[tokio::test]
+    async fn completed_response_is_the_result() {
+        let client = TestClient::new(Vec::new());
+        let poller: Poller<serde_json::Value> = Poller::from_response(
+            Method::PUT,
+            "https://management.azure.com/vm",
+            "2020-06-01",
+            StatusCode::OK,
+            &HeaderMap::new(),
+            Bytes::from_static(
+                br#"{"name": "vm", "properties": {"provisioningState": "Succeeded"}}"#,
+            ),
+        );
+        assert_eq!(poller.status(), LroStatus::Succeeded);
+        assert_eq!(poller.wait(&client).await.unwrap()["name"], "vm");
+
+        let poller: Poller<()> = Poller::from_response(
+            Method::POST,
+            "https://management.azure.com/account/failover",
+            "2019-06-01",
+            StatusCode::OK,
+            &HeaderMap::new(),
+            Bytes::from_static(br#"{"unexpected": "body"}"#),
+        );
+        poller.wait(&client).await.unwrap();
+
+        assert!(client.uris().is_empty());
+    }
+
+    #[tokio::test]
+    async fn failure_is_an_arm_error() {
+        let client = TestClient::new(vec![ScriptedResponse::new(
+            StatusCode::OK,
+            r#"{"status": "Failed", "error": {"code": "QuotaExceeded", "message": "no more cores"}}"#,
+        )]);
+        let poller: Poller<()> = Poller::new(
+            Method::DELETE,
+            "https://management.azure.com/vm",
+            "2020-06-01",
+            &headers(&[("azure-asyncoperation", "https://management.azure.com/op")]),
+        )
+        .with_polling_interval(Duration::from_millis(1));
+
+        match poller.wait(&client).await {
+            Err(AzureError::LongRunningOperationFailed(status, error)) => {
+                assert_eq!(status, LroStatus::Failed);
+                assert_eq!(error.code, "QuotaExceeded");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn follows_the_provisioning_state() {
+        let client = TestClient::new(vec![
+            ScriptedResponse::new(
+                StatusCode::OK,
+                r#"{"properties": {"provisioningState": "Creating"}}"#,
+            ),
+            ScriptedResponse::new(
+                StatusCode::OK,
+                r#"{"properties": {"provisioningState": "Succeeded"}}"#,
+            ),
+        ]);
+        let mut poller: Poller<serde_json::Value> = Poller::new(
+            Method::PUT,
+            "https://management.azure.com/cluster?api-version=2020-11-01",
+            "2020-11-01",
+            &HeaderMap::new(),
+        );
+
+        assert_eq!(poller.poll(&client).await.unwrap(), LroStatus::InProgress);
+        assert_eq!(poller.poll(&client).await.unwrap(), LroStatus::Succeeded);
+        let cluster = poller.result(&client).await.unwrap();
+
+        assert_eq!(cluster["properties"]["provisioningState"], "Succeeded");
+        assert_eq!(client.uris().len(), 2);
+    }
+}
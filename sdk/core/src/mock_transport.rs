@@ -0,0 +1,771 @@
+//! Record and replay the HTTP traffic of a test.
+//!
+//! A [`RecordingHttpClient`] forwards every request to a real `HttpClient`
+//! and appends the request/response pair to a JSON file. A
+//! [`PlaybackHttpClient`] later answers the same requests from that file
+//! without touching the network, matching them by method, path and query.
+//!
+//! Secrets (the `Authorization` header, the `sig` SAS parameter, account
+//! keys...) are replaced with `REDACTED` before anything is written.
+//!
+//! [`new_test_http_client`] picks the client according to the
+//! `AZURE_TEST_MODE` environment variable (`live`, `record` or `playback`),
+//! and [`initialize_recorded`] builds the client and pipeline of a test for
+//! a given mode.
+//!
+//! A [`ScriptedTransport`] answers responses written by the test itself, for
+//! the unit tests that do not need a recording.
+use crate::errors::AzureError;
+use crate::pipeline::Pipeline;
+use crate::policies::{Policy, PolicyResult, RetryMode, RetryOptions};
+use crate::{Context, HttpClient, PinnedStream, Request, Response};
+use bytes::Bytes;
+use http::header::{HeaderName, HeaderValue};
+use http::{HeaderMap, StatusCode};
+use std::collections::{BTreeMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+const REDACTED: &str = "REDACTED";
+
+const SECRET_HEADERS: &[&str] = &[
+    "authorization",
+    "api-key",
+    "ocp-apim-subscription-key",
+    "x-ms-copy-source-authorization",
+    "x-ms-encryption-key",
+];
+
+/// Query parameters holding a secret. They are also redacted where a URL
+/// shows up in a header value or a body, like the SAS of `x-ms-copy-source`.
+const SECRET_QUERY_PARAMETERS: &[&str] = &["sig"];
+
+/// Headers telling apart requests of the same path and query, like the
+/// parallel ranged reads of a blob.
+const MATCHED_HEADERS: &[&str] = &["range", "x-ms-range"];
+
+/// Query parameters whose value changes from one run to the next, like the
+/// random block ids of an upload. Only their presence is matched.
+const UNMATCHED_QUERY_PARAMETERS: &[&str] = &["blockid"];
+
+const SECRET_JSON_FIELDS: &[&str] = &[
+    "access_token",
+    "accessToken",
+    "client_secret",
+    "connectionString",
+    "primaryKey",
+    "primaryConnectionString",
+    "primaryMasterKey",
+    "primaryReadonlyMasterKey",
+    "refresh_token",
+    "secondaryConnectionString",
+    "secondaryKey",
+    "secondaryMasterKey",
+    "secondaryReadonlyMasterKey",
+];
+
+/// Fields holding a secret only next to the given sibling field, like the
+/// `value` of the keys listed by a storage account (`keys[].keyName`).
+const SECRET_JSON_FIELDS_BESIDE: &[(&str, &str)] = &[("value", "keyName")];
+
+/// How the tests reach the services.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestMode {
+    /// Talk to the real services.
+    Live,
+    /// Talk to the real services and write what was exchanged to disk.
+    Record,
+    /// Answer from a previous recording.
+    Playback,
+}
+
+impl TestMode {
+    /// Reads `AZURE_TEST_MODE`. Defaults to `Live` when it is not set.
+    pub fn from_env() -> Result<Self, AzureError> {
+        match std::env::var("AZURE_TEST_MODE") {
+            Err(_) => Ok(TestMode::Live),
+            Ok(mode) => match mode.to_lowercase().as_str() {
+                "live" => Ok(TestMode::Live),
+                "record" => Ok(TestMode::Record),
+                "playback" => Ok(TestMode::Playback),
+                _ => Err(AzureError::GenericErrorWithText(format!(
+                    "unsupported AZURE_TEST_MODE \"{}\", expected live, record or playback",
+                    mode
+                ))),
+            },
+        }
+    }
+}
+
+/// Where the recording called `name` is stored: `AZURE_TEST_RECORDINGS` if
+/// set, `tests/recordings` otherwise.
+pub fn recording_path(name: &str) -> PathBuf {
+    let directory =
+        std::env::var("AZURE_TEST_RECORDINGS").unwrap_or_else(|_| "tests/recordings".to_owned());
+    Path::new(&directory).join(format!("{}.json", name))
+}
+
+/// Creates the `HttpClient` a test should use according to `AZURE_TEST_MODE`.
+#[cfg(any(feature = "enable_hyper", feature = "enable_reqwest"))]
+pub fn new_test_http_client(recording_name: &str) -> Result<Arc<dyn HttpClient>, AzureError> {
+    test_http_client(TestMode::from_env()?, recording_name)
+}
+
+#[cfg(any(feature = "enable_hyper", feature = "enable_reqwest"))]
+fn test_http_client(
+    mode: TestMode,
+    recording_name: &str,
+) -> Result<Arc<dyn HttpClient>, AzureError> {
+    let path = recording_path(recording_name);
+    Ok(match mode {
+        TestMode::Live => crate::new_http_client(),
+        TestMode::Record => Arc::new(RecordingHttpClient::new(crate::new_http_client(), path)),
+        TestMode::Playback => Arc::new(PlaybackHttpClient::from_file(path)?),
+    })
+}
+
+/// Creates the client of a test that runs in `mode` against the recording
+/// called `recording_name`, and the pipeline it should send its requests
+/// through.
+///
+/// `live_client` creates a client for the account of the test environment.
+/// On playback it is not called: the signatures of the requests are not
+/// checked, so `playback_client` can use any well-formed credentials.
+#[cfg(any(feature = "enable_hyper", feature = "enable_reqwest"))]
+pub fn initialize_recorded<C, L, P>(
+    mode: TestMode,
+    recording_name: &str,
+    live_client: L,
+    playback_client: P,
+) -> Result<(C, Pipeline), AzureError>
+where
+    L: FnOnce() -> Result<C, AzureError>,
+    P: FnOnce() -> Result<C, AzureError>,
+{
+    let client = match mode {
+        TestMode::Live | TestMode::Record => live_client()?,
+        TestMode::Playback => playback_client()?,
+    };
+    let http_client = test_http_client(mode, recording_name)?;
+    let pipeline = Pipeline::new(
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        Vec::new(),
+        RetryOptions::default(),
+        Vec::new(),
+        Arc::new(crate::policies::TransportPolicy::new(http_client)),
+    );
+    Ok((client, pipeline))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    headers: BTreeMap<String, String>,
+    body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    headers: BTreeMap<String, String>,
+    body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+impl RecordedRequest {
+    fn new(request: &http::Request<Bytes>) -> Self {
+        let mut query: Vec<(String, String)> =
+            url::form_urlencoded::parse(request.uri().query().unwrap_or("").as_bytes())
+                .map(|(key, value)| {
+                    let value = if SECRET_QUERY_PARAMETERS.contains(&key.as_ref()) {
+                        REDACTED.to_owned()
+                    } else {
+                        value.into_owned()
+                    };
+                    (key.into_owned(), value)
+                })
+                .collect();
+        query.sort();
+
+        Self {
+            method: request.method().to_string(),
+            path: request.uri().path().to_owned(),
+            query,
+            headers: scrub_headers(request.headers()),
+            body: base64::encode(scrub_body(request.body())),
+        }
+    }
+
+    fn matches(&self, other: &RecordedRequest) -> bool {
+        self.method == other.method
+            && self.path == other.path
+            && self.query.len() == other.query.len()
+            && self.query.iter().zip(&other.query).all(
+                |((key, value), (other_key, other_value))| {
+                    key == other_key
+                        && (value == other_value
+                            || UNMATCHED_QUERY_PARAMETERS.contains(&key.as_str()))
+                },
+            )
+            && MATCHED_HEADERS
+                .iter()
+                .all(|name| self.headers.get(*name) == other.headers.get(*name))
+    }
+}
+
+impl RecordedResponse {
+    fn new(status: StatusCode, headers: &HeaderMap, body: &Bytes) -> Self {
+        Self {
+            status: status.as_u16(),
+            headers: scrub_headers(headers),
+            body: base64::encode(scrub_body(body)),
+        }
+    }
+
+    fn to_response(&self) -> Result<http::Response<PinnedStream>, AzureError> {
+        let mut builder = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        let body = Bytes::from(base64::decode(&self.body)?);
+        Ok(builder.body(bytes_stream(body))?)
+    }
+}
+
+fn scrub_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if SECRET_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_owned()
+            } else {
+                value
+                    .to_str()
+                    .map(scrub_text)
+                    .unwrap_or_else(|_| REDACTED.to_owned())
+            };
+            (name.as_str().to_owned(), value)
+        })
+        .collect()
+}
+
+/// Redacts the value of every secret query parameter found in `text`.
+fn scrub_text(text: &str) -> String {
+    let mut scrubbed = text.to_owned();
+    for parameter in SECRET_QUERY_PARAMETERS {
+        let pattern = format!("{}=", parameter);
+        let mut from = 0;
+        while let Some(position) = scrubbed[from..].find(&pattern) {
+            let start = from + position + pattern.len();
+            let preceded_by_separator = from + position == 0
+                || !scrubbed[..from + position]
+                    .ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_');
+            let end = scrubbed[start..]
+                .find(|c: char| c == '&' || c == '"' || c == '\'' || c == '<' || c.is_whitespace())
+                .map_or(scrubbed.len(), |end| start + end);
+            if preceded_by_separator && end > start {
+                scrubbed.replace_range(start..end, REDACTED);
+                from = start + REDACTED.len();
+            } else {
+                from = start;
+            }
+        }
+    }
+    scrubbed
+}
+
+fn scrub_body(body: &Bytes) -> Bytes {
+    if let Ok(mut json) = serde_json::from_slice::<serde_json::Value>(body) {
+        return if scrub_json(&mut json) {
+            Bytes::from(json.to_string())
+        } else {
+            body.clone()
+        };
+    }
+    match std::str::from_utf8(body) {
+        Ok(text) => {
+            let scrubbed = scrub_text(text);
+            if scrubbed == text {
+                body.clone()
+            } else {
+                Bytes::from(scrubbed)
+            }
+        }
+        Err(_) => body.clone(),
+    }
+}
+
+/// Redacts the secret fields of `value`. Returns whether anything changed.
+fn scrub_json(value: &mut serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Object(map) => {
+            let secret_fields: Vec<&str> = SECRET_JSON_FIELDS_BESIDE
+                .iter()
+                .filter(|(_, sibling)| map.contains_key(*sibling))
+                .map(|(field, _)| *field)
+                .collect();
+            let mut changed = false;
+            for (key, value) in map.iter_mut() {
+                let secret = SECRET_JSON_FIELDS.contains(&key.as_str())
+                    || secret_fields.contains(&key.as_str());
+                if secret && value.is_string() {
+                    *value = serde_json::Value::String(REDACTED.to_owned());
+                    changed = true;
+                } else {
+                    changed |= scrub_json(value);
+                }
+            }
+            changed
+        }
+        serde_json::Value::Array(values) => {
+            let mut changed = false;
+            for value in values.iter_mut() {
+                changed |= scrub_json(value);
+            }
+            changed
+        }
+        serde_json::Value::String(text) => {
+            let scrubbed = scrub_text(text);
+            let changed = scrubbed != *text;
+            *text = scrubbed;
+            changed
+        }
+        _ => false,
+    }
+}
+
+fn bytes_stream(body: Bytes) -> PinnedStream {
+    Box::pin(futures::stream::once(async move { Ok(body) }))
+}
+
+/// Sends the requests with another `HttpClient` and appends every exchange
+/// to a JSON file.
+#[derive(Debug)]
+pub struct RecordingHttpClient {
+    http_client: Arc<dyn HttpClient>,
+    path: PathBuf,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+impl RecordingHttpClient {
+    /// Any existing recording at `path` is overwritten.
+    pub fn new<P: Into<PathBuf>>(http_client: Arc<dyn HttpClient>, path: P) -> Self {
+        Self {
+            http_client,
+            path: path.into(),
+            interactions: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn save(&self, interaction: Interaction) -> Result<(), AzureError> {
+        let mut interactions = self.interactions.lock().unwrap();
+        interactions.push(interaction);
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // end with a newline, like the JSON files checked by check_json_format.sh
+        let json = serde_json::to_string_pretty(&*interactions)? + "\n";
+        std::fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for RecordingHttpClient {
+    async fn execute_request(
+        &self,
+        request: http::Request<Bytes>,
+    ) -> Result<http::Response<PinnedStream>, AzureError> {
+        let recorded_request = RecordedRequest::new(&request);
+
+        let response = self.http_client.execute_request(request).await?;
+        let (parts, body) = response.into_parts();
+        let body = crate::collect_pinned_stream(body).await?;
+
+        self.save(Interaction {
+            request: recorded_request,
+            response: RecordedResponse::new(parts.status, &parts.headers, &body),
+        })?;
+
+        Ok(http::Response::from_parts(parts, bytes_stream(body)))
+    }
+}
+
+/// Answers requests from a file written by a [`RecordingHttpClient`].
+///
+/// Each recorded response is served once, in the order of the recording,
+/// to the first request with the same method, path and query.
+#[derive(Debug)]
+pub struct PlaybackHttpClient {
+    interactions: Mutex<Vec<(Interaction, bool)>>,
+}
+
+impl PlaybackHttpClient {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, AzureError> {
+        let interactions: Vec<Interaction> =
+            serde_json::from_slice(&std::fs::read(path.as_ref())?)?;
+        Ok(Self {
+            interactions: Mutex::new(interactions.into_iter().map(|i| (i, false)).collect()),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for PlaybackHttpClient {
+    async fn execute_request(
+        &self,
+        request: http::Request<Bytes>,
+    ) -> Result<http::Response<PinnedStream>, AzureError> {
+        let recorded_request = RecordedRequest::new(&request);
+
+        let mut interactions = self.interactions.lock().unwrap();
+        let (interaction, used) = interactions
+            .iter_mut()
+            .find(|(interaction, used)| !used && interaction.request.matches(&recorded_request))
+            .ok_or_else(|| {
+                AzureError::GenericErrorWithText(format!(
+                    "no recorded response left for {} {}",
+                    request.method(),
+                    request.uri()
+                ))
+            })?;
+        *used = true;
+        interaction.response.to_response()
+    }
+}
+
+/// A response of a [`ScriptedTransport`].
+#[derive(Debug, Clone)]
+pub struct ScriptedResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl ScriptedResponse {
+    pub fn new<B: Into<Bytes>>(status: StatusCode, body: B) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// A response carrying the headers every Azure Storage response has
+    /// (`x-ms-request-id`, `x-ms-version` and `date`).
+    pub fn storage<B: Into<Bytes>>(status: StatusCode, body: B) -> Self {
+        Self::new(status, body)
+            .with_header("x-ms-request-id", "e0d2ba3e-f01e-4b5b-8d0d-0f4a2cb4b2a1")
+            .with_header("x-ms-version", "2019-12-12")
+            .with_header("date", "Wed, 04 Nov 2020 14:56:03 GMT")
+    }
+
+    /// Panics if `value` is not a valid header value.
+    pub fn with_header(mut self, name: &'static str, value: &str) -> Self {
+        self.headers.insert(
+            HeaderName::from_static(name),
+            HeaderValue::from_str(value).expect("an invalid scripted header value"),
+        );
+        self
+    }
+}
+
+/// The transport policy of a pipeline that answers the scripted responses,
+/// in order, and remembers the requests it was sent.
+///
+/// It fails the requests once every response was served.
+#[derive(Debug, Default)]
+pub struct ScriptedTransport {
+    responses: Mutex<VecDeque<ScriptedResponse>>,
+    requests: Mutex<Vec<Request>>,
+}
+
+impl ScriptedTransport {
+    pub fn new<I>(responses: I) -> Self
+    where
+        I: IntoIterator<Item = ScriptedResponse>,
+    {
+        Self {
+            responses: Mutex::new(responses.into_iter().collect()),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// A pipeline sending its requests to `transport`, without retries.
+    pub fn pipeline(transport: Arc<ScriptedTransport>) -> Pipeline {
+        Pipeline::new(
+            "test",
+            "0",
+            Vec::new(),
+            RetryOptions::default().with_mode(RetryMode::None),
+            Vec::new(),
+            transport,
+        )
+    }
+
+    /// The requests sent so far, in order.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// How many responses are left to serve.
+    pub fn remaining(&self) -> usize {
+        self.responses.lock().unwrap().len()
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for ScriptedTransport {
+    async fn send(
+        &self,
+        _ctx: &mut Context,
+        request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        self.requests.lock().unwrap().push(request.clone());
+        let response = self.responses.lock().unwrap().pop_front().ok_or_else(|| {
+            AzureError::GenericErrorWithText(format!(
+                "no scripted response left for {} {}",
+                request.method(),
+                request.uri()
+            ))
+        })?;
+        Ok(Response::new(
+            response.status,
+            response.headers,
+            bytes_stream(response.body),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::Method;
+
+    #[derive(Debug)]
+    struct KeysService;
+
+    #[async_trait::async_trait]
+    impl HttpClient for KeysService {
+        async fn execute_request(
+            &self,
+            request: http::Request<Bytes>,
+        ) -> Result<http::Response<PinnedStream>, AzureError> {
+            assert_eq!(request.headers()["authorization"], "SharedKey secret");
+            let body = Bytes::from(r#"{"keys":[{"primaryKey":"a","name":"first"}]}"#);
+            Ok(http::Response::builder()
+                .status(StatusCode::OK)
+                .header("x-ms-request-id", "1234")
+                .body(bytes_stream(body))?)
+        }
+    }
+
+    #[derive(Debug)]
+    struct RangeService;
+
+    #[async_trait::async_trait]
+    impl HttpClient for RangeService {
+        async fn execute_request(
+            &self,
+            request: http::Request<Bytes>,
+        ) -> Result<http::Response<PinnedStream>, AzureError> {
+            let range = Bytes::copy_from_slice(request.headers()["x-ms-range"].as_bytes());
+            Ok(http::Response::builder()
+                .status(StatusCode::PARTIAL_CONTENT)
+                .body(bytes_stream(range))?)
+        }
+    }
+
+    #[derive(Debug)]
+    struct BlockService;
+
+    #[async_trait::async_trait]
+    impl HttpClient for BlockService {
+        async fn execute_request(
+            &self,
+            _request: http::Request<Bytes>,
+        ) -> Result<http::Response<PinnedStream>, AzureError> {
+            Ok(http::Response::builder()
+                .status(StatusCode::CREATED)
+                .body(bytes_stream(Bytes::new()))?)
+        }
+    }
+
+    fn put_block_request(block_id: &str) -> http::Request<Bytes> {
+        http::Request::builder()
+            .method(Method::PUT)
+            .uri(format!(
+                "https://account.example.com/container/blob?comp=block&blockid={}",
+                block_id
+            ))
+            .body(Bytes::new())
+            .unwrap()
+    }
+
+    fn ranged_request(range: &str) -> http::Request<Bytes> {
+        http::Request::builder()
+            .uri("https://account.example.com/container/blob")
+            .header("x-ms-range", range)
+            .body(Bytes::new())
+            .unwrap()
+    }
+
+    fn list_keys_request(sig: &str) -> http::Request<Bytes> {
+        http::Request::builder()
+            .method(Method::POST)
+            .uri(format!(
+                "https://account.example.com/keys?sig={}&api-version=1",
+                sig
+            ))
+            .header("authorization", "SharedKey secret")
+            .body(Bytes::new())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn record_then_playback() {
+        let path = std::env::temp_dir()
+            .join(format!("azure_core_{}", uuid::Uuid::new_v4()))
+            .join("list_keys.json");
+
+        let recorder = RecordingHttpClient::new(Arc::new(KeysService), &path);
+        let response = recorder
+            .execute_request(list_keys_request("first"))
+            .await
+            .unwrap();
+        let live_body = crate::collect_pinned_stream(response.into_body())
+            .await
+            .unwrap();
+        assert!(live_body.starts_with(b"{\"keys\":[{\"primaryKey\":\"a\""));
+
+        let recording = std::fs::read_to_string(&path).unwrap();
+        assert!(!recording.contains("secret"));
+        assert!(!recording.contains("first\""));
+
+        let player = PlaybackHttpClient::from_file(&path).unwrap();
+        let response = player
+            .execute_request(list_keys_request("second"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["x-ms-request-id"], "1234");
+        let body: serde_json::Value = serde_json::from_slice(
+            &crate::collect_pinned_stream(response.into_body())
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(body["keys"][0]["primaryKey"], REDACTED);
+        assert_eq!(body["keys"][0]["name"], "first");
+
+        // every recorded response is served only once
+        assert!(player
+            .execute_request(list_keys_request("third"))
+            .await
+            .is_err());
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn playback_ignores_the_block_ids() {
+        let path = std::env::temp_dir()
+            .join(format!("azure_core_{}", uuid::Uuid::new_v4()))
+            .join("put_block.json");
+
+        let recorder = RecordingHttpClient::new(Arc::new(BlockService), &path);
+        recorder
+            .execute_request(put_block_request("cmVjb3JkZWQ="))
+            .await
+            .unwrap();
+
+        // every upload names its blocks after a new random id
+        let player = PlaybackHttpClient::from_file(&path).unwrap();
+        let response = player
+            .execute_request(put_block_request("cGxheWVkIGJhY2s="))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn playback_matches_ranged_reads_by_range() {
+        let path = std::env::temp_dir()
+            .join(format!("azure_core_{}", uuid::Uuid::new_v4()))
+            .join("ranged_reads.json");
+
+        let recorder = RecordingHttpClient::new(Arc::new(RangeService), &path);
+        for range in &["bytes=0-9", "bytes=10-19"] {
+            recorder
+                .execute_request(ranged_request(range))
+                .await
+                .unwrap();
+        }
+
+        // parallel reads do not replay in the order they were recorded
+        let player = PlaybackHttpClient::from_file(&path).unwrap();
+        for range in &["bytes=10-19", "bytes=0-9"] {
+            let response = player.execute_request(ranged_request(range)).await.unwrap();
+            let body = crate::collect_pinned_stream(response.into_body())
+                .await
+                .unwrap();
+            assert_eq!(body, range.as_bytes());
+        }
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn scrubs_sas_signatures_in_headers_and_bodies() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-ms-copy-source",
+            HeaderValue::from_static(
+                "https://account.blob.core.windows.net/c/b?sv=2019-12-12&sig=abc%2B&se=2020",
+            ),
+        );
+        let headers = scrub_headers(&headers);
+        assert_eq!(
+            headers["x-ms-copy-source"],
+            "https://account.blob.core.windows.net/c/b?sv=2019-12-12&sig=REDACTED&se=2020"
+        );
+
+        let xml = Bytes::from("<Url>https://a/c/b?sig=abc&amp;se=1</Url><Design>1</Design>");
+        assert_eq!(
+            scrub_body(&xml),
+            "<Url>https://a/c/b?sig=REDACTED&amp;se=1</Url><Design>1</Design>"
+        );
+
+        let json = Bytes::from(r#"{"sasUrl":"https://a/c?sig=abc","design":"x"}"#);
+        let json: serde_json::Value = serde_json::from_slice(&scrub_body(&json)).unwrap();
+        assert_eq!(json["sasUrl"], "https://a/c?sig=REDACTED");
+        assert_eq!(json["design"], "x");
+    }
+
+    #[test]
+    fn scrubs_storage_account_keys_and_connection_strings() {
+        let body = Bytes::from(
+            r#"{"keys":[{"keyName":"key1","value":"c2VjcmV0","permissions":"FULL"}],
+                "connectionString":"AccountName=a;AccountKey=c2VjcmV0",
+                "tags":{"value":"kept"}}"#,
+        );
+        let body: serde_json::Value = serde_json::from_slice(&scrub_body(&body)).unwrap();
+        assert_eq!(body["keys"][0]["keyName"], "key1");
+        assert_eq!(body["keys"][0]["value"], REDACTED);
+        assert_eq!(body["keys"][0]["permissions"], "FULL");
+        assert_eq!(body["connectionString"], REDACTED);
+        assert_eq!(body["tags"]["value"], "kept");
+    }
+}
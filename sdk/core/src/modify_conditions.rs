@@ -3,8 +3,8 @@ use crate::headers::{
     SOURCE_IF_MODIFIED_SINCE, SOURCE_IF_NONE_MATCH, SOURCE_IF_UNMODIFIED_SINCE,
 };
 use chrono::{DateTime, Utc};
+use http::header::{IF_MATCH, IF_MODIFIED_SINCE, IF_NONE_MATCH, IF_UNMODIFIED_SINCE};
 use http::request::Builder;
-use hyper::header::{IF_MATCH, IF_MODIFIED_SINCE, IF_NONE_MATCH, IF_UNMODIFIED_SINCE};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum IfSinceCondition {
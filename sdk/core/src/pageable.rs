@@ -0,0 +1,232 @@
+use crate::errors::AzureError;
+use crate::incompletevector::ContinuationToken;
+use futures::future::{BoxFuture, FutureExt};
+use futures::stream::{self, Stream, TryStreamExt};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A page of a listing: some items and, unless it is the last page, the
+/// token of the next one.
+pub trait Page {
+    type Item;
+
+    /// The token to pass to get the next page, `None` on the last page.
+    fn continuation(&self) -> Option<ContinuationToken>;
+
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+type NextPage<'a, P, E> =
+    Box<dyn Fn(Option<ContinuationToken>) -> BoxFuture<'a, Result<P, E>> + Send + Sync + 'a>;
+
+/// A stream of the pages of a listing, following the continuation tokens
+/// (`NextMarker`, `x-ms-continuation`, `nextLink`...) of the service.
+///
+/// Use it as a stream of pages, or call [`into_items`](Pageable::into_items)
+/// to get the items one by one. The listing can be stopped and resumed
+/// later, even in another process, from its
+/// [`continuation_token`](Pageable::continuation_token):
+///
+/// ```no_run
+/// # use azure_core::{Page, Pageable};
+/// # use futures::StreamExt;
+/// # async fn example<P: Page + 'static>(mut pages: Pageable<'_, P>) {
+/// // read a single page...
+/// let first = pages.next().await;
+/// let token = pages.continuation_token().map(ToOwned::to_owned);
+/// // ...and pick up from there: `pages.with_continuation_token(token)`
+/// # }
+/// ```
+///
+/// The stream ends after the first error; its continuation token is then
+/// the one of the page that failed, so the listing can be retried.
+pub struct Pageable<'a, P, E = AzureError> {
+    next_page: NextPage<'a, P, E>,
+    continuation: Option<ContinuationToken>,
+    complete: bool,
+    failed: bool,
+    pending: Option<BoxFuture<'a, Result<P, E>>>,
+}
+
+impl<'a, P, E> std::fmt::Debug for Pageable<'a, P, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pageable")
+            .field("continuation", &self.continuation)
+            .field("complete", &self.complete)
+            .field("failed", &self.failed)
+            .finish()
+    }
+}
+
+impl<'a, P, E> Pageable<'a, P, E>
+where
+    P: Page + 'a,
+    E: 'a,
+{
+    /// `next_page` gets the page of the given token, `None` being the
+    /// first page. The listing starts at `continuation`.
+    pub fn new<F, Fut>(continuation: Option<ContinuationToken>, next_page: F) -> Self
+    where
+        F: Fn(Option<ContinuationToken>) -> Fut + Send + Sync + 'a,
+        Fut: Future<Output = Result<P, E>> + Send + 'a,
+    {
+        Self {
+            next_page: Box::new(move |continuation| Box::pin(next_page(continuation))),
+            continuation,
+            complete: false,
+            failed: false,
+            pending: None,
+        }
+    }
+
+    /// Restarts the listing at `continuation` (the first page if `None`).
+    pub fn with_continuation_token(self, continuation: Option<ContinuationToken>) -> Self {
+        Self {
+            continuation,
+            complete: false,
+            failed: false,
+            pending: None,
+            ..self
+        }
+    }
+
+    /// The token of the next page to read, `None` if it is the first page
+    /// or if the listing is complete.
+    pub fn continuation_token(&self) -> Option<&str> {
+        if self.complete {
+            None
+        } else {
+            self.continuation.as_deref()
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// The items of every page, in order.
+    pub fn into_items(self) -> impl Stream<Item = Result<P::Item, E>> + 'a {
+        self.map_ok(|page| stream::iter(page.into_items().into_iter().map(Ok)))
+            .try_flatten()
+    }
+}
+
+impl<'a, P, E> Stream for Pageable<'a, P, E>
+where
+    P: Page,
+{
+    type Item = Result<P, E>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.complete || self.failed {
+            return Poll::Ready(None);
+        }
+        if self.pending.is_none() {
+            let pending = (self.next_page)(self.continuation.clone());
+            self.pending = Some(pending);
+        }
+
+        let result = match self.pending.as_mut().unwrap().poll_unpin(cx) {
+            Poll::Ready(result) => result,
+            Poll::Pending => return Poll::Pending,
+        };
+        self.pending = None;
+        match &result {
+            Ok(page) => {
+                self.continuation = page.continuation();
+                self.complete = self.continuation.is_none();
+            }
+            Err(_) => self.failed = true,
+        }
+        Poll::Ready(Some(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    struct NumbersPage {
+        numbers: Vec<u32>,
+        next: Option<ContinuationToken>,
+    }
+
+    impl Page for NumbersPage {
+        type Item = u32;
+
+        fn continuation(&self) -> Option<ContinuationToken> {
+            self.next.clone()
+        }
+
+        fn into_items(self) -> Vec<u32> {
+            self.numbers
+        }
+    }
+
+    // three pages of two numbers, the token being the first number of the
+    // page
+    fn numbers<'a>() -> Pageable<'a, NumbersPage> {
+        Pageable::new(None, |continuation: Option<ContinuationToken>| async move {
+            let first: u32 = match continuation {
+                Some(token) => token
+                    .parse()
+                    .map_err(|_| AzureError::GenericErrorWithText(token))?,
+                None => 0,
+            };
+            Ok(NumbersPage {
+                numbers: vec![first, first + 1],
+                next: if first < 4 {
+                    Some((first + 2).to_string())
+                } else {
+                    None
+                },
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn yields_every_page() {
+        let mut pages = numbers();
+        let mut count = 0;
+        while let Some(page) = pages.next().await {
+            page.unwrap();
+            count += 1;
+        }
+        assert_eq!(count, 3);
+        assert!(pages.is_complete());
+        assert_eq!(pages.continuation_token(), None);
+    }
+
+    #[tokio::test]
+    async fn flattens_the_items() {
+        let items: Vec<u32> = numbers().into_items().try_collect().await.unwrap();
+        assert_eq!(items, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn resumes_from_the_continuation_token() {
+        let mut pages = numbers();
+        pages.next().await.unwrap().unwrap();
+        let token = pages.continuation_token().map(ToOwned::to_owned);
+        assert_eq!(token.as_deref(), Some("2"));
+
+        let items: Vec<u32> = numbers()
+            .with_continuation_token(token)
+            .into_items()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items, vec![2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn stops_at_the_first_error() {
+        let mut pages = numbers().with_continuation_token(Some("not a number".to_owned()));
+        assert!(pages.next().await.unwrap().is_err());
+        assert!(pages.next().await.is_none());
+        assert!(!pages.is_complete());
+        assert_eq!(pages.continuation_token(), Some("not a number"));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::errors::AzureError;
+use crate::policies::{
+    send_next, LoggingPolicy, Policy, RetryOptions, RetryPolicy, TelemetryPolicy,
+};
+use crate::{Context, Request, Response};
+use std::sync::Arc;
+
+/// An ordered list of policies every request of a client goes through.
+///
+/// The policies run in this order:
+///
+/// 1. the telemetry policy (adds the SDK `User-Agent`) and, with the
+///    `tracing` feature, the tracing policy,
+/// 2. the per-call policies supplied by the client,
+/// 3. the retry policy configured by `retry_options`,
+/// 4. the per-retry policies supplied by the client (e.g. authentication),
+///    which run again for every attempt,
+/// 5. the logging policy,
+/// 6. the transport policy, which performs the actual HTTP call.
+///
+/// Cloning a `Pipeline` is cheap: the policies are shared.
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    policies: Vec<Arc<dyn Policy>>,
+}
+
+impl Pipeline {
+    pub fn new(
+        crate_name: &str,
+        crate_version: &str,
+        per_call_policies: Vec<Arc<dyn Policy>>,
+        retry_options: RetryOptions,
+        per_retry_policies: Vec<Arc<dyn Policy>>,
+        transport_policy: Arc<dyn Policy>,
+    ) -> Self {
+        let mut policies: Vec<Arc<dyn Policy>> =
+            Vec::with_capacity(per_call_policies.len() + per_retry_policies.len() + 5);
+        policies.push(Arc::new(TelemetryPolicy::new(crate_name, crate_version)));
+        #[cfg(feature = "tracing")]
+        policies.push(Arc::new(crate::policies::TracingPolicy::new()));
+        policies.extend(per_call_policies);
+        policies.push(Arc::new(RetryPolicy::new(retry_options)));
+        policies.extend(per_retry_policies);
+        policies.push(Arc::new(LoggingPolicy::new()));
+        policies.push(transport_policy);
+
+        Self { policies }
+    }
+
+    pub fn policies(&self) -> &[Arc<dyn Policy>] {
+        &self.policies
+    }
+
+    /// Sends the request in `ctx`: it fails with
+    /// `AzureError::DeadlineExceeded` or `AzureError::Cancelled` (aborting
+    /// the request in flight) when the deadline of the context passes or it
+    /// is cancelled.
+    pub async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+    ) -> Result<Response, AzureError> {
+        let guard = ctx.clone();
+        guard.guard(send_next(ctx, request, &self.policies)).await
+    }
+
+    /// Same as `send` but takes ownership of the request and uses the
+    /// context of the operation being run by [`Context::run`], if any.
+    pub async fn execute<R>(&self, request: R) -> Result<Response, AzureError>
+    where
+        R: Into<Request>,
+    {
+        let mut ctx = Context::current().unwrap_or_default();
+        let mut request = request.into();
+        self.send(&mut ctx, &mut request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policies::PolicyResult;
+    use bytes::Bytes;
+    use http::{header::USER_AGENT, HeaderMap, Method, StatusCode};
+    use std::sync::Mutex;
+
+    #[derive(Debug)]
+    struct RecordingPolicy {
+        name: &'static str,
+        calls: Arc<Mutex<Vec<&'static str>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for RecordingPolicy {
+        async fn send(
+            &self,
+            ctx: &mut Context,
+            request: &mut Request,
+            next: &[Arc<dyn Policy>],
+        ) -> PolicyResult<Response> {
+            self.calls.lock().unwrap().push(self.name);
+            send_next(ctx, request, next).await
+        }
+    }
+
+    #[derive(Debug)]
+    struct EchoTransport;
+
+    #[async_trait::async_trait]
+    impl Policy for EchoTransport {
+        async fn send(
+            &self,
+            _ctx: &mut Context,
+            request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult<Response> {
+            let body: Vec<Result<Bytes, AzureError>> = vec![Ok(request.body().clone())];
+            Ok(Response::new(
+                StatusCode::OK,
+                request.headers().clone(),
+                Box::pin(futures::stream::iter(body)),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn policies_run_in_order() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let policy = |name| -> Arc<dyn Policy> {
+            Arc::new(RecordingPolicy {
+                name,
+                calls: calls.clone(),
+            })
+        };
+        let pipeline = Pipeline::new(
+            "azure_core",
+            "0.1.0",
+            vec![policy("per_call")],
+            RetryOptions::default(),
+            vec![policy("per_retry")],
+            Arc::new(EchoTransport),
+        );
+
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::PUT);
+        request.set_body("payload");
+        let response = pipeline
+            .send(&mut Context::new(), &mut request)
+            .await
+            .unwrap();
+
+        assert_eq!(*calls.lock().unwrap(), vec!["per_call", "per_retry"]);
+        let headers: HeaderMap = response.headers().clone();
+        assert!(headers
+            .get(USER_AGENT)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("azsdk-rust-core/0.1.0"));
+        assert_eq!(response.into_body().await.unwrap(), Bytes::from("payload"));
+    }
+
+    #[tokio::test]
+    async fn missing_transport_is_an_error() {
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::GET);
+        assert!(send_next(&mut Context::new(), &mut request, &[])
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,51 @@
+use crate::policies::{send_next, Policy, PolicyResult};
+use crate::util::format_header_value;
+use crate::{Context, Request, Response, TokenCredential};
+use http::header::AUTHORIZATION;
+use std::sync::Arc;
+
+/// Authenticates requests with an Azure Active Directory bearer token
+/// obtained from a `TokenCredential` for the given resource.
+#[derive(Clone)]
+pub struct BearerTokenPolicy {
+    credential: Arc<dyn TokenCredential + Send + Sync>,
+    resource: String,
+}
+
+impl BearerTokenPolicy {
+    pub fn new<R>(credential: Arc<dyn TokenCredential + Send + Sync>, resource: R) -> Self
+    where
+        R: Into<String>,
+    {
+        Self {
+            credential,
+            resource: resource.into(),
+        }
+    }
+}
+
+impl std::fmt::Debug for BearerTokenPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BearerTokenPolicy")
+            .field("resource", &self.resource)
+            .finish()
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for BearerTokenPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        let token = self.credential.get_token(&self.resource).await?;
+        request.headers_mut().insert(
+            AUTHORIZATION,
+            format_header_value(format!("Bearer {}", token.token.secret()))?,
+        );
+
+        send_next(ctx, request, next).await
+    }
+}
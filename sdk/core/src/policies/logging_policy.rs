@@ -0,0 +1,42 @@
+use crate::policies::{send_next, Policy, PolicyResult};
+use crate::{Context, Request, Response};
+use std::sync::Arc;
+
+/// Logs every request and the status of its response through the `log`
+/// crate.
+#[derive(Debug, Clone, Default)]
+pub struct LoggingPolicy;
+
+impl LoggingPolicy {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for LoggingPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        debug!("{} {}", request.method(), request.uri());
+        trace!("request headers == {:?}", request.headers());
+
+        let response = send_next(ctx, request, next).await;
+        match &response {
+            Ok(response) => {
+                debug!(
+                    "{} {} -> {}",
+                    request.method(),
+                    request.uri(),
+                    response.status()
+                );
+                trace!("response headers == {:?}", response.headers());
+            }
+            Err(err) => debug!("{} {} -> error: {}", request.method(), request.uri(), err),
+        }
+        response
+    }
+}
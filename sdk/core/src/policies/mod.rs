@@ -0,0 +1,55 @@
+mod bearer_token_policy;
+mod logging_policy;
+mod retry_policy;
+mod telemetry_policy;
+#[cfg(feature = "tracing")]
+mod tracing_policy;
+mod transport_policy;
+
+pub use bearer_token_policy::BearerTokenPolicy;
+pub use logging_policy::LoggingPolicy;
+pub(crate) use retry_policy::retry_after;
+pub use retry_policy::{RetryMode, RetryOptions, RetryPolicy};
+pub use telemetry_policy::TelemetryPolicy;
+#[cfg(feature = "tracing")]
+pub use tracing_policy::TracingPolicy;
+pub use transport_policy::TransportPolicy;
+
+use crate::errors::AzureError;
+use crate::{Context, Request, Response};
+use std::sync::Arc;
+
+pub type PolicyResult<T> = Result<T, AzureError>;
+
+/// A step of a [`Pipeline`](crate::pipeline::Pipeline).
+///
+/// A policy can inspect or change the request, hand it to the rest of the
+/// pipeline (`next`) and inspect the response. The last policy of a pipeline
+/// (the transport) must not call `next`.
+///
+/// The [`Context`] of the call is handed down the pipeline along with the
+/// request: a policy can read the values the caller put in it, or add its
+/// own for the policies after it.
+#[async_trait::async_trait]
+pub trait Policy: Send + Sync + std::fmt::Debug {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response>;
+}
+
+/// Calls the first policy of `next`, passing it the remaining ones.
+pub async fn send_next(
+    ctx: &mut Context,
+    request: &mut Request,
+    next: &[Arc<dyn Policy>],
+) -> PolicyResult<Response> {
+    match next.split_first() {
+        Some((policy, rest)) => policy.send(ctx, request, rest).await,
+        None => Err(AzureError::GenericErrorWithText(
+            "the pipeline ended without a transport policy".to_owned(),
+        )),
+    }
+}
@@ -0,0 +1,446 @@
+use crate::headers::{MS_RETRY_AFTER_MS, RETRY_AFTER_MS};
+use crate::policies::{send_next, Policy, PolicyResult};
+use crate::{Context, Request, Response};
+use chrono::{DateTime, Utc};
+use http::header::RETRY_AFTER;
+use http::{HeaderMap, StatusCode};
+use rand::Rng;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How the delay between two attempts grows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryMode {
+    /// The delay doubles after every attempt.
+    Exponential,
+    /// The delay is the same for every attempt.
+    Fixed,
+    /// Requests are never retried.
+    None,
+}
+
+/// Configuration of the [`RetryPolicy`].
+///
+/// The defaults retry a failed request 3 times, starting with an 800ms
+/// delay that doubles after every attempt and never exceeds 60 seconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryOptions {
+    mode: RetryMode,
+    max_retries: u32,
+    delay: Duration,
+    max_delay: Duration,
+}
+
+impl Default for RetryOptions {
+    fn default() -> Self {
+        Self {
+            mode: RetryMode::Exponential,
+            max_retries: 3,
+            delay: Duration::from_millis(800),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryOptions {
+    pub fn mode(&self) -> RetryMode {
+        self.mode
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    pub fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    pub fn with_mode(self, mode: RetryMode) -> Self {
+        Self { mode, ..self }
+    }
+
+    pub fn with_max_retries(self, max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..self
+        }
+    }
+
+    pub fn with_delay(self, delay: Duration) -> Self {
+        Self { delay, ..self }
+    }
+
+    pub fn with_max_delay(self, max_delay: Duration) -> Self {
+        Self { max_delay, ..self }
+    }
+}
+
+/// Sends the request again when the service is throttling or temporarily
+/// unavailable, or when the transport fails.
+///
+/// If the response tells how long to wait (`retry-after-ms`,
+/// `x-ms-retry-after-ms` or `Retry-After`), the policy waits that long but
+/// never more than the configured maximum delay. Otherwise it uses the
+/// backoff configured in [`RetryOptions`].
+///
+/// Other errors, such as a failure to get a token, are returned at once.
+/// When the retries run out, or when the deadline of the [`Context`] would
+/// pass before the next attempt, the result of the last attempt is returned
+/// as is.
+#[derive(Debug, Clone, Default)]
+pub struct RetryPolicy {
+    options: RetryOptions,
+}
+
+impl RetryPolicy {
+    pub fn new(options: RetryOptions) -> Self {
+        Self { options }
+    }
+
+    /// The backoff before retry number `retry` (starting at 0), jitter
+    /// included.
+    fn backoff(&self, retry: u32) -> Duration {
+        let delay = match self.options.mode {
+            RetryMode::Exponential => self
+                .options
+                .delay
+                .checked_mul(1u32.checked_shl(retry).unwrap_or(u32::MAX))
+                .unwrap_or(self.options.max_delay),
+            RetryMode::Fixed | RetryMode::None => self.options.delay,
+        };
+        let jitter = rand::thread_rng().gen_range(0.8, 1.2);
+        std::cmp::min(delay.mul_f64(jitter), self.options.max_delay)
+    }
+}
+
+/// The statuses worth retrying: timeouts, throttling (including Cosmos'
+/// 449 "retry with") and transient server errors.
+fn is_retriable(status: StatusCode) -> bool {
+    matches!(status.as_u16(), 408 | 429 | 449 | 500 | 502 | 503 | 504)
+}
+
+/// The delay requested by the service, if any.
+///
+/// Values that are not finite (`inf`, `NaN`) are ignored; the conversion of
+/// the others saturates instead of overflowing.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    for header in &[RETRY_AFTER_MS, MS_RETRY_AFTER_MS] {
+        if let Some(millis) = headers
+            .get(*header)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<f64>().ok())
+            .filter(|millis| millis.is_finite())
+        {
+            // float to int casts saturate, so this cannot overflow
+            return Some(Duration::from_millis(millis.max(0.0) as u64));
+        }
+    }
+
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?;
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+#[async_trait::async_trait]
+impl Policy for RetryPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        let max_retries = match self.options.mode {
+            RetryMode::None => 0,
+            _ => self.options.max_retries,
+        };
+
+        let mut retry = 0;
+        loop {
+            // every attempt starts from the request as it entered the
+            // policy so the per-retry policies do not pile up headers
+            let result = send_next(ctx, &mut request.clone(), next).await;
+            if retry >= max_retries {
+                return result;
+            }
+
+            let delay = match &result {
+                Ok(response) if is_retriable(response.status()) => {
+                    match retry_after(response.headers()) {
+                        Some(delay) => std::cmp::min(delay, self.options.max_delay),
+                        None => self.backoff(retry),
+                    }
+                }
+                Ok(_) => return result,
+                Err(err) if !err.is_transport_error() => return result,
+                Err(err) => {
+                    debug!("attempt {} failed: {}", retry + 1, err);
+                    self.backoff(retry)
+                }
+            };
+            // no point waiting for an attempt that could not complete in
+            // time: the last response is more useful than a timeout
+            if ctx.is_cancelled()
+                || matches!(ctx.remaining(), Some(remaining) if remaining <= delay)
+            {
+                return result;
+            }
+            debug!(
+                "retrying {} {} in {:?}",
+                request.method(),
+                request.uri(),
+                delay
+            );
+            tokio::time::delay_for(delay).await;
+            retry += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::AzureError;
+    use bytes::Bytes;
+    use http::{HeaderValue, Method};
+    use std::sync::Mutex;
+
+    #[derive(Debug)]
+    struct ScriptedTransport {
+        responses: Mutex<Vec<Result<(StatusCode, HeaderMap), AzureError>>>,
+        calls: Mutex<u32>,
+    }
+
+    impl ScriptedTransport {
+        fn new(responses: Vec<(StatusCode, HeaderMap)>) -> Self {
+            Self::with_errors(responses.into_iter().map(Ok).collect())
+        }
+
+        fn with_errors(mut responses: Vec<Result<(StatusCode, HeaderMap), AzureError>>) -> Self {
+            responses.reverse();
+            Self {
+                responses: Mutex::new(responses),
+                calls: Mutex::new(0),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for ScriptedTransport {
+        async fn send(
+            &self,
+            _ctx: &mut Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult<Response> {
+            *self.calls.lock().unwrap() += 1;
+            let (status, headers) = self.responses.lock().unwrap().pop().unwrap()?;
+            let body: Vec<Result<Bytes, AzureError>> = vec![];
+            Ok(Response::new(
+                status,
+                headers,
+                Box::pin(futures::stream::iter(body)),
+            ))
+        }
+    }
+
+    fn throttled(retry_after_ms: &'static str) -> (StatusCode, HeaderMap) {
+        let mut headers = HeaderMap::new();
+        headers.insert(MS_RETRY_AFTER_MS, HeaderValue::from_static(retry_after_ms));
+        (StatusCode::TOO_MANY_REQUESTS, headers)
+    }
+
+    fn quick_options() -> RetryOptions {
+        RetryOptions::default().with_delay(Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn retries_until_success() {
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            throttled("1"),
+            (StatusCode::SERVICE_UNAVAILABLE, HeaderMap::new()),
+            (StatusCode::CREATED, HeaderMap::new()),
+        ]));
+        let next: Vec<Arc<dyn Policy>> = vec![transport.clone()];
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::PUT);
+
+        let response = RetryPolicy::new(quick_options())
+            .send(&mut Context::new(), &mut request, &next)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(*transport.calls.lock().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_retries() {
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            throttled("1"),
+            throttled("1"),
+            throttled("1"),
+        ]));
+        let next: Vec<Arc<dyn Policy>> = vec![transport.clone()];
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::GET);
+
+        let response = RetryPolicy::new(quick_options().with_max_retries(2))
+            .send(&mut Context::new(), &mut request, &next)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(*transport.calls.lock().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors() {
+        let transport = Arc::new(ScriptedTransport::new(vec![(
+            StatusCode::CONFLICT,
+            HeaderMap::new(),
+        )]));
+        let next: Vec<Arc<dyn Policy>> = vec![transport.clone()];
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::PUT);
+
+        let response = RetryPolicy::new(quick_options())
+            .send(&mut Context::new(), &mut request, &next)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(*transport.calls.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn retries_transport_errors_only() {
+        let reset = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset");
+        let transport = Arc::new(ScriptedTransport::with_errors(vec![
+            Err(AzureError::IOError(reset)),
+            Ok((StatusCode::OK, HeaderMap::new())),
+        ]));
+        let next: Vec<Arc<dyn Policy>> = vec![transport.clone()];
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::GET);
+
+        let response = RetryPolicy::new(quick_options())
+            .send(&mut Context::new(), &mut request, &next)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(*transport.calls.lock().unwrap(), 2);
+
+        let transport = Arc::new(ScriptedTransport::with_errors(vec![Err(
+            AzureError::GenericErrorWithText("no token".to_owned()),
+        )]));
+        let next: Vec<Arc<dyn Policy>> = vec![transport.clone()];
+
+        let err = RetryPolicy::new(quick_options())
+            .send(&mut Context::new(), &mut request, &next)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AzureError::GenericErrorWithText(_)));
+        assert_eq!(*transport.calls.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_past_the_deadline() {
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            throttled("60000"),
+            (StatusCode::OK, HeaderMap::new()),
+        ]));
+        let next: Vec<Arc<dyn Policy>> = vec![transport.clone()];
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::GET);
+        let mut ctx = Context::new().with_timeout(Duration::from_secs(5));
+
+        let response = RetryPolicy::new(quick_options())
+            .send(&mut ctx, &mut request, &next)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(*transport.calls.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn retry_after_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+
+        headers.insert(MS_RETRY_AFTER_MS, HeaderValue::from_static("250"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_millis(250)));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(0)));
+    }
+
+    #[test]
+    fn retry_after_ignores_non_finite_and_saturates() {
+        let mut headers = HeaderMap::new();
+        headers.insert(MS_RETRY_AFTER_MS, HeaderValue::from_static("inf"));
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(MS_RETRY_AFTER_MS, HeaderValue::from_static("NaN"));
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("3"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(3)));
+
+        headers.insert(MS_RETRY_AFTER_MS, HeaderValue::from_static("1e300"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_millis(u64::MAX)));
+
+        headers.insert(MS_RETRY_AFTER_MS, HeaderValue::from_static("-5"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(0)));
+    }
+
+    #[tokio::test]
+    async fn caps_the_requested_delay() {
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            throttled("1e300"),
+            (StatusCode::OK, HeaderMap::new()),
+        ]));
+        let next: Vec<Arc<dyn Policy>> = vec![transport.clone()];
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::GET);
+
+        let response = RetryPolicy::new(quick_options().with_max_delay(Duration::from_millis(1)))
+            .send(&mut Context::new(), &mut request, &next)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(*transport.calls.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn backoff_grows_and_is_capped() {
+        let policy = RetryPolicy::new(
+            RetryOptions::default()
+                .with_delay(Duration::from_secs(1))
+                .with_max_delay(Duration::from_secs(5)),
+        );
+        let first = policy.backoff(0);
+        assert!(first >= Duration::from_millis(800) && first <= Duration::from_millis(1200));
+        let third = policy.backoff(2);
+        assert!(third >= Duration::from_millis(3200) && third <= Duration::from_millis(4800));
+        assert_eq!(policy.backoff(10), Duration::from_secs(5));
+
+        let fixed = RetryPolicy::new(
+            RetryOptions::default()
+                .with_mode(RetryMode::Fixed)
+                .with_delay(Duration::from_secs(1)),
+        );
+        assert!(fixed.backoff(5) <= Duration::from_millis(1200));
+    }
+}
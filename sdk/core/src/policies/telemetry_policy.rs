@@ -0,0 +1,53 @@
+use crate::policies::{send_next, Policy, PolicyResult};
+use crate::util::format_header_value;
+use crate::{Context, Request, Response};
+use http::header::USER_AGENT;
+use std::sync::Arc;
+
+/// Adds the SDK `User-Agent` token to every request.
+///
+/// The token has the form `azsdk-rust-{crate}/{version} ({os}; {arch})`. If
+/// the caller already set a user agent (for example with
+/// `UserAgentSupport`) the SDK token is appended to it.
+#[derive(Debug, Clone)]
+pub struct TelemetryPolicy {
+    header: String,
+}
+
+impl TelemetryPolicy {
+    pub fn new(crate_name: &str, crate_version: &str) -> Self {
+        Self {
+            header: format!(
+                "azsdk-rust-{}/{} ({}; {})",
+                crate_name.trim_start_matches("azure_"),
+                crate_version,
+                std::env::consts::OS,
+                std::env::consts::ARCH,
+            ),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for TelemetryPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        let user_agent = match request
+            .headers()
+            .get(USER_AGENT)
+            .and_then(|v| v.to_str().ok())
+        {
+            Some(existing) => format!("{} {}", existing, self.header),
+            None => self.header.clone(),
+        };
+        request
+            .headers_mut()
+            .insert(USER_AGENT, format_header_value(user_agent)?);
+
+        send_next(ctx, request, next).await
+    }
+}
@@ -0,0 +1,237 @@
+use crate::context::new_trace_id;
+use crate::headers::{ACTIVITY_ID, CLIENT_REQUEST_ID, REQUEST_ID, TRACEPARENT};
+use crate::policies::{send_next, Policy, PolicyResult};
+use crate::{Context, Request, Response};
+use http::header::HeaderValue;
+use std::sync::Arc;
+use tracing::field::{display, Empty};
+use tracing::{Instrument, Span};
+
+/// Runs the request in an `azure_request` span and sends a W3C
+/// `traceparent` header so the service logs can be correlated with the
+/// caller's traces.
+///
+/// The outcome of the request is also recorded on the enclosing
+/// `azure_operation` span, if any (see
+/// [`instrumentation`](crate::instrumentation)). The policy runs before the
+/// retry policy so its span and `traceparent` cover every attempt.
+#[derive(Debug, Clone, Default)]
+pub struct TracingPolicy;
+
+impl TracingPolicy {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Returns the trace id of the `traceparent` of the request, adding the
+/// header if the caller did not.
+///
+/// The trace id is the one of `ctx`, or of the operation being run (see
+/// [`Context::trace_id`]), so the requests of one operation share a trace.
+/// The header is added before the retry policy runs, so every attempt of a
+/// request sends the same one.
+fn ensure_traceparent(ctx: &Context, request: &mut Request) -> String {
+    if let Some(trace_id) = request
+        .headers()
+        .get(TRACEPARENT)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split('-').nth(1))
+    {
+        return trace_id.to_owned();
+    }
+
+    let trace_id = ctx
+        .trace_id()
+        .map(str::to_owned)
+        .or_else(|| Context::current().and_then(|current| current.trace_id().map(str::to_owned)))
+        .unwrap_or_else(new_trace_id);
+    let parent_id = format!("{:016x}", rand::random::<u64>().max(1));
+    let traceparent = format!("00-{}-{}-01", trace_id, parent_id);
+    request.headers_mut().insert(
+        TRACEPARENT,
+        HeaderValue::from_str(&traceparent).expect("hex digits are a valid header value"),
+    );
+    trace_id
+}
+
+fn header<'a>(headers: &'a http::HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+#[async_trait::async_trait]
+impl Policy for TracingPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        let trace_id = ensure_traceparent(ctx, request);
+        let operation = Span::current();
+        // the query string is left out as it can carry a SAS token
+        let span = tracing::info_span!(
+            "azure_request",
+            method = %request.method(),
+            host = request.uri().host().unwrap_or_default(),
+            path = request.uri().path(),
+            status = Empty,
+            request_id = Empty,
+            client_request_id = Empty,
+            trace_id = trace_id.as_str(),
+            duration_ms = Empty,
+            error = Empty,
+        );
+
+        let recorded = [&span, &operation];
+        for span in &recorded {
+            span.record("trace_id", trace_id.as_str());
+            if let Some(client_request_id) = header(request.headers(), CLIENT_REQUEST_ID) {
+                span.record("client_request_id", client_request_id);
+            }
+        }
+
+        let start = std::time::Instant::now();
+        let response = send_next(ctx, request, next).instrument(span.clone()).await;
+        span.record("duration_ms", start.elapsed().as_millis() as u64);
+
+        for span in &recorded {
+            match &response {
+                Ok(response) => {
+                    span.record("status", response.status().as_u16());
+                    if let Some(request_id) = header(response.headers(), REQUEST_ID)
+                        .or_else(|| header(response.headers(), ACTIVITY_ID))
+                    {
+                        span.record("request_id", request_id);
+                    }
+                }
+                Err(err) => {
+                    span.record("error", display(err));
+                }
+            }
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::AzureError;
+    use bytes::Bytes;
+    use http::{HeaderMap, Method, StatusCode};
+
+    #[derive(Debug)]
+    struct EchoHeaders;
+
+    #[async_trait::async_trait]
+    impl Policy for EchoHeaders {
+        async fn send(
+            &self,
+            _ctx: &mut Context,
+            request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult<Response> {
+            let body: Vec<Result<Bytes, AzureError>> = vec![];
+            Ok(Response::new(
+                StatusCode::OK,
+                request.headers().clone(),
+                Box::pin(futures::stream::iter(body)),
+            ))
+        }
+    }
+
+    fn traceparent(headers: &HeaderMap) -> &str {
+        headers.get(TRACEPARENT).unwrap().to_str().unwrap()
+    }
+
+    #[tokio::test]
+    async fn adds_a_traceparent() {
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(EchoHeaders)];
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::GET);
+
+        let response = TracingPolicy::new()
+            .send(&mut Context::new(), &mut request, &next)
+            .await
+            .unwrap();
+
+        let parts: Vec<&str> = traceparent(response.headers()).split('-').collect();
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts[0], "00");
+        assert_eq!(parts[1].len(), 32);
+        assert_eq!(parts[2].len(), 16);
+    }
+
+    #[tokio::test]
+    async fn keeps_the_callers_traceparent() {
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(EchoHeaders)];
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::GET);
+        let traceparent_value = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01";
+        request
+            .headers_mut()
+            .insert(TRACEPARENT, HeaderValue::from_static(traceparent_value));
+
+        let response = TracingPolicy::new()
+            .send(&mut Context::new(), &mut request, &next)
+            .await
+            .unwrap();
+
+        assert_eq!(traceparent(response.headers()), traceparent_value);
+    }
+
+    #[tokio::test]
+    async fn the_requests_of_an_operation_share_its_trace() {
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(EchoHeaders)];
+        let trace_id = "0af7651916cd43dd8448eb211c80319c";
+        let context = Context::new().with_trace_id(trace_id);
+
+        let parents = context
+            .run(async {
+                let mut parents = Vec::new();
+                for _ in 0..2 {
+                    let mut request =
+                        Request::new("https://example.com".parse().unwrap(), Method::GET);
+                    let response = TracingPolicy::new()
+                        .send(&mut Context::new(), &mut request, &next)
+                        .await?;
+                    let parts: Vec<String> = traceparent(response.headers())
+                        .split('-')
+                        .map(str::to_owned)
+                        .collect();
+                    assert_eq!(parts[1], trace_id);
+                    parents.push(parts[2].clone());
+                }
+                Ok::<_, AzureError>(parents)
+            })
+            .await
+            .unwrap();
+        assert_ne!(parents[0], parents[1]);
+    }
+
+    #[tokio::test]
+    async fn an_operation_without_a_trace_gets_one() {
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(EchoHeaders)];
+
+        let trace_ids = crate::instrumentation::instrument("Builder::finalize", async {
+            let mut trace_ids = Vec::new();
+            for _ in 0..2 {
+                let mut request = Request::new("https://example.com".parse().unwrap(), Method::GET);
+                let mut ctx = Context::current().unwrap();
+                let response = TracingPolicy::new()
+                    .send(&mut ctx, &mut request, &next)
+                    .await?;
+                trace_ids.push(
+                    traceparent(response.headers())
+                        .split('-')
+                        .nth(1)
+                        .unwrap()
+                        .to_owned(),
+                );
+            }
+            Ok(trace_ids)
+        })
+        .await
+        .unwrap();
+        assert_eq!(trace_ids[0], trace_ids[1]);
+    }
+}
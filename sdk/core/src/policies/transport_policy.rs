@@ -0,0 +1,88 @@
+use crate::errors::AzureError;
+use crate::policies::{Policy, PolicyResult};
+use crate::{Context, HttpClient, Request, Response};
+use std::sync::Arc;
+
+/// Sends the request over the network with an [`HttpClient`]. This must be
+/// the last policy of a pipeline.
+#[derive(Debug, Clone)]
+pub struct TransportPolicy {
+    http_client: Arc<dyn HttpClient>,
+}
+
+impl TransportPolicy {
+    pub fn new(http_client: Arc<dyn HttpClient>) -> Self {
+        Self { http_client }
+    }
+}
+
+#[cfg(any(feature = "enable_hyper", feature = "enable_reqwest"))]
+impl Default for TransportPolicy {
+    fn default() -> Self {
+        Self::new(crate::new_http_client())
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for TransportPolicy {
+    async fn send(
+        &self,
+        _ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        if !next.is_empty() {
+            return Err(AzureError::GenericErrorWithText(
+                "the transport policy must be the last policy of the pipeline".to_owned(),
+            ));
+        }
+
+        let response = self
+            .http_client
+            .execute_request(request.clone().into())
+            .await?;
+        Ok(response.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PinnedStream;
+    use bytes::Bytes;
+    use http::{Method, StatusCode};
+
+    #[derive(Debug)]
+    struct MockHttpClient;
+
+    #[async_trait::async_trait]
+    impl HttpClient for MockHttpClient {
+        async fn execute_request(
+            &self,
+            request: http::Request<Bytes>,
+        ) -> Result<http::Response<PinnedStream>, AzureError> {
+            assert_eq!(request.method(), Method::POST);
+            assert_eq!(request.uri(), "https://example.com/path");
+            let body: Vec<Result<Bytes, AzureError>> = vec![Ok(request.into_body())];
+            let body: PinnedStream = Box::pin(futures::stream::iter(body));
+            Ok(http::Response::builder()
+                .status(StatusCode::ACCEPTED)
+                .body(body)?)
+        }
+    }
+
+    #[tokio::test]
+    async fn sends_through_the_http_client() {
+        let policy = TransportPolicy::new(Arc::new(MockHttpClient));
+        let mut request = Request::new("https://example.com/path".parse().unwrap(), Method::POST);
+        request.set_body("hello");
+
+        let response = policy
+            .send(&mut Context::new(), &mut request, &[])
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        assert_eq!(response.into_body().await.unwrap(), Bytes::from("hello"));
+    }
+}
@@ -90,6 +90,36 @@ impl FromStr for Range {
     }
 }
 
+/// The `Content-Range` of a partial response, e.g. `bytes 0-499/1234`: the
+/// range returned and the length of the whole resource.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ContentRange {
+    pub range: Range,
+    pub total_length: u64,
+}
+
+impl FromStr for ContentRange {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<ContentRange, ParseError> {
+        let s = s.trim_start_matches("bytes ");
+        let mut v = s.split('/');
+        let (range, total_length) = match (v.next(), v.next(), v.next()) {
+            (Some(range), Some(total_length), None) => (range, total_length),
+            _ => return Err(ParseError::SplitNotFound),
+        };
+        let mut v = range.split('-');
+        let (start, end) = match (v.next(), v.next(), v.next()) {
+            (Some(start), Some(end), None) => (start, end),
+            _ => return Err(ParseError::SplitNotFound),
+        };
+
+        Ok(ContentRange {
+            range: Range::new(start.parse()?, end.parse::<u64>()? + 1),
+            total_length: total_length.parse()?,
+        })
+    }
+}
+
 impl fmt::Display for Range {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "bytes={}-{}", self.start, self.end - 1)
@@ -120,6 +150,20 @@ mod test {
         "1000-2000".parse::<Range>().unwrap();
     }
 
+    #[test]
+    fn test_content_range_parse() {
+        let content_range = "bytes 0-499/1234".parse::<ContentRange>().unwrap();
+
+        assert_eq!(content_range.range, Range::new(0, 500));
+        assert_eq!(content_range.total_length, 1234);
+    }
+
+    #[test]
+    #[should_panic(expected = "SplitNotFound")]
+    fn test_content_range_parse_panic() {
+        "bytes 0-499".parse::<ContentRange>().unwrap();
+    }
+
     #[test]
     fn test_range_display() {
         let range = Range {
@@ -0,0 +1,96 @@
+use bytes::Bytes;
+use http::{HeaderMap, Method, Uri};
+
+/// A request travelling through a [`Pipeline`](crate::pipeline::Pipeline).
+///
+/// Unlike `http::Request` it is cheap to clone (the body is a `Bytes`), so a
+/// policy can send the same request more than once.
+#[derive(Debug, Clone)]
+pub struct Request {
+    uri: Uri,
+    method: Method,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl Request {
+    pub fn new(uri: Uri, method: Method) -> Self {
+        Self {
+            uri,
+            method,
+            headers: HeaderMap::new(),
+            body: Bytes::new(),
+        }
+    }
+
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+
+    pub fn uri_mut(&mut self) -> &mut Uri {
+        &mut self.uri
+    }
+
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+    pub fn set_body<B: Into<Bytes>>(&mut self, body: B) {
+        self.body = body.into();
+    }
+}
+
+impl From<Request> for http::Request<Bytes> {
+    fn from(request: Request) -> Self {
+        let mut http_request = http::Request::new(request.body);
+        *http_request.method_mut() = request.method;
+        *http_request.uri_mut() = request.uri;
+        *http_request.headers_mut() = request.headers;
+        http_request
+    }
+}
+
+impl From<http::Request<Bytes>> for Request {
+    fn from(request: http::Request<Bytes>) -> Self {
+        let (parts, body) = request.into_parts();
+        Self {
+            uri: parts.uri,
+            method: parts.method,
+            headers: parts.headers,
+            body,
+        }
+    }
+}
+
+#[cfg(feature = "enable_reqwest")]
+impl From<reqwest::Request> for Request {
+    fn from(request: reqwest::Request) -> Self {
+        let body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map(Bytes::copy_from_slice)
+            .unwrap_or_default();
+        Self {
+            uri: request
+                .url()
+                .as_str()
+                .parse()
+                .expect("a parsed Url is always a valid Uri"),
+            method: request.method().clone(),
+            headers: request.headers().clone(),
+            body,
+        }
+    }
+}
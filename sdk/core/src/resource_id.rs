@@ -0,0 +1,480 @@
+use crate::errors::ResourceIdParseError;
+use std::fmt;
+use std::str::FromStr;
+
+const SUBSCRIPTIONS: &str = "subscriptions";
+const RESOURCE_GROUPS: &str = "resourceGroups";
+const PROVIDERS: &str = "providers";
+const MANAGEMENT_NAMESPACE: &str = "Microsoft.Management";
+const MANAGEMENT_GROUPS: &str = "managementGroups";
+
+/// The ID of an Azure Resource Manager resource, such as
+/// `/subscriptions/{subscription}/resourceGroups/{group}/providers/Microsoft.Storage/storageAccounts/{account}`.
+///
+/// It is made of:
+///
+/// * the subscription and resource group the resource lives in, if any,
+/// * a `/providers/{namespace}/{type}/{name}` section for the resource,
+///   followed by a `/{type}/{name}` pair for each child resource
+///   (`.../storageAccounts/{account}/blobServices/default`),
+/// * another provider section for an extension resource, which extends the
+///   resource before it (a lock on a virtual machine:
+///   `.../virtualMachines/{vm}/providers/Microsoft.Authorization/locks/{lock}`).
+///
+/// Resources at the scope of a management group are the extensions of
+/// `/providers/Microsoft.Management/managementGroups/{group}`.
+///
+/// ```
+/// use azure_core::ResourceId;
+///
+/// let id: ResourceId = "/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/rg/providers/Microsoft.Storage/storageAccounts/account/blobServices/default/containers/images"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(id.resource_group_name(), Some("rg"));
+/// assert_eq!(id.resource_type().unwrap(), "Microsoft.Storage/storageAccounts/blobServices/containers");
+/// assert_eq!(id.names(), vec!["account", "default", "images"]);
+/// assert_eq!(id.name_of("storageAccounts"), Some("account"));
+/// ```
+///
+/// The keywords are parsed ignoring their case, as Azure does, and
+/// formatted with their usual case. The values keep their case: two IDs
+/// differing only by the case of a name are different `ResourceId`s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ResourceId {
+    subscription_id: Option<String>,
+    resource_group_name: Option<String>,
+    providers: Vec<ProviderSection>,
+}
+
+/// `/providers/{namespace}/{type}/{name}[/{type}/{name}...]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ProviderSection {
+    namespace: String,
+    types: Vec<(String, String)>,
+}
+
+impl ResourceId {
+    /// The root of the tenant, `/`.
+    pub fn tenant() -> Self {
+        Self::default()
+    }
+
+    pub fn subscription<S>(subscription_id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            subscription_id: Some(subscription_id.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn resource_group<S, G>(subscription_id: S, resource_group_name: G) -> Self
+    where
+        S: Into<String>,
+        G: Into<String>,
+    {
+        Self {
+            resource_group_name: Some(resource_group_name.into()),
+            ..Self::subscription(subscription_id)
+        }
+    }
+
+    pub fn management_group<G>(management_group_name: G) -> Self
+    where
+        G: Into<String>,
+    {
+        Self::tenant().with_resource(
+            MANAGEMENT_NAMESPACE,
+            MANAGEMENT_GROUPS,
+            management_group_name,
+        )
+    }
+
+    /// A resource in this scope: a top level resource of a subscription or
+    /// a resource group, or an extension of this resource.
+    pub fn with_resource<N, T, R>(mut self, namespace: N, resource_type: T, name: R) -> Self
+    where
+        N: Into<String>,
+        T: Into<String>,
+        R: Into<String>,
+    {
+        self.providers.push(ProviderSection {
+            namespace: namespace.into(),
+            types: vec![(resource_type.into(), name.into())],
+        });
+        self
+    }
+
+    /// A child of this resource, such as the `blobServices` of a storage
+    /// account.
+    ///
+    /// # Panics
+    ///
+    /// If this ID is not the one of a resource (`with_resource`).
+    pub fn with_child<T, R>(mut self, resource_type: T, name: R) -> Self
+    where
+        T: Into<String>,
+        R: Into<String>,
+    {
+        self.providers
+            .last_mut()
+            .expect("only a resource has children")
+            .types
+            .push((resource_type.into(), name.into()));
+        self
+    }
+
+    pub fn subscription_id(&self) -> Option<&str> {
+        self.subscription_id.as_deref()
+    }
+
+    pub fn resource_group_name(&self) -> Option<&str> {
+        self.resource_group_name.as_deref()
+    }
+
+    /// The management group of the management group scoped IDs.
+    pub fn management_group_name(&self) -> Option<&str> {
+        if self.subscription_id.is_some() {
+            return None;
+        }
+        let section = self.providers.first()?;
+        let (resource_type, name) = &section.types[0];
+        if section.namespace.eq_ignore_ascii_case(MANAGEMENT_NAMESPACE)
+            && resource_type.eq_ignore_ascii_case(MANAGEMENT_GROUPS)
+        {
+            Some(name)
+        } else {
+            None
+        }
+    }
+
+    /// The namespace of the resource provider, e.g. `Microsoft.Storage`.
+    pub fn provider_namespace(&self) -> Option<&str> {
+        self.providers
+            .last()
+            .map(|section| section.namespace.as_str())
+    }
+
+    /// The full type of the resource, e.g.
+    /// `Microsoft.Storage/storageAccounts/blobServices`.
+    pub fn resource_type(&self) -> Option<String> {
+        let section = self.providers.last()?;
+        let mut resource_type = section.namespace.clone();
+        for (child_type, _) in &section.types {
+            resource_type.push('/');
+            resource_type.push_str(child_type);
+        }
+        Some(resource_type)
+    }
+
+    /// The name of the resource, the last segment of the ID.
+    pub fn name(&self) -> Option<&str> {
+        self.providers
+            .last()
+            .and_then(|section| section.types.last())
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// The names of the resource and of its parents, from the top level
+    /// one: the arguments the generated operations take after the resource
+    /// group, e.g. `["account", "default", "images"]` for a blob container.
+    pub fn names(&self) -> Vec<&str> {
+        self.providers
+            .last()
+            .map(|section| {
+                section
+                    .types
+                    .iter()
+                    .map(|(_, name)| name.as_str())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// The name following the `resource_type` segment (ignoring its case),
+    /// e.g. the account of a blob container with `"storageAccounts"`.
+    pub fn name_of(&self, resource_type: &str) -> Option<&str> {
+        self.providers
+            .iter()
+            .rev()
+            .flat_map(|section| section.types.iter().rev())
+            .find(|(child_type, _)| child_type.eq_ignore_ascii_case(resource_type))
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// The subscription and the resource group of the ID, the arguments of
+    /// the operations on the resources of a resource group.
+    pub fn resource_group_scope(&self) -> Result<(&str, &str), ResourceIdParseError> {
+        match (self.subscription_id(), self.resource_group_name()) {
+            (Some(subscription_id), Some(resource_group_name)) => {
+                Ok((subscription_id, resource_group_name))
+            }
+            (None, _) => Err(ResourceIdParseError::MissingSegment(SUBSCRIPTIONS)),
+            (Some(_), None) => Err(ResourceIdParseError::MissingSegment(RESOURCE_GROUPS)),
+        }
+    }
+
+    /// Whether this is an extension resource, see [`scope`](Self::scope).
+    pub fn is_extension(&self) -> bool {
+        self.providers.len() > 1
+    }
+
+    /// What the resource lives in: the resource an extension resource
+    /// extends, the resource group, subscription or tenant of the others.
+    /// `None` if this is not a resource.
+    pub fn scope(&self) -> Option<ResourceId> {
+        let mut scope = self.clone();
+        scope.providers.pop()?;
+        Some(scope)
+    }
+
+    /// The resource (or scope) containing this one: the parent of a child
+    /// resource, the scope of a top level one, the subscription of a resource
+    /// group... `None` for the tenant.
+    pub fn parent(&self) -> Option<ResourceId> {
+        let mut parent = self.clone();
+        match parent.providers.last_mut() {
+            Some(section) if section.types.len() > 1 => {
+                section.types.pop();
+            }
+            Some(_) => {
+                parent.providers.pop();
+            }
+            None if parent.resource_group_name.is_some() => parent.resource_group_name = None,
+            None if parent.subscription_id.is_some() => parent.subscription_id = None,
+            None => return None,
+        }
+        Some(parent)
+    }
+}
+
+impl fmt::Display for ResourceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(subscription_id) = &self.subscription_id {
+            write!(f, "/{}/{}", SUBSCRIPTIONS, subscription_id)?;
+        }
+        if let Some(resource_group_name) = &self.resource_group_name {
+            write!(f, "/{}/{}", RESOURCE_GROUPS, resource_group_name)?;
+        }
+        for section in &self.providers {
+            write!(f, "/{}/{}", PROVIDERS, section.namespace)?;
+            for (resource_type, name) in &section.types {
+                write!(f, "/{}/{}", resource_type, name)?;
+            }
+        }
+        if self.subscription_id.is_none() && self.providers.is_empty() {
+            write!(f, "/")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ResourceId {
+    type Err = ResourceIdParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let path = s
+            .strip_prefix('/')
+            .ok_or(ResourceIdParseError::MissingLeadingSlash)?;
+        let path = path.strip_suffix('/').unwrap_or(path);
+        let mut id = ResourceId::tenant();
+        if path.is_empty() {
+            return Ok(id);
+        }
+
+        let mut segments = path.split('/').peekable();
+        if segments.clone().any(str::is_empty) {
+            return Err(ResourceIdParseError::EmptySegment);
+        }
+        if let Some(segment) = segments.peek().copied() {
+            if segment.eq_ignore_ascii_case(SUBSCRIPTIONS) {
+                segments.next();
+                id.subscription_id = Some(value_after(segment, &mut segments)?);
+                if let Some(segment) = segments.peek().copied() {
+                    if segment.eq_ignore_ascii_case(RESOURCE_GROUPS) {
+                        segments.next();
+                        id.resource_group_name = Some(value_after(segment, &mut segments)?);
+                    }
+                }
+            }
+        }
+
+        while let Some(segment) = segments.next() {
+            if !segment.eq_ignore_ascii_case(PROVIDERS) {
+                return Err(ResourceIdParseError::UnexpectedSegment(segment.to_owned()));
+            }
+            let namespace = value_after(segment, &mut segments)?;
+            let mut types = Vec::new();
+            while let Some(resource_type) = segments.peek().copied() {
+                if resource_type.eq_ignore_ascii_case(PROVIDERS) && !types.is_empty() {
+                    break;
+                }
+                segments.next();
+                let name = value_after(resource_type, &mut segments)?;
+                types.push((resource_type.to_owned(), name));
+            }
+            if types.is_empty() {
+                return Err(ResourceIdParseError::MissingValue(namespace));
+            }
+            id.providers.push(ProviderSection { namespace, types });
+        }
+        Ok(id)
+    }
+}
+
+/// The segment following `key`.
+fn value_after<'a, I>(key: &str, segments: &mut I) -> Result<String, ResourceIdParseError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    segments
+        .next()
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| ResourceIdParseError::MissingValue(key.to_owned()))
+}
+
+impl std::convert::TryFrom<&str> for ResourceId {
+    type Error = ResourceIdParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUBSCRIPTION: &str = "00000000-0000-0000-0000-000000000000";
+
+    fn parse(s: &str) -> ResourceId {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_a_child_resource() {
+        let s = format!("/subscriptions/{}/resourceGroups/rg/providers/Microsoft.Storage/storageAccounts/account/blobServices/default", SUBSCRIPTION);
+        let id = parse(&s);
+
+        assert_eq!(id.subscription_id(), Some(SUBSCRIPTION));
+        assert_eq!(id.resource_group_scope().unwrap(), (SUBSCRIPTION, "rg"));
+        assert_eq!(id.provider_namespace(), Some("Microsoft.Storage"));
+        assert_eq!(
+            id.resource_type().unwrap(),
+            "Microsoft.Storage/storageAccounts/blobServices"
+        );
+        assert_eq!(id.name(), Some("default"));
+        assert_eq!(id.names(), vec!["account", "default"]);
+        assert_eq!(id.name_of("storageaccounts"), Some("account"));
+        assert!(!id.is_extension());
+        assert_eq!(id.to_string(), s);
+        assert_eq!(
+            id,
+            ResourceId::resource_group(SUBSCRIPTION, "rg")
+                .with_resource("Microsoft.Storage", "storageAccounts", "account")
+                .with_child("blobServices", "default")
+        );
+    }
+
+    #[test]
+    fn parses_an_extension_resource() {
+        let vm = ResourceId::resource_group(SUBSCRIPTION, "rg").with_resource(
+            "Microsoft.Compute",
+            "virtualMachines",
+            "vm",
+        );
+        let id = parse(&format!(
+            "/subscriptions/{}/resourcegroups/rg/providers/Microsoft.Compute/virtualMachines/vm/providers/Microsoft.Authorization/locks/lock/",
+            SUBSCRIPTION
+        ));
+
+        assert!(id.is_extension());
+        assert_eq!(id.resource_type().unwrap(), "Microsoft.Authorization/locks");
+        assert_eq!(id.name(), Some("lock"));
+        assert_eq!(id.name_of("virtualMachines"), Some("vm"));
+        assert_eq!(id.scope(), Some(vm.clone()));
+        assert_eq!(id.parent(), Some(vm.clone()));
+        assert_eq!(
+            id,
+            vm.with_resource("Microsoft.Authorization", "locks", "lock")
+        );
+    }
+
+    #[test]
+    fn parses_the_scopes() {
+        let id = parse("/providers/Microsoft.Management/managementGroups/mg/providers/Microsoft.Authorization/policyDefinitions/policy");
+        assert_eq!(id.management_group_name(), Some("mg"));
+        assert_eq!(id.subscription_id(), None);
+        assert_eq!(id.scope(), Some(ResourceId::management_group("mg")));
+
+        let id = parse(&format!("/subscriptions/{}", SUBSCRIPTION));
+        assert_eq!(id, ResourceId::subscription(SUBSCRIPTION));
+        assert_eq!(id.resource_type(), None);
+        assert_eq!(
+            id.resource_group_scope(),
+            Err(ResourceIdParseError::MissingSegment("resourceGroups"))
+        );
+        assert_eq!(id.parent(), Some(ResourceId::tenant()));
+        assert_eq!(ResourceId::tenant().parent(), None);
+        assert_eq!(parse("/"), ResourceId::tenant());
+        assert_eq!(ResourceId::tenant().to_string(), "/");
+
+        let id = parse("/providers/Microsoft.Billing/billingAccounts/account");
+        assert_eq!(
+            id.to_string(),
+            "/providers/Microsoft.Billing/billingAccounts/account"
+        );
+        assert_eq!(id.management_group_name(), None);
+    }
+
+    #[test]
+    fn walks_up_the_parents() {
+        let id = ResourceId::resource_group(SUBSCRIPTION, "rg")
+            .with_resource("Microsoft.Storage", "storageAccounts", "account")
+            .with_child("blobServices", "default");
+        let parents: Vec<String> = std::iter::successors(id.parent(), ResourceId::parent)
+            .map(|parent| parent.to_string())
+            .collect();
+
+        assert_eq!(
+            parents,
+            vec![
+                format!("/subscriptions/{}/resourceGroups/rg/providers/Microsoft.Storage/storageAccounts/account", SUBSCRIPTION),
+                format!("/subscriptions/{}/resourceGroups/rg", SUBSCRIPTION),
+                format!("/subscriptions/{}", SUBSCRIPTION),
+                "/".to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_ids() {
+        let err = |s: &str| s.parse::<ResourceId>().unwrap_err();
+
+        assert_eq!(
+            err("subscriptions/sub"),
+            ResourceIdParseError::MissingLeadingSlash
+        );
+        assert_eq!(
+            err("/subscriptions//resourceGroups/rg"),
+            ResourceIdParseError::EmptySegment
+        );
+        assert_eq!(
+            err("/subscriptions"),
+            ResourceIdParseError::MissingValue("subscriptions".to_owned())
+        );
+        assert_eq!(
+            err("/subscriptions/sub/resourceGroups/rg/providers/Microsoft.Storage/storageAccounts"),
+            ResourceIdParseError::MissingValue("storageAccounts".to_owned())
+        );
+        assert_eq!(
+            err("/subscriptions/sub/resourceGroups/rg/providers/Microsoft.Storage"),
+            ResourceIdParseError::MissingValue("Microsoft.Storage".to_owned())
+        );
+        assert_eq!(
+            err("/subscriptions/sub/locations/westus"),
+            ResourceIdParseError::UnexpectedSegment("locations".to_owned())
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::errors::AzureError;
+use bytes::{Bytes, BytesMut};
+use futures::{Future, Stream, StreamExt};
+use http::{HeaderMap, StatusCode};
+use std::pin::Pin;
+
+pub type PinnedStream = Pin<Box<dyn Stream<Item = Result<Bytes, AzureError>> + Send>>;
+
+/// The future returned by clients that hand a request to a pipeline without
+/// awaiting it.
+pub type ResponseFuture = Pin<Box<dyn Future<Output = Result<Response, AzureError>> + Send>>;
+
+/// A response coming back from a [`Pipeline`](crate::pipeline::Pipeline).
+///
+/// The body is a stream so large payloads do not have to be buffered in
+/// memory. Use `into_body` to collect it.
+pub struct Response {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: PinnedStream,
+}
+
+impl Response {
+    pub fn new(status: StatusCode, headers: HeaderMap, body: PinnedStream) -> Self {
+        Self {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn deconstruct(self) -> (StatusCode, HeaderMap, PinnedStream) {
+        (self.status, self.headers, self.body)
+    }
+
+    pub async fn into_body(self) -> Result<Bytes, AzureError> {
+        collect_pinned_stream(self.body).await
+    }
+}
+
+impl std::fmt::Debug for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Response")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .field("body", &"<BODY>")
+            .finish()
+    }
+}
+
+impl From<http::Response<PinnedStream>> for Response {
+    fn from(response: http::Response<PinnedStream>) -> Self {
+        let (parts, body) = response.into_parts();
+        Self::new(parts.status, parts.headers, body)
+    }
+}
+
+#[cfg(feature = "enable_hyper")]
+impl From<hyper::Response<hyper::Body>> for Response {
+    fn from(response: hyper::Response<hyper::Body>) -> Self {
+        let (parts, body) = response.into_parts();
+        let body: PinnedStream = Box::pin(body.map(|chunk| chunk.map_err(AzureError::from)));
+        Self::new(parts.status, parts.headers, body)
+    }
+}
+
+/// Reads a body stream to its end.
+pub async fn collect_pinned_stream(mut stream: PinnedStream) -> Result<Bytes, AzureError> {
+    let mut body = BytesMut::new();
+    while let Some(chunk) = stream.next().await {
+        body.extend_from_slice(&chunk?);
+    }
+    Ok(body.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn into_body_concatenates_chunks() {
+        let chunks: Vec<Result<Bytes, AzureError>> =
+            vec![Ok(Bytes::from("hello ")), Ok(Bytes::from("world"))];
+        let response = Response::new(
+            StatusCode::OK,
+            HeaderMap::new(),
+            Box::pin(futures::stream::iter(chunks)),
+        );
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.into_body().await.unwrap(),
+            Bytes::from("hello world")
+        );
+    }
+}
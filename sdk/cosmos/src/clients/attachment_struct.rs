@@ -37,7 +37,7 @@ where
     }
 }
 
-impl<'a, C, D, COLL, DOC> HasHyperClient for AttachmentStruct<'a, C, D, COLL, DOC>
+impl<'a, C, D, COLL, DOC> HasPipeline for AttachmentStruct<'a, C, D, COLL, DOC>
 where
     C: CosmosClient + Clone,
     D: DatabaseClient<C> + Clone,
@@ -45,10 +45,8 @@ where
     DOC: DocumentClient<C, D, COLL> + Clone,
 {
     #[inline]
-    fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
-        self.document_client().hyper_client()
+    fn pipeline(&self) -> &azure_core::pipeline::Pipeline {
+        self.document_client().pipeline()
     }
 }
 
@@ -2,7 +2,7 @@ use crate::clients::*;
 use crate::requests;
 use crate::{
     CollectionClient, CosmosClient, DatabaseClient, HasCosmosClient, HasDatabaseClient,
-    HasHyperClient, IntoDocumentClient, IntoStoredProcedureClient, IntoTriggerClient,
+    HasPipeline, IntoDocumentClient, IntoStoredProcedureClient, IntoTriggerClient,
     IntoUserDefinedFunctionClient, PartitionKeys, UserDefinedFunctionStruct, WithDocumentClient,
     WithStoredProcedureClient, WithTriggerClient, WithUserDefinedFunctionClient,
 };
@@ -36,16 +36,14 @@ where
     }
 }
 
-impl<'a, C, D> HasHyperClient for CollectionStruct<'a, C, D>
+impl<'a, C, D> HasPipeline for CollectionStruct<'a, C, D>
 where
     C: CosmosClient + Clone,
     D: DatabaseClient<C> + Clone,
 {
     #[inline]
-    fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
-        self.cosmos_client().hyper_client()
+    fn pipeline(&self) -> &azure_core::pipeline::Pipeline {
+        self.cosmos_client().pipeline()
     }
 }
 
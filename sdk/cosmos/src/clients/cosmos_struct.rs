@@ -2,11 +2,13 @@ use crate::clients::DatabaseStruct;
 use crate::headers::*;
 use crate::requests;
 use crate::{
-    AuthorizationToken, CosmosClient, HasHyperClient, IntoDatabaseClient, ResourceType,
+    AuthorizationToken, CosmosClient, HasPipeline, IntoDatabaseClient, ResourceType,
     WithDatabaseClient,
 };
 use azure_core::errors::AzureError;
-use azure_core::No;
+use azure_core::pipeline::Pipeline;
+use azure_core::policies::{RetryOptions, TransportPolicy};
+use azure_core::{AzureEnvironment, No};
 use base64;
 use chrono;
 use http::request::Builder as RequestBuilder;
@@ -14,10 +16,10 @@ use hyper::{
     self,
     header::{self, HeaderValue},
 };
-use hyper_rustls::HttpsConnector;
 use ring::hmac;
 use std::borrow::Cow;
 use std::fmt::Debug;
+use std::sync::Arc;
 use url::form_urlencoded;
 
 const AZURE_VERSION: &str = "2018-12-31";
@@ -33,7 +35,7 @@ pub struct CosmosStruct<'a, CUB>
 where
     CUB: CosmosUriBuilder,
 {
-    hyper_client: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+    pipeline: Pipeline,
     account: Cow<'a, str>,
     auth_token: AuthorizationToken,
     cosmos_uri_builder: CUB,
@@ -43,14 +45,37 @@ impl<'a, CUB> CosmosStruct<'a, CUB>
 where
     CUB: CosmosUriBuilder + Clone,
 {
+    /// Replaces the pipeline the requests of this client are sent through.
+    pub fn with_pipeline(self, pipeline: Pipeline) -> Self {
+        Self { pipeline, ..self }
+    }
+
     pub fn with_auth_token(&self, auth_token: AuthorizationToken) -> Self {
         Self {
-            hyper_client: self.hyper_client.clone(),
+            pipeline: self.pipeline.clone(),
             account: self.account.clone(),
             auth_token,
             cosmos_uri_builder: self.cosmos_uri_builder.clone(),
         }
     }
+
+    /// Points the client to the account endpoint in another Azure cloud.
+    pub fn with_environment(
+        self,
+        environment: &AzureEnvironment,
+    ) -> CosmosStruct<'a, CustomCosmosUri> {
+        let uri = format!(
+            "https://{}.{}",
+            self.account,
+            environment.cosmos_endpoint_suffix()
+        );
+        CosmosStruct {
+            pipeline: self.pipeline,
+            account: self.account,
+            auth_token: self.auth_token,
+            cosmos_uri_builder: CustomCosmosUri { uri },
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -61,7 +86,11 @@ pub struct DefaultCosmosUri {
 impl DefaultCosmosUri {
     fn new(account: &str) -> DefaultCosmosUri {
         DefaultCosmosUri {
-            uri: format!("https://{}.documents.azure.com", account),
+            uri: format!(
+                "https://{}.{}",
+                account,
+                AzureEnvironment::public().cosmos_endpoint_suffix()
+            ),
         }
     }
 }
@@ -80,7 +109,11 @@ pub struct ChinaCosmosUri {
 impl ChinaCosmosUri {
     fn new(account: &str) -> ChinaCosmosUri {
         ChinaCosmosUri {
-            uri: format!("https://{}.documents.azure.cn", account),
+            uri: format!(
+                "https://{}.{}",
+                account,
+                AzureEnvironment::china().cosmos_endpoint_suffix()
+            ),
         }
     }
 }
@@ -103,6 +136,19 @@ impl CosmosUriBuilder for CustomCosmosUri {
     }
 }
 
+/// The pipeline used by the Cosmos clients unless one is supplied with
+/// `CosmosStruct::with_pipeline`.
+pub fn default_pipeline() -> Pipeline {
+    Pipeline::new(
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        Vec::new(),
+        RetryOptions::default(),
+        Vec::new(),
+        Arc::new(TransportPolicy::default()),
+    )
+}
+
 pub struct ClientBuilder {}
 
 impl ClientBuilder {
@@ -115,11 +161,11 @@ impl ClientBuilder {
         IntoCowStr: Into<Cow<'a, str>>,
     {
         let account = account.into();
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        let pipeline = default_pipeline();
         let cosmos_uri_builder = DefaultCosmosUri::new(account.as_ref());
 
         Ok(CosmosStruct {
-            hyper_client: client,
+            pipeline,
             account: account,
             auth_token,
             cosmos_uri_builder,
@@ -134,11 +180,11 @@ impl ClientBuilder {
         IntoCowStr: Into<Cow<'a, str>>,
     {
         let account = account.into();
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        let pipeline = default_pipeline();
         let cosmos_uri_builder = ChinaCosmosUri::new(account.as_ref());
 
         Ok(CosmosStruct {
-            hyper_client: client,
+            pipeline,
             account,
             auth_token,
             cosmos_uri_builder,
@@ -153,10 +199,10 @@ impl ClientBuilder {
     where
         IntoCowStr: Into<Cow<'a, str>>,
     {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        let pipeline = default_pipeline();
 
         Ok(CosmosStruct {
-            hyper_client: client,
+            pipeline,
             account: account.into(),
             auth_token,
             cosmos_uri_builder: CustomCosmosUri { uri },
@@ -167,7 +213,7 @@ impl ClientBuilder {
         address: &str,
         port: u16,
     ) -> Result<CosmosStruct<CustomCosmosUri>, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        let pipeline = default_pipeline();
 
         //Account name: localhost:<port>
         //Account key: C2y6yDjf5/R+ob0N8A7Cgv30VRDJIWEHLM+4QDU5DE2nQ9nDuVTqobD4b8mGGyPMbIZnqyMsEcaGQy67XIw/Jw==
@@ -175,7 +221,7 @@ impl ClientBuilder {
             "C2y6yDjf5/R+ob0N8A7Cgv30VRDJIWEHLM+4QDU5DE2nQ9nDuVTqobD4b8mGGyPMbIZnqyMsEcaGQy67XIw/Jw==",
         ).unwrap();
         Ok(CosmosStruct {
-            hyper_client: client,
+            pipeline,
             account: Cow::Owned(format!("{}:{}", address, port)),
             auth_token,
             cosmos_uri_builder: CustomCosmosUri {
@@ -185,13 +231,13 @@ impl ClientBuilder {
     }
 }
 
-impl<'a, CUB> HasHyperClient for CosmosStruct<'a, CUB>
+impl<'a, CUB> HasPipeline for CosmosStruct<'a, CUB>
 where
     CUB: CosmosUriBuilder + Debug,
 {
     #[inline]
-    fn hyper_client(&self) -> &hyper::Client<HttpsConnector<hyper::client::HttpConnector>> {
-        &self.hyper_client
+    fn pipeline(&self) -> &azure_core::pipeline::Pipeline {
+        &self.pipeline
     }
 }
 
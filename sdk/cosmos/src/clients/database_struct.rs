@@ -26,15 +26,13 @@ where
     }
 }
 
-impl<'a, C> HasHyperClient for DatabaseStruct<'a, C>
+impl<'a, C> HasPipeline for DatabaseStruct<'a, C>
 where
     C: CosmosClient + Clone,
 {
     #[inline]
-    fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
-        self.cosmos_client().hyper_client()
+    fn pipeline(&self) -> &azure_core::pipeline::Pipeline {
+        self.cosmos_client().pipeline()
     }
 }
 
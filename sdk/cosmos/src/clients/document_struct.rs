@@ -1,7 +1,7 @@
 use crate::requests;
 use crate::{
     AttachmentStruct, CollectionClient, CosmosClient, DatabaseClient, DocumentClient,
-    HasCollectionClient, HasCosmosClient, HasDatabaseClient, HasHyperClient, IntoAttachmentClient,
+    HasCollectionClient, HasCosmosClient, HasDatabaseClient, HasPipeline, IntoAttachmentClient,
     PartitionKeys, WithAttachmentClient,
 };
 use std::borrow::Cow;
@@ -42,17 +42,15 @@ where
     }
 }
 
-impl<'a, 'b, C, D, COLL> HasHyperClient for DocumentStruct<'a, 'b, C, D, COLL>
+impl<'a, 'b, C, D, COLL> HasPipeline for DocumentStruct<'a, 'b, C, D, COLL>
 where
     C: CosmosClient + Clone,
     D: DatabaseClient<C> + Clone,
     COLL: CollectionClient<C, D> + Clone,
 {
     #[inline]
-    fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
-        self.collection_client().hyper_client()
+    fn pipeline(&self) -> &azure_core::pipeline::Pipeline {
+        self.collection_client().pipeline()
     }
 }
 
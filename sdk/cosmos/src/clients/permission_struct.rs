@@ -33,17 +33,15 @@ where
     }
 }
 
-impl<'a, C, D, USER> HasHyperClient for PermissionStruct<'a, C, D, USER>
+impl<'a, C, D, USER> HasPipeline for PermissionStruct<'a, C, D, USER>
 where
     C: CosmosClient + Clone,
     D: DatabaseClient<C> + Clone,
     USER: UserClient<C, D> + Clone,
 {
     #[inline]
-    fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
-        self.user_client.hyper_client()
+    fn pipeline(&self) -> &azure_core::pipeline::Pipeline {
+        self.user_client.pipeline()
     }
 }
 
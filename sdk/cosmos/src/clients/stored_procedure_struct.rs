@@ -36,17 +36,15 @@ where
     }
 }
 
-impl<'a, C, D, COLL> HasHyperClient for StoredProcedureStruct<'a, C, D, COLL>
+impl<'a, C, D, COLL> HasPipeline for StoredProcedureStruct<'a, C, D, COLL>
 where
     C: CosmosClient + Clone,
     D: DatabaseClient<C> + Clone,
     COLL: CollectionClient<C, D> + Clone,
 {
     #[inline]
-    fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
-        self.collection_client.hyper_client()
+    fn pipeline(&self) -> &azure_core::pipeline::Pipeline {
+        self.collection_client.pipeline()
     }
 }
 
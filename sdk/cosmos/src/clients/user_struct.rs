@@ -31,16 +31,14 @@ where
     }
 }
 
-impl<'a, C, D> HasHyperClient for UserStruct<'a, C, D>
+impl<'a, C, D> HasPipeline for UserStruct<'a, C, D>
 where
     C: CosmosClient + Clone,
     D: DatabaseClient<C> + Clone,
 {
     #[inline]
-    fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
-        self.database_client().hyper_client()
+    fn pipeline(&self) -> &azure_core::pipeline::Pipeline {
+        self.database_client().pipeline()
     }
 }
 
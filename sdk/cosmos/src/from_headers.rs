@@ -2,15 +2,18 @@ use crate::headers::*;
 use crate::resource_quota::resource_quotas_from_str;
 use crate::{IndexingDirective, ResourceQuota};
 use azure_core::errors::AzureError;
+use azure_core::instrumentation::record_request_charge;
 use chrono::{DateTime, Utc};
 use http::HeaderMap;
 
 pub(crate) fn request_charge_from_headers(headers: &HeaderMap) -> Result<f64, AzureError> {
-    Ok(headers
+    let request_charge = headers
         .get(HEADER_REQUEST_CHARGE)
         .ok_or_else(|| AzureError::HeaderNotFound(HEADER_REQUEST_CHARGE.to_owned()))?
         .to_str()?
-        .parse()?)
+        .parse()?;
+    record_request_charge(request_charge);
+    Ok(request_charge)
 }
 
 //pub(crate) fn request_item_count_from_headers(headers: &HeaderMap) -> Result<u32, AzureError> {
@@ -4,6 +4,7 @@ use crate::prelude::*;
 use crate::responses::CreateCollectionResponse;
 use crate::{Offer, ResourceType};
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -469,40 +470,43 @@ where
     C: CosmosClient,
 {
     pub async fn execute(&self) -> Result<CreateCollectionResponse, AzureError> {
-        trace!("CreateCollectionBuilder::execute called");
-
-        let mut req = self.database_client.cosmos_client().prepare_request(
-            &format!("dbs/{}/colls", self.database_client.database_name()),
-            hyper::Method::POST,
-            ResourceType::Collections,
-        );
-
-        req = req.header(http::header::CONTENT_TYPE, "application/json");
-
-        // add trait headers
-        let req = OfferRequired::add_header(self, req);
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
-
-        let mut collection = Collection::new(
-            self.collection_name().name(),
-            self.indexing_policy().to_owned(),
-        );
-        collection.parition_key = self.partition_key().to_owned();
-
-        let body = serde_json::to_string(&collection)?;
-        debug!("body == {}", body);
-
-        let req = req.body(hyper::Body::from(body))?;
-        debug!("\nreq == {:?}", req);
-
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.database_client.hyper_client().request(req),
-            StatusCode::CREATED,
-        )
-        .await?;
-
-        Ok((&headers, &body as &[u8]).try_into()?)
+        instrument("CreateCollectionBuilder::execute", async move {
+            trace!("CreateCollectionBuilder::execute called");
+
+            let mut req = self.database_client.cosmos_client().prepare_request(
+                &format!("dbs/{}/colls", self.database_client.database_name()),
+                hyper::Method::POST,
+                ResourceType::Collections,
+            );
+
+            req = req.header(http::header::CONTENT_TYPE, "application/json");
+
+            // add trait headers
+            let req = OfferRequired::add_header(self, req);
+            let req = UserAgentOption::add_header(self, req);
+            let req = ActivityIdOption::add_header(self, req);
+            let req = ConsistencyLevelOption::add_header(self, req);
+
+            let mut collection = Collection::new(
+                self.collection_name().name(),
+                self.indexing_policy().to_owned(),
+            );
+            collection.parition_key = self.partition_key().to_owned();
+
+            let body = serde_json::to_string(&collection)?;
+            debug!("body == {}", body);
+
+            let req = req.body(bytes::Bytes::from(body))?;
+            debug!("\nreq == {:?}", req);
+
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.database_client.pipeline().execute(req),
+                StatusCode::CREATED,
+            )
+            .await?;
+
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
@@ -2,6 +2,7 @@ use crate::prelude::*;
 use crate::responses::CreateDatabaseResponse;
 use crate::ResourceType;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -151,35 +152,38 @@ where
 // methods callable only when every mandatory field has been filled
 impl<'a> CreateDatabaseBuilder<'a, Yes> {
     pub async fn execute(&self) -> Result<CreateDatabaseResponse, AzureError> {
-        trace!("CreateDatabaseBuilder::execute called");
+        instrument("CreateDatabaseBuilder::execute", async move {
+            trace!("CreateDatabaseBuilder::execute called");
 
-        #[derive(Serialize, Debug)]
-        struct CreateDatabaseRequest<'a> {
-            pub id: &'a str,
-        }
+            #[derive(Serialize, Debug)]
+            struct CreateDatabaseRequest<'a> {
+                pub id: &'a str,
+            }
 
-        let req = serde_json::to_string(&CreateDatabaseRequest {
-            id: self.database_name().name(),
-        })?;
+            let req = serde_json::to_string(&CreateDatabaseRequest {
+                id: self.database_name().name(),
+            })?;
 
-        let request = self.cosmos_client().prepare_request(
-            "dbs",
-            hyper::Method::POST,
-            ResourceType::Databases,
-        );
+            let request = self.cosmos_client().prepare_request(
+                "dbs",
+                hyper::Method::POST,
+                ResourceType::Databases,
+            );
 
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
+            let request = UserAgentOption::add_header(self, request);
+            let request = ActivityIdOption::add_header(self, request);
+            let request = ConsistencyLevelOption::add_header(self, request);
 
-        let request = request.body(hyper::Body::from(req))?; // todo: set content-length here and elsewhere without builders
+            let request = request.body(bytes::Bytes::from(req))?; // todo: set content-length here and elsewhere without builders
 
-        debug!("create database request prepared == {:?}", request);
+            debug!("create database request prepared == {:?}", request);
 
-        let future_response = self.cosmos_client().hyper_client().request(request);
-        let (headers, body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+            let future_response = self.cosmos_client().pipeline().execute(request);
+            let (headers, body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
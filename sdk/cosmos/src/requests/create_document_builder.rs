@@ -1,7 +1,8 @@
 use crate::prelude::*;
 use crate::responses::CreateDocumentResponse;
 use crate::ResourceType;
-use azure_core::errors::{extract_status_headers_and_body, AzureError, UnexpectedHTTPResult};
+use azure_core::errors::{extract_status_headers_and_body, AzureError, HttpError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use chrono::{DateTime, Utc};
@@ -449,56 +450,61 @@ where
     where
         T: Serialize,
     {
-        let mut req = self.collection_client.cosmos_client().prepare_request(
-            &format!(
-                "dbs/{}/colls/{}/docs",
-                self.collection_client.database_client().database_name(),
-                self.collection_client.collection_name()
-            ),
-            hyper::Method::POST,
-            ResourceType::Documents,
-        );
-
-        // add trait headers
-        req = IfMatchConditionOption::add_header(self, req);
-        req = IfModifiedSinceOption::add_header(self, req);
-        req = UserAgentOption::add_header(self, req);
-        req = ActivityIdOption::add_header(self, req);
-        req = ConsistencyLevelOption::add_header(self, req);
-        req = PartitionKeysRequired::add_header(self, req);
-        req = IsUpsertOption::add_header(self, req);
-        req = IndexingDirectiveOption::add_header(self, req);
-        req = AllowTentativeWritesOption::add_header(self, req);
-
-        let serialized = serde_json::to_string(document)?;
-        let req = req.body(hyper::Body::from(serialized))?;
-
-        let (status_code, headers, whole_body) =
-            extract_status_headers_and_body(self.collection_client.hyper_client().request(req))
-                .await?;
-
-        debug!("status_core == {:?}", status_code);
-        debug!("headers == {:?}", headers);
-        debug!("whole body == {:#?}", whole_body);
-
-        // expect CREATED is IsUpsert is off. Otherwise either
-        // CREATED or OK means success.
-        if !self.is_upsert() && status_code != StatusCode::CREATED {
-            return Err(UnexpectedHTTPResult::new(
-                StatusCode::CREATED,
-                status_code,
-                std::str::from_utf8(&whole_body)?,
-            )
-            .into());
-        } else if status_code != StatusCode::CREATED && status_code != StatusCode::OK {
-            return Err(UnexpectedHTTPResult::new_multiple(
-                vec![StatusCode::CREATED, StatusCode::OK],
-                status_code,
-                std::str::from_utf8(&whole_body)?,
-            )
-            .into());
-        }
-
-        CreateDocumentResponse::try_from((status_code, &headers, &whole_body as &[u8]))
+        instrument("CreateDocumentBuilder::execute_with_document", async move {
+            let mut req = self.collection_client.cosmos_client().prepare_request(
+                &format!(
+                    "dbs/{}/colls/{}/docs",
+                    self.collection_client.database_client().database_name(),
+                    self.collection_client.collection_name()
+                ),
+                hyper::Method::POST,
+                ResourceType::Documents,
+            );
+
+            // add trait headers
+            req = IfMatchConditionOption::add_header(self, req);
+            req = IfModifiedSinceOption::add_header(self, req);
+            req = UserAgentOption::add_header(self, req);
+            req = ActivityIdOption::add_header(self, req);
+            req = ConsistencyLevelOption::add_header(self, req);
+            req = PartitionKeysRequired::add_header(self, req);
+            req = IsUpsertOption::add_header(self, req);
+            req = IndexingDirectiveOption::add_header(self, req);
+            req = AllowTentativeWritesOption::add_header(self, req);
+
+            let serialized = serde_json::to_string(document)?;
+            let req = req.body(bytes::Bytes::from(serialized))?;
+
+            let (status_code, headers, whole_body) =
+                extract_status_headers_and_body(self.collection_client.pipeline().execute(req))
+                    .await?;
+
+            debug!("status_core == {:?}", status_code);
+            debug!("headers == {:?}", headers);
+            debug!("whole body == {:#?}", whole_body);
+
+            // expect CREATED is IsUpsert is off. Otherwise either
+            // CREATED or OK means success.
+            if !self.is_upsert() && status_code != StatusCode::CREATED {
+                return Err(HttpError::new(
+                    StatusCode::CREATED,
+                    status_code,
+                    &headers,
+                    &whole_body,
+                )
+                .into());
+            } else if status_code != StatusCode::CREATED && status_code != StatusCode::OK {
+                return Err(HttpError::new_multiple(
+                    vec![StatusCode::CREATED, StatusCode::OK],
+                    status_code,
+                    &headers,
+                    &whole_body,
+                )
+                .into());
+            }
+
+            CreateDocumentResponse::try_from((status_code, &headers, &whole_body as &[u8]))
+        })
+        .await
     }
 }
@@ -2,6 +2,7 @@ use crate::prelude::*;
 use crate::responses::CreateTriggerResponse;
 use crate::trigger::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -381,52 +382,55 @@ where
     COLL: CollectionClient<C, D>,
 {
     pub async fn execute(&self) -> Result<CreateTriggerResponse, AzureError> {
-        trace!("CreateOrReplaceTriggerBuilder::execute called");
+        instrument("CreateOrReplaceTriggerBuilder::execute", async move {
+            trace!("CreateOrReplaceTriggerBuilder::execute called");
 
-        let req = self.trigger_client;
-        let req = if self.is_create() {
-            req.prepare_request(hyper::Method::POST)
-        } else {
-            req.prepare_request_with_trigger_name(hyper::Method::PUT)
-        };
+            let req = self.trigger_client;
+            let req = if self.is_create() {
+                req.prepare_request(hyper::Method::POST)
+            } else {
+                req.prepare_request_with_trigger_name(hyper::Method::PUT)
+            };
 
-        // add trait headers
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
+            // add trait headers
+            let req = UserAgentOption::add_header(self, req);
+            let req = ActivityIdOption::add_header(self, req);
+            let req = ConsistencyLevelOption::add_header(self, req);
 
-        let req = req.header(http::header::CONTENT_TYPE, "application/json");
+            let req = req.header(http::header::CONTENT_TYPE, "application/json");
 
-        #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
-        struct _Request<'a> {
-            pub id: &'a str,
-            #[serde(rename = "triggerOperation")]
-            pub trigger_operation: TriggerOperation,
-            #[serde(rename = "triggerType")]
-            pub trigger_type: TriggerType,
-            pub body: &'a str,
-        }
+            #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+            struct _Request<'a> {
+                pub id: &'a str,
+                #[serde(rename = "triggerOperation")]
+                pub trigger_operation: TriggerOperation,
+                #[serde(rename = "triggerType")]
+                pub trigger_type: TriggerType,
+                pub body: &'a str,
+            }
 
-        let request = _Request {
-            id: self.trigger_client.trigger_name(),
-            trigger_operation: self.trigger_operation(),
-            trigger_type: self.trigger_type(),
-            body: self.body(),
-        };
+            let request = _Request {
+                id: self.trigger_client.trigger_name(),
+                trigger_operation: self.trigger_operation(),
+                trigger_type: self.trigger_type(),
+                body: self.body(),
+            };
 
-        let request = serde_json::to_string(&request)?;
-        let request = req.body(hyper::Body::from(request))?;
+            let request = serde_json::to_string(&request)?;
+            let request = req.body(bytes::Bytes::from(request))?;
 
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.trigger_client().hyper_client().request(request),
-            if self.is_create() {
-                StatusCode::CREATED
-            } else {
-                StatusCode::OK
-            },
-        )
-        .await?;
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.trigger_client().pipeline().execute(request),
+                if self.is_create() {
+                    StatusCode::CREATED
+                } else {
+                    StatusCode::OK
+                },
+            )
+            .await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::CreateUserDefinedFunctionResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -220,54 +221,60 @@ where
     COLL: CollectionClient<C, D>,
 {
     pub async fn execute(&self) -> Result<CreateUserDefinedFunctionResponse, AzureError> {
-        trace!("CreateOrReplaceUserDefinedFunctionBuilder::execute called");
+        instrument(
+            "CreateOrReplaceUserDefinedFunctionBuilder::execute",
+            async move {
+                trace!("CreateOrReplaceUserDefinedFunctionBuilder::execute called");
 
-        // Create is POST with no name in the URL. Expected return is CREATED.
-        // See https://docs.microsoft.com/en-us/rest/api/cosmos-db/create-a-user-defined-function
-        // Replace is PUT with name appended to the URL. Expected return is OK.
-        // See: https://docs.microsoft.com/en-us/rest/api/cosmos-db/replace-a-user-defined-function
-        let req = match self.is_create {
-            true => self
-                .user_defined_function_client
-                .prepare_request(hyper::Method::POST),
-            false => self
-                .user_defined_function_client
-                .prepare_request_with_user_defined_function_name(hyper::Method::PUT),
-        };
+                // Create is POST with no name in the URL. Expected return is CREATED.
+                // See https://docs.microsoft.com/en-us/rest/api/cosmos-db/create-a-user-defined-function
+                // Replace is PUT with name appended to the URL. Expected return is OK.
+                // See: https://docs.microsoft.com/en-us/rest/api/cosmos-db/replace-a-user-defined-function
+                let req = match self.is_create {
+                    true => self
+                        .user_defined_function_client
+                        .prepare_request(hyper::Method::POST),
+                    false => self
+                        .user_defined_function_client
+                        .prepare_request_with_user_defined_function_name(hyper::Method::PUT),
+                };
 
-        // add trait headers
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
+                // add trait headers
+                let req = UserAgentOption::add_header(self, req);
+                let req = ActivityIdOption::add_header(self, req);
+                let req = ConsistencyLevelOption::add_header(self, req);
 
-        let req = req.header(http::header::CONTENT_TYPE, "application/json");
+                let req = req.header(http::header::CONTENT_TYPE, "application/json");
 
-        #[derive(Debug, Serialize)]
-        struct Request<'a> {
-            body: &'a str,
-            id: &'a str,
-        }
-        let request = Request {
-            body: self.body(),
-            id: self
-                .user_defined_function_client
-                .user_defined_function_name(),
-        };
+                #[derive(Debug, Serialize)]
+                struct Request<'a> {
+                    body: &'a str,
+                    id: &'a str,
+                }
+                let request = Request {
+                    body: self.body(),
+                    id: self
+                        .user_defined_function_client
+                        .user_defined_function_name(),
+                };
+
+                let request = serde_json::to_string(&request)?;
+                let request = req.body(bytes::Bytes::from(request))?;
 
-        let request = serde_json::to_string(&request)?;
-        let request = req.body(hyper::Body::from(request))?;
+                let (headers, body) = check_status_extract_headers_and_body(
+                    self.user_defined_function_client()
+                        .pipeline()
+                        .execute(request),
+                    match self.is_create {
+                        true => StatusCode::CREATED,
+                        false => StatusCode::OK,
+                    },
+                )
+                .await?;
 
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.user_defined_function_client()
-                .hyper_client()
-                .request(request),
-            match self.is_create {
-                true => StatusCode::CREATED,
-                false => StatusCode::OK,
+                Ok((&headers, &body as &[u8]).try_into()?)
             },
         )
-        .await?;
-
-        Ok((&headers, &body as &[u8]).try_into()?)
+        .await
     }
 }
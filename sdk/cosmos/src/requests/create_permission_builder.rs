@@ -3,6 +3,7 @@ use crate::responses::CreatePermissionResponse;
 use crate::ResourceType;
 use crate::{PermissionMode, PermissionResource};
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -199,50 +200,56 @@ where
     where
         R: PermissionResource,
     {
-        trace!("CreatePermissionBuilder::execute called");
-
-        let request = self.permission_client.cosmos_client().prepare_request(
-            &format!(
-                "dbs/{}/users/{}/permissions",
-                self.permission_client.database_client().database_name(),
-                self.permission_client.user_client().user_name().id(),
-            ),
-            hyper::Method::POST,
-            ResourceType::Permissions,
-        );
-
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
-
-        let request = request.header(http::header::CONTENT_TYPE, "application/json");
-
-        #[derive(Serialize, Deserialize)]
-        struct RequestBody<'x> {
-            id: &'x str,
-            #[serde(rename = "permissionMode")]
-            permission_mode: &'x str,
-            resource: &'x str,
-        }
-
-        let (permission_mode, resource) = permission_mode.to_elements();
-
-        let request_body = RequestBody {
-            id: self.permission_client.permission_name(),
-            permission_mode,
-            resource: resource.uri(),
-        };
-        let request_body = serde_json::to_string(&request_body)?;
-
-        let request = request.body(hyper::Body::from(request_body))?;
-        debug!("\nrequest == {:#?}", request);
-
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.permission_client.hyper_client().request(request),
-            StatusCode::CREATED,
+        instrument(
+            "CreatePermissionBuilder::execute_with_permission",
+            async move {
+                trace!("CreatePermissionBuilder::execute called");
+
+                let request = self.permission_client.cosmos_client().prepare_request(
+                    &format!(
+                        "dbs/{}/users/{}/permissions",
+                        self.permission_client.database_client().database_name(),
+                        self.permission_client.user_client().user_name().id(),
+                    ),
+                    hyper::Method::POST,
+                    ResourceType::Permissions,
+                );
+
+                let request = UserAgentOption::add_header(self, request);
+                let request = ActivityIdOption::add_header(self, request);
+                let request = ConsistencyLevelOption::add_header(self, request);
+
+                let request = request.header(http::header::CONTENT_TYPE, "application/json");
+
+                #[derive(Serialize, Deserialize)]
+                struct RequestBody<'x> {
+                    id: &'x str,
+                    #[serde(rename = "permissionMode")]
+                    permission_mode: &'x str,
+                    resource: &'x str,
+                }
+
+                let (permission_mode, resource) = permission_mode.to_elements();
+
+                let request_body = RequestBody {
+                    id: self.permission_client.permission_name(),
+                    permission_mode,
+                    resource: resource.uri(),
+                };
+                let request_body = serde_json::to_string(&request_body)?;
+
+                let request = request.body(bytes::Bytes::from(request_body))?;
+                debug!("\nrequest == {:#?}", request);
+
+                let (headers, body) = check_status_extract_headers_and_body(
+                    self.permission_client.pipeline().execute(request),
+                    StatusCode::CREATED,
+                )
+                .await?;
+
+                Ok((&headers, &body as &[u8]).try_into()?)
+            },
         )
-        .await?;
-
-        Ok((&headers, &body as &[u8]).try_into()?)
+        .await
     }
 }
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::CreateStoredProcedureResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -225,40 +226,41 @@ where
     COLL: CollectionClient<C, D>,
 {
     pub async fn execute(&self) -> Result<CreateStoredProcedureResponse, AzureError> {
-        trace!("CreateStoredProcedureBuilder::execute called");
+        instrument("CreateStoredProcedureBuilder::execute", async move {
+            trace!("CreateStoredProcedureBuilder::execute called");
 
-        let req = self
-            .stored_procedure_client
-            .prepare_request(hyper::Method::POST);
+            let req = self
+                .stored_procedure_client
+                .prepare_request(hyper::Method::POST);
 
-        // add trait headers
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
+            // add trait headers
+            let req = UserAgentOption::add_header(self, req);
+            let req = ActivityIdOption::add_header(self, req);
+            let req = ConsistencyLevelOption::add_header(self, req);
 
-        let req = req.header(http::header::CONTENT_TYPE, "application/json");
+            let req = req.header(http::header::CONTENT_TYPE, "application/json");
 
-        #[derive(Debug, Serialize)]
-        struct Request<'a> {
-            body: &'a str,
-            id: &'a str,
-        }
-        let request = Request {
-            body: self.body(),
-            id: self.stored_procedure_client.stored_procedure_name(),
-        };
+            #[derive(Debug, Serialize)]
+            struct Request<'a> {
+                body: &'a str,
+                id: &'a str,
+            }
+            let request = Request {
+                body: self.body(),
+                id: self.stored_procedure_client.stored_procedure_name(),
+            };
 
-        let request = serde_json::to_string(&request)?;
-        let request = req.body(hyper::Body::from(request))?;
+            let request = serde_json::to_string(&request)?;
+            let request = req.body(bytes::Bytes::from(request))?;
 
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.stored_procedure_client()
-                .hyper_client()
-                .request(request),
-            StatusCode::CREATED,
-        )
-        .await?;
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.stored_procedure_client().pipeline().execute(request),
+                StatusCode::CREATED,
+            )
+            .await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
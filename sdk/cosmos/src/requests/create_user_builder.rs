@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::CreateUserResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -141,34 +142,37 @@ where
     D: DatabaseClient<C>,
 {
     pub async fn execute(&self) -> Result<CreateUserResponse, AzureError> {
-        trace!("CreateUserBuilder::execute called");
-
-        let req = self.user_client.prepare_request(hyper::Method::POST);
-
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
-
-        let req = req.header(http::header::CONTENT_TYPE, "application/json");
-
-        #[derive(Serialize, Deserialize)]
-        struct RequestBody<'x> {
-            id: &'x str,
-        }
-        let request_body = RequestBody {
-            id: self.user_client().user_name(),
-        };
-        let request_body = serde_json::to_string(&request_body)?;
-
-        let req = req.body(hyper::Body::from(request_body))?;
-        debug!("\nreq == {:?}", req);
-
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.user_client.hyper_client().request(req),
-            StatusCode::CREATED,
-        )
-        .await?;
-
-        Ok((&headers, &body as &[u8]).try_into()?)
+        instrument("CreateUserBuilder::execute", async move {
+            trace!("CreateUserBuilder::execute called");
+
+            let req = self.user_client.prepare_request(hyper::Method::POST);
+
+            let req = UserAgentOption::add_header(self, req);
+            let req = ActivityIdOption::add_header(self, req);
+            let req = ConsistencyLevelOption::add_header(self, req);
+
+            let req = req.header(http::header::CONTENT_TYPE, "application/json");
+
+            #[derive(Serialize, Deserialize)]
+            struct RequestBody<'x> {
+                id: &'x str,
+            }
+            let request_body = RequestBody {
+                id: self.user_client().user_name(),
+            };
+            let request_body = serde_json::to_string(&request_body)?;
+
+            let req = req.body(bytes::Bytes::from(request_body))?;
+            debug!("\nreq == {:?}", req);
+
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.user_client.pipeline().execute(req),
+                StatusCode::CREATED,
+            )
+            .await?;
+
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
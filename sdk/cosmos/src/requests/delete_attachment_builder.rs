@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -210,34 +211,37 @@ where
     DOC: DocumentClient<C, D, COLL>,
 {
     pub async fn execute(&self) -> Result<crate::responses::DeleteAttachmentResponse, AzureError> {
-        let mut req = self
-            .attachment_client
-            .prepare_request_with_attachment_name(hyper::Method::DELETE);
-
-        // add trait headers
-        req = IfMatchConditionOption::add_header(self, req);
-        req = UserAgentOption::add_header(self, req);
-        req = ActivityIdOption::add_header(self, req);
-        req = ConsistencyLevelOption::add_header(self, req);
-
-        req = crate::add_partition_keys_header(
-            self.attachment_client.document_client().partition_keys(),
-            req,
-        );
-
-        let req = req.body(hyper::Body::empty())?;
-
-        debug!("req == {:#?}", req);
-
-        let (headers, whole_body) = check_status_extract_headers_and_body(
-            self.attachment_client.hyper_client().request(req),
-            StatusCode::NO_CONTENT,
-        )
-        .await?;
-
-        debug!("\nheaders == {:?}", headers);
-        debug!("\nwhole body == {:#?}", whole_body);
-
-        Ok((&headers, &whole_body as &[u8]).try_into()?)
+        instrument("DeleteAttachmentBuilder::execute", async move {
+            let mut req = self
+                .attachment_client
+                .prepare_request_with_attachment_name(hyper::Method::DELETE);
+
+            // add trait headers
+            req = IfMatchConditionOption::add_header(self, req);
+            req = UserAgentOption::add_header(self, req);
+            req = ActivityIdOption::add_header(self, req);
+            req = ConsistencyLevelOption::add_header(self, req);
+
+            req = crate::add_partition_keys_header(
+                self.attachment_client.document_client().partition_keys(),
+                req,
+            );
+
+            let req = req.body(bytes::Bytes::new())?;
+
+            debug!("req == {:#?}", req);
+
+            let (headers, whole_body) = check_status_extract_headers_and_body(
+                self.attachment_client.pipeline().execute(req),
+                StatusCode::NO_CONTENT,
+            )
+            .await?;
+
+            debug!("\nheaders == {:?}", headers);
+            debug!("\nwhole body == {:#?}", whole_body);
+
+            Ok((&headers, &whole_body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
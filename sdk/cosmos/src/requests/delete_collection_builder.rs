@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::DeleteCollectionResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -143,24 +144,27 @@ where
     D: DatabaseClient<C>,
 {
     pub async fn execute(&self) -> Result<DeleteCollectionResponse, AzureError> {
-        trace!("DeleteCollectionBuilder::execute called");
+        instrument("DeleteCollectionBuilder::execute", async move {
+            trace!("DeleteCollectionBuilder::execute called");
 
-        let request = self
-            .collection_client()
-            .prepare_request_with_collection_name(hyper::Method::DELETE);
+            let request = self
+                .collection_client()
+                .prepare_request_with_collection_name(hyper::Method::DELETE);
 
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
+            let request = UserAgentOption::add_header(self, request);
+            let request = ActivityIdOption::add_header(self, request);
+            let request = ConsistencyLevelOption::add_header(self, request);
 
-        let request = request.body(hyper::Body::empty())?;
+            let request = request.body(bytes::Bytes::new())?;
 
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.collection_client().hyper_client().request(request),
-            StatusCode::NO_CONTENT,
-        )
-        .await?;
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.collection_client().pipeline().execute(request),
+                StatusCode::NO_CONTENT,
+            )
+            .await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
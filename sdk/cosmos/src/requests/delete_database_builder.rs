@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::DeleteDatabaseResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -123,24 +124,28 @@ where
     C: CosmosClient,
 {
     pub async fn execute(&self) -> Result<DeleteDatabaseResponse, AzureError> {
-        trace!("DeleteDatabaseResponse::execute called");
+        instrument("DeleteDatabaseBuilder::execute", async move {
+            trace!("DeleteDatabaseResponse::execute called");
 
-        let request = self
-            .database_client()
-            .prepare_request_with_database_name(hyper::Method::DELETE);
+            let request = self
+                .database_client()
+                .prepare_request_with_database_name(hyper::Method::DELETE);
 
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
+            let request = UserAgentOption::add_header(self, request);
+            let request = ActivityIdOption::add_header(self, request);
+            let request = ConsistencyLevelOption::add_header(self, request);
 
-        let request = request.body(hyper::Body::empty())?;
+            let request = request.body(bytes::Bytes::new())?;
 
-        trace!("request prepared == {:?}", request);
+            trace!("request prepared == {:?}", request);
 
-        let future_response = self.database_client().hyper_client().request(request);
-        let (headers, body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT).await?;
+            let future_response = self.database_client().pipeline().execute(request);
+            let (headers, body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT)
+                    .await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
@@ -2,6 +2,7 @@ use crate::prelude::*;
 use crate::responses::DeleteDocumentResponse;
 use crate::DocumentClientRequired;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::modify_conditions::IfMatchCondition;
 use azure_core::prelude::*;
 use azure_core::{IfMatchConditionOption, IfMatchConditionSupport};
@@ -275,31 +276,34 @@ where
     COLL: CollectionClient<C, D>,
 {
     pub async fn execute(&self) -> Result<DeleteDocumentResponse, AzureError> {
-        trace!("DeleteDocumentBuilder::execute called");
+        instrument("DeleteDocumentBuilder::execute", async move {
+            trace!("DeleteDocumentBuilder::execute called");
 
-        let mut req = self
-            .document_client
-            .prepare_request_with_document_name(hyper::Method::DELETE);
+            let mut req = self
+                .document_client
+                .prepare_request_with_document_name(hyper::Method::DELETE);
 
-        // add trait headers
-        req = IfMatchConditionOption::add_header(self, req);
-        req = IfModifiedSinceOption::add_header(self, req);
-        req = UserAgentOption::add_header(self, req);
-        req = ActivityIdOption::add_header(self, req);
-        req = ConsistencyLevelOption::add_header(self, req);
-        req = AllowTentativeWritesOption::add_header(self, req);
+            // add trait headers
+            req = IfMatchConditionOption::add_header(self, req);
+            req = IfModifiedSinceOption::add_header(self, req);
+            req = UserAgentOption::add_header(self, req);
+            req = ActivityIdOption::add_header(self, req);
+            req = ConsistencyLevelOption::add_header(self, req);
+            req = AllowTentativeWritesOption::add_header(self, req);
 
-        req = crate::add_partition_keys_header(self.document_client.partition_keys(), req);
+            req = crate::add_partition_keys_header(self.document_client.partition_keys(), req);
 
-        let req = req.body(hyper::Body::empty())?;
-        debug!("{:?}", req);
+            let req = req.body(bytes::Bytes::new())?;
+            debug!("{:?}", req);
 
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.document_client.hyper_client().request(req),
-            StatusCode::NO_CONTENT,
-        )
-        .await?;
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.document_client.pipeline().execute(req),
+                StatusCode::NO_CONTENT,
+            )
+            .await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::DeletePermissionResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -155,25 +156,28 @@ where
     USER: UserClient<C, D>,
 {
     pub async fn execute(&self) -> Result<DeletePermissionResponse, AzureError> {
-        trace!("DeletePermissionBuilder::execute called");
+        instrument("DeletePermissionsBuilder::execute", async move {
+            trace!("DeletePermissionBuilder::execute called");
 
-        let request = self
-            .permission_client
-            .prepare_request_with_permission_name(hyper::Method::DELETE);
+            let request = self
+                .permission_client
+                .prepare_request_with_permission_name(hyper::Method::DELETE);
 
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
+            let request = UserAgentOption::add_header(self, request);
+            let request = ActivityIdOption::add_header(self, request);
+            let request = ConsistencyLevelOption::add_header(self, request);
 
-        let request = request.body(hyper::Body::empty())?;
-        debug!("\nrequest == {:#?}", request);
+            let request = request.body(bytes::Bytes::new())?;
+            debug!("\nrequest == {:#?}", request);
 
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.permission_client.hyper_client().request(request),
-            StatusCode::NO_CONTENT,
-        )
-        .await?;
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.permission_client.pipeline().execute(request),
+                StatusCode::NO_CONTENT,
+            )
+            .await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
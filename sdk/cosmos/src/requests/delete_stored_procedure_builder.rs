@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::DeleteStoredProcedureResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -156,27 +157,28 @@ where
     COLL: CollectionClient<C, D>,
 {
     pub async fn execute(&self) -> Result<DeleteStoredProcedureResponse, AzureError> {
-        trace!("DeleteStoredProcedureBuilder::execute called");
+        instrument("DeleteStoredProcedureBuilder::execute", async move {
+            trace!("DeleteStoredProcedureBuilder::execute called");
 
-        let request = self
-            .stored_procedure_client
-            .prepare_request_with_stored_procedure_name(hyper::Method::DELETE);
+            let request = self
+                .stored_procedure_client
+                .prepare_request_with_stored_procedure_name(hyper::Method::DELETE);
 
-        // add trait headers
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
+            // add trait headers
+            let request = UserAgentOption::add_header(self, request);
+            let request = ActivityIdOption::add_header(self, request);
+            let request = ConsistencyLevelOption::add_header(self, request);
 
-        let request = request.body(hyper::Body::empty())?;
+            let request = request.body(bytes::Bytes::new())?;
 
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.stored_procedure_client()
-                .hyper_client()
-                .request(request),
-            StatusCode::NO_CONTENT,
-        )
-        .await?;
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.stored_procedure_client().pipeline().execute(request),
+                StatusCode::NO_CONTENT,
+            )
+            .await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
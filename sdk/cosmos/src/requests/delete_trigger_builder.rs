@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::DeleteTriggerResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -154,25 +155,28 @@ where
     COLL: CollectionClient<C, D>,
 {
     pub async fn execute(&self) -> Result<DeleteTriggerResponse, AzureError> {
-        trace!("DeleteTriggerBuilder::execute called");
+        instrument("DeleteTriggerBuilder::execute", async move {
+            trace!("DeleteTriggerBuilder::execute called");
 
-        let req = self
-            .trigger_client
-            .prepare_request_with_trigger_name(hyper::Method::DELETE);
+            let req = self
+                .trigger_client
+                .prepare_request_with_trigger_name(hyper::Method::DELETE);
 
-        // add trait headers
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
+            // add trait headers
+            let req = UserAgentOption::add_header(self, req);
+            let req = ActivityIdOption::add_header(self, req);
+            let req = ConsistencyLevelOption::add_header(self, req);
 
-        let request = req.body(hyper::Body::empty())?;
+            let request = req.body(bytes::Bytes::new())?;
 
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.trigger_client().hyper_client().request(request),
-            StatusCode::NO_CONTENT,
-        )
-        .await?;
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.trigger_client().pipeline().execute(request),
+                StatusCode::NO_CONTENT,
+            )
+            .await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::DeleteUserResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -141,25 +142,28 @@ where
     D: DatabaseClient<C>,
 {
     pub async fn execute(&self) -> Result<DeleteUserResponse, AzureError> {
-        trace!("DeleteUserBuilder::execute called");
+        instrument("DeleteUserBuilder::execute", async move {
+            trace!("DeleteUserBuilder::execute called");
 
-        let req = self
-            .user_client
-            .prepare_request_with_user_name(hyper::Method::DELETE);
+            let req = self
+                .user_client
+                .prepare_request_with_user_name(hyper::Method::DELETE);
 
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
+            let req = UserAgentOption::add_header(self, req);
+            let req = ActivityIdOption::add_header(self, req);
+            let req = ConsistencyLevelOption::add_header(self, req);
 
-        let req = req.body(hyper::Body::empty())?;
-        debug!("\nreq == {:?}", req);
+            let req = req.body(bytes::Bytes::new())?;
+            debug!("\nreq == {:?}", req);
 
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.user_client.hyper_client().request(req),
-            StatusCode::NO_CONTENT,
-        )
-        .await?;
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.user_client.pipeline().execute(req),
+                StatusCode::NO_CONTENT,
+            )
+            .await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
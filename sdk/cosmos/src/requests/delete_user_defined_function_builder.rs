@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::DeleteUserDefinedFunctionResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -160,27 +161,30 @@ where
     COLL: CollectionClient<C, D>,
 {
     pub async fn execute(&self) -> Result<DeleteUserDefinedFunctionResponse, AzureError> {
-        trace!("DeleteUserDefinedFunctionBuilder::execute called");
-
-        let request = self
-            .user_defined_function_client
-            .prepare_request_with_user_defined_function_name(hyper::Method::DELETE);
-
-        // add trait headers
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
-
-        let request = request.body(hyper::Body::empty())?;
-
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.user_defined_function_client()
-                .hyper_client()
-                .request(request),
-            StatusCode::NO_CONTENT,
-        )
-        .await?;
-
-        Ok((&headers, &body as &[u8]).try_into()?)
+        instrument("DeleteUserDefinedFunctionBuilder::execute", async move {
+            trace!("DeleteUserDefinedFunctionBuilder::execute called");
+
+            let request = self
+                .user_defined_function_client
+                .prepare_request_with_user_defined_function_name(hyper::Method::DELETE);
+
+            // add trait headers
+            let request = UserAgentOption::add_header(self, request);
+            let request = ActivityIdOption::add_header(self, request);
+            let request = ConsistencyLevelOption::add_header(self, request);
+
+            let request = request.body(bytes::Bytes::new())?;
+
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.user_defined_function_client()
+                    .pipeline()
+                    .execute(request),
+                StatusCode::NO_CONTENT,
+            )
+            .await?;
+
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
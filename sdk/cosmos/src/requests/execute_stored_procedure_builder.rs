@@ -2,6 +2,7 @@ use crate::prelude::*;
 use crate::responses::ExecuteStoredProcedureResponse;
 use crate::stored_procedure::Parameters;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use serde::de::DeserializeOwned;
@@ -282,33 +283,34 @@ where
     where
         T: DeserializeOwned,
     {
-        trace!("ExecuteStoredProcedureBuilder::execute called");
+        instrument("ExecuteStoredProcedureBuilder::execute", async move {
+            trace!("ExecuteStoredProcedureBuilder::execute called");
 
-        let request = self
-            .stored_procedure_client()
-            .prepare_request_with_stored_procedure_name(hyper::Method::POST);
+            let request = self
+                .stored_procedure_client()
+                .prepare_request_with_stored_procedure_name(hyper::Method::POST);
 
-        // add trait headers
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
-        let request = AllowTentativeWritesOption::add_header(self, request);
-        let request = PartitionKeysOption::add_header(self, request);
+            // add trait headers
+            let request = UserAgentOption::add_header(self, request);
+            let request = ActivityIdOption::add_header(self, request);
+            let request = ConsistencyLevelOption::add_header(self, request);
+            let request = AllowTentativeWritesOption::add_header(self, request);
+            let request = PartitionKeysOption::add_header(self, request);
 
-        let request = request.header(http::header::CONTENT_TYPE, "application/json");
+            let request = request.header(http::header::CONTENT_TYPE, "application/json");
 
-        let body = ParametersOption::generate_body(self);
+            let body = ParametersOption::generate_body(self);
 
-        let request = request.body(hyper::Body::from(body))?;
+            let request = request.body(bytes::Bytes::from(body))?;
 
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.stored_procedure_client()
-                .hyper_client()
-                .request(request),
-            StatusCode::OK,
-        )
-        .await?;
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.stored_procedure_client().pipeline().execute(request),
+                StatusCode::OK,
+            )
+            .await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
@@ -2,6 +2,7 @@ use crate::prelude::*;
 use crate::responses::GetCollectionResponse;
 use crate::CollectionClientRequired;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -152,22 +153,25 @@ where
     D: DatabaseClient<C>,
 {
     pub async fn execute(&self) -> Result<GetCollectionResponse, AzureError> {
-        trace!("GetCollectionResponse::execute called");
+        instrument("GetCollectionBuilder::execute", async move {
+            trace!("GetCollectionResponse::execute called");
 
-        let request = self
-            .collection_client()
-            .prepare_request_with_collection_name(hyper::Method::GET);
+            let request = self
+                .collection_client()
+                .prepare_request_with_collection_name(hyper::Method::GET);
 
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
+            let request = UserAgentOption::add_header(self, request);
+            let request = ActivityIdOption::add_header(self, request);
+            let request = ConsistencyLevelOption::add_header(self, request);
 
-        let request = request.body(hyper::Body::empty())?;
+            let request = request.body(bytes::Bytes::new())?;
 
-        let future_response = self.collection_client().hyper_client().request(request);
-        let (headers, body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+            let future_response = self.collection_client().pipeline().execute(request);
+            let (headers, body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
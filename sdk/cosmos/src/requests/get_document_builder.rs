@@ -1,7 +1,8 @@
 use crate::prelude::*;
 use crate::responses::GetDocumentResponse;
 use crate::DocumentClientRequired;
-use azure_core::errors::{extract_status_headers_and_body, AzureError, UnexpectedHTTPResult};
+use azure_core::errors::{extract_status_headers_and_body, AzureError, HttpError};
+use azure_core::instrumentation::instrument;
 use azure_core::modify_conditions::IfMatchCondition;
 use azure_core::prelude::*;
 use azure_core::{IfMatchConditionOption, IfMatchConditionSupport};
@@ -238,45 +239,50 @@ where
     where
         T: DeserializeOwned,
     {
-        let mut req = self
-            .document_client
-            .prepare_request_with_document_name(hyper::Method::GET);
+        instrument("GetDocumentBuilder::execute", async move {
+            let mut req = self
+                .document_client
+                .prepare_request_with_document_name(hyper::Method::GET);
 
-        // add trait headers
-        req = IfMatchConditionOption::add_header(self, req);
-        req = IfModifiedSinceOption::add_header(self, req);
-        req = UserAgentOption::add_header(self, req);
-        req = ActivityIdOption::add_header(self, req);
-        req = ConsistencyLevelOption::add_header(self, req);
+            // add trait headers
+            req = IfMatchConditionOption::add_header(self, req);
+            req = IfModifiedSinceOption::add_header(self, req);
+            req = UserAgentOption::add_header(self, req);
+            req = ActivityIdOption::add_header(self, req);
+            req = ConsistencyLevelOption::add_header(self, req);
 
-        req = crate::add_partition_keys_header(self.document_client.partition_keys(), req);
+            req = crate::add_partition_keys_header(self.document_client.partition_keys(), req);
 
-        let req = req.body(hyper::Body::empty())?;
+            let req = req.body(bytes::Bytes::new())?;
 
-        let (status_code, headers, whole_body) =
-            extract_status_headers_and_body(self.document_client.hyper_client().request(req))
-                .await?;
+            let (status_code, headers, whole_body) =
+                extract_status_headers_and_body(self.document_client.pipeline().execute(req))
+                    .await?;
 
-        if status_code != StatusCode::OK
-            && status_code != StatusCode::NOT_MODIFIED
-            && status_code != StatusCode::NOT_FOUND
-        {
-            return Err(UnexpectedHTTPResult::new_multiple(
-                vec![
-                    StatusCode::OK,
-                    StatusCode::NOT_MODIFIED,
-                    StatusCode::NOT_FOUND,
-                ],
-                status_code,
-                std::str::from_utf8(&whole_body)?,
-            )
-            .into());
-        }
+            if status_code != StatusCode::OK
+                && status_code != StatusCode::NOT_MODIFIED
+                && status_code != StatusCode::NOT_FOUND
+            {
+                return Err(HttpError::new_multiple(
+                    vec![
+                        StatusCode::OK,
+                        StatusCode::NOT_MODIFIED,
+                        StatusCode::NOT_FOUND,
+                    ],
+                    status_code,
+                    &headers,
+                    &whole_body,
+                )
+                .into());
+            }
 
-        debug!("\nheaders == {:?}", headers);
-        debug!("\nwhole body == {:#?}", whole_body);
+            debug!("\nheaders == {:?}", headers);
+            debug!("\nwhole body == {:#?}", whole_body);
 
-        let resp = GetDocumentResponse::try_from((status_code, &headers, &whole_body as &[u8]))?;
-        Ok(resp)
+            let resp =
+                GetDocumentResponse::try_from((status_code, &headers, &whole_body as &[u8]))?;
+            Ok(resp)
+        })
+        .await
     }
 }
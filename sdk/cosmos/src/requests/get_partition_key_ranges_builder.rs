@@ -2,6 +2,7 @@ use crate::prelude::*;
 use crate::responses::GetPartitionKeyRangesResponse;
 use crate::ResourceType;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use chrono::{DateTime, Utc};
 use hyper::StatusCode;
@@ -217,31 +218,34 @@ where
     D: DatabaseClient<C>,
 {
     pub async fn execute(&self) -> Result<GetPartitionKeyRangesResponse, AzureError> {
-        trace!("GetPartitionKeyRangesBuilder::execute called");
-
-        let request = self.collection_client().cosmos_client().prepare_request(
-            &format!(
-                "dbs/{}/colls/{}/pkranges",
-                self.collection_client.database_client().database_name(),
-                self.collection_client.collection_name()
-            ),
-            hyper::Method::GET,
-            ResourceType::PartitionKeyRanges,
-        );
-
-        let request = request.header(hyper::header::CONTENT_LENGTH, "0");
-        let request = IfMatchConditionOption::add_header(self, request);
-        let request = IfModifiedSinceOption::add_header(self, request);
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
-
-        let request = request.body(hyper::Body::empty())?;
-
-        let future_response = self.collection_client().hyper_client().request(request);
-        let (headers, body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
-
-        Ok((&headers, &body as &[u8]).try_into()?)
+        instrument("GetPartitionKeyRangesBuilder::execute", async move {
+            trace!("GetPartitionKeyRangesBuilder::execute called");
+
+            let request = self.collection_client().cosmos_client().prepare_request(
+                &format!(
+                    "dbs/{}/colls/{}/pkranges",
+                    self.collection_client.database_client().database_name(),
+                    self.collection_client.collection_name()
+                ),
+                hyper::Method::GET,
+                ResourceType::PartitionKeyRanges,
+            );
+
+            let request = request.header(hyper::header::CONTENT_LENGTH, "0");
+            let request = IfMatchConditionOption::add_header(self, request);
+            let request = IfModifiedSinceOption::add_header(self, request);
+            let request = UserAgentOption::add_header(self, request);
+            let request = ActivityIdOption::add_header(self, request);
+            let request = ConsistencyLevelOption::add_header(self, request);
+
+            let request = request.body(bytes::Bytes::new())?;
+
+            let future_response = self.collection_client().pipeline().execute(request);
+            let (headers, body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
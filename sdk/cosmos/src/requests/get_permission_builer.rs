@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::GetPermissionResponse;
-use azure_core::errors::{extract_status_headers_and_body, AzureError, UnexpectedHTTPResult};
+use azure_core::errors::{extract_status_headers_and_body, AzureError, HttpError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -154,32 +155,36 @@ where
     USER: UserClient<C, D>,
 {
     pub async fn execute(&self) -> Result<Option<GetPermissionResponse<'a>>, AzureError> {
-        trace!("GetPermissionBuilder::execute called");
-
-        let request = self
-            .permission_client
-            .prepare_request_with_permission_name(hyper::Method::GET);
-
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
-
-        let request = request.body(hyper::Body::empty())?;
-        debug!("\nrequest == {:#?}", request);
-
-        let (status, headers, body) =
-            extract_status_headers_and_body(self.permission_client.hyper_client().request(request))
-                .await?;
-
-        match status {
-            StatusCode::OK => Ok(Some((&headers, &body as &[u8]).try_into()?)),
-            StatusCode::NOT_FOUND => Ok(None),
-            _ => Err(UnexpectedHTTPResult::new_multiple(
-                vec![StatusCode::OK, StatusCode::NOT_FOUND],
-                status,
-                std::str::from_utf8(&body)?,
-            )
-            .into()),
-        }
+        instrument("GetPermissionBuilder::execute", async move {
+            trace!("GetPermissionBuilder::execute called");
+
+            let request = self
+                .permission_client
+                .prepare_request_with_permission_name(hyper::Method::GET);
+
+            let request = UserAgentOption::add_header(self, request);
+            let request = ActivityIdOption::add_header(self, request);
+            let request = ConsistencyLevelOption::add_header(self, request);
+
+            let request = request.body(bytes::Bytes::new())?;
+            debug!("\nrequest == {:#?}", request);
+
+            let (status, headers, body) =
+                extract_status_headers_and_body(self.permission_client.pipeline().execute(request))
+                    .await?;
+
+            match status {
+                StatusCode::OK => Ok(Some((&headers, &body as &[u8]).try_into()?)),
+                StatusCode::NOT_FOUND => Ok(None),
+                _ => Err(HttpError::new_multiple(
+                    vec![StatusCode::OK, StatusCode::NOT_FOUND],
+                    status,
+                    &headers,
+                    &body,
+                )
+                .into()),
+            }
+        })
+        .await
     }
 }
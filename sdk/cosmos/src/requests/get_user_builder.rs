@@ -1,7 +1,8 @@
 use crate::prelude::*;
 use crate::responses::CreateUserResponse;
-use azure_core::errors::UnexpectedHTTPResult;
+use azure_core::errors::HttpError;
 use azure_core::errors::{extract_status_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -142,31 +143,35 @@ where
     D: DatabaseClient<C>,
 {
     pub async fn execute(&self) -> Result<Option<CreateUserResponse>, AzureError> {
-        trace!("GetUserBuilder::execute called");
-
-        let req = self
-            .user_client
-            .prepare_request_with_user_name(hyper::Method::GET);
-
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
-
-        let req = req.body(hyper::Body::empty())?;
-        debug!("\nreq == {:?}", req);
-
-        let (status_code, headers, body) =
-            extract_status_headers_and_body(self.user_client.hyper_client().request(req)).await?;
-
-        match status_code {
-            StatusCode::NOT_FOUND => Ok(None),
-            StatusCode::OK => Ok(Some((&headers, &body as &[u8]).try_into()?)),
-            _ => Err(UnexpectedHTTPResult::new_multiple(
-                vec![StatusCode::OK, StatusCode::NOT_FOUND],
-                status_code,
-                std::str::from_utf8(&body)?,
-            )
-            .into()),
-        }
+        instrument("GetUserBuilder::execute", async move {
+            trace!("GetUserBuilder::execute called");
+
+            let req = self
+                .user_client
+                .prepare_request_with_user_name(hyper::Method::GET);
+
+            let req = UserAgentOption::add_header(self, req);
+            let req = ActivityIdOption::add_header(self, req);
+            let req = ConsistencyLevelOption::add_header(self, req);
+
+            let req = req.body(bytes::Bytes::new())?;
+            debug!("\nreq == {:?}", req);
+
+            let (status_code, headers, body) =
+                extract_status_headers_and_body(self.user_client.pipeline().execute(req)).await?;
+
+            match status_code {
+                StatusCode::NOT_FOUND => Ok(None),
+                StatusCode::OK => Ok(Some((&headers, &body as &[u8]).try_into()?)),
+                _ => Err(HttpError::new_multiple(
+                    vec![StatusCode::OK, StatusCode::NOT_FOUND],
+                    status_code,
+                    &headers,
+                    &body,
+                )
+                .into()),
+            }
+        })
+        .await
     }
 }
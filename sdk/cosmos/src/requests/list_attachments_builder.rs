@@ -3,8 +3,10 @@ use crate::responses::ListAttachmentsResponse;
 use crate::DocumentClientRequired;
 use crate::{DocumentClient, ResourceType};
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
-use futures::stream::{unfold, Stream};
+use azure_core::Pageable;
 use hyper::StatusCode;
 use std::convert::TryInto;
 
@@ -337,78 +339,61 @@ where
     COLL: CollectionClient<C, D>,
 {
     pub async fn execute(&self) -> Result<ListAttachmentsResponse, AzureError> {
-        let mut req = self.document_client.cosmos_client().prepare_request(
-            &format!(
-                "dbs/{}/colls/{}/docs/{}/attachments",
-                self.document_client.database_client().database_name(),
-                self.document_client.collection_client().collection_name(),
-                self.document_client.document_name().name()
-            ),
-            hyper::Method::GET,
-            ResourceType::Attachments,
-        );
-
-        // add trait headers
-        req = IfMatchConditionOption::add_header(self, req);
-        req = UserAgentOption::add_header(self, req);
-        req = ActivityIdOption::add_header(self, req);
-        req = ConsistencyLevelOption::add_header(self, req);
-        req = ContinuationOption::add_header(self, req);
-        req = MaxItemCountOption::add_header(self, req);
-        req = AIMOption::add_header(self, req);
-
-        req = crate::add_partition_keys_header(self.document_client.partition_keys(), req);
-
-        let req = req.body(hyper::Body::empty())?;
-
-        let (headers, whole_body) = check_status_extract_headers_and_body(
-            self.document_client.hyper_client().request(req),
-            StatusCode::OK,
-        )
-        .await?;
-
-        debug!("\nheaders == {:?}", headers);
-        debug!("\nwhole body == {:#?}", whole_body);
-
-        Ok((&headers, &whole_body as &[u8]).try_into()?)
+        instrument("ListAttachmentsBuilder::execute", async move {
+            let mut req = self.document_client.cosmos_client().prepare_request(
+                &format!(
+                    "dbs/{}/colls/{}/docs/{}/attachments",
+                    self.document_client.database_client().database_name(),
+                    self.document_client.collection_client().collection_name(),
+                    self.document_client.document_name().name()
+                ),
+                hyper::Method::GET,
+                ResourceType::Attachments,
+            );
+
+            // add trait headers
+            req = IfMatchConditionOption::add_header(self, req);
+            req = UserAgentOption::add_header(self, req);
+            req = ActivityIdOption::add_header(self, req);
+            req = ConsistencyLevelOption::add_header(self, req);
+            req = ContinuationOption::add_header(self, req);
+            req = MaxItemCountOption::add_header(self, req);
+            req = AIMOption::add_header(self, req);
+
+            req = crate::add_partition_keys_header(self.document_client.partition_keys(), req);
+
+            let req = req.body(bytes::Bytes::new())?;
+
+            let (headers, whole_body) = check_status_extract_headers_and_body(
+                self.document_client.pipeline().execute(req),
+                StatusCode::OK,
+            )
+            .await?;
+
+            debug!("\nheaders == {:?}", headers);
+            debug!("\nwhole body == {:#?}", whole_body);
+
+            Ok((&headers, &whole_body as &[u8]).try_into()?)
+        })
+        .await
     }
 
-    pub fn stream(&self) -> impl Stream<Item = Result<ListAttachmentsResponse, AzureError>> + '_ {
-        #[derive(Debug, Clone, PartialEq)]
-        enum States {
-            Init,
-            Continuation(String),
-        };
-
-        unfold(
-            Some(States::Init),
-            move |continuation_token: Option<States>| {
-                async move {
-                    debug!("continuation_token == {:?}", &continuation_token);
-                    let response = match continuation_token {
-                        Some(States::Init) => self.execute().await,
-                        Some(States::Continuation(continuation_token)) => {
-                            self.clone()
-                                .with_continuation(&continuation_token)
-                                .execute()
-                                .await
-                        }
-                        None => return None,
-                    };
-
-                    // the ? operator does not work in async move (yet?)
-                    // so we have to resort to this boilerplate
-                    let response = match response {
-                        Ok(response) => response,
-                        Err(err) => return Some((Err(err), None)),
-                    };
-
-                    let continuation_token = match &response.continuation_token {
-                        Some(ct) => Some(States::Continuation(ct.to_owned())),
-                        None => None,
-                    };
-
-                    Some((Ok(response), continuation_token))
+    /// Lists every page, following the continuation tokens. The listing
+    /// starts at the builder's continuation, if any.
+    pub fn stream(&self) -> Pageable<'_, ListAttachmentsResponse> {
+        let continuation = self.continuation.map(ToOwned::to_owned);
+        Pageable::new(
+            continuation,
+            move |continuation: Option<ContinuationToken>| async move {
+                debug!("continuation_token == {:?}", &continuation);
+                match continuation {
+                    Some(continuation) => {
+                        self.clone()
+                            .with_continuation(&continuation)
+                            .execute()
+                            .await
+                    }
+                    None => self.execute().await,
                 }
             },
         )
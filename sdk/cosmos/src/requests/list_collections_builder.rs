@@ -2,8 +2,10 @@ use crate::prelude::*;
 use crate::responses::ListCollectionsResponse;
 use crate::{DatabaseClientRequired, ResourceType};
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
-use futures::stream::{unfold, Stream};
+use azure_core::Pageable;
 use hyper::StatusCode;
 use std::convert::TryInto;
 
@@ -205,65 +207,48 @@ where
     C: CosmosClient,
 {
     pub async fn execute(&self) -> Result<ListCollectionsResponse, AzureError> {
-        trace!("ListCollectionsBuilder::execute called");
-        let request = self.database_client.cosmos_client().prepare_request(
-            &format!("dbs/{}/colls", self.database_client.database_name().name()),
-            hyper::Method::GET,
-            ResourceType::Collections,
-        );
+        instrument("ListCollectionsBuilder::execute", async move {
+            trace!("ListCollectionsBuilder::execute called");
+            let request = self.database_client.cosmos_client().prepare_request(
+                &format!("dbs/{}/colls", self.database_client.database_name().name()),
+                hyper::Method::GET,
+                ResourceType::Collections,
+            );
 
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
-        let request = ContinuationOption::add_header(self, request);
-        let request = MaxItemCountOption::add_header(self, request);
+            let request = UserAgentOption::add_header(self, request);
+            let request = ActivityIdOption::add_header(self, request);
+            let request = ConsistencyLevelOption::add_header(self, request);
+            let request = ContinuationOption::add_header(self, request);
+            let request = MaxItemCountOption::add_header(self, request);
 
-        let request = request.body(hyper::Body::empty())?;
+            let request = request.body(bytes::Bytes::new())?;
 
-        trace!("request prepared == {:?}", request);
+            trace!("request prepared == {:?}", request);
 
-        let future_response = self.database_client.hyper_client().request(request);
-        let (headers, body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
-        Ok((&headers, &body as &[u8]).try_into()?)
+            let future_response = self.database_client.pipeline().execute(request);
+            let (headers, body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 
-    pub fn stream(&self) -> impl Stream<Item = Result<ListCollectionsResponse, AzureError>> + '_ {
-        #[derive(Debug, Clone, PartialEq)]
-        enum States {
-            Init,
-            Continuation(String),
-        };
-
-        unfold(
-            Some(States::Init),
-            move |continuation_token: Option<States>| {
-                async move {
-                    debug!("continuation_token == {:?}", &continuation_token);
-                    let response = match continuation_token {
-                        Some(States::Init) => self.execute().await,
-                        Some(States::Continuation(continuation_token)) => {
-                            self.clone()
-                                .with_continuation(&continuation_token)
-                                .execute()
-                                .await
-                        }
-                        None => return None,
-                    };
-
-                    // the ? operator does not work in async move (yet?)
-                    // so we have to resort to this boilerplate
-                    let response = match response {
-                        Ok(response) => response,
-                        Err(err) => return Some((Err(err), None)),
-                    };
-
-                    let continuation_token = match &response.continuation_token {
-                        Some(ct) => Some(States::Continuation(ct.to_owned())),
-                        None => None,
-                    };
-
-                    Some((Ok(response), continuation_token))
+    /// Lists every page, following the continuation tokens. The listing
+    /// starts at the builder's continuation, if any.
+    pub fn stream(&self) -> Pageable<'_, ListCollectionsResponse> {
+        let continuation = self.continuation.map(ToOwned::to_owned);
+        Pageable::new(
+            continuation,
+            move |continuation: Option<ContinuationToken>| async move {
+                debug!("continuation_token == {:?}", &continuation);
+                match continuation {
+                    Some(continuation) => {
+                        self.clone()
+                            .with_continuation(&continuation)
+                            .execute()
+                            .await
+                    }
+                    None => self.execute().await,
                 }
             },
         )
@@ -2,8 +2,10 @@ use crate::prelude::*;
 use crate::responses::ListDocumentsResponse;
 use crate::ResourceType;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
-use futures::stream::{unfold, Stream};
+use azure_core::Pageable;
 use hyper::StatusCode;
 use serde::de::DeserializeOwned;
 use std::convert::TryInto;
@@ -364,79 +366,62 @@ where
     where
         T: DeserializeOwned,
     {
-        let req = self.collection_client.cosmos_client().prepare_request(
-            &format!(
-                "dbs/{}/colls/{}/docs",
-                self.collection_client.database_client().database_name(),
-                self.collection_client.collection_name()
-            ),
-            hyper::Method::GET,
-            ResourceType::Documents,
-        );
-
-        // add trait headers
-        let req = IfMatchConditionOption::add_header(self, req);
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
-        let req = ContinuationOption::add_header(self, req);
-        let req = MaxItemCountOption::add_header(self, req);
-        let req = AIMOption::add_header(self, req);
-        let req = PartitionRangeIdOption::add_header(self, req);
-
-        let req = req.body(hyper::Body::empty())?;
-
-        let (headers, whole_body) = check_status_extract_headers_and_body(
-            self.collection_client.hyper_client().request(req),
-            StatusCode::OK,
-        )
-        .await?;
-
-        debug!("\nheaders == {:?}", headers);
-        debug!("\nwhole body == {:#?}", whole_body);
-
-        Ok((&headers, &whole_body as &[u8]).try_into()?)
+        instrument("ListDocumentsBuilder::execute", async move {
+            let req = self.collection_client.cosmos_client().prepare_request(
+                &format!(
+                    "dbs/{}/colls/{}/docs",
+                    self.collection_client.database_client().database_name(),
+                    self.collection_client.collection_name()
+                ),
+                hyper::Method::GET,
+                ResourceType::Documents,
+            );
+
+            // add trait headers
+            let req = IfMatchConditionOption::add_header(self, req);
+            let req = UserAgentOption::add_header(self, req);
+            let req = ActivityIdOption::add_header(self, req);
+            let req = ConsistencyLevelOption::add_header(self, req);
+            let req = ContinuationOption::add_header(self, req);
+            let req = MaxItemCountOption::add_header(self, req);
+            let req = AIMOption::add_header(self, req);
+            let req = PartitionRangeIdOption::add_header(self, req);
+
+            let req = req.body(bytes::Bytes::new())?;
+
+            let (headers, whole_body) = check_status_extract_headers_and_body(
+                self.collection_client.pipeline().execute(req),
+                StatusCode::OK,
+            )
+            .await?;
+
+            debug!("\nheaders == {:?}", headers);
+            debug!("\nwhole body == {:#?}", whole_body);
+
+            Ok((&headers, &whole_body as &[u8]).try_into()?)
+        })
+        .await
     }
 
-    pub fn stream<T>(&self) -> impl Stream<Item = Result<ListDocumentsResponse<T>, AzureError>> + '_
+    /// Lists every page, following the continuation tokens. The listing
+    /// starts at the builder's continuation, if any.
+    pub fn stream<'s, T>(&'s self) -> Pageable<'s, ListDocumentsResponse<T>>
     where
-        T: DeserializeOwned,
+        T: DeserializeOwned + Send + 's,
     {
-        #[derive(Debug, Clone, PartialEq)]
-        enum States {
-            Init,
-            Continuation(String),
-        };
-
-        unfold(
-            Some(States::Init),
-            move |continuation_token: Option<States>| {
-                async move {
-                    debug!("continuation_token == {:?}", &continuation_token);
-                    let response = match continuation_token {
-                        Some(States::Init) => self.execute().await,
-                        Some(States::Continuation(continuation_token)) => {
-                            self.clone()
-                                .with_continuation(&continuation_token)
-                                .execute()
-                                .await
-                        }
-                        None => return None,
-                    };
-
-                    // the ? operator does not work in async move (yet?)
-                    // so we have to resort to this boilerplate
-                    let response = match response {
-                        Ok(response) => response,
-                        Err(err) => return Some((Err(err), None)),
-                    };
-
-                    let continuation_token = match &response.continuation_token {
-                        Some(ct) => Some(States::Continuation(ct.to_owned())),
-                        None => None,
-                    };
-
-                    Some((Ok(response), continuation_token))
+        let continuation = self.continuation.map(ToOwned::to_owned);
+        Pageable::new(
+            continuation,
+            move |continuation: Option<ContinuationToken>| async move {
+                debug!("continuation_token == {:?}", &continuation);
+                match continuation {
+                    Some(continuation) => {
+                        self.clone()
+                            .with_continuation(&continuation)
+                            .execute::<T>()
+                            .await
+                    }
+                    None => self.execute::<T>().await,
                 }
             },
         )
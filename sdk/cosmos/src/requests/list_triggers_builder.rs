@@ -2,8 +2,10 @@ use crate::prelude::*;
 use crate::responses::ListTriggersResponse;
 use crate::ResourceType;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
-use futures::stream::{unfold, Stream};
+use azure_core::Pageable;
 use hyper::StatusCode;
 use std::convert::TryInto;
 
@@ -274,73 +276,56 @@ where
     D: DatabaseClient<C>,
 {
     pub async fn execute(&self) -> Result<ListTriggersResponse, AzureError> {
-        trace!("ListTriggersBuilder::execute called");
-
-        let request = self.collection_client().cosmos_client().prepare_request(
-            &format!(
-                "dbs/{}/colls/{}/triggers",
-                self.collection_client.database_client().database_name(),
-                self.collection_client.collection_name()
-            ),
-            hyper::Method::GET,
-            ResourceType::Triggers,
-        );
-
-        // add trait headers
-        let request = IfMatchConditionOption::add_header(self, request);
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
-        let request = ContinuationOption::add_header(self, request);
-        let request = MaxItemCountOption::add_header(self, request);
-
-        let request = request.body(hyper::Body::empty())?;
-
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.collection_client().hyper_client().request(request),
-            StatusCode::OK,
-        )
-        .await?;
-
-        Ok((&headers, &body as &[u8]).try_into()?)
+        instrument("ListTriggersBuilder::execute", async move {
+            trace!("ListTriggersBuilder::execute called");
+
+            let request = self.collection_client().cosmos_client().prepare_request(
+                &format!(
+                    "dbs/{}/colls/{}/triggers",
+                    self.collection_client.database_client().database_name(),
+                    self.collection_client.collection_name()
+                ),
+                hyper::Method::GET,
+                ResourceType::Triggers,
+            );
+
+            // add trait headers
+            let request = IfMatchConditionOption::add_header(self, request);
+            let request = UserAgentOption::add_header(self, request);
+            let request = ActivityIdOption::add_header(self, request);
+            let request = ConsistencyLevelOption::add_header(self, request);
+            let request = ContinuationOption::add_header(self, request);
+            let request = MaxItemCountOption::add_header(self, request);
+
+            let request = request.body(bytes::Bytes::new())?;
+
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.collection_client().pipeline().execute(request),
+                StatusCode::OK,
+            )
+            .await?;
+
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 
-    pub fn stream(&self) -> impl Stream<Item = Result<ListTriggersResponse, AzureError>> + '_ {
-        #[derive(Debug, Clone, PartialEq)]
-        enum States {
-            Init,
-            Continuation(String),
-        };
-
-        unfold(
-            Some(States::Init),
-            move |continuation_token: Option<States>| {
-                async move {
-                    debug!("continuation_token == {:?}", &continuation_token);
-                    let response = match continuation_token {
-                        Some(States::Init) => self.execute().await,
-                        Some(States::Continuation(continuation_token)) => {
-                            self.clone()
-                                .with_continuation(&continuation_token)
-                                .execute()
-                                .await
-                        }
-                        None => return None,
-                    };
-
-                    // the ? operator does not work in async move (yet?)
-                    // so we have to resort to this boilerplate
-                    let response = match response {
-                        Ok(response) => response,
-                        Err(err) => return Some((Err(err), None)),
-                    };
-
-                    let continuation_token = match &response.continuation_token {
-                        Some(ct) => Some(States::Continuation(ct.to_owned())),
-                        None => None,
-                    };
-
-                    Some((Ok(response), continuation_token))
+    /// Lists every page, following the continuation tokens. The listing
+    /// starts at the builder's continuation, if any.
+    pub fn stream(&self) -> Pageable<'_, ListTriggersResponse> {
+        let continuation = self.continuation.map(ToOwned::to_owned);
+        Pageable::new(
+            continuation,
+            move |continuation: Option<ContinuationToken>| async move {
+                debug!("continuation_token == {:?}", &continuation);
+                match continuation {
+                    Some(continuation) => {
+                        self.clone()
+                            .with_continuation(&continuation)
+                            .execute()
+                            .await
+                    }
+                    None => self.execute().await,
                 }
             },
         )
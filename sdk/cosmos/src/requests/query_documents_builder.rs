@@ -2,10 +2,12 @@ use crate::prelude::*;
 use crate::responses::QueryDocumentsResponse;
 use crate::{Query, ResourceType};
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
+use azure_core::Pageable;
 use azure_core::{No, ToAssign, Yes};
 use chrono::{DateTime, Utc};
-use futures::stream::{unfold, Stream};
 use hyper::StatusCode;
 use serde::de::DeserializeOwned;
 use std::convert::TryInto;
@@ -566,89 +568,74 @@ where
     where
         T: DeserializeOwned,
     {
-        trace!("QueryDocumentBuilder::execute called");
-
-        let req = self.collection_client.cosmos_client().prepare_request(
-            &format!(
-                "dbs/{}/colls/{}/docs",
-                self.collection_client.database_client().database_name(),
-                self.collection_client.collection_name()
-            ),
-            hyper::Method::POST,
-            ResourceType::Documents,
-        );
-
-        // signal that this is a query
-        let req = req.header(crate::headers::HEADER_DOCUMENTDB_ISQUERY, true.to_string());
-        let req = req.header(http::header::CONTENT_TYPE, "application/query+json");
-
-        // add trait headers
-        let req = IfMatchConditionOption::add_header(self, req);
-        let req = IfModifiedSinceOption::add_header(self, req);
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
-        let req = ContinuationOption::add_header(self, req);
-        let req = MaxItemCountOption::add_header(self, req);
-        let req = PartitionKeysOption::add_header(self, req);
-        let req = QueryCrossPartitionOption::add_header(self, req);
-
-        let body = serde_json::to_string(self.query())?;
-        debug!("body == {}", body);
-
-        let req = req.body(hyper::Body::from(body))?;
-        debug!("{:?}", req);
-
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.collection_client.hyper_client().request(req),
-            StatusCode::OK,
-        )
-        .await?;
-
-        debug!("\nheaders == {:?}", headers);
-        debug!("\nbody == {:#?}", body);
-
-        Ok((&headers, &body as &[u8]).try_into()?)
+        instrument("QueryDocumentsBuilder::execute", async move {
+            trace!("QueryDocumentBuilder::execute called");
+
+            let req = self.collection_client.cosmos_client().prepare_request(
+                &format!(
+                    "dbs/{}/colls/{}/docs",
+                    self.collection_client.database_client().database_name(),
+                    self.collection_client.collection_name()
+                ),
+                hyper::Method::POST,
+                ResourceType::Documents,
+            );
+
+            // signal that this is a query
+            let req = req.header(crate::headers::HEADER_DOCUMENTDB_ISQUERY, true.to_string());
+            let req = req.header(http::header::CONTENT_TYPE, "application/query+json");
+
+            // add trait headers
+            let req = IfMatchConditionOption::add_header(self, req);
+            let req = IfModifiedSinceOption::add_header(self, req);
+            let req = UserAgentOption::add_header(self, req);
+            let req = ActivityIdOption::add_header(self, req);
+            let req = ConsistencyLevelOption::add_header(self, req);
+            let req = ContinuationOption::add_header(self, req);
+            let req = MaxItemCountOption::add_header(self, req);
+            let req = PartitionKeysOption::add_header(self, req);
+            let req = QueryCrossPartitionOption::add_header(self, req);
+
+            let body = serde_json::to_string(self.query())?;
+            debug!("body == {}", body);
+
+            let req = req.body(bytes::Bytes::from(body))?;
+            debug!("{:?}", req);
+
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.collection_client.pipeline().execute(req),
+                StatusCode::OK,
+            )
+            .await?;
+
+            debug!("\nheaders == {:?}", headers);
+            debug!("\nbody == {:#?}", body);
+
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 
-    pub fn stream<T>(
-        &self,
-    ) -> impl Stream<Item = Result<QueryDocumentsResponse<T>, AzureError>> + '_
+    /// Lists every page, following the continuation tokens. The listing
+    /// starts at the builder's continuation, if any.
+    pub fn stream<'s, T>(&'s self) -> Pageable<'s, QueryDocumentsResponse<T>>
     where
-        T: DeserializeOwned,
+        T: DeserializeOwned + Send + 's,
     {
-        #[derive(Debug, Clone, PartialEq)]
-        enum States {
-            Init,
-            Continuation(String),
-        };
-
-        unfold(
-            Some(States::Init),
-            move |continuation_token: Option<States>| async move {
-                debug!("continuation_token == {:?}", &continuation_token);
-                let response = match continuation_token {
-                    Some(States::Init) => self.execute().await,
-                    Some(States::Continuation(continuation_token)) => {
+        let continuation = self.continuation.map(ToOwned::to_owned);
+        Pageable::new(
+            continuation,
+            move |continuation: Option<ContinuationToken>| async move {
+                debug!("continuation_token == {:?}", &continuation);
+                match continuation {
+                    Some(continuation) => {
                         self.clone()
-                            .with_continuation(&continuation_token)
-                            .execute()
+                            .with_continuation(&continuation)
+                            .execute::<T>()
                             .await
                     }
-                    None => return None,
-                };
-
-                let response = match response {
-                    Ok(response) => response,
-                    Err(err) => return Some((Err(err), None)),
-                };
-
-                let continuation_token = match &response.continuation_token {
-                    Some(ct) => Some(States::Continuation(ct.to_owned())),
-                    None => None,
-                };
-
-                Some((Ok(response), continuation_token))
+                    None => self.execute::<T>().await,
+                }
             },
         )
     }
@@ -3,6 +3,7 @@ use crate::collection::PartitionKey;
 use crate::prelude::*;
 use crate::responses::CreateCollectionResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -252,49 +253,52 @@ where
     D: DatabaseClient<C>,
 {
     pub async fn execute(&self) -> Result<CreateCollectionResponse, AzureError> {
-        trace!("ReplaceCollectionBuilder::execute called");
+        instrument("ReplaceCollectionBuilder::execute", async move {
+            trace!("ReplaceCollectionBuilder::execute called");
 
-        let req = self
-            .collection_client
-            .prepare_request_with_collection_name(hyper::Method::PUT);
+            let req = self
+                .collection_client
+                .prepare_request_with_collection_name(hyper::Method::PUT);
 
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
+            let req = UserAgentOption::add_header(self, req);
+            let req = ActivityIdOption::add_header(self, req);
+            let req = ConsistencyLevelOption::add_header(self, req);
 
-        let req = req.header(http::header::CONTENT_TYPE, "application/json");
+            let req = req.header(http::header::CONTENT_TYPE, "application/json");
 
-        #[derive(Debug, Clone, Serialize)]
-        struct Request<'k> {
-            id: &'k str,
-            #[serde(rename = "indexingPolicy")]
-            indexing_policy: &'k IndexingPolicy,
-            #[serde(rename = "partitionKey")]
-            partition_key: &'k crate::collection::PartitionKey,
-        };
+            #[derive(Debug, Clone, Serialize)]
+            struct Request<'k> {
+                id: &'k str,
+                #[serde(rename = "indexingPolicy")]
+                indexing_policy: &'k IndexingPolicy,
+                #[serde(rename = "partitionKey")]
+                partition_key: &'k crate::collection::PartitionKey,
+            };
 
-        let request = Request {
-            id: self.collection_client().collection_name(),
-            indexing_policy: self.indexing_policy(),
-            partition_key: self.partition_key(),
-        };
+            let request = Request {
+                id: self.collection_client().collection_name(),
+                indexing_policy: self.indexing_policy(),
+                partition_key: self.partition_key(),
+            };
 
-        let body = serde_json::to_string(&request)?;
-        debug!("body == {}", body);
+            let body = serde_json::to_string(&request)?;
+            debug!("body == {}", body);
 
-        let req = req.body(hyper::Body::from(body))?;
-        debug!("\nreq == {:?}", req);
+            let req = req.body(bytes::Bytes::from(body))?;
+            debug!("\nreq == {:?}", req);
 
-        // the docs are wrong here
-        // [https://docs.microsoft.com/en-us/rest/api/cosmos-db/replace-a-collection](https://docs.microsoft.com/en-us/rest/api/cosmos-db/replace-a-collection).
-        // They say you should receive 201 instead azure returns 200 upon success. I've filed a PR
-        // to correct it.
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.collection_client.hyper_client().request(req),
-            StatusCode::OK,
-        )
-        .await?;
+            // the docs are wrong here
+            // [https://docs.microsoft.com/en-us/rest/api/cosmos-db/replace-a-collection](https://docs.microsoft.com/en-us/rest/api/cosmos-db/replace-a-collection).
+            // They say you should receive 201 instead azure returns 200 upon success. I've filed a PR
+            // to correct it.
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.collection_client.pipeline().execute(req),
+                StatusCode::OK,
+            )
+            .await?;
 
-        Ok((&headers, &body as &[u8]).try_into()?)
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
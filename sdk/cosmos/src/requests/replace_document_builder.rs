@@ -2,6 +2,7 @@ use crate::prelude::*;
 use crate::responses::ReplaceDocumentResponse;
 use crate::ResourceType;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use chrono::{DateTime, Utc};
@@ -471,40 +472,46 @@ where
     where
         T: Serialize,
     {
-        trace!("ReplaceDocumentBuilder::execute() called");
-
-        let req = self.collection_client.cosmos_client().prepare_request(
-            &format!(
-                "dbs/{}/colls/{}/docs/{}",
-                self.collection_client.database_client().database_name(),
-                self.collection_client.collection_name(),
-                self.document_id()
-            ),
-            hyper::Method::PUT,
-            ResourceType::Documents,
-        );
-
-        // add trait headers
-        let req = IndexingDirectiveOption::add_header(self, req);
-        let req = IfMatchConditionOption::add_header(self, req);
-        let req = IfModifiedSinceOption::add_header(self, req);
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
-        let req = PartitionKeysRequired::add_header(self, req);
-        let req = AllowTentativeWritesOption::add_header(self, req);
-
-        let serialized = serde_json::to_string(document)?;
-
-        let req = req.body(hyper::Body::from(serialized))?;
-        debug!("request == {:#?}", req);
-
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.collection_client.hyper_client().request(req),
-            StatusCode::OK,
+        instrument(
+            "ReplaceDocumentBuilder::execute_with_document",
+            async move {
+                trace!("ReplaceDocumentBuilder::execute() called");
+
+                let req = self.collection_client.cosmos_client().prepare_request(
+                    &format!(
+                        "dbs/{}/colls/{}/docs/{}",
+                        self.collection_client.database_client().database_name(),
+                        self.collection_client.collection_name(),
+                        self.document_id()
+                    ),
+                    hyper::Method::PUT,
+                    ResourceType::Documents,
+                );
+
+                // add trait headers
+                let req = IndexingDirectiveOption::add_header(self, req);
+                let req = IfMatchConditionOption::add_header(self, req);
+                let req = IfModifiedSinceOption::add_header(self, req);
+                let req = UserAgentOption::add_header(self, req);
+                let req = ActivityIdOption::add_header(self, req);
+                let req = ConsistencyLevelOption::add_header(self, req);
+                let req = PartitionKeysRequired::add_header(self, req);
+                let req = AllowTentativeWritesOption::add_header(self, req);
+
+                let serialized = serde_json::to_string(document)?;
+
+                let req = req.body(bytes::Bytes::from(serialized))?;
+                debug!("request == {:#?}", req);
+
+                let (headers, body) = check_status_extract_headers_and_body(
+                    self.collection_client.pipeline().execute(req),
+                    StatusCode::OK,
+                )
+                .await?;
+
+                (&headers, &body as &[u8]).try_into()
+            },
         )
-        .await?;
-
-        (&headers, &body as &[u8]).try_into()
+        .await
     }
 }
@@ -2,6 +2,7 @@ use crate::prelude::*;
 use crate::responses::ReplacePermissionResponse;
 use crate::{PermissionMode, PermissionResource};
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -199,44 +200,50 @@ where
     where
         R: PermissionResource,
     {
-        trace!("ReplacePermissionBuilder::execute called");
-
-        let request = self
-            .permission_client
-            .prepare_request_with_permission_name(hyper::Method::PUT);
-
-        let request = UserAgentOption::add_header(self, request);
-        let request = ActivityIdOption::add_header(self, request);
-        let request = ConsistencyLevelOption::add_header(self, request);
-
-        let request = request.header(http::header::CONTENT_TYPE, "application/json");
-
-        #[derive(Serialize, Deserialize)]
-        struct RequestBody<'a> {
-            id: &'a str,
-            #[serde(rename = "permissionMode")]
-            permission_mode: &'a str,
-            resource: &'a str,
-        }
-
-        let (permission_mode, resource) = permission_mode.to_elements();
-
-        let request_body = RequestBody {
-            id: self.permission_client.permission_name(),
-            permission_mode,
-            resource: resource.uri(),
-        };
-        let request_body = serde_json::to_string(&request_body)?;
-
-        let request = request.body(hyper::Body::from(request_body))?;
-        debug!("\nrequest == {:#?}", request);
-
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.permission_client.hyper_client().request(request),
-            StatusCode::OK,
+        instrument(
+            "ReplacePermissionBuilder::execute_with_permission",
+            async move {
+                trace!("ReplacePermissionBuilder::execute called");
+
+                let request = self
+                    .permission_client
+                    .prepare_request_with_permission_name(hyper::Method::PUT);
+
+                let request = UserAgentOption::add_header(self, request);
+                let request = ActivityIdOption::add_header(self, request);
+                let request = ConsistencyLevelOption::add_header(self, request);
+
+                let request = request.header(http::header::CONTENT_TYPE, "application/json");
+
+                #[derive(Serialize, Deserialize)]
+                struct RequestBody<'a> {
+                    id: &'a str,
+                    #[serde(rename = "permissionMode")]
+                    permission_mode: &'a str,
+                    resource: &'a str,
+                }
+
+                let (permission_mode, resource) = permission_mode.to_elements();
+
+                let request_body = RequestBody {
+                    id: self.permission_client.permission_name(),
+                    permission_mode,
+                    resource: resource.uri(),
+                };
+                let request_body = serde_json::to_string(&request_body)?;
+
+                let request = request.body(bytes::Bytes::from(request_body))?;
+                debug!("\nrequest == {:#?}", request);
+
+                let (headers, body) = check_status_extract_headers_and_body(
+                    self.permission_client.pipeline().execute(request),
+                    StatusCode::OK,
+                )
+                .await?;
+
+                Ok((&headers, &body as &[u8]).try_into()?)
+            },
         )
-        .await?;
-
-        Ok((&headers, &body as &[u8]).try_into()?)
+        .await
     }
 }
@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -343,50 +344,53 @@ where
     pub async fn execute(
         &self,
     ) -> Result<crate::responses::ReplaceReferenceAttachmentResponse, AzureError> {
-        let mut req = self
-            .attachment_client
-            .prepare_request_with_attachment_name(hyper::Method::PUT);
+        instrument("ReplaceReferenceAttachmentBuilder::execute", async move {
+            let mut req = self
+                .attachment_client
+                .prepare_request_with_attachment_name(hyper::Method::PUT);
 
-        // add trait headers
-        req = IfMatchConditionOption::add_header(self, req);
-        req = UserAgentOption::add_header(self, req);
-        req = ActivityIdOption::add_header(self, req);
-        req = ConsistencyLevelOption::add_header(self, req);
+            // add trait headers
+            req = IfMatchConditionOption::add_header(self, req);
+            req = UserAgentOption::add_header(self, req);
+            req = ActivityIdOption::add_header(self, req);
+            req = ConsistencyLevelOption::add_header(self, req);
 
-        req = crate::add_partition_keys_header(
-            self.attachment_client.document_client().partition_keys(),
-            req,
-        );
+            req = crate::add_partition_keys_header(
+                self.attachment_client.document_client().partition_keys(),
+                req,
+            );
 
-        // create serialized request
-        #[derive(Debug, Clone, Serialize)]
-        struct _Request<'r> {
-            pub id: &'r str,
-            #[serde(rename = "contentType")]
-            pub content_type: &'r str,
-            pub media: &'r str,
-        }
+            // create serialized request
+            #[derive(Debug, Clone, Serialize)]
+            struct _Request<'r> {
+                pub id: &'r str,
+                #[serde(rename = "contentType")]
+                pub content_type: &'r str,
+                pub media: &'r str,
+            }
 
-        let request = serde_json::to_string(&_Request {
-            id: self.attachment_client.attachment_name().name(),
-            content_type: ContentTypeRequired::content_type(self),
-            media: self.media(),
-        })?;
+            let request = serde_json::to_string(&_Request {
+                id: self.attachment_client.attachment_name().name(),
+                content_type: ContentTypeRequired::content_type(self),
+                media: self.media(),
+            })?;
 
-        req = req.header(http::header::CONTENT_TYPE, "application/json");
-        req = req.header(http::header::CONTENT_LENGTH, request.len());
-        let req = req.body(hyper::Body::from(request))?;
-        debug!("req == {:#?}", req);
+            req = req.header(http::header::CONTENT_TYPE, "application/json");
+            req = req.header(http::header::CONTENT_LENGTH, request.len());
+            let req = req.body(bytes::Bytes::from(request))?;
+            debug!("req == {:#?}", req);
 
-        let (headers, whole_body) = check_status_extract_headers_and_body(
-            self.attachment_client.hyper_client().request(req),
-            StatusCode::OK,
-        )
-        .await?;
+            let (headers, whole_body) = check_status_extract_headers_and_body(
+                self.attachment_client.pipeline().execute(req),
+                StatusCode::OK,
+            )
+            .await?;
 
-        debug!("\nheaders == {:?}", headers);
-        debug!("\nwhole body == {:#?}", whole_body);
+            debug!("\nheaders == {:?}", headers);
+            debug!("\nwhole body == {:#?}", whole_body);
 
-        Ok((&headers, &whole_body as &[u8]).try_into()?)
+            Ok((&headers, &whole_body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::CreateSlugAttachmentResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -341,37 +342,40 @@ where
     DOC: DocumentClient<C, D, COLL>,
 {
     pub async fn execute(&self) -> Result<CreateSlugAttachmentResponse, AzureError> {
-        let mut req = self.attachment_client.prepare_request(hyper::Method::PUT);
+        instrument("ReplaceSlugAttachmentBuilder::execute", async move {
+            let mut req = self.attachment_client.prepare_request(hyper::Method::PUT);
 
-        // add trait headers
-        req = IfMatchConditionOption::add_header(self, req);
-        req = UserAgentOption::add_header(self, req);
-        req = ActivityIdOption::add_header(self, req);
-        req = ConsistencyLevelOption::add_header(self, req);
+            // add trait headers
+            req = IfMatchConditionOption::add_header(self, req);
+            req = UserAgentOption::add_header(self, req);
+            req = ActivityIdOption::add_header(self, req);
+            req = ConsistencyLevelOption::add_header(self, req);
 
-        req = crate::add_partition_keys_header(
-            self.attachment_client.document_client().partition_keys(),
-            req,
-        );
+            req = crate::add_partition_keys_header(
+                self.attachment_client.document_client().partition_keys(),
+                req,
+            );
 
-        req = ContentTypeRequired::add_header(self, req);
+            req = ContentTypeRequired::add_header(self, req);
 
-        req = req.header("Slug", self.attachment_client.attachment_name().name());
-        req = req.header(http::header::CONTENT_LENGTH, self.body().len());
+            req = req.header("Slug", self.attachment_client.attachment_name().name());
+            req = req.header(http::header::CONTENT_LENGTH, self.body().len());
 
-        let req = req.body(hyper::Body::from(self.body().to_owned()))?;
+            let req = req.body(bytes::Bytes::from(self.body().to_owned()))?;
 
-        debug!("req == {:#?}", req);
+            debug!("req == {:#?}", req);
 
-        let (headers, whole_body) = check_status_extract_headers_and_body(
-            self.attachment_client.hyper_client().request(req),
-            StatusCode::OK,
-        )
-        .await?;
+            let (headers, whole_body) = check_status_extract_headers_and_body(
+                self.attachment_client.pipeline().execute(req),
+                StatusCode::OK,
+            )
+            .await?;
 
-        debug!("\nheaders == {:?}", headers);
-        debug!("\nwhole body == {:#?}", whole_body);
+            debug!("\nheaders == {:?}", headers);
+            debug!("\nwhole body == {:#?}", whole_body);
 
-        Ok((&headers, &whole_body as &[u8]).try_into()?)
+            Ok((&headers, &whole_body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
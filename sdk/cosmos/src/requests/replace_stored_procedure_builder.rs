@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::ReplaceStoredProcedureResponse;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -215,40 +216,41 @@ where
     COLL: CollectionClient<C, D>,
 {
     pub async fn execute(&self) -> Result<ReplaceStoredProcedureResponse, AzureError> {
-        trace!("ReplaceStoredProcedureBuilder::execute called");
+        instrument("ReplaceStoredProcedureBuilder::execute", async move {
+            trace!("ReplaceStoredProcedureBuilder::execute called");
 
-        let req = self
-            .stored_procedure_client
-            .prepare_request_with_stored_procedure_name(hyper::Method::PUT);
+            let req = self
+                .stored_procedure_client
+                .prepare_request_with_stored_procedure_name(hyper::Method::PUT);
 
-        // add trait headers
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
+            // add trait headers
+            let req = UserAgentOption::add_header(self, req);
+            let req = ActivityIdOption::add_header(self, req);
+            let req = ConsistencyLevelOption::add_header(self, req);
 
-        let req = req.header(http::header::CONTENT_TYPE, "application/json");
+            let req = req.header(http::header::CONTENT_TYPE, "application/json");
 
-        #[derive(Debug, Serialize)]
-        struct Request<'a> {
-            body: &'a str,
-            id: &'a str,
-        }
-        let request = Request {
-            body: self.body(),
-            id: self.stored_procedure_client.stored_procedure_name(),
-        };
-
-        let request = serde_json::to_string(&request)?;
-        let request = req.body(hyper::Body::from(request))?;
-
-        let (headers, body) = check_status_extract_headers_and_body(
-            self.stored_procedure_client()
-                .hyper_client()
-                .request(request),
-            StatusCode::OK,
-        )
-        .await?;
-
-        Ok((&headers, &body as &[u8]).try_into()?)
+            #[derive(Debug, Serialize)]
+            struct Request<'a> {
+                body: &'a str,
+                id: &'a str,
+            }
+            let request = Request {
+                body: self.body(),
+                id: self.stored_procedure_client.stored_procedure_name(),
+            };
+
+            let request = serde_json::to_string(&request)?;
+            let request = req.body(bytes::Bytes::from(request))?;
+
+            let (headers, body) = check_status_extract_headers_and_body(
+                self.stored_procedure_client().pipeline().execute(request),
+                StatusCode::OK,
+            )
+            .await?;
+
+            Ok((&headers, &body as &[u8]).try_into()?)
+        })
+        .await
     }
 }
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::responses::CreateUserResponse;
-use azure_core::errors::{extract_status_headers_and_body, AzureError, UnexpectedHTTPResult};
+use azure_core::errors::{extract_status_headers_and_body, AzureError, HttpError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -201,40 +202,44 @@ where
     D: DatabaseClient<C>,
 {
     pub async fn execute(&self) -> Result<Option<CreateUserResponse>, AzureError> {
-        trace!("ReplaceUserBuilder::execute called");
-
-        let req = self
-            .user_client
-            .prepare_request_with_user_name(hyper::Method::PUT);
-
-        let req = UserAgentOption::add_header(self, req);
-        let req = ActivityIdOption::add_header(self, req);
-        let req = ConsistencyLevelOption::add_header(self, req);
-
-        #[derive(Serialize, Deserialize)]
-        struct RequestBody<'x> {
-            id: &'x str,
-        }
-        let request_body = RequestBody {
-            id: self.user_name().id(),
-        };
-        let request_body = serde_json::to_string(&request_body)?;
-
-        let req = req.body(hyper::Body::from(request_body))?;
-        debug!("\nreq == {:?}", req);
-
-        let (status_code, headers, body) =
-            extract_status_headers_and_body(self.user_client.hyper_client().request(req)).await?;
-
-        match status_code {
-            StatusCode::NOT_FOUND => Ok(None),
-            StatusCode::OK => Ok(Some((&headers, &body as &[u8]).try_into()?)),
-            _ => Err(UnexpectedHTTPResult::new_multiple(
-                vec![StatusCode::OK, StatusCode::NOT_FOUND],
-                status_code,
-                std::str::from_utf8(&body)?,
-            )
-            .into()),
-        }
+        instrument("ReplaceUserBuilder::execute", async move {
+            trace!("ReplaceUserBuilder::execute called");
+
+            let req = self
+                .user_client
+                .prepare_request_with_user_name(hyper::Method::PUT);
+
+            let req = UserAgentOption::add_header(self, req);
+            let req = ActivityIdOption::add_header(self, req);
+            let req = ConsistencyLevelOption::add_header(self, req);
+
+            #[derive(Serialize, Deserialize)]
+            struct RequestBody<'x> {
+                id: &'x str,
+            }
+            let request_body = RequestBody {
+                id: self.user_name().id(),
+            };
+            let request_body = serde_json::to_string(&request_body)?;
+
+            let req = req.body(bytes::Bytes::from(request_body))?;
+            debug!("\nreq == {:?}", req);
+
+            let (status_code, headers, body) =
+                extract_status_headers_and_body(self.user_client.pipeline().execute(req)).await?;
+
+            match status_code {
+                StatusCode::NOT_FOUND => Ok(None),
+                StatusCode::OK => Ok(Some((&headers, &body as &[u8]).try_into()?)),
+                _ => Err(HttpError::new_multiple(
+                    vec![StatusCode::OK, StatusCode::NOT_FOUND],
+                    status_code,
+                    &headers,
+                    &body,
+                )
+                .into()),
+            }
+        })
+        .await
     }
 }
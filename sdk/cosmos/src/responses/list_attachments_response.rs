@@ -2,6 +2,8 @@ use crate::from_headers::*;
 use crate::{Attachment, ResourceQuota};
 use azure_core::errors::AzureError;
 use azure_core::headers::{continuation_token_from_headers_optional, session_token_from_headers};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::Page;
 use azure_core::SessionToken;
 use chrono::{DateTime, Utc};
 use hyper::header::HeaderMap;
@@ -86,3 +88,15 @@ impl std::convert::TryFrom<(&HeaderMap, &[u8])> for ListAttachmentsResponse {
 
 #[cfg(test)]
 mod tests {}
+
+impl Page for ListAttachmentsResponse {
+    type Item = Attachment;
+
+    fn continuation(&self) -> Option<ContinuationToken> {
+        self.continuation_token.clone()
+    }
+
+    fn into_items(self) -> Vec<Attachment> {
+        self.attachments
+    }
+}
@@ -3,6 +3,8 @@ use crate::from_headers::*;
 use crate::ResourceQuota;
 use azure_core::errors::AzureError;
 use azure_core::headers::{continuation_token_from_headers_optional, session_token_from_headers};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::Page;
 use chrono::{DateTime, Utc};
 use http::HeaderMap;
 
@@ -60,3 +62,15 @@ impl std::convert::TryFrom<(&HeaderMap, &[u8])> for ListDatabasesResponse {
         })
     }
 }
+
+impl Page for ListDatabasesResponse {
+    type Item = Database;
+
+    fn continuation(&self) -> Option<ContinuationToken> {
+        self.continuation_token.clone()
+    }
+
+    fn into_items(self) -> Vec<Database> {
+        self.databases
+    }
+}
@@ -3,6 +3,8 @@ use crate::ResourceQuota;
 use crate::{Document, DocumentAttributes};
 use azure_core::errors::AzureError;
 use azure_core::headers::{continuation_token_from_headers_optional, session_token_from_headers};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::Page;
 use azure_core::SessionToken;
 use chrono::{DateTime, Utc};
 use hyper::header::HeaderMap;
@@ -182,3 +184,15 @@ mod tests {
                 .unwrap();
     }
 }
+
+impl<T> Page for ListDocumentsResponse<T> {
+    type Item = Document<T>;
+
+    fn continuation(&self) -> Option<ContinuationToken> {
+        self.continuation_token.clone()
+    }
+
+    fn into_items(self) -> Vec<Document<T>> {
+        self.documents
+    }
+}
@@ -3,6 +3,8 @@ use crate::permission::CosmosPermission;
 use crate::Permission;
 use azure_core::errors::AzureError;
 use azure_core::headers::{continuation_token_from_headers_optional, session_token_from_headers};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::Page;
 use http::HeaderMap;
 use std::borrow::Cow;
 
@@ -57,3 +59,15 @@ impl<'a> std::convert::TryFrom<(&HeaderMap, &[u8])> for ListPermissionsResponse<
         })
     }
 }
+
+impl<'a> Page for ListPermissionsResponse<'a> {
+    type Item = Permission<'a, Cow<'a, str>>;
+
+    fn continuation(&self) -> Option<ContinuationToken> {
+        self.continuation_token.clone()
+    }
+
+    fn into_items(self) -> Vec<Permission<'a, Cow<'a, str>>> {
+        self.permissions
+    }
+}
@@ -3,6 +3,8 @@ use crate::stored_procedure::StoredProcedure;
 use crate::ResourceQuota;
 use azure_core::errors::AzureError;
 use azure_core::headers::{continuation_token_from_headers_optional, session_token_from_headers};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::Page;
 use chrono::{DateTime, Utc};
 use http::HeaderMap;
 
@@ -46,3 +48,15 @@ impl std::convert::TryFrom<(&HeaderMap, &[u8])> for ListStoredProceduresResponse
         })
     }
 }
+
+impl Page for ListStoredProceduresResponse {
+    type Item = StoredProcedure;
+
+    fn continuation(&self) -> Option<ContinuationToken> {
+        self.continuation_token.clone()
+    }
+
+    fn into_items(self) -> Vec<StoredProcedure> {
+        self.stored_procedures
+    }
+}
@@ -2,6 +2,8 @@ use crate::from_headers::*;
 use crate::User;
 use azure_core::errors::AzureError;
 use azure_core::headers::{continuation_token_from_headers_optional, session_token_from_headers};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::Page;
 use azure_core::SessionToken;
 use http::HeaderMap;
 use serde::Deserialize;
@@ -40,3 +42,15 @@ impl std::convert::TryFrom<(&HeaderMap, &[u8])> for ListUsersResponse {
         Ok(list_users_response)
     }
 }
+
+impl Page for ListUsersResponse {
+    type Item = User;
+
+    fn continuation(&self) -> Option<ContinuationToken> {
+        self.continuation_token.clone()
+    }
+
+    fn into_items(self) -> Vec<User> {
+        self.users
+    }
+}
@@ -4,6 +4,8 @@ use crate::from_headers::*;
 use crate::ResourceQuota;
 use azure_core::errors::AzureError;
 use azure_core::headers::{continuation_token_from_headers_optional, session_token_from_headers};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::Page;
 use azure_core::SessionToken;
 use chrono::{DateTime, Utc};
 use hyper::header::HeaderMap;
@@ -324,3 +326,15 @@ impl<T> std::convert::TryFrom<QueryDocumentsResponse<T>> for QueryDocumentsRespo
         })
     }
 }
+
+impl<T> Page for QueryDocumentsResponse<T> {
+    type Item = QueryResult<T>;
+
+    fn continuation(&self) -> Option<ContinuationToken> {
+        self.continuation_token.clone()
+    }
+
+    fn into_items(self) -> Vec<QueryResult<T>> {
+        self.results
+    }
+}
@@ -2,15 +2,14 @@ use crate::requests;
 use crate::{PartitionKeys, ResourceType};
 use azure_core::No;
 use http::request::Builder;
-use hyper_rustls::HttpsConnector;
 use std::borrow::Cow;
 use std::fmt::Debug;
 
-pub trait HasHyperClient: Debug + Send + Sync {
-    fn hyper_client(&self) -> &hyper::Client<HttpsConnector<hyper::client::HttpConnector>>;
+pub trait HasPipeline: Debug + Send + Sync {
+    fn pipeline(&self) -> &azure_core::pipeline::Pipeline;
 }
 
-pub trait CosmosClient: HasHyperClient + Send + Sync {
+pub trait CosmosClient: HasPipeline + Send + Sync {
     fn create_database(&self) -> requests::CreateDatabaseBuilder<'_, No>;
     fn list_databases(&self) -> requests::ListDatabasesBuilder<'_>;
 
@@ -22,7 +21,7 @@ pub trait CosmosClient: HasHyperClient + Send + Sync {
     ) -> Builder;
 }
 
-pub trait HasCosmosClient<C>: HasHyperClient
+pub trait HasCosmosClient<C>: HasPipeline
 where
     C: CosmosClient,
 {
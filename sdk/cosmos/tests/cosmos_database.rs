@@ -7,7 +7,7 @@ mod setup;
 async fn create_and_delete_database() {
     const DATABASE_NAME: &str = "cosmos-test-db-create-and-delete-database";
 
-    let client = setup::initialize().unwrap();
+    let client = setup::initialize_recorded("create_and_delete_database").unwrap();
 
     // list existing databases and remember their number
     let databases = client.list_databases().execute().await.unwrap();
@@ -21,7 +21,7 @@ async fn create_and_delete_document() {
     const COLLECTION_NAME: &str = "test-collection-create-and-delete-document";
     const DOCUMENT_NAME: &str = "test-document-name-create-and-delete-document";
 
-    let client = setup::initialize().unwrap();
+    let client = setup::initialize_recorded("create_and_delete_document").unwrap();
 
     client
         .create_database()
@@ -97,7 +97,7 @@ async fn create_and_delete_document() {
         .await
         .unwrap()
         .documents;
-    assert!(documents.len() == 0);
+    assert!(documents.is_empty());
 
     database_client.delete_database().execute().await.unwrap();
 }
@@ -108,7 +108,7 @@ async fn query_documents() {
     const COLLECTION_NAME: &str = "test-collection-query-documents";
     const DOCUMENT_NAME: &str = "test-document-name-query-documents";
 
-    let client = setup::initialize().unwrap();
+    let client = setup::initialize_recorded("query_documents").unwrap();
 
     client
         .create_database()
@@ -183,7 +183,7 @@ async fn replace_document() {
     const COLLECTION_NAME: &str = "test-collection-replace-documents";
     const DOCUMENT_NAME: &str = "test-document-name-replace-documents";
 
-    let client = setup::initialize().unwrap();
+    let client = setup::initialize_recorded("replace_document").unwrap();
 
     client
         .create_database()
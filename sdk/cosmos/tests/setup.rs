@@ -1,4 +1,8 @@
+// every test file includes this module but only uses part of it
+#![allow(dead_code)]
+
 use azure_core::errors::AzureError;
+use azure_core::mock_transport::{self, TestMode};
 use azure_cosmos::clients::DefaultCosmosUri;
 use azure_cosmos::prelude::*;
 use azure_cosmos::AuthorizationToken;
@@ -13,3 +17,24 @@ pub fn initialize() -> Result<CosmosStruct<'static, DefaultCosmosUri>, AzureErro
 
     Ok(client)
 }
+
+/// Like [`initialize`], unless `AZURE_TEST_MODE` asks to record the traffic
+/// or to play it back (see `azure_core::mock_transport`).
+///
+/// The files in `tests/fixtures` are written by hand to mirror the Cosmos
+/// REST reference; they were never captured from the service. Point
+/// `AZURE_TEST_RECORDINGS` at them to run the tests offline.
+pub fn initialize_recorded(
+    recording_name: &str,
+) -> Result<CosmosStruct<'static, DefaultCosmosUri>, AzureError> {
+    let (client, pipeline) = mock_transport::initialize_recorded(
+        TestMode::from_env()?,
+        recording_name,
+        initialize,
+        || {
+            let authorization_token = AuthorizationToken::new_master(&base64::encode("key"))?;
+            ClientBuilder::new("playback", authorization_token)
+        },
+    )?;
+    Ok(client.with_pipeline(pipeline))
+}
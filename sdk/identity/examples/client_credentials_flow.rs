@@ -1,3 +1,4 @@
+use azure_core::AzureEnvironment;
 use azure_identity::client_credentials_flow;
 use oauth2::{ClientId, ClientSecret};
 use url::Url;
@@ -24,6 +25,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         &client_secret,
         "https://management.azure.com/",
         &tenant_id,
+        &AzureEnvironment::default(),
     )
     .await?;
     println!("Non interactive authorization == {:?}", token);
@@ -1,3 +1,4 @@
+use azure_core::AzureEnvironment;
 use azure_identity::client_credentials_flow;
 use oauth2::{ClientId, ClientSecret};
 
@@ -31,6 +32,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
             storage_account_name
         ),
         &tenant_id,
+        &AzureEnvironment::default(),
     )
     .await?;
 
@@ -1,3 +1,4 @@
+use azure_core::AzureEnvironment;
 use azure_identity::*;
 use oauth2::{ClientId, ClientSecret, TokenResponse};
 use std::env;
@@ -22,6 +23,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         &tenant_id,
         Url::parse("http://localhost:3003/redirect").unwrap(),
         "https://management.azure.com/user_impersonation",
+        &AzureEnvironment::default(),
     );
 
     println!("c == {:?}", c);
@@ -1,3 +1,4 @@
+use azure_core::AzureEnvironment;
 use azure_identity::*;
 use oauth2::{ClientId, ClientSecret, TokenResponse};
 use std::env;
@@ -30,6 +31,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
             "https://{}.blob.core.windows.net/user_impersonation",
             storage_account_name
         ),
+        &AzureEnvironment::default(),
     );
 
     println!("c == {:?}", c);
@@ -1,3 +1,4 @@
+use azure_core::AzureEnvironment;
 use azure_identity::device_code_flow::{self, DeviceCodeResponse};
 use azure_identity::refresh_token;
 use azure_storage::blob::prelude::*;
@@ -36,6 +37,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
             ),
             "offline_access",
         ],
+        &AzureEnvironment::default(),
     )
     .await?;
 
@@ -88,8 +90,15 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     // now let's refresh the token, if available
     if let Some(refresh_token) = authorization.refresh_token() {
-        let refreshed_token =
-            refresh_token::exchange(&client, &tenant_id, &client_id, None, refresh_token).await?;
+        let refreshed_token = refresh_token::exchange(
+            &client,
+            &tenant_id,
+            &client_id,
+            None,
+            refresh_token,
+            &AzureEnvironment::default(),
+        )
+        .await?;
         println!("refreshed token == {:#?}", refreshed_token);
     }
 
@@ -0,0 +1,28 @@
+use azure_identity::subscriptions::{self, SubscriptionClient};
+use azure_identity::token_credentials::DefaultCredential;
+use futures::TryStreamExt;
+use std::error::Error;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let client = SubscriptionClient::new(Arc::new(DefaultCredential::default()));
+
+    let mut tenants = client.list_tenants().into_items();
+    while let Some(tenant) = tenants.try_next().await? {
+        println!("tenant {} {:?}", tenant.tenant_id, tenant.display_name);
+    }
+    let mut subscriptions = client.list_subscriptions().into_items();
+    while let Some(subscription) = subscriptions.try_next().await? {
+        println!(
+            "subscription {} {} ({})",
+            subscription.subscription_id, subscription.display_name, subscription.state
+        );
+    }
+
+    println!(
+        "default subscription {}",
+        subscriptions::default_subscription_id()?
+    );
+    Ok(())
+}
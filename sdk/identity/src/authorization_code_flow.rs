@@ -2,6 +2,7 @@
 //!
 //! You can learn more about the OAuth2 authorization code flow [here](https://docs.microsoft.com/en-us/azure/active-directory/develop/v2-oauth2-auth-code-flow).
 
+use azure_core::AzureEnvironment;
 use log::debug;
 use oauth2::basic::BasicClient;
 use oauth2::reqwest::async_http_client;
@@ -18,17 +19,20 @@ pub fn start(
     tenant_id: &str,
     redirect_url: Url,
     resource: &str,
+    environment: &AzureEnvironment,
 ) -> AuthorizationCodeFlow {
     let auth_url = oauth2::AuthUrl::from_url(
         Url::parse(&format!(
-            "https://login.microsoftonline.com/{}/oauth2/v2.0/authorize",
+            "{}/{}/oauth2/v2.0/authorize",
+            environment.authority_host(),
             tenant_id
         ))
         .expect("Invalid authorization endpoint URL"),
     );
     let token_url = oauth2::TokenUrl::from_url(
         Url::parse(&format!(
-            "https://login.microsoftonline.com/{}/oauth2/v2.0/token",
+            "{}/{}/oauth2/v2.0/token",
+            environment.authority_host(),
             tenant_id
         ))
         .expect("Invalid token endpoint URL"),
@@ -39,7 +43,7 @@ pub fn start(
         // Microsoft Graph requires client_id and client_secret in URL rather than
         // using Basic authentication.
         .set_auth_type(oauth2::AuthType::RequestBody)
-        .set_redirect_url(oauth2::RedirectUrl::from_url(redirect_url));
+        .set_redirect_uri(oauth2::RedirectUrl::from_url(redirect_url));
 
     // Microsoft Graph supports Proof Key for Code Exchange (PKCE - https://oauth.net/2/pkce/).
     // Create a PKCE code verifier and SHA-256 encode it as a code challenge.
@@ -81,7 +85,7 @@ impl AuthorizationCodeFlow {
     ) -> Result<
         oauth2::StandardTokenResponse<oauth2::EmptyExtraTokenFields, oauth2::basic::BasicTokenType>,
         oauth2::RequestTokenError<
-            oauth2::reqwest::Error<reqwest::Error>,
+            oauth2::reqwest::AsyncHttpClientError,
             oauth2::StandardErrorResponse<oauth2::basic::BasicErrorResponseType>,
         >,
     > {
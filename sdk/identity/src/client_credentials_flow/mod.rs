@@ -3,6 +3,7 @@
 //! For example:
 //!
 //! ```no_run
+//! use azure_core::AzureEnvironment;
 //! use azure_identity::client_credentials_flow;
 //! use oauth2::{ClientId, ClientSecret};
 //! use url::Url;
@@ -29,6 +30,7 @@
 //!         &client_secret,
 //!         "https://management.azure.com/",
 //!         &tenant_id,
+//!         &AzureEnvironment::default(),
 //!     )
 //!     .await?;
 //!     Ok(())
@@ -43,6 +45,7 @@ use super::errors;
 use login_response::LoginResponse;
 
 use azure_core::errors::AzureError;
+use azure_core::AzureEnvironment;
 use futures::TryFutureExt;
 use url::form_urlencoded;
 
@@ -53,6 +56,7 @@ pub async fn perform(
     client_secret: &oauth2::ClientSecret,
     scope: &str,
     tenant_id: &str,
+    environment: &AzureEnvironment,
 ) -> Result<LoginResponse, AzureError> {
     let encoded: String = form_urlencoded::Serializer::new(String::new())
         .append_pair("client_id", client_id.as_str())
@@ -62,7 +66,8 @@ pub async fn perform(
         .finish();
 
     let url = url::Url::parse(&format!(
-        "https://login.microsoftonline.com/{}/oauth2/v2.0/token",
+        "{}/{}/oauth2/v2.0/token",
+        environment.authority_host(),
         tenant_id
     ))
     .map_err(|error| AzureError::GenericErrorWithText(error.to_string()))?;
@@ -9,6 +9,7 @@ pub use device_code_responses::*;
 
 use async_timer::timer::new_timer;
 use azure_core::errors::AzureError;
+use azure_core::AzureEnvironment;
 use futures::stream::unfold;
 use log::debug;
 use oauth2::ClientId;
@@ -24,6 +25,7 @@ pub async fn start<'a, 'b, T>(
     tenant_id: T,
     client_id: &'a ClientId,
     scopes: &'b [&'b str],
+    environment: &AzureEnvironment,
 ) -> Result<DeviceCodePhaseOneResponse<'a>, AzureError>
 where
     T: Into<Cow<'a, str>>,
@@ -38,7 +40,8 @@ where
     debug!("encoded ==> {}", encoded);
 
     let url = url::Url::parse(&format!(
-        "https://login.microsoftonline.com/{}/oauth2/v2.0/devicecode",
+        "{}/{}/oauth2/v2.0/devicecode",
+        environment.authority_host(),
         tenant_id
     ))?;
 
@@ -64,6 +67,7 @@ where
                     interval: device_code_reponse.interval,
                     message: device_code_reponse.message,
                     client: Some(client),
+                    authority_host: environment.authority_host().to_owned(),
                     tenant_id,
                     client_id: client_id.as_str().to_string(),
                 })
@@ -94,6 +98,8 @@ pub struct DeviceCodePhaseOneResponse<'a> {
     #[serde(skip)]
     client: Option<&'a reqwest::Client>,
     #[serde(skip)]
+    authority_host: String,
+    #[serde(skip)]
     tenant_id: Cow<'a, str>,
     // we store the ClientId as string instead of
     // the original type because it does not
@@ -121,8 +127,8 @@ impl<'a> DeviceCodePhaseOneResponse<'a> {
             match state {
                 NextState::Continue => {
                     let uri = format!(
-                        "https://login.microsoftonline.com/{}/oauth2/v2.0/token",
-                        self.tenant_id,
+                        "{}/{}/oauth2/v2.0/token",
+                        self.authority_host, self.tenant_id,
                     );
 
                     // throttle down as specified by Azure. This could be
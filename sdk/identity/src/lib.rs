@@ -3,6 +3,7 @@
 //! For example, to authenticate using the client credential flow, you can do the following:
 //!
 //! ```no_run
+//! use azure_core::AzureEnvironment;
 //! use azure_identity::client_credentials_flow;
 //! use oauth2::{ClientId, ClientSecret};
 //! use url::Url;
@@ -29,6 +30,7 @@
 //!         &client_secret,
 //!         "https://management.azure.com/",
 //!         &tenant_id,
+//!         &AzureEnvironment::default(),
 //!     )
 //!     .await?;
 //!     Ok(())
@@ -40,7 +42,8 @@
 //! * [Client credentials flow](https://docs.microsoft.com/en-us/azure/active-directory/develop/v2-oauth2-client-creds-grant-flow).
 //! * [Device code flow](https://docs.microsoft.com/en-us/azure/active-directory/develop/v2-oauth2-device-code).
 //!
-//! This crate also includes utilities for handling refresh tokens and accessing token credentials from many different sources.
+//! This crate also includes utilities for handling refresh tokens and accessing token credentials from many different sources,
+//! and for finding the [`subscriptions`] and tenants a credential has access to.
 
 pub mod authorization_code_flow;
 pub mod client_credentials_flow;
@@ -49,5 +52,6 @@ pub mod development;
 pub mod device_code_flow;
 pub mod errors;
 pub mod refresh_token;
+pub mod subscriptions;
 pub mod token_credentials;
 mod traits;
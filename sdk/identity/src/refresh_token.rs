@@ -2,6 +2,7 @@
 
 use crate::traits::{BearerToken, ExtExpiresIn, RefreshToken};
 use azure_core::errors::AzureError;
+use azure_core::AzureEnvironment;
 use log::debug;
 use oauth2::{AccessToken, ClientId, ClientSecret};
 use serde::Deserialize;
@@ -15,6 +16,7 @@ pub async fn exchange(
     client_id: &ClientId,
     client_secret: Option<&ClientSecret>,
     refresh_token: &AccessToken,
+    environment: &AzureEnvironment,
 ) -> Result<RefreshTokenResponse, AzureError> {
     let mut encoded = form_urlencoded::Serializer::new(String::new());
     let encoded = encoded.append_pair("grant_type", "refresh_token");
@@ -31,7 +33,8 @@ pub async fn exchange(
     debug!("encoded ==> {}", encoded);
 
     let url = url::Url::parse(&format!(
-        "https://login.microsoftonline.com/{}/oauth2/v2.0/token",
+        "{}/{}/oauth2/v2.0/token",
+        environment.authority_host(),
         tenant_id
     ))?;
 
@@ -0,0 +1,379 @@
+//! Discovery of the tenants and subscriptions a credential has access to.
+//!
+//! ```no_run
+//! use azure_identity::subscriptions::{self, Subscription, SubscriptionClient};
+//! use azure_identity::token_credentials::DefaultCredential;
+//! use futures::TryStreamExt;
+//! use std::sync::Arc;
+//!
+//! # async fn example() -> Result<(), azure_core::errors::AzureError> {
+//! let client = SubscriptionClient::new(Arc::new(DefaultCredential::default()));
+//! let subscriptions: Vec<Subscription> =
+//!     client.list_subscriptions().into_items().try_collect().await?;
+//! for subscription in subscriptions {
+//!     println!("{} {}", subscription.subscription_id, subscription.display_name);
+//! }
+//! // `AZURE_SUBSCRIPTION_ID`, else the default subscription of the Azure CLI
+//! let subscription_id = subscriptions::default_subscription_id()?;
+//! # Ok(())
+//! # }
+//! ```
+use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::pipeline::Pipeline;
+use azure_core::policies::{BearerTokenPolicy, Policy, RetryOptions, TransportPolicy};
+use azure_core::{AzureEnvironment, Page, Pageable, TokenCredential};
+use bytes::Bytes;
+use http::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+const AZURE_SUBSCRIPTION_ID_ENV_KEY: &str = "AZURE_SUBSCRIPTION_ID";
+const AZURE_CONFIG_DIR_ENV_KEY: &str = "AZURE_CONFIG_DIR";
+const API_VERSION: &str = "2020-01-01";
+
+/// An Azure Active Directory tenant.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tenant {
+    pub tenant_id: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub default_domain: Option<String>,
+}
+
+/// An Azure subscription.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Subscription {
+    pub subscription_id: String,
+    pub display_name: String,
+    /// `Enabled`, `Disabled`, `Warned`, `PastDue` or `Deleted`.
+    pub state: String,
+    #[serde(default)]
+    pub tenant_id: Option<String>,
+}
+
+/// A page of tenants or subscriptions. Its continuation token is the
+/// `nextLink` of the page.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPage<T> {
+    pub value: Vec<T>,
+    #[serde(default)]
+    pub next_link: Option<String>,
+}
+
+impl<T> Page for ListPage<T> {
+    type Item = T;
+
+    fn continuation(&self) -> Option<ContinuationToken> {
+        self.next_link
+            .clone()
+            .filter(|next_link| !next_link.is_empty())
+    }
+
+    fn into_items(self) -> Vec<T> {
+        self.value
+    }
+}
+
+/// Lists the tenants and subscriptions a credential can see, through the
+/// Azure Resource Manager.
+pub struct SubscriptionClient {
+    credential: Arc<dyn TokenCredential + Send + Sync>,
+    environment: AzureEnvironment,
+    retry_options: RetryOptions,
+    transport_policy: Arc<dyn Policy>,
+}
+
+impl SubscriptionClient {
+    pub fn new(credential: Arc<dyn TokenCredential + Send + Sync>) -> Self {
+        Self {
+            credential,
+            environment: AzureEnvironment::default(),
+            retry_options: RetryOptions::default(),
+            transport_policy: Arc::new(TransportPolicy::default()),
+        }
+    }
+
+    /// Uses the Azure Resource Manager of another Azure cloud.
+    pub fn with_environment(self, environment: &AzureEnvironment) -> Self {
+        Self {
+            environment: environment.clone(),
+            ..self
+        }
+    }
+
+    pub fn with_retry_options(self, retry_options: RetryOptions) -> Self {
+        Self {
+            retry_options,
+            ..self
+        }
+    }
+
+    /// Sends the requests with `transport_policy`, the last policy of the
+    /// pipeline, instead of the default `HttpClient`.
+    pub fn with_transport_policy(self, transport_policy: Arc<dyn Policy>) -> Self {
+        Self {
+            transport_policy,
+            ..self
+        }
+    }
+
+    /// Lists the tenants the credential has access to.
+    pub fn list_tenants(&self) -> Pageable<'_, ListPage<Tenant>> {
+        self.list("tenants")
+    }
+
+    /// Lists the subscriptions the credential has access to, in all its tenants.
+    pub fn list_subscriptions(&self) -> Pageable<'_, ListPage<Subscription>> {
+        self.list("subscriptions")
+    }
+
+    fn pipeline(&self) -> Pipeline {
+        Pipeline::new(
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION"),
+            Vec::new(),
+            self.retry_options.clone(),
+            vec![Arc::new(BearerTokenPolicy::new(
+                self.credential.clone(),
+                self.environment.resource_manager_resource(),
+            ))],
+            self.transport_policy.clone(),
+        )
+    }
+
+    fn list<T>(&self, collection: &str) -> Pageable<'_, ListPage<T>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let pipeline = self.pipeline();
+        let first_page = format!(
+            "{}/{}?api-version={}",
+            self.environment.resource_manager_endpoint(),
+            collection,
+            API_VERSION
+        );
+        Pageable::new(None, move |continuation: Option<ContinuationToken>| {
+            let pipeline = pipeline.clone();
+            let uri = continuation.unwrap_or_else(|| first_page.clone());
+            async move {
+                let request = http::Request::builder()
+                    .method(Method::GET)
+                    .uri(uri)
+                    .body(Bytes::new())?;
+                let (_headers, body) = check_status_extract_headers_and_body(
+                    pipeline.execute(request),
+                    StatusCode::OK,
+                )
+                .await?;
+                Ok(serde_json::from_slice(&body)?)
+            }
+        })
+    }
+}
+
+/// A subscription of the profile of the Azure CLI.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileSubscription {
+    #[serde(rename = "id")]
+    pub subscription_id: String,
+    #[serde(rename = "name")]
+    pub display_name: String,
+    pub state: String,
+    pub tenant_id: String,
+    /// The name of the Azure cloud, as in [`AzureEnvironment::name`].
+    pub environment_name: String,
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+/// The profile of the Azure CLI: the subscriptions `az login` found and the
+/// one `az account set` selected.
+///
+/// It is read from `azureProfile.json`, in the directory set by
+/// `AZURE_CONFIG_DIR` or else `~/.azure`, without running the CLI.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AzureProfile {
+    #[serde(default)]
+    pub subscriptions: Vec<ProfileSubscription>,
+}
+
+impl AzureProfile {
+    /// Reads the profile of the Azure CLI of the current user.
+    pub fn load() -> Result<Self, AzureError> {
+        let config_dir = match std::env::var_os(AZURE_CONFIG_DIR_ENV_KEY) {
+            Some(config_dir) => PathBuf::from(config_dir),
+            None => home_dir()
+                .ok_or_else(|| {
+                    AzureError::GenericErrorWithText("Home directory not found".to_owned())
+                })?
+                .join(".azure"),
+        };
+        Self::from_file(config_dir.join("azureProfile.json"))
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, AzureError> {
+        let json = std::fs::read_to_string(path)?;
+        Self::from_json(&json)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, AzureError> {
+        // the CLI writes the file with a byte order mark
+        Ok(serde_json::from_str(json.trim_start_matches('\u{feff}'))?)
+    }
+
+    /// The subscription selected with `az account set`.
+    pub fn default_subscription(&self) -> Option<&ProfileSubscription> {
+        self.subscriptions
+            .iter()
+            .find(|subscription| subscription.is_default)
+    }
+}
+
+/// The subscription to use when none is given: `AZURE_SUBSCRIPTION_ID` if it
+/// is set and not empty, else the default subscription of the
+/// [`AzureProfile`].
+pub fn default_subscription_id() -> Result<String, AzureError> {
+    match std::env::var(AZURE_SUBSCRIPTION_ID_ENV_KEY) {
+        Ok(subscription_id) if !subscription_id.is_empty() => return Ok(subscription_id),
+        _ => {}
+    }
+    AzureProfile::load()?
+        .default_subscription()
+        .map(|subscription| subscription.subscription_id.clone())
+        .ok_or_else(|| {
+            AzureError::GenericErrorWithText(format!(
+                "Missing subscription id set in {} environment variable or Azure CLI profile",
+                AZURE_SUBSCRIPTION_ID_ENV_KEY
+            ))
+        })
+}
+
+fn home_dir() -> Option<PathBuf> {
+    let key = if cfg!(target_os = "windows") {
+        "USERPROFILE"
+    } else {
+        "HOME"
+    };
+    std::env::var_os(key).map(PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::errors::{ErrorKind, HttpError};
+    use azure_core::mock_transport::{ScriptedResponse, ScriptedTransport};
+    use azure_core::TokenResponse;
+    use futures::TryStreamExt;
+    use http::header::AUTHORIZATION;
+    use oauth2::AccessToken;
+
+    struct StaticTokenCredential;
+
+    #[async_trait::async_trait]
+    impl TokenCredential for StaticTokenCredential {
+        async fn get_token(&self, resource: &str) -> Result<TokenResponse, AzureError> {
+            Ok(TokenResponse::new(
+                AccessToken::new(format!("token for {}", resource)),
+                chrono::Utc::now(),
+            ))
+        }
+    }
+
+    fn client(transport: &Arc<ScriptedTransport>) -> SubscriptionClient {
+        SubscriptionClient::new(Arc::new(StaticTokenCredential))
+            .with_transport_policy(transport.clone())
+    }
+
+    #[tokio::test]
+    async fn lists_every_page() {
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            ScriptedResponse::new(
+                StatusCode::OK,
+                r#"{"value": [{"subscriptionId": "1111", "displayName": "Dev", "state": "Enabled"}],
+                    "nextLink": "https://management.azure.com/subscriptions?api-version=2020-01-01&$skiptoken=abc"}"#,
+            ),
+            ScriptedResponse::new(
+                StatusCode::OK,
+                r#"{"value": [{"subscriptionId": "2222", "displayName": "Prod", "state": "Enabled", "tenantId": "aaaa"}]}"#,
+            ),
+        ]));
+
+        let subscriptions: Vec<Subscription> = client(&transport)
+            .list_subscriptions()
+            .into_items()
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(subscriptions.len(), 2);
+        assert_eq!(subscriptions[1].subscription_id, "2222");
+        assert_eq!(subscriptions[1].tenant_id.as_deref(), Some("aaaa"));
+        let requests = transport.requests();
+        assert_eq!(
+            requests[0].uri(),
+            "https://management.azure.com/subscriptions?api-version=2020-01-01"
+        );
+        assert!(requests[1].uri().to_string().ends_with("&$skiptoken=abc"));
+        assert_eq!(
+            requests[0].headers()[AUTHORIZATION],
+            "Bearer token for https://management.azure.com/"
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_with_the_http_error() {
+        let transport = Arc::new(ScriptedTransport::new(vec![ScriptedResponse::new(
+            StatusCode::FORBIDDEN,
+            r#"{"error": {"code": "AuthorizationFailed", "message": "no access"}}"#,
+        )]));
+
+        let err = client(&transport)
+            .list_tenants()
+            .into_items()
+            .try_collect::<Vec<Tenant>>()
+            .await
+            .unwrap_err();
+
+        match err {
+            AzureError::HttpError(err) => {
+                let err: HttpError = *err;
+                assert_eq!(err.status(), StatusCode::FORBIDDEN);
+                assert_eq!(err.kind(), ErrorKind::Auth);
+                assert_eq!(err.error_code(), Some("AuthorizationFailed"));
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    #[test]
+    fn reads_the_azure_cli_profile() {
+        let json = "\u{feff}{\"installationId\": \"4b7d\", \"subscriptions\": [\
+            {\"id\": \"1111\", \"name\": \"Dev\", \"state\": \"Enabled\", \"user\": {\"name\": \"me\", \"type\": \"user\"}, \
+             \"isDefault\": false, \"tenantId\": \"aaaa\", \"environmentName\": \"AzureCloud\"}, \
+            {\"id\": \"2222\", \"name\": \"Prod\", \"state\": \"Enabled\", \"user\": {\"name\": \"me\", \"type\": \"user\"}, \
+             \"isDefault\": true, \"tenantId\": \"aaaa\", \"environmentName\": \"AzureCloud\", \"homeTenantId\": \"aaaa\"}]}";
+
+        let profile = AzureProfile::from_json(json).unwrap();
+
+        assert_eq!(profile.subscriptions.len(), 2);
+        let default = profile.default_subscription().unwrap();
+        assert_eq!(default.subscription_id, "2222");
+        assert_eq!(default.display_name, "Prod");
+        assert_eq!(default.environment_name, AzureEnvironment::public().name());
+    }
+
+    #[test]
+    fn reads_a_profile_without_subscriptions() {
+        let profile = AzureProfile::from_json("{\"installationId\": \"4b7d\"}").unwrap();
+        assert_eq!(profile.default_subscription(), None);
+    }
+}
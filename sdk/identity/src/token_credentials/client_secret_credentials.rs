@@ -1,5 +1,5 @@
 use azure_core::errors::AzureError;
-use azure_core::{TokenCredential, TokenResponse};
+use azure_core::{AzureEnvironment, TokenCredential, TokenResponse};
 use chrono::Utc;
 use oauth2::{
     basic::BasicClient, reqwest::async_http_client, AccessToken, AuthType, AuthUrl, Scope, TokenUrl,
@@ -15,6 +15,7 @@ pub struct ClientSecretCredential {
     tenant_id: String,
     client_id: oauth2::ClientId,
     client_secret: Option<oauth2::ClientSecret>,
+    authority_host: String,
 }
 
 impl ClientSecretCredential {
@@ -27,6 +28,15 @@ impl ClientSecretCredential {
             tenant_id,
             client_id: oauth2::ClientId::new(client_id),
             client_secret: Some(oauth2::ClientSecret::new(client_secret)),
+            authority_host: AzureEnvironment::public().authority_host().to_owned(),
+        }
+    }
+
+    /// Requests the tokens from the Azure Active Directory of another Azure cloud.
+    pub fn with_environment(self, environment: &AzureEnvironment) -> Self {
+        Self {
+            authority_host: environment.authority_host().to_owned(),
+            ..self
         }
     }
 }
@@ -36,8 +46,8 @@ impl TokenCredential for ClientSecretCredential {
     async fn get_token(&self, resource: &str) -> Result<TokenResponse, AzureError> {
         let token_url = TokenUrl::from_url(
             Url::parse(&format!(
-                "https://login.microsoftonline.com/{}/oauth2/v2.0/token",
-                self.tenant_id
+                "{}/{}/oauth2/v2.0/token",
+                self.authority_host, self.tenant_id
             ))
             .map_err(|_| {
                 AzureError::GenericErrorWithText(format!(
@@ -49,8 +59,8 @@ impl TokenCredential for ClientSecretCredential {
 
         let auth_url = AuthUrl::from_url(
             Url::parse(&format!(
-                "https://login.microsoftonline.com/{}/oauth2/v2.0/authorize",
-                self.tenant_id
+                "{}/{}/oauth2/v2.0/authorize",
+                self.authority_host, self.tenant_id
             ))
             .map_err(|_| {
                 AzureError::GenericErrorWithText(format!(
@@ -1,6 +1,6 @@
 use super::{AzureCliCredential, EnvironmentCredential, ManagedIdentityCredential};
 use azure_core::errors::AzureError;
-use azure_core::{TokenCredential, TokenResponse};
+use azure_core::{AzureEnvironment, TokenCredential, TokenResponse};
 use log::debug;
 
 /// Provides a mechanism of selectively disabling credentials used for a `DefaultCredential` instance
@@ -8,6 +8,7 @@ pub struct DefaultCredentialBuilder {
     include_environment_credential: bool,
     include_managed_identity_credential: bool,
     include_cli_credential: bool,
+    environment: AzureEnvironment,
 }
 
 impl DefaultCredentialBuilder {
@@ -17,9 +18,16 @@ impl DefaultCredentialBuilder {
             include_cli_credential: true,
             include_managed_identity_credential: true,
             include_environment_credential: true,
+            environment: AzureEnvironment::default(),
         }
     }
 
+    /// Requests the tokens from the Azure Active Directory of another Azure cloud
+    pub fn with_environment(&mut self, environment: &AzureEnvironment) -> &mut Self {
+        self.environment = environment.clone();
+        self
+    }
+
     /// Exclude using credentials from the environment
     pub fn exclude_environment_credential(&mut self) -> &mut Self {
         self.include_environment_credential = false;
@@ -45,7 +53,9 @@ impl DefaultCredentialBuilder {
         let mut sources =
             Vec::<Box<dyn TokenCredential + Send + Sync>>::with_capacity(source_count);
         if self.include_environment_credential {
-            sources.push(Box::new(EnvironmentCredential {}));
+            sources.push(Box::new(
+                EnvironmentCredential::default().with_environment(&self.environment),
+            ));
         }
         if self.include_managed_identity_credential {
             sources.push(Box::new(ManagedIdentityCredential {}))
@@ -78,7 +88,7 @@ impl Default for DefaultCredential {
     fn default() -> Self {
         DefaultCredential {
             sources: vec![
-                Box::new(EnvironmentCredential {}),
+                Box::new(EnvironmentCredential::default()),
                 Box::new(ManagedIdentityCredential {}),
                 Box::new(AzureCliCredential {}),
             ],
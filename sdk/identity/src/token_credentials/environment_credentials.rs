@@ -1,6 +1,6 @@
 use super::ClientSecretCredential;
 use azure_core::errors::AzureError;
-use azure_core::{TokenCredential, TokenResponse};
+use azure_core::{AzureEnvironment, TokenCredential, TokenResponse};
 
 const AZURE_TENANT_ID_ENV_KEY: &str = "AZURE_TENANT_ID";
 const AZURE_CLIENT_ID_ENV_KEY: &str = "AZURE_CLIENT_ID";
@@ -22,7 +22,19 @@ const AZURE_CLIENT_CERTIFICATE_PATH_ENV_KEY: &str = "AZURE_CLIENT_CERTIFICATE_PA
 /// This credential ultimately uses a `ClientSecretCredential` to perform the authentication using
 /// these details.
 /// Please consult the documentation of that class for more details.
-pub struct EnvironmentCredential;
+#[derive(Debug, Clone, Default)]
+pub struct EnvironmentCredential {
+    environment: AzureEnvironment,
+}
+
+impl EnvironmentCredential {
+    /// Requests the tokens from the Azure Active Directory of another Azure cloud.
+    pub fn with_environment(self, environment: &AzureEnvironment) -> Self {
+        Self {
+            environment: environment.clone(),
+        }
+    }
+}
 
 #[async_trait::async_trait]
 impl TokenCredential for EnvironmentCredential {
@@ -46,7 +58,8 @@ impl TokenCredential for EnvironmentCredential {
         let client_certificate_path = std::env::var(AZURE_CLIENT_CERTIFICATE_PATH_ENV_KEY);
 
         if let Ok(client_secret) = client_secret {
-            let credential = ClientSecretCredential::new(tenant_id, client_id, client_secret);
+            let credential = ClientSecretCredential::new(tenant_id, client_id, client_secret)
+                .with_environment(&self.environment);
             return credential.get_token(resource).await;
         } else if username.is_ok() && password.is_ok() {
             // Could use multiple if-let with #![feature(let_chains)] once stabilised - see https://github.com/rust-lang/rust/issues/53667
@@ -5,12 +5,16 @@
 //! * Azure CLI credentials cache
 //! * Managed identity
 //! * Client secret
+//!
+//! Wrap a credential in a [`CachedTokenCredential`] to reuse its tokens until they are about to
+//! expire.
 mod cli_credentials;
 mod client_secret_credentials;
 mod default_credentials;
 mod environment_credentials;
 mod managed_identity_credentials;
 
+pub use azure_core::CachedTokenCredential;
 pub use cli_credentials::*;
 pub use client_secret_credentials::*;
 pub use default_credentials::*;
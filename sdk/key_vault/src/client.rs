@@ -1,9 +1,14 @@
 use crate::KeyVaultError;
 use anyhow::Context;
 use anyhow::Result;
-use azure_core::{TokenCredential, TokenResponse};
+use azure_core::errors::{AzureError, HttpError};
+use azure_core::pipeline::Pipeline;
+use azure_core::policies::{RetryOptions, TransportPolicy};
+use azure_core::{AzureEnvironment, CachedTokenCredential, Request, TokenCredential};
+use http::header::{HeaderValue, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE};
+use http::{Method, StatusCode};
+use std::sync::Arc;
 
-pub(crate) const PUBLIC_ENDPOINT_SUFFIX: &str = "vault.azure.net";
 pub(crate) const API_VERSION: &str = "7.0";
 
 /// Client for Key Vault operations - getting a secret, listing secrets, etc.
@@ -18,14 +23,14 @@ pub(crate) const API_VERSION: &str = "7.0";
 /// ```
 #[derive(Debug)]
 pub struct KeyVaultClient<'a, T> {
-    pub(crate) token_credential: &'a T,
+    pub(crate) token_credential: CachedTokenCredential<&'a T>,
     pub(crate) keyvault_name: &'a str,
     pub(crate) endpoint_suffix: String,
     pub(crate) keyvault_endpoint: String,
-    pub(crate) token: Option<TokenResponse>,
+    pub(crate) pipeline: Pipeline,
 }
 
-impl<'a, T: TokenCredential> KeyVaultClient<'a, T> {
+impl<'a, T: TokenCredential + Sync> KeyVaultClient<'a, T> {
     /// Creates a new `KeyVaultClient` with an endpoint suffix. Useful for non-public Azure clouds.
     /// For the default public environment, use `KeyVaultClient::new`.
     ///
@@ -44,11 +49,11 @@ impl<'a, T: TokenCredential> KeyVaultClient<'a, T> {
     ) -> Self {
         let endpoint = format!("https://{}.{}", keyvault_name, endpoint_suffix);
         Self {
-            token_credential,
+            token_credential: CachedTokenCredential::new(token_credential),
             keyvault_name,
             endpoint_suffix,
             keyvault_endpoint: endpoint,
-            token: None,
+            pipeline: default_pipeline(),
         }
     }
 
@@ -66,15 +71,39 @@ impl<'a, T: TokenCredential> KeyVaultClient<'a, T> {
         KeyVaultClient::with_endpoint_suffix(
             token_credential,
             keyvault_name,
-            PUBLIC_ENDPOINT_SUFFIX.to_owned(),
+            AzureEnvironment::public()
+                .key_vault_endpoint_suffix()
+                .to_owned(),
         )
     }
 
-    pub(crate) async fn refresh_token(&mut self) -> Result<(), KeyVaultError> {
-        if matches!(&self.token, Some(token) if token.expires_on > chrono::Utc::now()) {
-            // Token is valid, return it.
-            return Ok(());
+    /// Points the client to the vault in another Azure cloud.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use azure_core::AzureEnvironment;
+    /// use azure_key_vault::KeyVaultClient;
+    /// use azure_identity::token_credentials::DefaultCredential;
+    /// let creds = DefaultCredential::default();
+    /// let client = KeyVaultClient::new(&creds, &"test-keyvault")
+    ///     .with_environment(&AzureEnvironment::china());
+    /// ```
+    pub fn with_environment(self, environment: &AzureEnvironment) -> Self {
+        let endpoint_suffix = environment.key_vault_endpoint_suffix().to_owned();
+        Self {
+            keyvault_endpoint: format!("https://{}.{}", self.keyvault_name, endpoint_suffix),
+            endpoint_suffix,
+            ..self
         }
+    }
+
+    /// Replaces the pipeline the requests of this client are sent through.
+    pub fn with_pipeline(self, pipeline: Pipeline) -> Self {
+        Self { pipeline, ..self }
+    }
+
+    pub(crate) async fn token(&self) -> Result<String, KeyVaultError> {
         let resource = format!("https://{}", &self.endpoint_suffix);
         let token = self
             .token_credential
@@ -82,20 +111,12 @@ impl<'a, T: TokenCredential> KeyVaultClient<'a, T> {
             .await
             .with_context(|| "Failed to authenticate to Azure Active Directory")
             .map_err(|e| KeyVaultError::AuthorizationError(e))?;
-        self.token = Some(token);
-        Ok(())
+        Ok(token.token.secret().to_owned())
     }
 
-    pub(crate) async fn get_authed(&mut self, uri: String) -> Result<String, KeyVaultError> {
-        self.refresh_token().await?;
-
-        let resp = reqwest::Client::new()
-            .get(&uri)
-            .bearer_auth(self.token.as_ref().unwrap().token.secret())
-            .send()
-            .await
-            .unwrap();
-        let body = resp.text().await.unwrap();
+    pub(crate) async fn get_authed(&self, uri: String) -> Result<String, KeyVaultError> {
+        let req = self.request(Method::GET, &uri).await?;
+        let body = self.execute(req).await?;
         Ok(body)
     }
 
@@ -104,17 +125,11 @@ impl<'a, T: TokenCredential> KeyVaultClient<'a, T> {
         uri: String,
         body: String,
     ) -> Result<String, KeyVaultError> {
-        self.refresh_token().await?;
-
-        let resp = reqwest::Client::new()
-            .put(&uri)
-            .bearer_auth(self.token.as_ref().unwrap().token.secret())
-            .header("Content-Type", "application/json")
-            .body(body)
-            .send()
-            .await
-            .unwrap();
-        let body = resp.text().await.unwrap();
+        let mut req = self.request(Method::PUT, &uri).await?;
+        req.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        req.set_body(body);
+        let body = self.execute(req).await?;
         Ok(body)
     }
 
@@ -123,21 +138,18 @@ impl<'a, T: TokenCredential> KeyVaultClient<'a, T> {
         uri: String,
         json_body: Option<String>,
     ) -> Result<String, KeyVaultError> {
-        self.refresh_token().await?;
-
-        let mut req = reqwest::Client::new()
-            .post(&uri)
-            .bearer_auth(self.token.as_ref().unwrap().token.secret());
+        let mut req = self.request(Method::POST, &uri).await?;
 
         if let Some(body) = json_body {
-            req = req.header("Content-Type", "application/json").body(body);
+            req.headers_mut()
+                .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            req.set_body(body);
         } else {
-            req = req.header("Content-Length", 0);
+            req.headers_mut()
+                .insert(CONTENT_LENGTH, HeaderValue::from_static("0"));
         }
 
-        let resp = req.send().await.unwrap();
-
-        let body = resp.text().await.unwrap();
+        let body = self.execute(req).await?;
         Ok(body)
     }
 
@@ -146,42 +158,61 @@ impl<'a, T: TokenCredential> KeyVaultClient<'a, T> {
         uri: String,
         body: String,
     ) -> Result<String, KeyVaultError> {
-        self.refresh_token().await?;
-
-        let resp = reqwest::Client::new()
-            .patch(&uri)
-            .bearer_auth(self.token.as_ref().unwrap().token.secret())
-            .header("Content-Type", "application/json")
-            .body(body)
-            .send()
-            .await
-            .unwrap();
-
-        let body = resp.text().await.unwrap();
-
-        let body_serialized = serde_json::from_str::<serde_json::Value>(&body).unwrap();
-        if let Some(err) = body_serialized.get("error") {
-            return Err(KeyVaultError::GeneralError(
-                err.get("message")
-                    .expect("Received an error accessing the Key Vault, which could not be parsed as expected.")
-                    .to_string(),
-            ));
-        }
-
+        let mut req = self.request(Method::PATCH, &uri).await?;
+        req.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        req.set_body(body);
+        let body = self.execute(req).await?;
         Ok(body)
     }
 
     pub(crate) async fn delete_authed(&mut self, uri: String) -> Result<String, KeyVaultError> {
-        self.refresh_token().await?;
-
-        let resp = reqwest::Client::new()
-            .delete(&uri)
-            .bearer_auth(self.token.as_ref().unwrap().token.secret())
-            .header("Content-Type", "application/json")
-            .send()
-            .await
-            .unwrap();
-        let body = resp.text().await.unwrap();
+        let mut req = self.request(Method::DELETE, &uri).await?;
+        req.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        let body = self.execute(req).await?;
         Ok(body)
     }
+
+    /// Builds a request to `uri` carrying the bearer token of the vault.
+    async fn request(&self, method: Method, uri: &str) -> Result<Request, KeyVaultError> {
+        let uri = uri
+            .parse()
+            .map_err(|e| KeyVaultError::GeneralError(format!("invalid uri {}: {}", uri, e)))?;
+        let token = self.token().await?;
+        let authorization = HeaderValue::from_str(&format!("Bearer {}", token))
+            .map_err(|e| KeyVaultError::GeneralError(e.to_string()))?;
+
+        let mut req = Request::new(uri, method);
+        req.headers_mut().insert(AUTHORIZATION, authorization);
+        Ok(req)
+    }
+
+    /// Sends `request` and returns the body of the response. Any status but
+    /// `200 OK` is returned as an `HttpError` carrying the Key Vault error.
+    async fn execute(&self, request: Request) -> Result<String, KeyVaultError> {
+        let response = self.pipeline.execute(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.into_body().await?;
+        if status != StatusCode::OK {
+            return Err(
+                AzureError::from(HttpError::new(StatusCode::OK, status, &headers, &body)).into(),
+            );
+        }
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+}
+
+/// The pipeline used by `KeyVaultClient` unless one is supplied with
+/// `with_pipeline`.
+pub(crate) fn default_pipeline() -> Pipeline {
+    Pipeline::new(
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        Vec::new(),
+        RetryOptions::default(),
+        Vec::new(),
+        Arc::new(TransportPolicy::new(Arc::new(reqwest::Client::new()))),
+    )
 }
@@ -13,6 +13,9 @@ pub enum KeyVaultError {
     #[error("Azure Active Directory authorization error")]
     AuthorizationError(#[from] anyhow::Error),
 
+    #[error("Key Vault request failed: {0}")]
+    RequestError(#[from] azure_core::errors::AzureError),
+
     #[error("General error: {0}")]
     GeneralError(String),
 }
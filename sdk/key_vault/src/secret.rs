@@ -1,9 +1,11 @@
 use crate::KeyVaultClient;
 use crate::{client::API_VERSION, KeyVaultError};
 use anyhow::{Context, Result};
-use azure_core::TokenCredential;
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::{Page, Pageable, TokenCredential};
 use chrono::serde::ts_seconds;
 use chrono::{DateTime, Utc};
+use futures::TryStreamExt;
 use getset::Getters;
 use reqwest::Url;
 use serde::Deserialize;
@@ -96,6 +98,26 @@ pub struct KeyVaultSecretBaseIdentifier {
     time_updated: DateTime<Utc>,
 }
 
+/// A page of the secrets (or of the versions of a secret) of a Key Vault.
+#[derive(Debug, Getters)]
+#[getset(get = "pub")]
+pub struct KeyVaultSecretsPage {
+    secrets: Vec<KeyVaultSecretBaseIdentifier>,
+    next_link: Option<String>,
+}
+
+impl Page for KeyVaultSecretsPage {
+    type Item = KeyVaultSecretBaseIdentifier;
+
+    fn continuation(&self) -> Option<ContinuationToken> {
+        self.next_link.clone()
+    }
+
+    fn into_items(self) -> Vec<KeyVaultSecretBaseIdentifier> {
+        self.secrets
+    }
+}
+
 #[derive(Debug, Getters)]
 #[getset(get = "pub")]
 pub struct KeyVaultSecret {
@@ -106,7 +128,7 @@ pub struct KeyVaultSecret {
     time_updated: DateTime<Utc>,
 }
 
-impl<'a, T: TokenCredential> KeyVaultClient<'a, T> {
+impl<'a, T: TokenCredential + Sync> KeyVaultClient<'a, T> {
     /// Gets a secret from the Key Vault.
     /// Note that the latest version is fetched. For a specific version, use `get_version_with_version`.
     ///
@@ -205,8 +227,30 @@ impl<'a, T: TokenCredential> KeyVaultClient<'a, T> {
     pub async fn list_secrets(
         &mut self,
     ) -> Result<Vec<KeyVaultSecretBaseIdentifier>, KeyVaultError> {
-        let mut secrets = Vec::<KeyVaultSecretBaseIdentifier>::new();
-        let mut uri = Url::parse_with_params(
+        self.stream_secrets().into_items().try_collect().await
+    }
+
+    /// Lists the secrets of the Key Vault page by page, following the
+    /// `nextLink`s. Unlike `list_secrets` it does not wait for every page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use azure_key_vault::KeyVaultClient;
+    /// use azure_identity::token_credentials::DefaultCredential;
+    /// use futures::StreamExt;
+    ///
+    /// async fn example() {
+    ///     let creds = DefaultCredential::default();
+    ///     let client = KeyVaultClient::new(&creds, &"KEYVAULT_NAME");
+    ///     let mut pages = client.stream_secrets();
+    ///     while let Some(page) = pages.next().await {
+    ///         dbg!(page.unwrap().secrets());
+    ///     }
+    /// }
+    /// ```
+    pub fn stream_secrets(&self) -> Pageable<'_, KeyVaultSecretsPage, KeyVaultError> {
+        let uri = Url::parse_with_params(
             &format!("{}/secrets", self.keyvault_endpoint),
             &[
                 ("api-version", API_VERSION),
@@ -214,32 +258,33 @@ impl<'a, T: TokenCredential> KeyVaultClient<'a, T> {
             ],
         )
         .unwrap();
+        self.stream_secrets_from(uri)
+    }
 
-        loop {
-            let resp_body = self.get_authed(uri.to_string()).await?;
-            let response = serde_json::from_str::<KeyVaultGetSecretsResponse>(&resp_body).unwrap();
-
-            secrets.extend(
-                response
-                    .value
-                    .into_iter()
-                    .map(|s| KeyVaultSecretBaseIdentifier {
-                        id: s.id.to_owned(),
-                        name: s.id.to_owned().split("/").last().unwrap().to_owned(),
-                        enabled: s.attributes.enabled,
-                        time_created: s.attributes.created,
-                        time_updated: s.attributes.updated,
-                    })
-                    .collect::<Vec<KeyVaultSecretBaseIdentifier>>(),
-            );
-
-            match response.next_link {
-                None => break,
-                Some(u) => uri = Url::parse(&u).unwrap(),
+    fn stream_secrets_from(&self, uri: Url) -> Pageable<'_, KeyVaultSecretsPage, KeyVaultError> {
+        Pageable::new(None, move |next_link: Option<ContinuationToken>| {
+            let uri = next_link.unwrap_or_else(|| uri.to_string());
+            async move {
+                let resp_body = self.get_authed(uri).await?;
+                let response = serde_json::from_str::<KeyVaultGetSecretsResponse>(&resp_body)
+                    .map_err(|e| KeyVaultError::GeneralError(e.to_string()))?;
+
+                Ok(KeyVaultSecretsPage {
+                    secrets: response
+                        .value
+                        .into_iter()
+                        .map(|s| KeyVaultSecretBaseIdentifier {
+                            id: s.id.to_owned(),
+                            name: s.id.rsplit('/').next().unwrap().to_owned(),
+                            enabled: s.attributes.enabled,
+                            time_created: s.attributes.created,
+                            time_updated: s.attributes.updated,
+                        })
+                        .collect(),
+                    next_link: response.next_link,
+                })
             }
-        }
-
-        Ok(secrets)
+        })
     }
 
     /// Gets all the versions for a secret in the Key Vault.
@@ -267,8 +312,7 @@ impl<'a, T: TokenCredential> KeyVaultClient<'a, T> {
         &mut self,
         secret_name: &'a str,
     ) -> Result<Vec<KeyVaultSecretBaseIdentifier>, KeyVaultError> {
-        let mut secret_versions = Vec::<KeyVaultSecretBaseIdentifier>::new();
-        let mut uri = Url::parse_with_params(
+        let uri = Url::parse_with_params(
             &format!(
                 "{}/secrets/{}/versions",
                 self.keyvault_endpoint, secret_name
@@ -279,29 +323,11 @@ impl<'a, T: TokenCredential> KeyVaultClient<'a, T> {
             ],
         )
         .unwrap();
-
-        loop {
-            let resp_body = self.get_authed(uri.to_string()).await?;
-            let response = serde_json::from_str::<KeyVaultGetSecretsResponse>(&resp_body).unwrap();
-
-            secret_versions.extend(
-                response
-                    .value
-                    .into_iter()
-                    .map(|s| KeyVaultSecretBaseIdentifier {
-                        id: s.id.to_owned(),
-                        name: s.id.to_owned().split("/").last().unwrap().to_owned(),
-                        enabled: s.attributes.enabled,
-                        time_created: s.attributes.created,
-                        time_updated: s.attributes.updated,
-                    })
-                    .collect::<Vec<KeyVaultSecretBaseIdentifier>>(),
-            );
-            match response.next_link {
-                None => break,
-                Some(u) => uri = Url::parse(&u).unwrap(),
-            }
-        }
+        let mut secret_versions: Vec<KeyVaultSecretBaseIdentifier> = self
+            .stream_secrets_from(uri)
+            .into_items()
+            .try_collect()
+            .await?;
 
         // Return the secret versions sorted by the time modified in descending order.
         secret_versions.sort_by(|a, b| {
@@ -713,6 +739,36 @@ mod tests {
         assert!(diff(time_updated, *secret.time_updated()) < Duration::seconds(1));
     }
 
+    #[tokio::test]
+    async fn get_missing_secret() {
+        let _m = mock("GET", "/secrets/missing-secret/")
+            .match_query(Matcher::UrlEncoded("api-version".into(), API_VERSION.into()))
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "error": {
+                        "code": "SecretNotFound",
+                        "message": "A secret with (name/id) missing-secret was not found in this key vault."
+                    }
+                })
+                .to_string(),
+            )
+            .with_status(404)
+            .create();
+
+        let creds = MockSecretCredential;
+        let mut client = mock_client!(&creds, &"test-keyvault");
+
+        match client.get_secret("missing-secret").await {
+            Err(KeyVaultError::RequestError(AzureError::HttpError(err))) => {
+                assert_eq!(err.status(), 404);
+                assert_eq!(err.kind(), azure_core::errors::ErrorKind::NotFound);
+                assert_eq!(err.error_code(), Some("SecretNotFound"));
+            }
+            other => panic!("expected a 404 HttpError, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn get_secret_versions() {
         let time_created_1 = Utc::now() - Duration::days(7);
@@ -0,0 +1,121 @@
+use azure_core::errors::AzureError;
+use azure_core::ArmError;
+use bytes::Bytes;
+use http::StatusCode;
+
+/// The error of a management operation.
+///
+/// `E` is the model of the `default` response of the operation, the error
+/// the service documents for any status code but the successful ones. The
+/// operations without one fail with `UnexpectedResponse` instead.
+#[derive(Debug)]
+pub enum Error<E = ArmError> {
+    /// The `default` response of the operation.
+    DefaultResponse {
+        status_code: StatusCode,
+        value: E,
+    },
+    /// A status code the operation does not document. `error` is the
+    /// [`ArmError`] in the body, if any.
+    UnexpectedResponse {
+        status_code: StatusCode,
+        body: Bytes,
+        error: Option<ArmError>,
+    },
+    BuildRequestError {
+        source: reqwest::Error,
+    },
+    ExecuteRequestError {
+        source: AzureError,
+    },
+    ResponseBytesError {
+        source: AzureError,
+    },
+    DeserializeError {
+        source: serde_json::Error,
+        body: Bytes,
+    },
+    GetTokenError {
+        source: AzureError,
+    },
+}
+
+impl<E> Error<E> {
+    /// The status code the service answered, if it answered.
+    pub fn status_code(&self) -> Option<StatusCode> {
+        match self {
+            Error::DefaultResponse { status_code, .. }
+            | Error::UnexpectedResponse { status_code, .. } => Some(*status_code),
+            _ => None,
+        }
+    }
+}
+
+/// The errors of the pipeline, such as the deadline or the cancellation of
+/// a [`Context`](azure_core::Context), so that management operations can be
+/// run with [`Context::run`](azure_core::Context::run).
+impl<E> From<AzureError> for Error<E> {
+    fn from(source: AzureError) -> Self {
+        Error::ExecuteRequestError { source }
+    }
+}
+
+impl<E> std::fmt::Display for Error<E>
+where
+    E: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::DefaultResponse { status_code, value } => {
+                write!(f, "the service answered {}: {:?}", status_code, value)
+            }
+            Error::UnexpectedResponse {
+                status_code,
+                error: Some(error),
+                ..
+            } => write!(f, "unexpected status code {}: {}", status_code, error),
+            Error::UnexpectedResponse {
+                status_code, body, ..
+            } => {
+                write!(
+                    f,
+                    "unexpected status code {}: {}",
+                    status_code,
+                    String::from_utf8_lossy(body)
+                )
+            }
+            Error::BuildRequestError { source } => {
+                write!(f, "cannot build the request: {}", source)
+            }
+            Error::ExecuteRequestError { source } => {
+                write!(f, "cannot send the request: {}", source)
+            }
+            Error::ResponseBytesError { source } => {
+                write!(f, "cannot read the response: {}", source)
+            }
+            Error::DeserializeError { source, .. } => {
+                write!(f, "cannot deserialize the response: {}", source)
+            }
+            Error::GetTokenError { source } => write!(f, "cannot get a token: {}", source),
+        }
+    }
+}
+
+impl<E> std::error::Error for Error<E>
+where
+    E: std::fmt::Debug,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::UnexpectedResponse {
+                error: Some(error), ..
+            } => Some(error),
+            Error::BuildRequestError { source } => Some(source),
+            Error::ExecuteRequestError { source }
+            | Error::ResponseBytesError { source }
+            | Error::GetTokenError { source } => Some(source),
+            Error::DeserializeError { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
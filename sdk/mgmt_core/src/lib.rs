@@ -0,0 +1,255 @@
+//! The runtime of the generated management crates (`azure_mgmt_*`).
+//!
+//! Their operations only know how to build the request of an operation and
+//! which status codes it answers; this crate does the rest: it authenticates
+//! the request, sends it through the [`Pipeline`](azure_core::pipeline::Pipeline)
+//! of an [`OperationConfig`] (so retries, telemetry and logging apply to every
+//! operation) and decodes the response or the [`Error`].
+//!
+//! ```no_run
+//! # async fn example(token_credential: Box<dyn azure_core::TokenCredential>) -> Result<(), azure_mgmt_core::Error> {
+//! use azure_mgmt_core::OperationConfig;
+//!
+//! let operation_config = OperationConfig::new(token_credential);
+//! let req_builder = operation_config
+//!     .client
+//!     .get("https://management.azure.com/subscriptions?api-version=2020-01-01");
+//! let rsp = azure_mgmt_core::send(&operation_config, req_builder).await?;
+//! let subscriptions: serde_json::Value = rsp.json().await?;
+//! # Ok(())
+//! # }
+//! ```
+#![warn(rust_2018_idioms)]
+
+mod errors;
+mod operation_config;
+mod response;
+
+pub use errors::Error;
+pub use operation_config::OperationConfig;
+pub use response::Response;
+
+use azure_core::lro::PollingClient;
+
+/// Authenticates the request with the credentials of `client` and sends it
+/// through its pipeline.
+///
+/// Any status code is a successful send: it is up to the operation to match
+/// the ones it expects and to turn the others into an [`Error`] with
+/// [`Response::unexpected_response`] or [`Response::default_response`].
+pub async fn send<C, E>(
+    client: &C,
+    mut req_builder: reqwest::RequestBuilder,
+) -> Result<Response, Error<E>>
+where
+    C: PollingClient + ?Sized,
+{
+    if let Some(token_credential) = client.token_credential() {
+        let token_response = token_credential
+            .get_token(client.token_credential_resource())
+            .await
+            .map_err(|source| Error::GetTokenError { source })?;
+        req_builder = req_builder.bearer_auth(token_response.token.secret());
+    } else if let Some(bearer_access_token) = client.bearer_access_token() {
+        req_builder = req_builder.bearer_auth(bearer_access_token);
+    }
+    let req = req_builder
+        .build()
+        .map_err(|source| Error::BuildRequestError { source })?;
+    let rsp = client
+        .pipeline()
+        .execute(req)
+        .await
+        .map_err(|source| Error::ExecuteRequestError { source })?;
+    Ok(Response::new(rsp))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::errors::AzureError;
+    use azure_core::mock_transport::{ScriptedResponse, ScriptedTransport};
+    use azure_core::{ArmError, CancellationToken, Context, TokenCredential, TokenResponse};
+    use http::header::AUTHORIZATION;
+    use http::StatusCode;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    struct StaticTokenCredential;
+
+    #[async_trait::async_trait]
+    impl TokenCredential for StaticTokenCredential {
+        async fn get_token(&self, resource: &str) -> Result<TokenResponse, AzureError> {
+            Ok(TokenResponse::new(
+                oauth2::AccessToken::new(format!("token for {}", resource)),
+                chrono::Utc::now(),
+            ))
+        }
+    }
+
+    fn operation_config(
+        responses: Vec<ScriptedResponse>,
+    ) -> (OperationConfig, Arc<ScriptedTransport>) {
+        let transport = Arc::new(ScriptedTransport::new(responses));
+        let operation_config = OperationConfig::new(Box::new(StaticTokenCredential))
+            .with_pipeline(ScriptedTransport::pipeline(transport.clone()));
+        (operation_config, transport)
+    }
+
+    struct CountingTokenCredential(Arc<AtomicU32>);
+
+    #[async_trait::async_trait]
+    impl TokenCredential for CountingTokenCredential {
+        async fn get_token(&self, resource: &str) -> Result<TokenResponse, AzureError> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(TokenResponse::new(
+                oauth2::AccessToken::new(format!("token for {}", resource)),
+                chrono::Utc::now() + chrono::Duration::hours(1),
+            ))
+        }
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct CloudError {
+        error: ArmError,
+    }
+
+    #[tokio::test]
+    async fn sends_an_authenticated_request() {
+        let (operation_config, transport) = operation_config(vec![ScriptedResponse::new(
+            StatusCode::OK,
+            r#"{"name": "account"}"#,
+        )]);
+        let req_builder = operation_config
+            .client
+            .get("https://management.azure.com/account");
+
+        let rsp = send::<_, ArmError>(&operation_config, req_builder)
+            .await
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::OK);
+        let account: serde_json::Value = rsp.json::<_, ArmError>().await.unwrap();
+
+        assert_eq!(account["name"], "account");
+        assert_eq!(
+            transport.requests()[0].headers()[AUTHORIZATION],
+            "Bearer token for https://management.azure.com/"
+        );
+    }
+
+    #[tokio::test]
+    async fn decodes_the_errors() {
+        let body = r#"{"error": {"code": "ResourceNotFound", "message": "not found"}}"#;
+        let (operation_config, _) = operation_config(vec![
+            ScriptedResponse::new(StatusCode::NOT_FOUND, body),
+            ScriptedResponse::new(StatusCode::NOT_FOUND, body),
+        ]);
+
+        let rsp = send::<_, CloudError>(
+            &operation_config,
+            operation_config
+                .client
+                .get("https://management.azure.com/account"),
+        )
+        .await
+        .unwrap();
+        match rsp.default_response::<CloudError>().await {
+            Error::DefaultResponse { status_code, value } => {
+                assert_eq!(status_code, StatusCode::NOT_FOUND);
+                assert_eq!(value.error.code, "ResourceNotFound");
+            }
+            error => panic!("unexpected error {:?}", error),
+        }
+
+        let rsp = send::<_, ArmError>(
+            &operation_config,
+            operation_config
+                .client
+                .get("https://management.azure.com/account"),
+        )
+        .await
+        .unwrap();
+        let error: Error = rsp.unexpected_response().await;
+        assert_eq!(error.status_code(), Some(StatusCode::NOT_FOUND));
+        match error {
+            Error::UnexpectedResponse { error, .. } => {
+                assert_eq!(error.unwrap().code, "ResourceNotFound")
+            }
+            error => panic!("unexpected error {:?}", error),
+        }
+    }
+
+    #[tokio::test]
+    async fn caches_the_tokens() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            ScriptedResponse::new(StatusCode::OK, "{}"),
+            ScriptedResponse::new(StatusCode::OK, "{}"),
+        ]));
+        let operation_config =
+            OperationConfig::new(Box::new(CountingTokenCredential(calls.clone())))
+                .with_pipeline(ScriptedTransport::pipeline(transport.clone()));
+
+        for _ in 0..2 {
+            send::<_, ArmError>(
+                &operation_config,
+                operation_config
+                    .client
+                    .get("https://management.azure.com/account"),
+            )
+            .await
+            .unwrap();
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            transport.requests()[1].headers()[AUTHORIZATION],
+            "Bearer token for https://management.azure.com/"
+        );
+    }
+
+    #[tokio::test]
+    async fn operations_run_in_a_context() {
+        let (operation_config, transport) = operation_config(vec![
+            ScriptedResponse::new(StatusCode::OK, "{}"),
+            ScriptedResponse::new(StatusCode::OK, "{}"),
+        ]);
+        let get_account = || async {
+            let rsp = send::<_, CloudError>(
+                &operation_config,
+                operation_config
+                    .client
+                    .get("https://management.azure.com/account"),
+            )
+            .await?;
+            tokio::time::delay_for(Duration::from_secs(10)).await;
+            rsp.json::<serde_json::Value, CloudError>().await
+        };
+
+        let result = Context::new()
+            .with_timeout(Duration::from_millis(10))
+            .run(get_account())
+            .await;
+        match result {
+            Err(Error::ExecuteRequestError {
+                source: AzureError::DeadlineExceeded,
+            }) => {}
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert_eq!(transport.remaining(), 1);
+
+        let cancellation_token = CancellationToken::new();
+        cancellation_token.cancel();
+        let result = Context::new()
+            .with_cancellation_token(cancellation_token)
+            .run(get_account())
+            .await;
+        match result {
+            Err(Error::ExecuteRequestError {
+                source: AzureError::Cancelled,
+            }) => {}
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert_eq!(transport.remaining(), 1);
+    }
+}
@@ -0,0 +1,84 @@
+use azure_core::lro::PollingClient;
+use azure_core::pipeline::Pipeline;
+use azure_core::policies::{RetryOptions, TransportPolicy};
+use azure_core::{AzureEnvironment, CachedTokenCredential, TokenCredential};
+use std::sync::Arc;
+
+/// Where and how the operations of the management crates send their
+/// requests: the Azure Resource Manager endpoint, the credential and the
+/// pipeline.
+pub struct OperationConfig {
+    pub client: reqwest::Client,
+    pub pipeline: Pipeline,
+    pub base_path: String,
+    pub token_credential: Option<Box<dyn TokenCredential>>,
+    pub token_credential_resource: String,
+}
+
+impl OperationConfig {
+    /// Authenticates the requests with `token_credential`, whose tokens are
+    /// cached until they are about to expire.
+    pub fn new(token_credential: Box<dyn TokenCredential>) -> Self {
+        Self::default()
+            .with_token_credential(Box::new(CachedTokenCredential::new(token_credential)))
+    }
+
+    /// Authenticates the requests with `token_credential` as is, without
+    /// caching its tokens, e.g. for a credential that caches them itself.
+    pub fn with_token_credential(self, token_credential: Box<dyn TokenCredential>) -> Self {
+        Self {
+            token_credential: Some(token_credential),
+            ..self
+        }
+    }
+
+    /// Targets the Resource Manager of a sovereign cloud, or of Azure Stack
+    /// Hub.
+    pub fn with_environment(self, environment: &AzureEnvironment) -> Self {
+        Self {
+            base_path: environment.resource_manager_endpoint().to_owned(),
+            token_credential_resource: environment.resource_manager_resource().to_owned(),
+            ..self
+        }
+    }
+
+    /// Sends the requests through `pipeline`, e.g. one with other retry
+    /// options or additional policies.
+    pub fn with_pipeline(self, pipeline: Pipeline) -> Self {
+        Self { pipeline, ..self }
+    }
+}
+
+impl Default for OperationConfig {
+    fn default() -> Self {
+        let client = reqwest::Client::new();
+        Self {
+            client: client.clone(),
+            pipeline: Pipeline::new(
+                env!("CARGO_PKG_NAME"),
+                env!("CARGO_PKG_VERSION"),
+                Vec::new(),
+                RetryOptions::default(),
+                Vec::new(),
+                Arc::new(TransportPolicy::new(Arc::new(client))),
+            ),
+            base_path: "https://management.azure.com".to_owned(),
+            token_credential: None,
+            token_credential_resource: "https://management.azure.com/".to_owned(),
+        }
+    }
+}
+
+impl PollingClient for OperationConfig {
+    fn pipeline(&self) -> &Pipeline {
+        &self.pipeline
+    }
+
+    fn token_credential(&self) -> Option<&dyn TokenCredential> {
+        self.token_credential.as_deref()
+    }
+
+    fn token_credential_resource(&self) -> &str {
+        &self.token_credential_resource
+    }
+}
@@ -0,0 +1,91 @@
+use crate::Error;
+use azure_core::lro::Poller;
+use azure_core::ArmError;
+use bytes::Bytes;
+use http::{HeaderMap, Method, StatusCode};
+use serde::de::DeserializeOwned;
+
+/// The response of a management operation, whatever its status code.
+#[derive(Debug)]
+pub struct Response(azure_core::Response);
+
+impl Response {
+    pub(crate) fn new(response: azure_core::Response) -> Self {
+        Self(response)
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.0.status()
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        self.0.headers()
+    }
+
+    pub fn into_inner(self) -> azure_core::Response {
+        self.0
+    }
+
+    pub async fn into_body<E>(self) -> Result<Bytes, Error<E>> {
+        self.0
+            .into_body()
+            .await
+            .map_err(|source| Error::ResponseBytesError { source })
+    }
+
+    /// Deserializes the JSON body.
+    pub async fn json<T, E>(self) -> Result<T, Error<E>>
+    where
+        T: DeserializeOwned,
+    {
+        let body = self.into_body().await?;
+        serde_json::from_slice(&body).map_err(|source| Error::DeserializeError { source, body })
+    }
+
+    /// The [`Poller`] of a long running operation sent with `method` to
+    /// `uri`, from its first response.
+    pub async fn into_poller<T, E>(
+        self,
+        method: Method,
+        uri: &str,
+        api_version: &str,
+    ) -> Result<Poller<T>, Error<E>> {
+        let status_code = self.status();
+        let headers = self.headers().clone();
+        let body = self.into_body().await?;
+        Ok(Poller::from_response(
+            method,
+            uri,
+            api_version,
+            status_code,
+            &headers,
+            body,
+        ))
+    }
+
+    /// The error of an operation answering a status code it does not
+    /// document.
+    pub async fn unexpected_response<E>(self) -> Error<E> {
+        let status_code = self.status();
+        match self.into_body().await {
+            Ok(body) => Error::UnexpectedResponse {
+                status_code,
+                error: ArmError::from_body(&body),
+                body,
+            },
+            Err(error) => error,
+        }
+    }
+
+    /// The error of an operation answering its `default` response.
+    pub async fn default_response<E>(self) -> Error<E>
+    where
+        E: DeserializeOwned,
+    {
+        let status_code = self.status();
+        match self.json().await {
+            Ok(value) => Error::DefaultResponse { status_code, value },
+            Err(error) => error,
+        }
+    }
+}
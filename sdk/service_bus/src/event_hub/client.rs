@@ -3,18 +3,20 @@ use crate::event_hub::{
     unlock_message, PeekLockResponse,
 };
 use azure_core::errors::AzureError;
+use azure_core::pipeline::Pipeline;
+use azure_core::policies::{RetryOptions, TransportPolicy};
+use azure_core::AzureEnvironment;
 use chrono::Duration;
-use hyper_rustls::HttpsConnector;
 use ring::hmac::Key;
-
-type HttpClient = hyper::Client<HttpsConnector<hyper::client::HttpConnector>>;
+use std::sync::Arc;
 
 pub struct Client {
     namespace: String,
+    endpoint_suffix: String,
     event_hub: String,
     policy_name: String,
     signing_key: Key,
-    http_client: HttpClient,
+    pipeline: Pipeline,
 }
 
 impl Client {
@@ -31,25 +33,47 @@ impl Client {
         K: AsRef<str>,
     {
         let signing_key = Key::new(ring::hmac::HMAC_SHA256, key.as_ref().as_bytes());
-        let http_client = hyper::Client::builder().build(HttpsConnector::new());
+        let pipeline = Pipeline::new(
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION"),
+            Vec::new(),
+            RetryOptions::default(),
+            Vec::new(),
+            Arc::new(TransportPolicy::default()),
+        );
 
         Ok(Client {
             namespace: namespace.into(),
+            endpoint_suffix: AzureEnvironment::public()
+                .service_bus_endpoint_suffix()
+                .to_owned(),
             event_hub: event_hub.into(),
             policy_name: policy_name.into(),
             signing_key,
-            http_client,
+            pipeline,
         })
     }
 
+    /// Points the client to the namespace in another Azure cloud.
+    pub fn with_environment(self, environment: &AzureEnvironment) -> Self {
+        Self {
+            endpoint_suffix: environment.service_bus_endpoint_suffix().to_owned(),
+            ..self
+        }
+    }
+
+    fn host(&self) -> String {
+        format!("{}.{}", self.namespace, self.endpoint_suffix)
+    }
+
     pub async fn send_event(
         &mut self,
         event_body: &str,
         duration: Duration,
     ) -> Result<(), AzureError> {
         send_event(
-            &self.http_client,
-            &self.namespace,
+            &self.pipeline,
+            &self.host(),
             &self.event_hub,
             &self.policy_name,
             &self.signing_key,
@@ -65,8 +89,8 @@ impl Client {
         timeout: Option<Duration>,
     ) -> Result<String, AzureError> {
         peek_lock(
-            &self.http_client,
-            &self.namespace,
+            &self.pipeline,
+            &self.host(),
             &self.event_hub,
             &self.policy_name,
             &self.signing_key,
@@ -82,8 +106,8 @@ impl Client {
         timeout: Option<Duration>,
     ) -> Result<PeekLockResponse, AzureError> {
         peek_lock_full(
-            &self.http_client,
-            &self.namespace,
+            &self.pipeline,
+            &self.host(),
             &self.event_hub,
             &self.policy_name,
             &self.signing_key,
@@ -95,8 +119,8 @@ impl Client {
 
     pub async fn receive_and_delete(&mut self, duration: Duration) -> Result<String, AzureError> {
         receive_and_delete(
-            &self.http_client,
-            &self.namespace,
+            &self.pipeline,
+            &self.host(),
             &self.event_hub,
             &self.policy_name,
             &self.signing_key,
@@ -112,8 +136,8 @@ impl Client {
         duration: Duration,
     ) -> Result<(), AzureError> {
         unlock_message(
-            &self.http_client,
-            &self.namespace,
+            &self.pipeline,
+            &self.host(),
             &self.event_hub,
             &self.policy_name,
             &self.signing_key,
@@ -131,8 +155,8 @@ impl Client {
         duration: Duration,
     ) -> Result<(), AzureError> {
         delete_message(
-            &self.http_client,
-            &self.namespace,
+            &self.pipeline,
+            &self.host(),
             &self.event_hub,
             &self.policy_name,
             &self.signing_key,
@@ -150,8 +174,8 @@ impl Client {
         duration: Duration,
     ) -> Result<(), AzureError> {
         renew_lock(
-            &self.http_client,
-            &self.namespace,
+            &self.pipeline,
+            &self.host(),
             &self.event_hub,
             &self.policy_name,
             &self.signing_key,
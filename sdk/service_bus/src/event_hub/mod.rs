@@ -1,7 +1,9 @@
 use azure_core::errors::{check_status_extract_body, extract_location_status_and_body, AzureError};
+use azure_core::pipeline::Pipeline;
+use azure_core::ResponseFuture;
+use bytes::Bytes;
 use chrono::Duration;
-use hyper::{self, header, Body, StatusCode};
-use hyper_rustls::HttpsConnector;
+use hyper::{self, header, StatusCode};
 use ring::hmac;
 use std::ops::Add;
 use url::{form_urlencoded, Url};
@@ -9,23 +11,18 @@ use url::{form_urlencoded, Url};
 mod client;
 pub use self::client::Client;
 
-type HttpClient = hyper::Client<HttpsConnector<hyper::client::HttpConnector>>;
-
 #[inline]
 fn send_event_prepare<B: Into<String>>(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     signing_key: &hmac::Key,
     event_body: B,
     duration: Duration,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
-    let url = format!(
-        "https://{}.servicebus.windows.net/{}/messages",
-        namespace, event_hub
-    );
+    let url = format!("https://{}/{}/messages", host, event_hub);
     debug!("url == {:?}", url);
 
     // generate sas signature based on key name, key value, url and duration.
@@ -35,25 +32,23 @@ fn send_event_prepare<B: Into<String>>(
     let event_body = event_body.into();
     let request = hyper::Request::post(url)
         .header(header::AUTHORIZATION, sas)
-        .body(event_body.into())?;
+        .body(Bytes::from(event_body))?;
 
-    Ok(http_client.request(request))
+    let pipeline = pipeline.clone();
+    Ok(Box::pin(async move { pipeline.execute(request).await }))
 }
 
 fn peek_lock_prepare(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     signing_key: &hmac::Key,
     duration: Duration,
     timeout: Option<Duration>,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
-    let mut url = Url::parse(&format!(
-        "https://{}.servicebus.windows.net/{}/messages/head",
-        namespace, event_hub
-    ))?;
+    let mut url = Url::parse(&format!("https://{}/{}/messages/head", host, event_hub))?;
     if let Some(t) = timeout {
         url.query_pairs_mut()
             .append_pair("timeout", &t.num_seconds().to_string());
@@ -67,14 +62,15 @@ fn peek_lock_prepare(
     let request = hyper::Request::post(url.into_string())
         .header(header::AUTHORIZATION, sas)
         .header(header::CONTENT_LENGTH, 0)
-        .body(Body::empty())?;
+        .body(Bytes::new())?;
 
-    Ok(http_client.request(request))
+    let pipeline = pipeline.clone();
+    Ok(Box::pin(async move { pipeline.execute(request).await }))
 }
 
 async fn peek_lock(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     hmac: &hmac::Key,
@@ -82,8 +78,8 @@ async fn peek_lock(
     timeout: Option<Duration>,
 ) -> Result<String, AzureError> {
     let req = peek_lock_prepare(
-        http_client,
-        namespace,
+        pipeline,
+        host,
         event_hub,
         policy_name,
         hmac,
@@ -95,8 +91,8 @@ async fn peek_lock(
 }
 
 async fn peek_lock_full(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     hmac: &hmac::Key,
@@ -104,8 +100,8 @@ async fn peek_lock_full(
     timeout: Option<Duration>,
 ) -> Result<PeekLockResponse, AzureError> {
     let req = peek_lock_prepare(
-        http_client,
-        namespace,
+        pipeline,
+        host,
         event_hub,
         policy_name,
         hmac,
@@ -116,7 +112,7 @@ async fn peek_lock_full(
     let a = extract_location_status_and_body(req?).await?;
 
     Ok(PeekLockResponse {
-        http_client: http_client.to_owned(),
+        pipeline: pipeline.to_owned(),
         status: a.0,
         delete_location: a.1,
         body: a.2,
@@ -127,7 +123,7 @@ async fn peek_lock_full(
 }
 
 pub struct PeekLockResponse {
-    http_client: HttpClient,
+    pipeline: Pipeline,
     status: StatusCode,
     delete_location: String,
     body: String,
@@ -145,7 +141,7 @@ impl PeekLockResponse {
     }
     pub async fn delete_message(&self) -> Result<String, AzureError> {
         let req = delete_message_get_request(
-            &self.http_client,
+            &self.pipeline,
             &self.policy_name,
             &self.signing_key,
             self.duration,
@@ -157,18 +153,15 @@ impl PeekLockResponse {
 }
 
 fn receive_and_delete_prepare(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     signing_key: &hmac::Key,
     duration: Duration,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
-    let url = format!(
-        "https://{}.servicebus.windows.net/{}/messages/head",
-        namespace, event_hub
-    );
+    let url = format!("https://{}/{}/messages/head", host, event_hub);
     debug!("url == {:?}", url);
 
     // generate sas signature based on key name, key value, url and duration.
@@ -177,73 +170,68 @@ fn receive_and_delete_prepare(
 
     let request = hyper::Request::delete(url)
         .header(header::AUTHORIZATION, sas)
-        .body(Body::empty())?;
+        .body(Bytes::new())?;
 
-    Ok(http_client.request(request))
+    let pipeline = pipeline.clone();
+    Ok(Box::pin(async move { pipeline.execute(request).await }))
 }
 
 async fn receive_and_delete(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     hmac: &hmac::Key,
     duration: Duration,
 ) -> Result<String, AzureError> {
-    let req = receive_and_delete_prepare(
-        http_client,
-        namespace,
-        event_hub,
-        policy_name,
-        hmac,
-        duration,
-    );
+    let req = receive_and_delete_prepare(pipeline, host, event_hub, policy_name, hmac, duration);
 
     check_status_extract_body(req?, StatusCode::OK).await
 }
 
 fn delete_message_prepare(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     signing_key: &hmac::Key,
     duration: Duration,
     message_id: &str,
     lock_token: &str,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
     let url = format!(
-        "https://{}.servicebus.windows.net/{}/messages/{}/{}",
-        namespace, event_hub, message_id, lock_token
+        "https://{}/{}/messages/{}/{}",
+        host, event_hub, message_id, lock_token
     );
     debug!("url == {:?}", url);
 
     // generate sas signature based on key name, key value, url and duration.
 
-    delete_message_get_request(http_client, policy_name, signing_key, duration, url)
+    delete_message_get_request(pipeline, policy_name, signing_key, duration, url)
 }
 
 fn delete_message_get_request(
-    http_client: &HttpClient,
+    pipeline: &Pipeline,
     policy_name: &str,
     signing_key: &hmac::Key,
     duration: Duration,
     url: String,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     let sas = generate_signature(policy_name, signing_key, &url, duration);
     debug!("sas == {}", sas);
 
     let request = hyper::Request::delete(url)
         .header(header::AUTHORIZATION, sas)
-        .body(Body::empty())?;
+        .body(Bytes::new())?;
 
-    Ok(http_client.request(request))
+    let pipeline = pipeline.clone();
+    Ok(Box::pin(async move { pipeline.execute(request).await }))
 }
 
 async fn delete_message(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     hmac: &hmac::Key,
@@ -253,8 +241,8 @@ async fn delete_message(
 ) -> Result<(), AzureError> {
     check_status_extract_body(
         delete_message_prepare(
-            http_client,
-            namespace,
+            pipeline,
+            host,
             event_hub,
             policy_name,
             hmac,
@@ -269,19 +257,19 @@ async fn delete_message(
 }
 
 fn unlock_message_prepare(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     signing_key: &hmac::Key,
     duration: Duration,
     message_id: &str,
     lock_token: &str,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
     let url = format!(
-        "https://{}.servicebus.windows.net/{}/messages/{}/{}",
-        namespace, event_hub, message_id, lock_token
+        "https://{}/{}/messages/{}/{}",
+        host, event_hub, message_id, lock_token
     );
     debug!("url == {:?}", url);
 
@@ -291,14 +279,15 @@ fn unlock_message_prepare(
 
     let request = hyper::Request::put(url)
         .header(header::AUTHORIZATION, sas)
-        .body(Body::empty())?;
+        .body(Bytes::new())?;
 
-    Ok(http_client.request(request))
+    let pipeline = pipeline.clone();
+    Ok(Box::pin(async move { pipeline.execute(request).await }))
 }
 
 async fn unlock_message(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     hmac: &hmac::Key,
@@ -308,8 +297,8 @@ async fn unlock_message(
 ) -> Result<(), AzureError> {
     check_status_extract_body(
         unlock_message_prepare(
-            http_client,
-            namespace,
+            pipeline,
+            host,
             event_hub,
             policy_name,
             hmac,
@@ -324,19 +313,19 @@ async fn unlock_message(
 }
 
 fn renew_lock_prepare(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     signing_key: &hmac::Key,
     duration: Duration,
     message_id: &str,
     lock_token: &str,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
     let url = format!(
-        "https://{}.servicebus.windows.net/{}/messages/{}/{}",
-        namespace, event_hub, message_id, lock_token
+        "https://{}/{}/messages/{}/{}",
+        host, event_hub, message_id, lock_token
     );
     debug!("url == {:?}", url);
 
@@ -346,14 +335,15 @@ fn renew_lock_prepare(
 
     let request = hyper::Request::post(url)
         .header(header::AUTHORIZATION, sas)
-        .body(Body::empty())?;
+        .body(Bytes::new())?;
 
-    Ok(http_client.request(request))
+    let pipeline = pipeline.clone();
+    Ok(Box::pin(async move { pipeline.execute(request).await }))
 }
 
 async fn renew_lock(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     hmac: &hmac::Key,
@@ -363,8 +353,8 @@ async fn renew_lock(
 ) -> Result<(), AzureError> {
     check_status_extract_body(
         renew_lock_prepare(
-            http_client,
-            namespace,
+            pipeline,
+            host,
             event_hub,
             policy_name,
             hmac,
@@ -379,8 +369,8 @@ async fn renew_lock(
 }
 
 async fn send_event(
-    http_client: &HttpClient,
-    namespace: &str,
+    pipeline: &Pipeline,
+    host: &str,
     event_hub: &str,
     policy_name: &str,
     hmac: &hmac::Key,
@@ -388,8 +378,8 @@ async fn send_event(
     duration: Duration,
 ) -> Result<(), AzureError> {
     let req = send_event_prepare(
-        http_client,
-        namespace,
+        pipeline,
+        host,
         event_hub,
         policy_name,
         hmac,
@@ -0,0 +1,74 @@
+#[macro_use]
+extern crate log;
+
+use azure_core::prelude::*;
+use azure_storage::blob::prelude::*;
+use azure_storage::core::prelude::*;
+use std::error::Error;
+
+// This example requires blob versioning and soft delete to be enabled on
+// the storage account.
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    env_logger::init();
+    debug!("log initialized");
+    // First we retrieve the account name and master key from environment variables.
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let container = std::env::args()
+        .nth(1)
+        .expect("please specify container name as command line parameter");
+    let blob_name = std::env::args()
+        .nth(2)
+        .expect("please specify blob name as command line parameter");
+
+    let client = client::with_access_key(&account, &master_key);
+
+    // bring the blob back if it has been soft-deleted
+    let res = client
+        .undelete_blob()
+        .with_container_name(&container)
+        .with_blob_name(&blob_name)
+        .finalize()
+        .await?;
+    println!("undelete_blob {:?}", res);
+
+    let blobs = client
+        .list_blobs()
+        .with_container_name(&container)
+        .with_prefix(&blob_name)
+        .with_include_versions()
+        .with_include_snapshots()
+        .with_include_deleted()
+        .finalize()
+        .await?
+        .incomplete_vector;
+
+    for blob in blobs.iter().filter(|blob| blob.name == blob_name) {
+        println!(
+            "version_id == {:?}, is_current_version == {:?}, snapshot_time == {:?}",
+            blob.version_id, blob.is_current_version, blob.snapshot_time
+        );
+    }
+
+    // read the oldest version of the blob
+    if let Some(version_id) = blobs
+        .iter()
+        .filter(|blob| blob.name == blob_name)
+        .find_map(|blob| blob.version_id.as_deref())
+    {
+        let res = client
+            .get_blob_properties()
+            .with_container_name(&container)
+            .with_blob_name(&blob_name)
+            .with_version_id(version_id)
+            .finalize()
+            .await?;
+        println!("get_blob_properties {:?}", res.blob);
+    }
+
+    Ok(())
+}
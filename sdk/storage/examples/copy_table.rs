@@ -42,9 +42,9 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     let mut stream = Box::pin(from_table.stream_query::<MyEntity>(None));
 
-    while let Some(Ok(entities)) = stream.next().await {
-        println!("segemnt len: {}", entities.len());
-        for entity in entities {
+    while let Some(Ok(page)) = stream.next().await {
+        println!("segemnt len: {}", page.entities.len());
+        for entity in page.entities {
             count += 1;
             println!("before {:?}", entity);
             let entity = to_table.insert_entity(entity).await?;
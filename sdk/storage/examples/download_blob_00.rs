@@ -0,0 +1,48 @@
+#[macro_use]
+extern crate log;
+
+use azure_core::prelude::*;
+use azure_storage::blob::prelude::*;
+use azure_storage::core::prelude::*;
+use std::error::Error;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    env_logger::init();
+    debug!("log initialized");
+    // First we retrieve the account name and master key from environment variables.
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let container = std::env::args()
+        .nth(1)
+        .expect("please specify container name as command line parameter");
+    let blob_name = std::env::args()
+        .nth(2)
+        .expect("please specify blob name as command line parameter");
+    let file_name = std::env::args()
+        .nth(3)
+        .expect("please specify the file to download to as command line parameter");
+
+    let client = client::with_access_key(&account, &master_key);
+
+    // The blob is fetched in ranges of 4 MiB, 8 ranges at a time, each
+    // checked against its MD5 and written to the file at its offset.
+    let res = client
+        .download_blob()
+        .with_container_name(&container)
+        .with_blob_name(&blob_name)
+        .with_chunk_size(4 * 1024 * 1024)
+        .with_parallelism(8)
+        .finalize_to_file(&file_name)
+        .await?;
+    println!(
+        "downloaded {} bytes of {:?}",
+        res.range.len(),
+        res.blob.etag
+    );
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+#[macro_use]
+extern crate log;
+
+use azure_core::prelude::*;
+use azure_storage::blob::prelude::*;
+use azure_storage::core::prelude::*;
+use std::error::Error;
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    env_logger::init();
+    debug!("log initialized");
+    // First we retrieve the account name and master key from environment variables.
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let container = std::env::args()
+        .nth(1)
+        .expect("please specify container name as command line parameter");
+    let blob_name = std::env::args()
+        .nth(2)
+        .expect("please specify blob name as command line parameter");
+
+    let client = client::with_access_key(&account, &master_key);
+
+    // Brings an archived blob back to the hot tier, checking every five
+    // minutes whether the rehydration is over.
+    let res = client
+        .rehydrate_blob()
+        .with_container_name(&container)
+        .with_blob_name(&blob_name)
+        .with_access_tier(AccessTier::Hot)
+        .with_rehydrate_priority(RehydratePriority::High)
+        .with_poll_interval(Duration::from_secs(5 * 60))
+        .finalize()
+        .await?;
+    println!("rehydrate_blob {:?}", res.blob);
+
+    // and sends it back to the archive
+    let res = client
+        .set_blob_tier()
+        .with_container_name(&container)
+        .with_blob_name(&blob_name)
+        .with_access_tier(AccessTier::Archive)
+        .finalize()
+        .await?;
+    println!("set_blob_tier {:?}", res);
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+#[macro_use]
+extern crate log;
+
+use azure_core::prelude::*;
+use azure_storage::blob::prelude::*;
+use azure_storage::core::prelude::*;
+use std::collections::HashMap;
+use std::error::Error;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    env_logger::init();
+    debug!("log initialized");
+    // First we retrieve the account name and master key from environment variables.
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let container = std::env::args()
+        .nth(1)
+        .expect("please specify container name as command line parameter");
+    let blob_name = std::env::args()
+        .nth(2)
+        .expect("please specify blob name as command line parameter");
+    let file_name = std::env::args()
+        .nth(3)
+        .expect("please specify the file to upload as command line parameter");
+
+    let client = client::with_access_key(&account, &master_key);
+
+    let mut metadata = HashMap::new();
+    metadata.insert("source", file_name.as_str());
+
+    // The file is sent in blocks of 8 MiB, 8 blocks at a time, each with its
+    // CRC64. The blocks are committed once all of them have been uploaded.
+    let res = client
+        .upload_blob()
+        .with_container_name(&container)
+        .with_blob_name(&blob_name)
+        .with_block_size(8 * 1024 * 1024)
+        .with_parallelism(8)
+        .with_block_checksum(BlockChecksum::Crc64)
+        .with_content_type("application/octet-stream")
+        .with_metadata(&metadata)
+        .finalize_from_file(&file_name)
+        .await?;
+    println!("upload_blob {:?}", res);
+
+    Ok(())
+}
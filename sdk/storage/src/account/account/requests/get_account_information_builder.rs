@@ -1,6 +1,7 @@
 use crate::account::account::responses::GetAccountInformationResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use hyper::{Method, StatusCode};
 
 #[derive(Debug, Clone)]
@@ -38,16 +39,19 @@ where
 {
     #[inline]
     pub async fn finalize(self) -> Result<GetAccountInformationResponse, AzureError> {
-        let uri = format!(
-            "{}/?restype=account&comp=properties",
-            self.client.blob_uri()
-        );
-        trace!("uri == {:?}", uri);
+        instrument("GetAccountInformationBuilder::finalize", async move {
+            let uri = format!(
+                "{}/?restype=account&comp=properties",
+                self.client.blob_uri()
+            );
+            trace!("uri == {:?}", uri);
 
-        let req = self
-            .client()
-            .perform_request(&uri, &Method::GET, &|request| request, None);
-        let (headers, _) = check_status_extract_headers_and_body(req?, StatusCode::OK).await?;
-        GetAccountInformationResponse::from_headers(&headers)
+            let req = self
+                .client()
+                .perform_request(&uri, &Method::GET, &|request| request, None);
+            let (headers, _) = check_status_extract_headers_and_body(req?, StatusCode::OK).await?;
+            GetAccountInformationResponse::from_headers(&headers)
+        })
+        .await
     }
 }
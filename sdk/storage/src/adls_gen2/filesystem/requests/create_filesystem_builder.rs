@@ -4,6 +4,7 @@ use crate::filesystem::{
     FilesystemRequired, FilesystemSupport, PropertiesOption, PropertiesSupport,
 };
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::{ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport};
 use azure_core::{No, ToAssign, Yes};
 use hyper::{Method, StatusCode};
@@ -186,29 +187,32 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<CreateFilesystemResponse, AzureError> {
-        let mut uri = format!(
-            "{}/{}?resource=filesystem",
-            self.client().filesystem_uri(),
-            self.filesystem()
-        );
-
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = ClientRequestIdOption::add_header(&self, request);
-                request = PropertiesOption::add_header(&self, request);
-                request
-            },
-            Some(&[]),
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
-        CreateFilesystemResponse::from_headers(&headers)
+        instrument("CreateFilesystemBuilder::finalize", async move {
+            let mut uri = format!(
+                "{}/{}?resource=filesystem",
+                self.client().filesystem_uri(),
+                self.filesystem()
+            );
+
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request = PropertiesOption::add_header(&self, request);
+                    request
+                },
+                Some(&[]),
+            )?;
+
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+            CreateFilesystemResponse::from_headers(&headers)
+        })
+        .await
     }
 }
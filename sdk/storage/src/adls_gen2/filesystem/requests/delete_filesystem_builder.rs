@@ -2,6 +2,7 @@ use crate::core::prelude::*;
 use crate::filesystem::responses::DeleteFilesystemResponse;
 use crate::filesystem::{FilesystemRequired, FilesystemSupport};
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{
     ClientRequestIdOption, ClientRequestIdSupport, IfSinceConditionOption, IfSinceConditionSupport,
@@ -188,29 +189,33 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<DeleteFilesystemResponse, AzureError> {
-        let mut uri = format!(
-            "{}/{}?resource=filesystem",
-            self.client().filesystem_uri(),
-            self.filesystem()
-        );
-
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::DELETE,
-            &|mut request| {
-                request = IfSinceConditionOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            Some(&[]),
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
-        DeleteFilesystemResponse::from_headers(&headers)
+        instrument("DeleteFilesystemBuilder::finalize", async move {
+            let mut uri = format!(
+                "{}/{}?resource=filesystem",
+                self.client().filesystem_uri(),
+                self.filesystem()
+            );
+
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::DELETE,
+                &|mut request| {
+                    request = IfSinceConditionOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                Some(&[]),
+            )?;
+
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED)
+                    .await?;
+            DeleteFilesystemResponse::from_headers(&headers)
+        })
+        .await
     }
 }
@@ -2,6 +2,7 @@ use crate::core::prelude::*;
 use crate::filesystem::responses::GetFilesystemPropertiesResponse;
 use crate::filesystem::{FilesystemRequired, FilesystemSupport};
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::{ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport};
 use azure_core::{No, ToAssign, Yes};
 use hyper::{Method, StatusCode};
@@ -149,28 +150,31 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<GetFilesystemPropertiesResponse, AzureError> {
-        let mut uri = format!(
-            "{}/{}?resource=filesystem",
-            self.client().filesystem_uri(),
-            self.filesystem()
-        );
-
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::HEAD,
-            &|mut request| {
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            Some(&[]),
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
-        GetFilesystemPropertiesResponse::from_headers(&headers)
+        instrument("GetFilesystemPropertiesBuilder::finalize", async move {
+            let mut uri = format!(
+                "{}/{}?resource=filesystem",
+                self.client().filesystem_uri(),
+                self.filesystem()
+            );
+
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::HEAD,
+                &|mut request| {
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                Some(&[]),
+            )?;
+
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+            GetFilesystemPropertiesResponse::from_headers(&headers)
+        })
+        .await
     }
 }
@@ -4,6 +4,7 @@ use crate::filesystem::{
     FilesystemRequired, FilesystemSupport, PropertiesOption, PropertiesSupport,
 };
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{
     ClientRequestIdOption, ClientRequestIdSupport, IfSinceConditionOption, IfSinceConditionSupport,
@@ -233,30 +234,33 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<SetFilesystemPropertiesResponse, AzureError> {
-        let mut uri = format!(
-            "{}/{}?resource=filesystem",
-            self.client().filesystem_uri(),
-            self.filesystem()
-        );
+        instrument("SetFilesystemPropertiesBuilder::finalize", async move {
+            let mut uri = format!(
+                "{}/{}?resource=filesystem",
+                self.client().filesystem_uri(),
+                self.filesystem()
+            );
 
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
 
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PATCH,
-            &|mut request| {
-                request = IfSinceConditionOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request = PropertiesOption::add_header(&self, request);
-                request
-            },
-            Some(&[]),
-        )?;
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PATCH,
+                &|mut request| {
+                    request = IfSinceConditionOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request = PropertiesOption::add_header(&self, request);
+                    request
+                },
+                Some(&[]),
+            )?;
 
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
-        SetFilesystemPropertiesResponse::from_headers(&headers)
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+            SetFilesystemPropertiesResponse::from_headers(&headers)
+        })
+        .await
     }
 }
@@ -0,0 +1,75 @@
+//! The CRC64 Azure Storage uses for the `x-ms-content-crc64` header.
+
+/// The reflected form of the polynomial of the Azure Storage CRC64.
+const POLYNOMIAL: u64 = 0x9A6C_9329_AC4B_C9B5;
+
+const TABLE: [u64; 256] = make_table(POLYNOMIAL);
+
+const fn make_table(polynomial: u64) -> [u64; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u64;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ polynomial
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+fn update(table: &[u64; 256], crc: u64, data: &[u8]) -> u64 {
+    let mut crc = !crc;
+    for byte in data {
+        crc = table[((crc as u8) ^ byte) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+/// Computes the CRC64 of `data`, in the little endian byte order expected
+/// by `with_content_crc64` and returned in `Consistency::Crc64`.
+pub fn compute(data: &[u8]) -> [u8; 8] {
+    update(&TABLE, 0, data).to_le_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc64_of_empty_data_is_zero() {
+        assert_eq!(compute(&[]), [0; 8]);
+    }
+
+    #[test]
+    fn crc64_matches_the_xz_check_value() {
+        // same algorithm with the ECMA-182 polynomial of CRC-64/XZ
+        let table = make_table(0xC96C_5795_D787_0F42);
+        assert_eq!(update(&table, 0, b"123456789"), 0x995D_C9BB_DF19_39FA);
+    }
+
+    #[test]
+    fn crc64_matches_the_azure_storage_check_value() {
+        // the service computes CRC-64/NVME, whose check value is
+        // 0xAE8B14860A799888, and returns it in x-ms-content-crc64 as the
+        // base64 of its little endian bytes
+        assert_eq!(base64::encode(compute(b"123456789")), "iJh5CoYUi64=");
+    }
+
+    #[test]
+    fn crc64_can_be_computed_incrementally() {
+        let data = b"The quick brown fox jumps over the lazy dog";
+        let crc = update(&TABLE, 0, &data[..10]);
+        assert_eq!(
+            update(&TABLE, crc, &data[10..]).to_le_bytes(),
+            compute(data)
+        );
+    }
+}
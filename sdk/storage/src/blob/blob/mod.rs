@@ -14,13 +14,18 @@ mod block_with_size_list;
 pub use self::block_with_size_list::BlockWithSizeList;
 mod block_list;
 pub use self::block_list::BlockList;
+pub mod crc64;
 pub mod requests;
 pub mod responses;
+use crate::blob::headers::{
+    ACCESS_TIER_CHANGE_TIME, ACCESS_TIER_INFERRED, ARCHIVE_STATUS, INCREMENTAL_COPY,
+    IS_CURRENT_VERSION, VERSION_ID,
+};
 use crate::core::Client;
 use azure_core::headers::{
-    BLOB_SEQUENCE_NUMBER, BLOB_TYPE, CONTENT_MD5, COPY_COMPLETION_TIME, COPY_ID, COPY_PROGRESS,
-    COPY_SOURCE, COPY_STATUS, COPY_STATUS_DESCRIPTION, CREATION_TIME, LEASE_DURATION, LEASE_STATE,
-    LEASE_STATUS, SERVER_ENCRYPTED,
+    BLOB_ACCESS_TIER, BLOB_SEQUENCE_NUMBER, BLOB_TYPE, CONTENT_MD5, COPY_COMPLETION_TIME, COPY_ID,
+    COPY_PROGRESS, COPY_SOURCE, COPY_STATUS, COPY_STATUS_DESCRIPTION, CREATION_TIME,
+    LEASE_DURATION, LEASE_STATE, LEASE_STATUS, META_PREFIX, SERVER_ENCRYPTED,
 };
 use azure_core::{
     errors::{AzureError, TraversingError},
@@ -36,6 +41,7 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use std::borrow::Borrow;
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::time::Duration;
 use xml::Element;
 use xml::Xml::ElementNode;
 
@@ -85,6 +91,79 @@ where
     }
 }
 
+/// The checksum sent with every block uploaded by `upload_blob`, so that the
+/// service rejects blocks corrupted in transit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockChecksum {
+    None,
+    Md5,
+    Crc64,
+}
+
+pub trait BlockChecksumSupport {
+    type O;
+    fn with_block_checksum(self, block_checksum: BlockChecksum) -> Self::O;
+}
+
+pub trait BlockChecksumOption {
+    fn block_checksum(&self) -> BlockChecksum;
+}
+
+pub trait BlockSizeSupport {
+    type O;
+    fn with_block_size(self, block_size: u64) -> Self::O;
+}
+
+pub trait BlockSizeOption {
+    fn block_size(&self) -> u64;
+}
+
+pub trait ParallelismSupport {
+    type O;
+    fn with_parallelism(self, parallelism: usize) -> Self::O;
+}
+
+pub trait ParallelismOption {
+    fn parallelism(&self) -> usize;
+}
+
+pub trait MaxRetriesSupport {
+    type O;
+    fn with_max_retries(self, max_retries: u32) -> Self::O;
+}
+
+pub trait MaxRetriesOption {
+    fn max_retries(&self) -> u32;
+}
+
+pub trait PollIntervalSupport {
+    type O;
+    fn with_poll_interval(self, poll_interval: Duration) -> Self::O;
+}
+
+pub trait PollIntervalOption {
+    fn poll_interval(&self) -> Duration;
+}
+
+/// The delay before the first retry of a block or of a range, doubled at
+/// every retry.
+pub(crate) const RETRY_DELAY: Duration = Duration::from_secs(1);
+/// The longest delay between two retries of a block or of a range.
+pub(crate) const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// The delay before retry number `retry` (starting at 0) of a block or of a
+/// range.
+pub(crate) fn retry_delay(retry: u32) -> Duration {
+    2u32.checked_pow(retry)
+        .and_then(|factor| RETRY_DELAY.checked_mul(factor))
+        .map_or(MAX_RETRY_DELAY, |delay| delay.min(MAX_RETRY_DELAY))
+}
+
+/// Whether `err` is a failure of a block or a range that the pipeline does not retry.
+pub(crate) fn is_transient(err: &AzureError) -> bool {
+    err.is_transport_error() || matches!(err, AzureError::ContentMD5Mismatch(_, _))
+}
+
 create_enum!(
     BlobType,
     (BlockBlob, "BlockBlob"),
@@ -107,6 +186,8 @@ pub struct Blob {
     pub name: String,
     pub container_name: String,
     pub snapshot_time: Option<DateTime<Utc>>,
+    pub version_id: Option<String>,
+    pub is_current_version: Option<bool>,
     #[cfg(not(feature = "azurite_workaround"))]
     pub creation_time: DateTime<Utc>,
     #[cfg(feature = "azurite_workaround")]
@@ -123,6 +204,9 @@ pub struct Blob {
     pub x_ms_blob_sequence_number: Option<u64>,
     pub blob_type: BlobType,
     pub access_tier: Option<String>,
+    /// Set while the blob is being rehydrated from the archive tier, ie
+    /// `rehydrate-pending-to-hot`.
+    pub archive_status: Option<String>,
     pub lease_status: Option<LeaseStatus>,
     pub lease_state: LeaseState,
     pub lease_duration: Option<LeaseDuration>,
@@ -144,7 +228,13 @@ pub struct Blob {
 impl Blob {
     pub(crate) fn parse(elem: &Element, container_name: &str) -> Result<Blob, AzureError> {
         let name = cast_must::<String>(elem, &["Name"])?;
-        let snapshot_time = cast_optional::<DateTime<Utc>>(elem, &["Snapshot"])?;
+        // unlike the other times of the listing, snapshots are ISO 8601
+        let snapshot_time = cast_optional::<String>(elem, &["Snapshot"])?
+            .map(|snapshot| DateTime::parse_from_rfc3339(&snapshot))
+            .transpose()?
+            .map(|snapshot| snapshot.with_timezone(&Utc));
+        let version_id = cast_optional::<String>(elem, &["VersionId"])?;
+        let is_current_version = cast_optional::<bool>(elem, &["IsCurrentVersion"])?;
 
         #[cfg(feature = "azurite_workaround")]
         let creation_time = cast_optional::<DateTime<Utc>>(elem, &["Properties", "Creation-Time"])?;
@@ -168,6 +258,7 @@ impl Blob {
 
         let blob_type = cast_must::<BlobType>(elem, &["Properties", "BlobType"])?;
         let access_tier = cast_optional::<String>(elem, &["Properties", "AccessTier"])?;
+        let archive_status = cast_optional::<String>(elem, &["Properties", "ArchiveStatus"])?;
 
         let lease_status = cast_optional::<LeaseStatus>(elem, &["Properties", "LeaseStatus"])?;
         let lease_state = cast_must::<LeaseState>(elem, &["Properties", "LeaseState"])?;
@@ -233,6 +324,8 @@ impl Blob {
             name,
             container_name: container_name.to_owned(),
             snapshot_time,
+            version_id,
+            is_current_version,
             creation_time,
             last_modified,
             etag,
@@ -246,6 +339,7 @@ impl Blob {
             x_ms_blob_sequence_number,
             blob_type,
             access_tier,
+            archive_status,
             lease_status,
             lease_state,
             lease_duration,
@@ -385,10 +479,53 @@ impl Blob {
             .ok_or_else(|| AzureError::HeaderNotFound(SERVER_ENCRYPTED.to_owned()))?
             .parse::<bool>()?;
 
+        let version_id = h.get_as_string(VERSION_ID);
+        trace!("version_id == {:?}", version_id);
+
+        let is_current_version = h
+            .get_as_str(IS_CURRENT_VERSION)
+            .map(|icv| icv.parse::<bool>())
+            .transpose()?;
+        trace!("is_current_version == {:?}", is_current_version);
+
+        let incremental_copy = h
+            .get_as_str(INCREMENTAL_COPY)
+            .map(|ic| ic.parse::<bool>())
+            .transpose()?;
+        trace!("incremental_copy == {:?}", incremental_copy);
+
+        let access_tier = h.get_as_string(BLOB_ACCESS_TIER);
+        trace!("access_tier == {:?}", access_tier);
+
+        let archive_status = h.get_as_string(ARCHIVE_STATUS);
+        trace!("archive_status == {:?}", archive_status);
+
+        let access_tier_inferred = h
+            .get_as_str(ACCESS_TIER_INFERRED)
+            .map(|ati| ati.parse::<bool>())
+            .transpose()?;
+        trace!("access_tier_inferred == {:?}", access_tier_inferred);
+
+        let access_tier_change_time = h
+            .get_as_str(ACCESS_TIER_CHANGE_TIME)
+            .map(from_azure_time)
+            .transpose()?;
+        trace!("access_tier_change_time == {:?}", access_tier_change_time);
+
+        let mut metadata = HashMap::new();
+        for (name, value) in h.iter() {
+            if let Some(key) = name.as_str().strip_prefix(META_PREFIX) {
+                metadata.insert(key.to_owned(), value.to_str()?.to_owned());
+            }
+        }
+        trace!("metadata == {:?}", metadata);
+
         Ok(Blob {
             name: blob_name.to_owned(),
             container_name: container_name.to_owned(),
             snapshot_time,
+            version_id,
+            is_current_version,
             creation_time,
             last_modified: Some(last_modified),
             etag: Some(etag),
@@ -401,7 +538,8 @@ impl Blob {
             content_disposition,
             x_ms_blob_sequence_number,
             blob_type,
-            access_tier: None,
+            access_tier,
+            archive_status,
             lease_status,
             lease_state,
             lease_duration,
@@ -411,13 +549,13 @@ impl Blob {
             copy_progress,
             copy_completion_time,
             copy_status_description,
-            incremental_copy: None, // TODO: Not present or documentation bug?
+            incremental_copy,
             server_encrypted,
-            access_tier_inferred: None,     // TODO: Not present
-            access_tier_change_time: None,  // TODO: Not present
+            access_tier_inferred,
+            access_tier_change_time,
             deleted_time: None,             // TODO
             remaining_retention_days: None, // TODO: Not present or documentation bug?
-            metadata: HashMap::new(),       // TODO: Not present or documentation bug?
+            metadata,
         })
     }
 }
@@ -484,3 +622,64 @@ pub(crate) fn copy_status_from_headers(
         .ok_or_else(|| AzureError::HeaderNotFound(azure_core::headers::COPY_STATUS.to_owned()))?;
     Ok(CopyStatus::from_str(val)?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::errors::HttpError;
+    use hyper::{HeaderMap, StatusCode};
+
+    fn http_error(status: StatusCode) -> AzureError {
+        HttpError::new(StatusCode::OK, status, &HeaderMap::new(), b"").into()
+    }
+
+    #[test]
+    fn statuses_are_left_to_the_pipeline() {
+        assert!(!is_transient(&http_error(StatusCode::TOO_MANY_REQUESTS)));
+        assert!(!is_transient(&http_error(StatusCode::SERVICE_UNAVAILABLE)));
+        assert!(!is_transient(&http_error(StatusCode::NOT_FOUND)));
+        assert!(!is_transient(&http_error(
+            StatusCode::RANGE_NOT_SATISFIABLE
+        )));
+    }
+
+    #[test]
+    fn broken_bodies_are_transient() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset");
+        assert!(is_transient(&AzureError::IOError(io_error)));
+        let reset = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset");
+        assert!(is_transient(&AzureError::TransportError(Box::new(reset))));
+        assert!(is_transient(&AzureError::ContentMD5Mismatch(
+            "expected".to_owned(),
+            "computed".to_owned()
+        )));
+    }
+
+    #[test]
+    fn retry_delay_doubles_and_is_capped() {
+        assert_eq!(retry_delay(0), RETRY_DELAY);
+        assert_eq!(retry_delay(3), RETRY_DELAY * 8);
+        assert_eq!(retry_delay(6), MAX_RETRY_DELAY);
+        assert_eq!(retry_delay(40), MAX_RETRY_DELAY);
+        assert_eq!(retry_delay(u32::MAX), MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn parses_listed_snapshots() {
+        let body = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+            <EnumerationResults ContainerName=\"container\"><Blobs><Blob>\
+            <Name>blob</Name><Snapshot>2020-11-04T14:56:03.5276931Z</Snapshot>\
+            <Properties><Creation-Time>Wed, 04 Nov 2020 14:56:02 GMT</Creation-Time>\
+            <Last-Modified>Wed, 04 Nov 2020 14:56:02 GMT</Last-Modified>\
+            <Etag>0x8D880CC3A4D6F2B</Etag><Content-Length>5</Content-Length>\
+            <Content-Type>application/octet-stream</Content-Type><BlobType>BlockBlob</BlobType>\
+            <LeaseState>available</LeaseState><ServerEncrypted>true</ServerEncrypted>\
+            </Properties></Blob></Blobs><NextMarker /></EnumerationResults>";
+
+        let blobs = incomplete_vector_from_response(body, "container").unwrap();
+        assert_eq!(
+            blobs[0].snapshot_time.unwrap().to_rfc3339(),
+            "2020-11-04T14:56:03.527693100+00:00"
+        );
+    }
+}
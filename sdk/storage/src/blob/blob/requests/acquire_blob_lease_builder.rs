@@ -3,6 +3,7 @@ use crate::blob::blob::responses::AcquireBlobLeaseResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_core::headers::LEASE_ACTION;
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -312,32 +313,35 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<AcquireBlobLeaseResponse, AzureError> {
-        let mut uri = generate_blob_uri(
-            self.client(),
-            self.container_name(),
-            self.blob_name(),
-            Some("comp=lease"),
-        );
+        instrument("AcquireBlobLeaseBuilder::finalize", async move {
+            let mut uri = generate_blob_uri(
+                self.client(),
+                self.container_name(),
+                self.blob_name(),
+                Some("comp=lease"),
+            );
 
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
 
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = request.header(LEASE_ACTION, "acquire");
-                request = LeaseDurationRequired::add_header(&self, request);
-                request = ProposedLeaseIdOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            None,
-        )?;
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = request.header(LEASE_ACTION, "acquire");
+                    request = LeaseDurationRequired::add_header(&self, request);
+                    request = ProposedLeaseIdOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                None,
+            )?;
 
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
-        AcquireBlobLeaseResponse::from_headers(&headers)
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+            AcquireBlobLeaseResponse::from_headers(&headers)
+        })
+        .await
     }
 }
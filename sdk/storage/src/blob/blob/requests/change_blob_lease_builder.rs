@@ -3,6 +3,7 @@ use crate::blob::blob::responses::ChangeBlobLeaseResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_core::headers::LEASE_ACTION;
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -354,32 +355,35 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<ChangeBlobLeaseResponse, AzureError> {
-        let mut uri = generate_blob_uri(
-            self.client(),
-            self.container_name(),
-            self.blob_name(),
-            Some("comp=lease"),
-        );
+        instrument("ChangeBlobLeaseBuilder::finalize", async move {
+            let mut uri = generate_blob_uri(
+                self.client(),
+                self.container_name(),
+                self.blob_name(),
+                Some("comp=lease"),
+            );
 
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
 
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = LeaseIdRequired::add_header(&self, request);
-                request = request.header(LEASE_ACTION, "change");
-                request = ProposedLeaseIdRequired::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            None,
-        )?;
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = LeaseIdRequired::add_header(&self, request);
+                    request = request.header(LEASE_ACTION, "change");
+                    request = ProposedLeaseIdRequired::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                None,
+            )?;
 
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
-        ChangeBlobLeaseResponse::from_headers(&headers)
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+            ChangeBlobLeaseResponse::from_headers(&headers)
+        })
+        .await
     }
 }
@@ -3,6 +3,7 @@ use crate::blob::blob::responses::ClearPageResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_core::headers::PAGE_WRITE;
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -473,37 +474,40 @@ where
 {
     #[inline]
     pub async fn finalize(self) -> Result<ClearPageResponse, AzureError> {
-        let mut uri = generate_blob_uri(
-            self.client(),
-            self.container_name(),
-            self.blob_name(),
-            Some("comp=page"),
-        );
-
-        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, timeout);
-        }
-
-        trace!("uri == {:?}", uri);
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = BA512RangeRequired::add_header(&self, request);
-                request = request.header(PAGE_WRITE, "clear");
-                request = LeaseIdOption::add_header(&self, request);
-                request = SequenceNumberConditionOption::add_header(&self, request);
-                request = IfSinceConditionOption::add_header(&self, request);
-                request = IfMatchConditionOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            None,
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
-        ClearPageResponse::from_headers(&headers)
+        instrument("ClearPageBuilder::finalize", async move {
+            let mut uri = generate_blob_uri(
+                self.client(),
+                self.container_name(),
+                self.blob_name(),
+                Some("comp=page"),
+            );
+
+            if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, timeout);
+            }
+
+            trace!("uri == {:?}", uri);
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = BA512RangeRequired::add_header(&self, request);
+                    request = request.header(PAGE_WRITE, "clear");
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = SequenceNumberConditionOption::add_header(&self, request);
+                    request = IfSinceConditionOption::add_header(&self, request);
+                    request = IfMatchConditionOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                None,
+            )?;
+
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+            ClearPageResponse::from_headers(&headers)
+        })
+        .await
     }
 }
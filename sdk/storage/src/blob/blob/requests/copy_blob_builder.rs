@@ -3,6 +3,7 @@ use crate::blob::blob::responses::CopyBlobResponse;
 use crate::core::prelude::*;
 use crate::{RehydratePriority, RehydratePriorityOption, RehydratePrioritySupport};
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -778,39 +779,43 @@ where
 {
     #[inline]
     pub async fn finalize(self) -> Result<CopyBlobResponse, AzureError> {
-        let mut uri =
-            generate_blob_uri(self.client(), self.container_name(), self.blob_name(), None);
-
-        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}?{}", uri, timeout);
-        }
-
-        trace!("uri == {:?}", uri);
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = SourceUrlRequired::add_header(&self, request);
-                request = MetadataOption::add_header(&self, request);
-                request = IfSinceConditionOption::add_header(&self, request);
-                request = IfSourceSinceConditionOption::add_header(&self, request);
-                request = IfMatchConditionOption::add_header(&self, request);
-                request = IfSourceMatchConditionOption::add_header(&self, request);
-                request = LeaseIdOption::add_header(&self, request);
-                request = SourceLeaseIdOption::add_header(&self, request);
-                request = AccessTierOption::add_header(&self, request);
-                request = RehydratePriorityOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-
-                request
-            },
-            None,
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
-
-        (&headers).try_into()
+        instrument("CopyBlobBuilder::finalize", async move {
+            let mut uri =
+                generate_blob_uri(self.client(), self.container_name(), self.blob_name(), None);
+
+            if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}?{}", uri, timeout);
+            }
+
+            trace!("uri == {:?}", uri);
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = SourceUrlRequired::add_header(&self, request);
+                    request = MetadataOption::add_header(&self, request);
+                    request = IfSinceConditionOption::add_header(&self, request);
+                    request = IfSourceSinceConditionOption::add_header(&self, request);
+                    request = IfMatchConditionOption::add_header(&self, request);
+                    request = IfSourceMatchConditionOption::add_header(&self, request);
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = SourceLeaseIdOption::add_header(&self, request);
+                    request = AccessTierOption::add_header(&self, request);
+                    request = RehydratePriorityOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+
+                    request
+                },
+                None,
+            )?;
+
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED)
+                    .await?;
+
+            (&headers).try_into()
+        })
+        .await
     }
 }
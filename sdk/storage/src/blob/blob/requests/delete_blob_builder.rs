@@ -2,6 +2,7 @@ use crate::blob::blob::generate_blob_uri;
 use crate::blob::blob::responses::DeleteBlobResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{DeleteSnapshotsMethod, No, ToAssign, Yes};
@@ -24,6 +25,7 @@ where
     container_name: Option<&'a str>,
     blob_name: Option<&'a str>,
     delete_snapshots_method: DeleteSnapshotsMethod,
+    version_id: Option<&'a str>,
     timeout: Option<u64>,
     lease_id: Option<&'a LeaseId>,
     client_request_id: Option<&'a str>,
@@ -43,6 +45,7 @@ where
             blob_name: None,
             p_delete_snapshots_method: PhantomData {},
             delete_snapshots_method: DeleteSnapshotsMethod::Include,
+            version_id: None,
             timeout: None,
             lease_id: None,
             client_request_id: None,
@@ -106,6 +109,20 @@ where
     }
 }
 
+impl<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet> VersionIdOption<'a>
+    for DeleteBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    DeleteSnapshotMethodSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn version_id(&self) -> Option<&'a str> {
+        self.version_id
+    }
+}
+
 impl<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet> TimeoutOption
     for DeleteBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet>
 where
@@ -167,6 +184,7 @@ where
             container_name: Some(container_name),
             blob_name: self.blob_name,
             delete_snapshots_method: self.delete_snapshots_method,
+            version_id: self.version_id,
             timeout: self.timeout,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
@@ -193,6 +211,7 @@ where
             container_name: self.container_name,
             blob_name: Some(blob_name),
             delete_snapshots_method: self.delete_snapshots_method,
+            version_id: self.version_id,
             timeout: self.timeout,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
@@ -222,6 +241,35 @@ where
             container_name: self.container_name,
             blob_name: self.blob_name,
             delete_snapshots_method,
+            version_id: self.version_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet> VersionIdSupport<'a>
+    for DeleteBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    DeleteSnapshotMethodSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet>;
+
+    #[inline]
+    fn with_version_id(self, version_id: &'a str) -> Self::O {
+        DeleteBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_delete_snapshots_method: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            delete_snapshots_method: self.delete_snapshots_method,
+            version_id: Some(version_id),
             timeout: self.timeout,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
@@ -249,6 +297,7 @@ where
             container_name: self.container_name,
             blob_name: self.blob_name,
             delete_snapshots_method: self.delete_snapshots_method,
+            version_id: self.version_id,
             timeout: Some(timeout),
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
@@ -276,6 +325,7 @@ where
             container_name: self.container_name,
             blob_name: self.blob_name,
             delete_snapshots_method: self.delete_snapshots_method,
+            version_id: self.version_id,
             timeout: self.timeout,
             lease_id: Some(lease_id),
             client_request_id: self.client_request_id,
@@ -303,6 +353,7 @@ where
             container_name: self.container_name,
             blob_name: self.blob_name,
             delete_snapshots_method: self.delete_snapshots_method,
+            version_id: self.version_id,
             timeout: self.timeout,
             lease_id: self.lease_id,
             client_request_id: Some(client_request_id),
@@ -316,28 +367,37 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<DeleteBlobResponse, AzureError> {
-        let mut uri =
-            generate_blob_uri(self.client(), self.container_name(), self.blob_name(), None);
+        instrument("DeleteBlobBuilder::finalize", async move {
+            let mut uri =
+                generate_blob_uri(self.client(), self.container_name(), self.blob_name(), None);
 
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}?{}", uri, nm);
-        }
+            let mut f_first = true;
+            if let Some(version_id) = VersionIdOption::to_uri_parameter(&self) {
+                uri = format!("{}?{}", uri, version_id);
+                f_first = false;
+            }
+            if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}{}{}", uri, if f_first { "?" } else { "&" }, timeout);
+            }
 
-        trace!("delete_blob uri == {:?}", uri);
+            trace!("delete_blob uri == {:?}", uri);
 
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::DELETE,
-            &|mut request| {
-                request = DeleteSnapshotsMethodRequired::add_header(&self, request);
-                request = LeaseIdOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            None,
-        )?;
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
-        DeleteBlobResponse::from_headers(&headers)
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::DELETE,
+                &|mut request| {
+                    request = DeleteSnapshotsMethodRequired::add_header(&self, request);
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                None,
+            )?;
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED)
+                    .await?;
+            DeleteBlobResponse::from_headers(&headers)
+        })
+        .await
     }
 }
@@ -2,6 +2,7 @@ use crate::blob::blob::generate_blob_uri;
 use crate::blob::blob::responses::DeleteBlobResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -313,30 +314,34 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<DeleteBlobResponse, AzureError> {
-        let mut uri = generate_blob_uri(
-            self.client(),
-            self.container_name(),
-            self.blob_name(),
-            Some(&SnapshotRequired::to_uri_parameter(&self)),
-        );
+        instrument("DeleteBlobSnapshotBuilder::finalize", async move {
+            let mut uri = generate_blob_uri(
+                self.client(),
+                self.container_name(),
+                self.blob_name(),
+                Some(&SnapshotRequired::to_uri_parameter(&self)),
+            );
 
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
 
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::DELETE,
-            &|mut request| {
-                request = LeaseIdOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            None,
-        )?;
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::DELETE,
+                &|mut request| {
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                None,
+            )?;
 
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
-        DeleteBlobResponse::from_headers(&headers)
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED)
+                    .await?;
+            DeleteBlobResponse::from_headers(&headers)
+        })
+        .await
     }
 }
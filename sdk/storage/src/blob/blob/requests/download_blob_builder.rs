@@ -0,0 +1,993 @@
+use crate::blob::blob::requests::GetBlobBuilder;
+use crate::blob::blob::responses::{DownloadBlobResponse, GetBlobResponse};
+use crate::blob::blob::{is_transient, retry_delay};
+use crate::blob::blob::{
+    MaxRetriesOption, MaxRetriesSupport, ParallelismOption, ParallelismSupport,
+};
+use crate::core::prelude::*;
+use azure_core::errors::AzureError;
+use azure_core::instrumentation::instrument;
+use azure_core::lease::LeaseId;
+use azure_core::prelude::*;
+use azure_core::range::Range;
+use azure_core::{No, ToAssign, Yes};
+use chrono::{DateTime, Utc};
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use hyper::StatusCode;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::io::SeekFrom;
+use std::marker::PhantomData;
+use std::path::Path;
+use tokio::fs::OpenOptions;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::time::delay_for;
+
+#[derive(Debug, Clone)]
+pub struct DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    snapshot: Option<DateTime<Utc>>,
+    range: Option<&'a Range>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    timeout: Option<u64>,
+    lease_id: Option<&'a LeaseId>,
+    client_request_id: Option<&'a str>,
+    chunk_size: u64,
+    parallelism: usize,
+    max_retries: u32,
+}
+
+impl<'a, C> DownloadBlobBuilder<'a, C, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> DownloadBlobBuilder<'a, C, No, No> {
+        DownloadBlobBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            snapshot: None,
+            range: None,
+            if_match_condition: None,
+            timeout: None,
+            lease_id: None,
+            client_request_id: None,
+            chunk_size: 4194304,
+            parallelism: 4,
+            max_retries: 3,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequired<'a, C>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet> ContainerNameRequired<'a> for DownloadBlobBuilder<'a, C, Yes, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet> BlobNameRequired<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> SnapshotOption
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn snapshot(&self) -> Option<DateTime<Utc>> {
+        self.snapshot
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> RangeOption<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn range(&self) -> Option<&'a Range> {
+        self.range
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> IfMatchConditionOption<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> TimeoutOption
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> LeaseIdOption<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequestIdOption<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ChunkSizeOption
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn chunk_size(&self) -> u64 {
+        self.chunk_size
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ParallelismOption
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn parallelism(&self) -> usize {
+        self.parallelism
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MaxRetriesOption
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+}
+
+impl<'a, C, BlobNameSet> ContainerNameSupport<'a> for DownloadBlobBuilder<'a, C, No, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, Yes, BlobNameSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            range: self.range,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            chunk_size: self.chunk_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet> BlobNameSupport<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, No>
+where
+    ContainerNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, Yes>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            snapshot: self.snapshot,
+            range: self.range,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            chunk_size: self.chunk_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> SnapshotSupport
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_snapshot(self, snapshot: DateTime<Utc>) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: Some(snapshot),
+            range: self.range,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            chunk_size: self.chunk_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> RangeSupport<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_range(self, range: &'a Range) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            range: Some(range),
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            chunk_size: self.chunk_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> IfMatchConditionSupport<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            range: self.range,
+            if_match_condition: Some(if_match_condition),
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            chunk_size: self.chunk_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> TimeoutSupport
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            range: self.range,
+            if_match_condition: self.if_match_condition,
+            timeout: Some(timeout),
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            chunk_size: self.chunk_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> LeaseIdSupport<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            range: self.range,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            lease_id: Some(lease_id),
+            client_request_id: self.client_request_id,
+            chunk_size: self.chunk_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequestIdSupport<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            range: self.range,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: Some(client_request_id),
+            chunk_size: self.chunk_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ChunkSizeSupport
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_chunk_size(self, chunk_size: u64) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            range: self.range,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            chunk_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ParallelismSupport
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_parallelism(self, parallelism: usize) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            range: self.range,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            chunk_size: self.chunk_size,
+            parallelism,
+            max_retries: self.max_retries,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MaxRetriesSupport
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_max_retries(self, max_retries: u32) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            range: self.range,
+            if_match_condition: self.if_match_condition,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            chunk_size: self.chunk_size,
+            parallelism: self.parallelism,
+            max_retries,
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> DownloadBlobBuilder<'a, C, Yes, Yes>
+where
+    C: Client,
+{
+    /// Downloads the blob, or the range given, into `writer`. The blob is
+    /// fetched in ranges of `chunk_size` bytes, `parallelism` ranges at a
+    /// time, written to `writer` in order. See `finalize_to_file` for the
+    /// consistency checks, the retries and the resumption of a download.
+    pub async fn finalize_to_writer<W>(
+        self,
+        mut writer: W,
+    ) -> Result<DownloadBlobResponse, AzureError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        instrument("DownloadBlobBuilder::finalize", async move {
+            let (first, range, ranges) = self.first_range().await?;
+            let etag = first.blob.etag.clone().unwrap_or_default();
+
+            let mut written = range.start;
+            let result: Result<(), AzureError> = async {
+                writer.write_all(&first.data).await?;
+                written += first.data.len() as u64;
+
+                let mut chunks = self.ranges(ranges, &etag).buffered(self.parallelism);
+                while let Some((_, data)) = chunks.try_next().await? {
+                    writer.write_all(&data).await?;
+                    written += data.len() as u64;
+                }
+                Ok(writer.flush().await?)
+            }
+            .await;
+            result.map_err(|err| interrupted(Range::new(written, range.end), etag.clone(), err))?;
+
+            Ok(DownloadBlobResponse {
+                blob: first.blob,
+                range,
+            })
+        })
+        .await
+    }
+
+    /// Downloads the blob, or the range given, into the file at `path`. The
+    /// blob is fetched in ranges of `chunk_size` bytes, `parallelism` ranges
+    /// at a time, each written at its offset as soon as it is received.
+    ///
+    /// Every range is requested with the ETag of the first one in
+    /// `If-Match`, so that the download fails instead of mixing two versions
+    /// of a blob changed meanwhile, and, for ranges up to 4 MiB, checked
+    /// against the MD5 computed by the service. Throttling and server errors
+    /// are retried by the pipeline of the client; a range whose connection
+    /// breaks off or that does not match its MD5 is fetched again up to
+    /// `max_retries` times, waiting twice as long every time (up to a
+    /// minute), without fetching the other ranges again.
+    ///
+    /// Without a range the file is truncated first. With a range the file is
+    /// written from the offset of the range. A download failing after its
+    /// first range returns `AzureError::DownloadInterrupted` with the range
+    /// left and the ETag of the blob: it is resumed by downloading that range
+    /// to the same file with `with_if_match_condition` on that ETag.
+    pub async fn finalize_to_file<P>(self, path: P) -> Result<DownloadBlobResponse, AzureError>
+    where
+        P: AsRef<Path>,
+    {
+        instrument("DownloadBlobBuilder::finalize", async move {
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(self.range.is_none())
+                .open(path)
+                .await?;
+
+            let (first, range, ranges) = self.first_range().await?;
+            let etag = first.blob.etag.clone().unwrap_or_default();
+
+            // the ranges complete out of order, `completed` maps the start of
+            // those past the contiguous part of the file to their end
+            let mut written = range.start;
+            let mut completed = BTreeMap::new();
+            let result: Result<(), AzureError> = async {
+                file.seek(SeekFrom::Start(range.start)).await?;
+                file.write_all(&first.data).await?;
+                written += first.data.len() as u64;
+
+                let mut chunks = self
+                    .ranges(ranges, &etag)
+                    .buffer_unordered(self.parallelism);
+                while let Some((offset, data)) = chunks.try_next().await? {
+                    file.seek(SeekFrom::Start(offset)).await?;
+                    file.write_all(&data).await?;
+                    completed.insert(offset, offset + data.len() as u64);
+                    while let Some(end) = completed.remove(&written) {
+                        written = end;
+                    }
+                }
+                Ok(file.flush().await?)
+            }
+            .await;
+            result.map_err(|err| interrupted(Range::new(written, range.end), etag.clone(), err))?;
+
+            Ok(DownloadBlobResponse {
+                blob: first.blob,
+                range,
+            })
+        })
+        .await
+    }
+
+    /// Fetches the first range, returning it with the range of the blob to
+    /// download and the ranges left to fetch.
+    async fn first_range(&self) -> Result<(GetBlobResponse, Range, Vec<Range>), AzureError> {
+        if self.chunk_size == 0 || self.parallelism == 0 {
+            return Err(AzureError::InputParametersError(
+                "the chunk size and the parallelism must be at least 1".to_owned(),
+            ));
+        }
+
+        let start = self.range.map_or(0, |range| range.start);
+        let mut end = start + self.chunk_size;
+        if let Some(range) = self.range {
+            end = end.min(range.end);
+        }
+
+        let mut first = match self
+            .get_range(Range::new(start, end), self.if_match_condition)
+            .await
+        {
+            Ok(first) => first,
+            // the service does not accept a range request on an empty blob
+            Err(AzureError::HttpError(err))
+                if err.status() == StatusCode::RANGE_NOT_SATISFIABLE && self.range.is_none() =>
+            {
+                let mut request = self.get_blob();
+                if let Some(if_match_condition) = self.if_match_condition {
+                    request = request.with_if_match_condition(if_match_condition);
+                }
+                return Ok((request.finalize().await?, Range::new(0, 0), Vec::new()));
+            }
+            Err(err) => return Err(err),
+        };
+
+        let content_range = first.content_range.ok_or_else(|| {
+            AzureError::HeaderNotFound(hyper::header::CONTENT_RANGE.as_str().to_owned())
+        })?;
+        first.blob.content_length = content_range.total_length;
+        let end = match self.range {
+            Some(range) => range.end.min(content_range.total_length),
+            None => content_range.total_length,
+        };
+
+        let chunk_size = self.chunk_size;
+        let ranges = (content_range.range.end..end)
+            .step_by(chunk_size as usize)
+            .map(|start| Range::new(start, (start + chunk_size).min(end)))
+            .collect();
+        Ok((first, Range::new(start, end), ranges))
+    }
+
+    /// Fetches `ranges` of the blob with the ETag `etag`, yielding the offset
+    /// and the content of every range.
+    fn ranges<'b>(
+        &'b self,
+        ranges: Vec<Range>,
+        etag: &'b str,
+    ) -> impl Stream<Item = impl Future<Output = Result<(u64, Vec<u8>), AzureError>> + 'b> + 'b
+    {
+        stream::iter(ranges).map(move |range| async move {
+            let response = self
+                .get_range(range, Some(IfMatchCondition::Match(etag)))
+                .await?;
+            Ok((range.start, response.data))
+        })
+    }
+
+    async fn get_range(
+        &self,
+        range: Range,
+        if_match_condition: Option<IfMatchCondition<'_>>,
+    ) -> Result<GetBlobResponse, AzureError> {
+        let mut retry = 0;
+        loop {
+            let mut request = self.get_blob().with_range(&range);
+            if let Some(if_match_condition) = if_match_condition {
+                request = request.with_if_match_condition(if_match_condition);
+            }
+
+            let result = match request.finalize().await {
+                Ok(response) => check_content_md5(&range, response),
+                Err(err) => Err(err),
+            };
+            match result {
+                Ok(response) => return Ok(response),
+                Err(err) if retry < self.max_retries && is_transient(&err) => {
+                    warn!("range {} failed, retrying: {}", range, err);
+                    delay_for(retry_delay(retry)).await;
+                    retry += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    fn get_blob(&self) -> GetBlobBuilder<'_, C, Yes, Yes> {
+        let mut request = GetBlobBuilder::new(self.client())
+            .with_container_name(self.container_name())
+            .with_blob_name(self.blob_name());
+        if let Some(snapshot) = self.snapshot {
+            request = request.with_snapshot(snapshot);
+        }
+        if let Some(timeout) = self.timeout {
+            request = request.with_timeout(timeout);
+        }
+        if let Some(lease_id) = self.lease_id {
+            request = request.with_lease_id(lease_id);
+        }
+        if let Some(client_request_id) = self.client_request_id {
+            request = request.with_client_request_id(client_request_id);
+        }
+        request
+    }
+}
+
+/// Records the range left to download when a download fails midway.
+fn interrupted(remaining: Range, etag: String, err: AzureError) -> AzureError {
+    AzureError::DownloadInterrupted(remaining, etag, Box::new(err))
+}
+
+/// The largest range for which the service computes the MD5.
+const MAX_RANGE_CONTENT_MD5: u64 = 4 * 1024 * 1024;
+
+/// Checks the content of `range` against the MD5 returned by the service.
+fn check_content_md5(
+    range: &Range,
+    response: GetBlobResponse,
+) -> Result<GetBlobResponse, AzureError> {
+    if range.len() <= MAX_RANGE_CONTENT_MD5 {
+        if let Some(expected) = &response.blob.content_md5 {
+            let computed = base64::encode(&md5::compute(&response.data)[..]);
+            if *expected != computed {
+                return Err(AzureError::ContentMD5Mismatch(expected.clone(), computed));
+            }
+        }
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::with_access_key;
+    use crate::key_client::KeyClient;
+    use azure_core::mock_transport::{ScriptedResponse, ScriptedTransport};
+    use std::sync::Arc;
+
+    const BLOB: &[u8] = b"0123456789";
+    const ETAG: &str = "\"0x8D880CC3A4D6F2B\"";
+
+    fn scripted_client(transport: &Arc<ScriptedTransport>) -> KeyClient {
+        with_access_key("account", &base64::encode("key"))
+            .with_pipeline(ScriptedTransport::pipeline(transport.clone()))
+    }
+
+    /// A response carrying `body` with the properties of `BLOB`.
+    fn blob_response(status: StatusCode, body: Vec<u8>) -> ScriptedResponse {
+        let content_length = body.len().to_string();
+        ScriptedResponse::storage(status, body)
+            .with_header("content-length", &content_length)
+            .with_header("etag", ETAG)
+            .with_header("last-modified", "Wed, 04 Nov 2020 14:56:02 GMT")
+            .with_header("x-ms-creation-time", "Wed, 04 Nov 2020 14:56:02 GMT")
+            .with_header("x-ms-blob-type", "BlockBlob")
+            .with_header("x-ms-lease-status", "unlocked")
+            .with_header("x-ms-lease-state", "available")
+            .with_header("x-ms-server-encrypted", "true")
+    }
+
+    /// The answer of the service to a request of the bytes `start..end` of
+    /// `BLOB`, with `body` received in place of them.
+    fn range_with_body(start: usize, end: usize, body: Vec<u8>) -> ScriptedResponse {
+        let content_md5 = base64::encode(&md5::compute(&BLOB[start..end])[..]);
+        blob_response(StatusCode::PARTIAL_CONTENT, body)
+            .with_header(
+                "content-range",
+                &format!("bytes {}-{}/{}", start, end - 1, BLOB.len()),
+            )
+            .with_header("content-md5", &content_md5)
+    }
+
+    fn range(start: usize, end: usize) -> ScriptedResponse {
+        range_with_body(start, end, BLOB[start..end].to_vec())
+    }
+
+    #[tokio::test]
+    async fn resumes_an_interrupted_download() {
+        let path = std::env::temp_dir().join(format!("download-{}", uuid::Uuid::new_v4()));
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            range(0, 4),
+            range(4, 8),
+            ScriptedResponse::new(StatusCode::INTERNAL_SERVER_ERROR, ""),
+        ]));
+        let client = scripted_client(&transport);
+        let err = DownloadBlobBuilder::new(&client)
+            .with_container_name("container")
+            .with_blob_name("blob")
+            .with_chunk_size(4)
+            .with_parallelism(1)
+            .finalize_to_file(&path)
+            .await
+            .unwrap_err();
+
+        let (remaining, etag) = match err {
+            AzureError::DownloadInterrupted(remaining, etag, _) => (remaining, etag),
+            err => panic!("unexpected error {:?}", err),
+        };
+        assert_eq!(remaining, Range::new(8, 10));
+        assert_eq!(etag, ETAG);
+        assert_eq!(std::fs::read(&path).unwrap(), &BLOB[..8]);
+
+        let transport = Arc::new(ScriptedTransport::new(vec![range(8, 10)]));
+        let client = scripted_client(&transport);
+        let response = DownloadBlobBuilder::new(&client)
+            .with_container_name("container")
+            .with_blob_name("blob")
+            .with_range(&remaining)
+            .with_if_match_condition(IfMatchCondition::Match(&etag))
+            .finalize_to_file(&path)
+            .await
+            .unwrap();
+
+        assert_eq!(response.range, remaining);
+        assert_eq!(std::fs::read(&path).unwrap(), BLOB);
+        let request = &transport.requests()[0];
+        assert_eq!(request.headers()["range"], "bytes=8-9");
+        assert_eq!(request.headers()["if-match"], ETAG);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn pins_the_etag_of_the_first_range() {
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            range(0, 4),
+            range(4, 8),
+            range(8, 10),
+        ]));
+        let client = scripted_client(&transport);
+        let mut data = Vec::new();
+        DownloadBlobBuilder::new(&client)
+            .with_container_name("container")
+            .with_blob_name("blob")
+            .with_chunk_size(4)
+            .with_parallelism(1)
+            .finalize_to_writer(&mut data)
+            .await
+            .unwrap();
+
+        assert_eq!(data, BLOB);
+        let requests = transport.requests();
+        let ranges: Vec<_> = requests.iter().map(|r| &r.headers()["range"]).collect();
+        assert_eq!(ranges, vec!["bytes=0-3", "bytes=4-7", "bytes=8-9"]);
+        assert!(requests[0].headers().get("if-match").is_none());
+        assert_eq!(requests[1].headers()["if-match"], ETAG);
+        assert_eq!(requests[2].headers()["if-match"], ETAG);
+    }
+
+    #[tokio::test]
+    async fn fetches_again_a_range_not_matching_its_md5() {
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            range(0, 4),
+            range_with_body(4, 8, b"4x67".to_vec()),
+            range(4, 8),
+            range(8, 10),
+        ]));
+        let client = scripted_client(&transport);
+        let mut data = Vec::new();
+        DownloadBlobBuilder::new(&client)
+            .with_container_name("container")
+            .with_blob_name("blob")
+            .with_chunk_size(4)
+            .with_parallelism(1)
+            .with_max_retries(1)
+            .finalize_to_writer(&mut data)
+            .await
+            .unwrap();
+
+        assert_eq!(data, BLOB);
+        assert_eq!(transport.requests()[2].headers()["range"], "bytes=4-7");
+        assert_eq!(transport.remaining(), 0);
+    }
+
+    #[tokio::test]
+    async fn fails_on_a_range_not_matching_its_md5() {
+        let transport = Arc::new(ScriptedTransport::new(vec![range_with_body(
+            0,
+            4,
+            b"0x23".to_vec(),
+        )]));
+        let client = scripted_client(&transport);
+        let err = DownloadBlobBuilder::new(&client)
+            .with_container_name("container")
+            .with_blob_name("blob")
+            .with_chunk_size(4)
+            .with_max_retries(0)
+            .finalize_to_writer(Vec::new())
+            .await
+            .unwrap_err();
+
+        match err {
+            AzureError::ContentMD5Mismatch(expected, computed) => {
+                assert_eq!(expected, base64::encode(&md5::compute(&BLOB[..4])[..]));
+                assert_eq!(computed, base64::encode(&md5::compute(b"0x23")[..]));
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn gets_an_empty_blob_without_a_range() {
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            ScriptedResponse::new(StatusCode::RANGE_NOT_SATISFIABLE, ""),
+            blob_response(StatusCode::OK, Vec::new()),
+        ]));
+        let client = scripted_client(&transport);
+        let mut data = Vec::new();
+        let response = DownloadBlobBuilder::new(&client)
+            .with_container_name("container")
+            .with_blob_name("blob")
+            .finalize_to_writer(&mut data)
+            .await
+            .unwrap();
+
+        assert!(data.is_empty());
+        assert_eq!(response.range, Range::new(0, 0));
+        assert_eq!(response.blob.content_length, 0);
+        assert!(transport.requests()[1].headers().get("range").is_none());
+    }
+}
@@ -3,6 +3,7 @@ use crate::blob::blob::{generate_blob_uri, Blob};
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_core::headers::RANGE_GET_CONTENT_MD5;
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::range::Range;
@@ -25,8 +26,10 @@ where
     container_name: Option<&'a str>,
     blob_name: Option<&'a str>,
     snapshot: Option<DateTime<Utc>>,
+    version_id: Option<&'a str>,
     timeout: Option<u64>,
     range: Option<&'a Range>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
     lease_id: Option<&'a LeaseId>,
     client_request_id: Option<&'a str>,
 }
@@ -44,8 +47,10 @@ where
             p_blob_name: PhantomData {},
             blob_name: None,
             snapshot: None,
+            version_id: None,
             timeout: None,
             range: None,
+            if_match_condition: None,
             lease_id: None,
             client_request_id: None,
         }
@@ -103,6 +108,19 @@ where
     }
 }
 
+impl<'a, C, ContainerNameSet, BlobNameSet> VersionIdOption<'a>
+    for GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn version_id(&self) -> Option<&'a str> {
+        self.version_id
+    }
+}
+
 impl<'a, C, ContainerNameSet, BlobNameSet> TimeoutOption
     for GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
 where
@@ -129,6 +147,19 @@ where
     }
 }
 
+impl<'a, C, ContainerNameSet, BlobNameSet> IfMatchConditionOption<'a>
+    for GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
 impl<'a, C, ContainerNameSet, BlobNameSet> LeaseIdOption<'a>
     for GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
 where
@@ -171,8 +202,10 @@ where
             container_name: Some(container_name),
             blob_name: self.blob_name,
             snapshot: self.snapshot,
+            version_id: self.version_id,
             timeout: self.timeout,
             range: self.range,
+            if_match_condition: self.if_match_condition,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -195,8 +228,10 @@ where
             container_name: self.container_name,
             blob_name: Some(blob_name),
             snapshot: self.snapshot,
+            version_id: self.version_id,
             timeout: self.timeout,
             range: self.range,
+            if_match_condition: self.if_match_condition,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -221,8 +256,38 @@ where
             container_name: self.container_name,
             blob_name: self.blob_name,
             snapshot: Some(snapshot),
+            version_id: self.version_id,
             timeout: self.timeout,
             range: self.range,
+            if_match_condition: self.if_match_condition,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> VersionIdSupport<'a>
+    for GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_version_id(self, version_id: &'a str) -> Self::O {
+        GetBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            version_id: Some(version_id),
+            timeout: self.timeout,
+            range: self.range,
+            if_match_condition: self.if_match_condition,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -247,8 +312,10 @@ where
             container_name: self.container_name,
             blob_name: self.blob_name,
             snapshot: self.snapshot,
+            version_id: self.version_id,
             timeout: Some(timeout),
             range: self.range,
+            if_match_condition: self.if_match_condition,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -273,8 +340,38 @@ where
             container_name: self.container_name,
             blob_name: self.blob_name,
             snapshot: self.snapshot,
+            version_id: self.version_id,
             timeout: self.timeout,
             range: Some(range),
+            if_match_condition: self.if_match_condition,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> IfMatchConditionSupport<'a>
+    for GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        GetBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            version_id: self.version_id,
+            timeout: self.timeout,
+            range: self.range,
+            if_match_condition: Some(if_match_condition),
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -299,8 +396,10 @@ where
             container_name: self.container_name,
             blob_name: self.blob_name,
             snapshot: self.snapshot,
+            version_id: self.version_id,
             timeout: self.timeout,
             range: self.range,
+            if_match_condition: self.if_match_condition,
             lease_id: Some(lease_id),
             client_request_id: self.client_request_id,
         }
@@ -325,8 +424,10 @@ where
             container_name: self.container_name,
             blob_name: self.blob_name,
             snapshot: self.snapshot,
+            version_id: self.version_id,
             timeout: self.timeout,
             range: self.range,
+            if_match_condition: self.if_match_condition,
             lease_id: self.lease_id,
             client_request_id: Some(client_request_id),
         }
@@ -339,50 +440,59 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<GetBlobResponse, AzureError> {
-        let container_name = self.container_name().to_owned();
-        let blob_name = self.blob_name().to_owned();
-        let snapshot_time = self.snapshot();
-
-        let mut uri =
-            generate_blob_uri(self.client(), self.container_name(), self.blob_name(), None);
-
-        let mut f_first = true;
-        if let Some(snapshot) = SnapshotOption::to_uri_parameter(&self) {
-            uri = format!("{}?{}", uri, snapshot);
-            f_first = false;
-        }
-        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}{}{}", uri, if f_first { "?" } else { "&" }, timeout);
-        }
-
-        trace!("uri == {:?}", uri);
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::GET,
-            &|mut request| {
-                if let Some(r) = self.range() {
-                    request = LeaseIdOption::add_header(&self, request);
-                    request = RangeOption::add_header(&self, request);
-
-                    if r.len() <= 4 * 1024 * 1024 {
-                        request = request.header_static(RANGE_GET_CONTENT_MD5, "true");
+        instrument("GetBlobBuilder::finalize", async move {
+            let container_name = self.container_name().to_owned();
+            let blob_name = self.blob_name().to_owned();
+            let snapshot_time = self.snapshot();
+
+            let mut uri =
+                generate_blob_uri(self.client(), self.container_name(), self.blob_name(), None);
+
+            let mut f_first = true;
+            if let Some(snapshot) = SnapshotOption::to_uri_parameter(&self) {
+                uri = format!("{}?{}", uri, snapshot);
+                f_first = false;
+            }
+            if let Some(version_id) = VersionIdOption::to_uri_parameter(&self) {
+                uri = format!("{}{}{}", uri, if f_first { "?" } else { "&" }, version_id);
+                f_first = false;
+            }
+            if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}{}{}", uri, if f_first { "?" } else { "&" }, timeout);
+            }
+
+            trace!("uri == {:?}", uri);
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::GET,
+                &|mut request| {
+                    if let Some(r) = self.range() {
+                        request = LeaseIdOption::add_header(&self, request);
+                        request = RangeOption::add_header(&self, request);
+
+                        if r.len() <= 4 * 1024 * 1024 {
+                            request = request.header_static(RANGE_GET_CONTENT_MD5, "true");
+                        }
                     }
-                }
-                request
-            },
-            None,
-        )?;
-
-        let expected_status_code = if self.range().is_some() {
-            StatusCode::PARTIAL_CONTENT
-        } else {
-            StatusCode::OK
-        };
-
-        let (headers, body) =
-            check_status_extract_headers_and_body(future_response, expected_status_code).await?;
-        let blob = Blob::from_headers(&blob_name, &container_name, snapshot_time, &headers)?;
-        GetBlobResponse::from_response(&headers, blob, &body)
+                    request = IfMatchConditionOption::add_header(&self, request);
+                    request
+                },
+                None,
+            )?;
+
+            let expected_status_code = if self.range().is_some() {
+                StatusCode::PARTIAL_CONTENT
+            } else {
+                StatusCode::OK
+            };
+
+            let (headers, body) =
+                check_status_extract_headers_and_body(future_response, expected_status_code)
+                    .await?;
+            let blob = Blob::from_headers(&blob_name, &container_name, snapshot_time, &headers)?;
+            GetBlobResponse::from_response(&headers, blob, &body)
+        })
+        .await
     }
 }
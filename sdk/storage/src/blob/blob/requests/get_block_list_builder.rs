@@ -4,6 +4,7 @@ use crate::blob::blob::{BlockListType, BlockListTypeRequired, BlockListTypeSuppo
 use crate::core::client::Client;
 use crate::core::ClientRequired;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -325,33 +326,36 @@ where
 {
     #[inline]
     pub async fn finalize(self) -> Result<GetBlockListResponse, AzureError> {
-        let mut uri = generate_blob_uri(
-            self.client,
-            self.container_name(),
-            self.blob_name(),
-            Some("comp=blocklist"),
-        );
+        instrument("GetBlockListBuilder::finalize", async move {
+            let mut uri = generate_blob_uri(
+                self.client,
+                self.container_name(),
+                self.blob_name(),
+                Some("comp=blocklist"),
+            );
 
-        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, timeout);
-        }
-        uri = format!("{}&{}", uri, BlockListTypeRequired::to_uri_parameter(&self));
+            if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, timeout);
+            }
+            uri = format!("{}&{}", uri, BlockListTypeRequired::to_uri_parameter(&self));
 
-        trace!("uri == {:?}", uri);
+            trace!("uri == {:?}", uri);
 
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::GET,
-            &|mut request| {
-                request = LeaseIdOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            None,
-        )?;
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::GET,
+                &|mut request| {
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                None,
+            )?;
 
-        let (headers, body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
-        GetBlockListResponse::from_response(&headers, &body)
+            let (headers, body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+            GetBlockListResponse::from_response(&headers, &body)
+        })
+        .await
     }
 }
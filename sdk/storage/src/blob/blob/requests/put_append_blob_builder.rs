@@ -3,6 +3,7 @@ use crate::blob::blob::responses::PutBlobResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_core::headers::BLOB_TYPE;
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::modify_conditions::IfMatchCondition;
 use azure_core::prelude::*;
@@ -606,36 +607,39 @@ where
 {
     #[inline]
     pub async fn finalize(self) -> Result<PutBlobResponse, AzureError> {
-        let mut uri =
-            generate_blob_uri(self.client(), self.container_name(), self.blob_name(), None);
-
-        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}?{}", uri, timeout);
-        }
-
-        trace!("uri == {:?}", uri);
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = ContentTypeOption::add_header(&self, request);
-                request = ContentEncodingOption::add_header(&self, request);
-                request = ContentLanguageOption::add_header(&self, request);
-                request = CacheControlOption::add_header(&self, request);
-                request = ContentDispositionOption::add_header(&self, request);
-                request = MetadataOption::add_header(&self, request);
-                request = request.header(BLOB_TYPE, "AppendBlob");
-                request = LeaseIdOption::add_header(&self, request);
-                request = IfMatchConditionOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            None,
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
-        PutBlobResponse::from_headers(&headers)
+        instrument("PutAppendBlobBuilder::finalize", async move {
+            let mut uri =
+                generate_blob_uri(self.client(), self.container_name(), self.blob_name(), None);
+
+            if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}?{}", uri, timeout);
+            }
+
+            trace!("uri == {:?}", uri);
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = ContentTypeOption::add_header(&self, request);
+                    request = ContentEncodingOption::add_header(&self, request);
+                    request = ContentLanguageOption::add_header(&self, request);
+                    request = CacheControlOption::add_header(&self, request);
+                    request = ContentDispositionOption::add_header(&self, request);
+                    request = MetadataOption::add_header(&self, request);
+                    request = request.header(BLOB_TYPE, "AppendBlob");
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = IfMatchConditionOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                None,
+            )?;
+
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+            PutBlobResponse::from_headers(&headers)
+        })
+        .await
     }
 }
@@ -2,6 +2,7 @@ use crate::blob::blob::generate_blob_uri;
 use crate::blob::blob::responses::PutBlockResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::modify_conditions::IfMatchCondition;
 use azure_core::prelude::*;
@@ -470,35 +471,38 @@ where
 {
     #[inline]
     pub async fn finalize(self) -> Result<PutBlockResponse, AzureError> {
-        let mut uri = generate_blob_uri(
-            self.client(),
-            self.container_name(),
-            self.blob_name(),
-            Some("comp=appendblock"),
-        );
-
-        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, timeout);
-        }
-
-        trace!("uri == {:?}", uri);
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = ContentMD5Option::add_header(&self, request);
-                request = LeaseIdOption::add_header(&self, request);
-                request = IfMatchConditionOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request = AppendPositionOption::add_header(&self, request);
-                request
-            },
-            Some(self.body()),
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
-        PutBlockResponse::from_headers(&headers)
+        instrument("PutAppendBlockBuilder::finalize", async move {
+            let mut uri = generate_blob_uri(
+                self.client(),
+                self.container_name(),
+                self.blob_name(),
+                Some("comp=appendblock"),
+            );
+
+            if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, timeout);
+            }
+
+            trace!("uri == {:?}", uri);
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = ContentMD5Option::add_header(&self, request);
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = IfMatchConditionOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request = AppendPositionOption::add_header(&self, request);
+                    request
+                },
+                Some(self.body()),
+            )?;
+
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+            PutBlockResponse::from_headers(&headers)
+        })
+        .await
     }
 }
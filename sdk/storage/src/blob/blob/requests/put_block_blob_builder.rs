@@ -4,6 +4,7 @@ use crate::core::client::Client;
 use crate::core::ClientRequired;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_core::headers::BLOB_TYPE;
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::modify_conditions::IfMatchCondition;
 use azure_core::prelude::*;
@@ -35,6 +36,7 @@ where
     content_md5: Option<&'a [u8]>,
     content_disposition: Option<&'a str>,
     metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    access_tier: Option<AccessTier>,
     lease_id: Option<&'a LeaseId>,
     if_match_condition: Option<IfMatchCondition<'a>>,
     client_request_id: Option<&'a str>,
@@ -62,6 +64,7 @@ where
             content_md5: None,
             content_disposition: None,
             metadata: None,
+            access_tier: None,
             lease_id: None,
             if_match_condition: None,
             client_request_id: None,
@@ -237,6 +240,20 @@ where
     }
 }
 
+impl<'a, C, ContainerNameSet, BlobNameSet, BodySet> AccessTierOption
+    for PutBlockBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn access_tier(&self) -> Option<AccessTier> {
+        self.access_tier
+    }
+}
+
 impl<'a, C, ContainerNameSet, BlobNameSet, BodySet> LeaseIdOption<'a>
     for PutBlockBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, BodySet>
 where
@@ -306,6 +323,7 @@ where
             content_md5: self.content_md5,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
@@ -340,6 +358,7 @@ where
             content_md5: self.content_md5,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
@@ -374,6 +393,7 @@ where
             content_md5: self.content_md5,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
@@ -409,6 +429,7 @@ where
             content_md5: self.content_md5,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
@@ -444,6 +465,7 @@ where
             content_md5: self.content_md5,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
@@ -479,6 +501,7 @@ where
             content_md5: self.content_md5,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
@@ -514,6 +537,7 @@ where
             content_md5: self.content_md5,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
@@ -549,6 +573,7 @@ where
             content_md5: self.content_md5,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
@@ -584,6 +609,7 @@ where
             content_md5: Some(content_md5),
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
@@ -619,6 +645,7 @@ where
             content_md5: self.content_md5,
             content_disposition: Some(content_disposition),
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
@@ -654,6 +681,43 @@ where
             content_md5: self.content_md5,
             content_disposition: self.content_disposition,
             metadata: Some(metadata),
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, BodySet> AccessTierSupport
+    for PutBlockBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = PutBlockBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, BodySet>;
+
+    #[inline]
+    fn with_access_tier(self, access_tier: AccessTier) -> Self::O {
+        PutBlockBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_body: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            body: self.body,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_md5: self.content_md5,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: Some(access_tier),
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
@@ -689,6 +753,7 @@ where
             content_md5: self.content_md5,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: Some(lease_id),
             if_match_condition: self.if_match_condition,
             client_request_id: self.client_request_id,
@@ -724,6 +789,7 @@ where
             content_md5: self.content_md5,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             if_match_condition: Some(if_match_condition),
             client_request_id: self.client_request_id,
@@ -759,6 +825,7 @@ where
             content_md5: self.content_md5,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             if_match_condition: self.if_match_condition,
             client_request_id: Some(client_request_id),
@@ -773,37 +840,41 @@ where
 {
     #[inline]
     pub async fn finalize(self) -> Result<PutBlockBlobResponse, AzureError> {
-        let mut uri =
-            generate_blob_uri(self.client(), self.container_name(), self.blob_name(), None);
-
-        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}?{}", uri, timeout);
-        }
-
-        trace!("uri == {:?}", uri);
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = ContentTypeOption::add_header(&self, request);
-                request = ContentEncodingOption::add_header(&self, request);
-                request = ContentLanguageOption::add_header(&self, request);
-                request = ContentMD5Option::add_header(&self, request);
-                request = CacheControlOption::add_header(&self, request);
-                request = ContentDispositionOption::add_header(&self, request);
-                request = MetadataOption::add_header(&self, request);
-                request = request.header(BLOB_TYPE, "BlockBlob");
-                request = LeaseIdOption::add_header(&self, request);
-                request = IfMatchConditionOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            Some(self.body()),
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
-        PutBlockBlobResponse::from_headers(&headers)
+        instrument("PutBlockBlobBuilder::finalize", async move {
+            let mut uri =
+                generate_blob_uri(self.client(), self.container_name(), self.blob_name(), None);
+
+            if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}?{}", uri, timeout);
+            }
+
+            trace!("uri == {:?}", uri);
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = ContentTypeOption::add_header(&self, request);
+                    request = ContentEncodingOption::add_header(&self, request);
+                    request = ContentLanguageOption::add_header(&self, request);
+                    request = ContentMD5Option::add_header(&self, request);
+                    request = CacheControlOption::add_header(&self, request);
+                    request = ContentDispositionOption::add_header(&self, request);
+                    request = MetadataOption::add_header(&self, request);
+                    request = AccessTierOption::add_header(&self, request);
+                    request = request.header(BLOB_TYPE, "BlockBlob");
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = IfMatchConditionOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                Some(self.body()),
+            )?;
+
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+            PutBlockBlobResponse::from_headers(&headers)
+        })
+        .await
     }
 }
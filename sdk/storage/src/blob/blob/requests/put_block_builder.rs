@@ -2,6 +2,7 @@ use crate::blob::blob::generate_blob_uri;
 use crate::blob::blob::responses::PutBlockResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -28,6 +29,7 @@ where
     block_id: Option<&'a [u8]>,
     timeout: Option<u64>,
     content_md5: Option<&'a [u8]>,
+    content_crc64: Option<&'a [u8; 8]>,
     lease_id: Option<&'a LeaseId>,
     client_request_id: Option<&'a str>,
 }
@@ -50,6 +52,7 @@ where
             block_id: None,
             timeout: None,
             content_md5: None,
+            content_crc64: None,
             lease_id: None,
             client_request_id: None,
         }
@@ -160,6 +163,21 @@ where
     }
 }
 
+impl<'a, C, ContainerNameSet, BlobNameSet, BodySet, BlockIdSet> ContentCRC64Option<'a>
+    for PutBlockBuilder<'a, C, ContainerNameSet, BlobNameSet, BodySet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_crc64(&self) -> Option<&'a [u8; 8]> {
+        self.content_crc64
+    }
+}
+
 impl<'a, C, ContainerNameSet, BlobNameSet, BodySet, BlockIdSet> LeaseIdOption<'a>
     for PutBlockBuilder<'a, C, ContainerNameSet, BlobNameSet, BodySet, BlockIdSet>
 where
@@ -214,6 +232,7 @@ where
             block_id: self.block_id,
             timeout: self.timeout,
             content_md5: self.content_md5,
+            content_crc64: self.content_crc64,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -244,6 +263,7 @@ where
             block_id: self.block_id,
             timeout: self.timeout,
             content_md5: self.content_md5,
+            content_crc64: self.content_crc64,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -274,6 +294,7 @@ where
             block_id: self.block_id,
             timeout: self.timeout,
             content_md5: self.content_md5,
+            content_crc64: self.content_crc64,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -304,6 +325,7 @@ where
             block_id: Some(block_id),
             timeout: self.timeout,
             content_md5: self.content_md5,
+            content_crc64: self.content_crc64,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -335,6 +357,7 @@ where
             block_id: self.block_id,
             timeout: Some(timeout),
             content_md5: self.content_md5,
+            content_crc64: self.content_crc64,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -366,6 +389,39 @@ where
             block_id: self.block_id,
             timeout: self.timeout,
             content_md5: Some(content_md5),
+            content_crc64: self.content_crc64,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, BodySet, BlockIdSet> ContentCRC64Support<'a>
+    for PutBlockBuilder<'a, C, ContainerNameSet, BlobNameSet, BodySet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    type O = PutBlockBuilder<'a, C, ContainerNameSet, BlobNameSet, BodySet, BlockIdSet>;
+
+    #[inline]
+    fn with_content_crc64(self, content_crc64: &'a [u8; 8]) -> Self::O {
+        PutBlockBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_body: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            body: self.body,
+            block_id: self.block_id,
+            timeout: self.timeout,
+            content_md5: self.content_md5,
+            content_crc64: Some(content_crc64),
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -397,6 +453,7 @@ where
             block_id: self.block_id,
             timeout: self.timeout,
             content_md5: self.content_md5,
+            content_crc64: self.content_crc64,
             lease_id: Some(lease_id),
             client_request_id: self.client_request_id,
         }
@@ -428,6 +485,7 @@ where
             block_id: self.block_id,
             timeout: self.timeout,
             content_md5: self.content_md5,
+            content_crc64: self.content_crc64,
             lease_id: self.lease_id,
             client_request_id: Some(client_request_id),
         }
@@ -441,34 +499,38 @@ where
 {
     #[inline]
     pub async fn finalize(self) -> Result<PutBlockResponse, AzureError> {
-        let mut uri = generate_blob_uri(
-            self.client(),
-            self.container_name(),
-            self.blob_name(),
-            Some("comp=block"),
-        );
-
-        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, timeout);
-        }
-        uri = format!("{}&{}", uri, BlockIdRequired::to_uri_parameter(&self));
-
-        trace!("uri == {:?}", uri);
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = ContentMD5Option::add_header(&self, request);
-                request = LeaseIdOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            Some(self.body()),
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
-        PutBlockResponse::from_headers(&headers)
+        instrument("PutBlockBuilder::finalize", async move {
+            let mut uri = generate_blob_uri(
+                self.client(),
+                self.container_name(),
+                self.blob_name(),
+                Some("comp=block"),
+            );
+
+            if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, timeout);
+            }
+            uri = format!("{}&{}", uri, BlockIdRequired::to_uri_parameter(&self));
+
+            trace!("uri == {:?}", uri);
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = ContentMD5Option::add_header(&self, request);
+                    request = ContentCRC64Option::add_header(&self, request);
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                Some(self.body()),
+            )?;
+
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+            PutBlockResponse::from_headers(&headers)
+        })
+        .await
     }
 }
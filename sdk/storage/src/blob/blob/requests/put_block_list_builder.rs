@@ -5,6 +5,7 @@ use crate::blob::blob::{BlockListRequired, BlockListSupport};
 use crate::core::prelude::*;
 use azure_core::add_content_md5_header;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -36,6 +37,7 @@ where
     cache_control: Option<&'a str>,
     content_disposition: Option<&'a str>,
     metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    access_tier: Option<AccessTier>,
     lease_id: Option<&'a LeaseId>,
     client_request_id: Option<&'a str>,
 }
@@ -62,6 +64,7 @@ where
             cache_control: None,
             content_disposition: None,
             metadata: None,
+            access_tier: None,
             lease_id: None,
             client_request_id: None,
         }
@@ -233,6 +236,21 @@ where
     }
 }
 
+impl<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet> AccessTierOption
+    for PutBlockListBuilder<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BlockListSet: ToAssign,
+    C: Client,
+    T: Borrow<[u8]> + 'a,
+{
+    #[inline]
+    fn access_tier(&self) -> Option<AccessTier> {
+        self.access_tier
+    }
+}
+
 impl<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet> LeaseIdOption<'a>
     for PutBlockListBuilder<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet>
 where
@@ -290,6 +308,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -323,6 +342,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -356,6 +376,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -390,6 +411,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -424,6 +446,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -458,6 +481,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -492,6 +516,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -526,6 +551,7 @@ where
             cache_control: Some(cache_control),
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -560,6 +586,7 @@ where
             cache_control: self.cache_control,
             content_disposition: Some(content_disposition),
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -594,6 +621,42 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: Some(metadata),
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet> AccessTierSupport
+    for PutBlockListBuilder<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BlockListSet: ToAssign,
+    C: Client,
+    T: Borrow<[u8]> + 'a,
+{
+    type O = PutBlockListBuilder<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet>;
+
+    #[inline]
+    fn with_access_tier(self, access_tier: AccessTier) -> Self::O {
+        PutBlockListBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_block_list: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_list: self.block_list,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: Some(access_tier),
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -628,6 +691,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: Some(lease_id),
             client_request_id: self.client_request_id,
         }
@@ -662,6 +726,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            access_tier: self.access_tier,
             lease_id: self.lease_id,
             client_request_id: Some(client_request_id),
         }
@@ -676,51 +741,55 @@ where
 {
     #[inline]
     pub async fn finalize(self) -> Result<PutBlockListResponse, AzureError> {
-        let mut uri = generate_blob_uri(
-            self.client(),
-            self.container_name(),
-            self.blob_name(),
-            Some("comp=blocklist"),
-        );
-
-        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, timeout);
-        }
-
-        trace!("uri == {:?}", uri);
-
-        let body = BlockListRequired::to_string(&self);
-        debug!("PutBlockListRequired::to_string == {}", body);
-        let body_bytes = body.as_bytes();
-
-        // calculate the xml MD5. This can be made optional
-        // if needed, but i think it's best to calculate it.
-        let md5 = {
-            let hash = md5::compute(body_bytes);
-            debug!("md5 hash: {:02X}", hash);
-            hash
-        };
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = ContentTypeOption::add_header(&self, request);
-                request = ContentEncodingOption::add_header(&self, request);
-                request = ContentLanguageOption::add_header(&self, request);
-                request = add_content_md5_header(&md5[..], request);
-                request = CacheControlOption::add_header(&self, request);
-                request = ContentDispositionOption::add_header(&self, request);
-                request = MetadataOption::add_header(&self, request);
-                request = LeaseIdOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            Some(body_bytes),
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
-        PutBlockListResponse::from_headers(&headers)
+        instrument("PutBlockListBuilder::finalize", async move {
+            let mut uri = generate_blob_uri(
+                self.client(),
+                self.container_name(),
+                self.blob_name(),
+                Some("comp=blocklist"),
+            );
+
+            if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, timeout);
+            }
+
+            trace!("uri == {:?}", uri);
+
+            let body = BlockListRequired::to_string(&self);
+            debug!("PutBlockListRequired::to_string == {}", body);
+            let body_bytes = body.as_bytes();
+
+            // calculate the xml MD5. This can be made optional
+            // if needed, but i think it's best to calculate it.
+            let md5 = {
+                let hash = md5::compute(body_bytes);
+                debug!("md5 hash: {:02X}", hash);
+                hash
+            };
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = ContentTypeOption::add_header(&self, request);
+                    request = ContentEncodingOption::add_header(&self, request);
+                    request = ContentLanguageOption::add_header(&self, request);
+                    request = add_content_md5_header(&md5[..], request);
+                    request = CacheControlOption::add_header(&self, request);
+                    request = ContentDispositionOption::add_header(&self, request);
+                    request = MetadataOption::add_header(&self, request);
+                    request = AccessTierOption::add_header(&self, request);
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                Some(body_bytes),
+            )?;
+
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+            PutBlockListResponse::from_headers(&headers)
+        })
+        .await
     }
 }
@@ -0,0 +1,627 @@
+use crate::blob::blob::requests::{GetBlobPropertiesBuilder, SetBlobTierBuilder};
+use crate::blob::blob::responses::GetBlobPropertiesResponse;
+use crate::blob::blob::{is_transient, retry_delay};
+use crate::blob::blob::{
+    MaxRetriesOption, MaxRetriesSupport, PollIntervalOption, PollIntervalSupport,
+};
+use crate::core::prelude::*;
+use crate::{RehydratePriority, RehydratePriorityOption, RehydratePrioritySupport};
+use azure_core::errors::AzureError;
+use azure_core::instrumentation::instrument;
+use azure_core::lease::LeaseId;
+use azure_core::prelude::*;
+use azure_core::{No, ToAssign, Yes};
+use std::marker::PhantomData;
+use std::time::Duration;
+use tokio::time::delay_for;
+
+#[derive(Debug, Clone)]
+pub struct RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_access_tier: PhantomData<AccessTierSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    access_tier: Option<AccessTier>,
+    rehydrate_priority: Option<RehydratePriority>,
+    poll_interval: Duration,
+    max_retries: u32,
+    lease_id: Option<&'a LeaseId>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> RehydrateBlobBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> RehydrateBlobBuilder<'a, C, No, No, No> {
+        RehydrateBlobBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_access_tier: PhantomData {},
+            access_tier: None,
+            rehydrate_priority: None,
+            poll_interval: Duration::from_secs(60),
+            max_retries: 3,
+            lease_id: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> ClientRequired<'a, C>
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet, AccessTierSet> ContainerNameRequired<'a>
+    for RehydrateBlobBuilder<'a, C, Yes, BlobNameSet, AccessTierSet>
+where
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, AccessTierSet> BlobNameRequired<'a>
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, Yes, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> AccessTierRequired
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn access_tier(&self) -> AccessTier {
+        self.access_tier.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> RehydratePriorityOption
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn rehydrate_priority(&self) -> Option<RehydratePriority> {
+        self.rehydrate_priority
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> PollIntervalOption
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> MaxRetriesOption
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> LeaseIdOption<'a>
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> ClientRequestIdOption<'a>
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, BlobNameSet, AccessTierSet> ContainerNameSupport<'a>
+    for RehydrateBlobBuilder<'a, C, No, BlobNameSet, AccessTierSet>
+where
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = RehydrateBlobBuilder<'a, C, Yes, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        RehydrateBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            poll_interval: self.poll_interval,
+            max_retries: self.max_retries,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, AccessTierSet> BlobNameSupport<'a>
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, No, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = RehydrateBlobBuilder<'a, C, ContainerNameSet, Yes, AccessTierSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        RehydrateBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            poll_interval: self.poll_interval,
+            max_retries: self.max_retries,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> AccessTierSupport
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, No>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_access_tier(self, access_tier: AccessTier) -> Self::O {
+        RehydrateBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: Some(access_tier),
+            rehydrate_priority: self.rehydrate_priority,
+            poll_interval: self.poll_interval,
+            max_retries: self.max_retries,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> RehydratePrioritySupport
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_rehydrate_priority(self, rehydrate_priority: RehydratePriority) -> Self::O {
+        RehydrateBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: Some(rehydrate_priority),
+            poll_interval: self.poll_interval,
+            max_retries: self.max_retries,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> PollIntervalSupport
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_poll_interval(self, poll_interval: Duration) -> Self::O {
+        RehydrateBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            poll_interval,
+            max_retries: self.max_retries,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> MaxRetriesSupport
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_max_retries(self, max_retries: u32) -> Self::O {
+        RehydrateBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            poll_interval: self.poll_interval,
+            max_retries,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> LeaseIdSupport<'a>
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        RehydrateBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            poll_interval: self.poll_interval,
+            max_retries: self.max_retries,
+            lease_id: Some(lease_id),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> ClientRequestIdSupport<'a>
+    for RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = RehydrateBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        RehydrateBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            poll_interval: self.poll_interval,
+            max_retries: self.max_retries,
+            lease_id: self.lease_id,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> RehydrateBlobBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    /// Moves the blob to the hot or cool tier and waits, polling the
+    /// blob properties every `poll_interval`, until the blob is readable.
+    /// A poll whose connection breaks off is sent again up to `max_retries`
+    /// times in a row, waiting twice as long every time (up to a minute).
+    /// Rehydrating from the archive tier can take hours: wrap the future
+    /// in a timeout to bound the wait.
+    pub async fn finalize(self) -> Result<GetBlobPropertiesResponse, AzureError> {
+        instrument("RehydrateBlobBuilder::finalize", async move {
+            if self.access_tier() == AccessTier::Archive {
+                return Err(AzureError::InputParametersError(
+                    "a blob can only be rehydrated to the hot or the cool tier".to_owned(),
+                ));
+            }
+
+            let response = self.set_blob_tier().finalize().await?;
+            debug!("rehydrate_pending == {}", response.rehydrate_pending);
+
+            let mut retry = 0;
+            loop {
+                match self.get_blob_properties().finalize().await {
+                    Ok(properties) if properties.blob.archive_status.is_none() => {
+                        return Ok(properties)
+                    }
+                    Ok(properties) => {
+                        debug!("archive_status == {:?}", properties.blob.archive_status);
+                        retry = 0;
+                        delay_for(self.poll_interval).await;
+                    }
+                    Err(err) if retry < self.max_retries && is_transient(&err) => {
+                        warn!("polling failed, retrying: {}", err);
+                        delay_for(retry_delay(retry)).await;
+                        retry += 1;
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+        })
+        .await
+    }
+
+    fn set_blob_tier(&self) -> SetBlobTierBuilder<'a, C, Yes, Yes, Yes> {
+        let mut request = SetBlobTierBuilder::new(self.client())
+            .with_container_name(self.container_name())
+            .with_blob_name(self.blob_name())
+            .with_access_tier(self.access_tier());
+        if let Some(rehydrate_priority) = self.rehydrate_priority {
+            request = request.with_rehydrate_priority(rehydrate_priority);
+        }
+        if let Some(lease_id) = self.lease_id {
+            request = request.with_lease_id(lease_id);
+        }
+        if let Some(client_request_id) = self.client_request_id {
+            request = request.with_client_request_id(client_request_id);
+        }
+        request
+    }
+
+    fn get_blob_properties(&self) -> GetBlobPropertiesBuilder<'a, C, Yes, Yes> {
+        let mut request = GetBlobPropertiesBuilder::new(self.client())
+            .with_container_name(self.container_name())
+            .with_blob_name(self.blob_name());
+        if let Some(lease_id) = self.lease_id {
+            request = request.with_lease_id(lease_id);
+        }
+        if let Some(client_request_id) = self.client_request_id {
+            request = request.with_client_request_id(client_request_id);
+        }
+        request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::with_access_key;
+    use azure_core::mock_transport::{ScriptedResponse, ScriptedTransport};
+    use azure_core::pipeline::Pipeline;
+    use azure_core::policies::{Policy, PolicyResult, RetryMode, RetryOptions};
+    use azure_core::{Context, Request, Response};
+    use hyper::StatusCode;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Answers the scripted responses, then fails every request as if the
+    /// connection broke off.
+    #[derive(Debug)]
+    struct Disconnecting {
+        transport: ScriptedTransport,
+        failures: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for Disconnecting {
+        async fn send(
+            &self,
+            ctx: &mut Context,
+            request: &mut Request,
+            next: &[Arc<dyn Policy>],
+        ) -> PolicyResult<Response> {
+            if self.transport.remaining() > 0 {
+                return self.transport.send(ctx, request, next).await;
+            }
+            self.failures.fetch_add(1, Ordering::SeqCst);
+            Err(AzureError::IOError(std::io::Error::new(
+                std::io::ErrorKind::ConnectionReset,
+                "connection reset",
+            )))
+        }
+    }
+
+    fn properties(archive_status: Option<&str>) -> ScriptedResponse {
+        let response = ScriptedResponse::storage(StatusCode::OK, "")
+            .with_header("content-length", "10")
+            .with_header("etag", "\"0x8D880CC3A4D6F2B\"")
+            .with_header("last-modified", "Wed, 04 Nov 2020 14:56:02 GMT")
+            .with_header("x-ms-creation-time", "Wed, 04 Nov 2020 14:56:02 GMT")
+            .with_header("x-ms-blob-type", "BlockBlob")
+            .with_header("x-ms-lease-status", "unlocked")
+            .with_header("x-ms-lease-state", "available")
+            .with_header("x-ms-server-encrypted", "true");
+        match archive_status {
+            Some(archive_status) => response
+                .with_header("x-ms-access-tier", "Archive")
+                .with_header("x-ms-archive-status", archive_status),
+            None => response.with_header("x-ms-access-tier", "Hot"),
+        }
+    }
+
+    #[tokio::test]
+    async fn polls_until_the_blob_is_rehydrated() {
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            ScriptedResponse::storage(StatusCode::ACCEPTED, ""),
+            properties(Some("rehydrate-pending-to-hot")),
+            properties(Some("rehydrate-pending-to-hot")),
+            properties(None),
+        ]));
+        let client = with_access_key("account", &base64::encode("key"))
+            .with_pipeline(ScriptedTransport::pipeline(transport.clone()));
+        let properties = RehydrateBlobBuilder::new(&client)
+            .with_container_name("container")
+            .with_blob_name("blob")
+            .with_access_tier(AccessTier::Hot)
+            .with_poll_interval(Duration::from_millis(1))
+            .finalize()
+            .await
+            .unwrap();
+
+        assert_eq!(properties.blob.access_tier.as_deref(), Some("Hot"));
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].headers()["x-ms-access-tier"], "Hot");
+        assert!(requests[1..]
+            .iter()
+            .all(|request| request.method() == hyper::Method::HEAD));
+    }
+
+    #[tokio::test]
+    async fn gives_up_polling_after_max_retries() {
+        let transport = Arc::new(Disconnecting {
+            transport: ScriptedTransport::new(vec![ScriptedResponse::storage(
+                StatusCode::ACCEPTED,
+                "",
+            )]),
+            failures: AtomicUsize::new(0),
+        });
+        let client =
+            with_access_key("account", &base64::encode("key")).with_pipeline(Pipeline::new(
+                "test",
+                "0",
+                Vec::new(),
+                RetryOptions::default().with_mode(RetryMode::None),
+                Vec::new(),
+                transport.clone(),
+            ));
+        let err = RehydrateBlobBuilder::new(&client)
+            .with_container_name("container")
+            .with_blob_name("blob")
+            .with_access_tier(AccessTier::Hot)
+            .with_poll_interval(Duration::from_millis(1))
+            .with_max_retries(1)
+            .finalize()
+            .await
+            .unwrap_err();
+
+        assert!(err.is_transport_error());
+        assert_eq!(transport.failures.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn refuses_to_rehydrate_to_the_archive() {
+        let transport = Arc::new(ScriptedTransport::new(Vec::new()));
+        let client = with_access_key("account", &base64::encode("key"))
+            .with_pipeline(ScriptedTransport::pipeline(transport.clone()));
+        let err = RehydrateBlobBuilder::new(&client)
+            .with_container_name("container")
+            .with_blob_name("blob")
+            .with_access_tier(AccessTier::Archive)
+            .finalize()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AzureError::InputParametersError(_)));
+        assert!(transport.requests().is_empty());
+    }
+}
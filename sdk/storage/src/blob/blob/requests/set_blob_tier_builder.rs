@@ -0,0 +1,527 @@
+use crate::blob::blob::generate_blob_uri;
+use crate::blob::blob::responses::SetBlobTierResponse;
+use crate::core::prelude::*;
+use crate::{RehydratePriority, RehydratePriorityOption, RehydratePrioritySupport};
+use azure_core::errors::{extract_status_headers_and_body, AzureError, HttpError};
+use azure_core::instrumentation::instrument;
+use azure_core::lease::LeaseId;
+use azure_core::prelude::*;
+use azure_core::{No, ToAssign, Yes};
+use chrono::{DateTime, Utc};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_access_tier: PhantomData<AccessTierSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    access_tier: Option<AccessTier>,
+    rehydrate_priority: Option<RehydratePriority>,
+    snapshot: Option<DateTime<Utc>>,
+    version_id: Option<&'a str>,
+    timeout: Option<u64>,
+    lease_id: Option<&'a LeaseId>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> SetBlobTierBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> SetBlobTierBuilder<'a, C, No, No, No> {
+        SetBlobTierBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_access_tier: PhantomData {},
+            access_tier: None,
+            rehydrate_priority: None,
+            snapshot: None,
+            version_id: None,
+            timeout: None,
+            lease_id: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> ClientRequired<'a, C>
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet, AccessTierSet> ContainerNameRequired<'a>
+    for SetBlobTierBuilder<'a, C, Yes, BlobNameSet, AccessTierSet>
+where
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, AccessTierSet> BlobNameRequired<'a>
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, Yes, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> AccessTierRequired
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn access_tier(&self) -> AccessTier {
+        self.access_tier.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> RehydratePriorityOption
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn rehydrate_priority(&self) -> Option<RehydratePriority> {
+        self.rehydrate_priority
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> SnapshotOption
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn snapshot(&self) -> Option<DateTime<Utc>> {
+        self.snapshot
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> VersionIdOption<'a>
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn version_id(&self) -> Option<&'a str> {
+        self.version_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> TimeoutOption
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> LeaseIdOption<'a>
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> ClientRequestIdOption<'a>
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, BlobNameSet, AccessTierSet> ContainerNameSupport<'a>
+    for SetBlobTierBuilder<'a, C, No, BlobNameSet, AccessTierSet>
+where
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTierBuilder<'a, C, Yes, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            snapshot: self.snapshot,
+            version_id: self.version_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, AccessTierSet> BlobNameSupport<'a>
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, No, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTierBuilder<'a, C, ContainerNameSet, Yes, AccessTierSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            snapshot: self.snapshot,
+            version_id: self.version_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> AccessTierSupport
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, No>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_access_tier(self, access_tier: AccessTier) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: Some(access_tier),
+            rehydrate_priority: self.rehydrate_priority,
+            snapshot: self.snapshot,
+            version_id: self.version_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> RehydratePrioritySupport
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_rehydrate_priority(self, rehydrate_priority: RehydratePriority) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: Some(rehydrate_priority),
+            snapshot: self.snapshot,
+            version_id: self.version_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> SnapshotSupport
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_snapshot(self, snapshot: DateTime<Utc>) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            snapshot: Some(snapshot),
+            version_id: self.version_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> VersionIdSupport<'a>
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_version_id(self, version_id: &'a str) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            snapshot: self.snapshot,
+            version_id: Some(version_id),
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> TimeoutSupport
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            snapshot: self.snapshot,
+            version_id: self.version_id,
+            timeout: Some(timeout),
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> LeaseIdSupport<'a>
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            snapshot: self.snapshot,
+            version_id: self.version_id,
+            timeout: self.timeout,
+            lease_id: Some(lease_id),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet> ClientRequestIdSupport<'a>
+    for SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    AccessTierSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTierBuilder<'a, C, ContainerNameSet, BlobNameSet, AccessTierSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetBlobTierBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_access_tier: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            access_tier: self.access_tier,
+            rehydrate_priority: self.rehydrate_priority,
+            snapshot: self.snapshot,
+            version_id: self.version_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> SetBlobTierBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    /// Moves the blob to the access tier. Moving a blob out of the archive
+    /// tier only starts its rehydration, see
+    /// `SetBlobTierResponse::rehydrate_pending`.
+    pub async fn finalize(self) -> Result<SetBlobTierResponse, AzureError> {
+        instrument("SetBlobTierBuilder::finalize", async move {
+            let mut uri = generate_blob_uri(
+                self.client(),
+                self.container_name(),
+                self.blob_name(),
+                Some("comp=tier"),
+            );
+
+            if let Some(snapshot) = SnapshotOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, snapshot);
+            }
+            if let Some(version_id) = VersionIdOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, version_id);
+            }
+            if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, timeout);
+            }
+
+            trace!("uri == {:?}", uri);
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = AccessTierRequired::add_header(&self, request);
+                    request = RehydratePriorityOption::add_header(&self, request);
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                None,
+            )?;
+
+            let (status_code, headers, body) =
+                extract_status_headers_and_body(future_response).await?;
+            // the service answers 202 when the tier change is asynchronous,
+            // ie while rehydrating an archived blob
+            if status_code != StatusCode::OK && status_code != StatusCode::ACCEPTED {
+                return Err(HttpError::new_multiple(
+                    vec![StatusCode::OK, StatusCode::ACCEPTED],
+                    status_code,
+                    &headers,
+                    &body,
+                )
+                .into());
+            }
+            SetBlobTierResponse::from_response(status_code, &headers)
+        })
+        .await
+    }
+}
@@ -5,6 +5,7 @@ use crate::core::ClientRequired;
 use azure_core::ba512_range::BA512Range;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_core::headers::PAGE_WRITE;
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -623,42 +624,45 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<UpdatePageResponse, AzureError> {
-        let mut uri = generate_blob_uri(
-            self.client(),
-            self.container_name(),
-            self.blob_name(),
-            Some("comp=page"),
-        );
-
-        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, timeout);
-        }
-
-        trace!("uri == {:?}", uri);
-
-        let upper = self.ba512_range().size() as usize;
-        trace!("upper == {}", upper);
-        let b = &self.body()[0..upper];
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = BA512RangeRequired::add_header(&self, request);
-                request = ContentMD5Option::add_header(&self, request);
-                request = request.header(PAGE_WRITE, "update");
-                request = LeaseIdOption::add_header(&self, request);
-                request = SequenceNumberConditionOption::add_header(&self, request);
-                request = IfSinceConditionOption::add_header(&self, request);
-                request = IfMatchConditionOption::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            Some(b),
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
-        UpdatePageResponse::from_headers(&headers)
+        instrument("UpdatePageBuilder::finalize", async move {
+            let mut uri = generate_blob_uri(
+                self.client(),
+                self.container_name(),
+                self.blob_name(),
+                Some("comp=page"),
+            );
+
+            if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, timeout);
+            }
+
+            trace!("uri == {:?}", uri);
+
+            let upper = self.ba512_range().size() as usize;
+            trace!("upper == {}", upper);
+            let b = &self.body()[0..upper];
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = BA512RangeRequired::add_header(&self, request);
+                    request = ContentMD5Option::add_header(&self, request);
+                    request = request.header(PAGE_WRITE, "update");
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = SequenceNumberConditionOption::add_header(&self, request);
+                    request = IfSinceConditionOption::add_header(&self, request);
+                    request = IfMatchConditionOption::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                Some(b),
+            )?;
+
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+            UpdatePageResponse::from_headers(&headers)
+        })
+        .await
     }
 }
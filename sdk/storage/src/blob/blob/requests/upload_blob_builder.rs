@@ -0,0 +1,1120 @@
+use crate::blob::blob::crc64;
+use crate::blob::blob::requests::{PutBlockBuilder, PutBlockListBuilder};
+use crate::blob::blob::responses::PutBlockListResponse;
+use crate::blob::blob::{is_transient, retry_delay, BlobBlockType, BlockList};
+use crate::blob::blob::{BlockChecksum, BlockChecksumOption, BlockChecksumSupport};
+use crate::blob::blob::{BlockListSupport, BlockSizeOption, BlockSizeSupport};
+use crate::blob::blob::{
+    MaxRetriesOption, MaxRetriesSupport, ParallelismOption, ParallelismSupport,
+};
+use crate::core::prelude::*;
+use azure_core::errors::AzureError;
+use azure_core::instrumentation::instrument;
+use azure_core::lease::LeaseId;
+use azure_core::prelude::*;
+use azure_core::{No, ToAssign, Yes};
+use futures::stream::{self, TryStreamExt};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::path::Path;
+use tokio::fs::File;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::time::delay_for;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    block_size: u64,
+    parallelism: usize,
+    max_retries: u32,
+    block_checksum: BlockChecksum,
+    upload_id: Option<&'a str>,
+    timeout: Option<u64>,
+    content_type: Option<&'a str>,
+    content_encoding: Option<&'a str>,
+    content_language: Option<&'a str>,
+    cache_control: Option<&'a str>,
+    content_disposition: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    access_tier: Option<AccessTier>,
+    lease_id: Option<&'a LeaseId>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> UploadBlobBuilder<'a, C, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> UploadBlobBuilder<'a, C, No, No> {
+        UploadBlobBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            block_size: 4194304,
+            parallelism: 4,
+            max_retries: 3,
+            block_checksum: BlockChecksum::Md5,
+            upload_id: None,
+            timeout: None,
+            content_type: None,
+            content_encoding: None,
+            content_language: None,
+            cache_control: None,
+            content_disposition: None,
+            metadata: None,
+            access_tier: None,
+            lease_id: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequired<'a, C>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet> ContainerNameRequired<'a> for UploadBlobBuilder<'a, C, Yes, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet> BlobNameRequired<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> BlockSizeOption
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn block_size(&self) -> u64 {
+        self.block_size
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ParallelismOption
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn parallelism(&self) -> usize {
+        self.parallelism
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MaxRetriesOption
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> BlockChecksumOption
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn block_checksum(&self) -> BlockChecksum {
+        self.block_checksum
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> TimeoutOption
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentTypeOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_type(&self) -> Option<&'a str> {
+        self.content_type
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentEncodingOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_encoding(&self) -> Option<&'a str> {
+        self.content_encoding
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentLanguageOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_language(&self) -> Option<&'a str> {
+        self.content_language
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> CacheControlOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn cache_control(&self) -> Option<&'a str> {
+        self.cache_control
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentDispositionOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_disposition(&self) -> Option<&'a str> {
+        self.content_disposition
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MetadataOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> AccessTierOption
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn access_tier(&self) -> Option<AccessTier> {
+        self.access_tier
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> LeaseIdOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequestIdOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, BlobNameSet> ContainerNameSupport<'a> for UploadBlobBuilder<'a, C, No, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, Yes, BlobNameSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet> BlobNameSupport<'a> for UploadBlobBuilder<'a, C, ContainerNameSet, No>
+where
+    ContainerNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, Yes>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> BlockSizeSupport
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_block_size(self, block_size: u64) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ParallelismSupport
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_parallelism(self, parallelism: usize) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MaxRetriesSupport
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_max_retries(self, max_retries: u32) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> BlockChecksumSupport
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_block_checksum(self, block_checksum: BlockChecksum) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> TimeoutSupport
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: Some(timeout),
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentTypeSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_type(self, content_type: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: Some(content_type),
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentEncodingSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_encoding(self, content_encoding: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: Some(content_encoding),
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentLanguageSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_language(self, content_language: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: Some(content_language),
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> CacheControlSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_cache_control(self, cache_control: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: Some(cache_control),
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentDispositionSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_disposition(self, content_disposition: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: Some(content_disposition),
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MetadataSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: Some(metadata),
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> AccessTierSupport
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_access_tier(self, access_tier: AccessTier) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: Some(access_tier),
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> LeaseIdSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: Some(lease_id),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequestIdSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_size: self.block_size,
+            parallelism: self.parallelism,
+            max_retries: self.max_retries,
+            block_checksum: self.block_checksum,
+            upload_id: self.upload_id,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            access_tier: self.access_tier,
+            lease_id: self.lease_id,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    /// Names the blocks after `upload_id` instead of a random id, so the
+    /// tests know the block ids in advance.
+    #[cfg(test)]
+    pub(crate) fn with_upload_id(self, upload_id: &'a str) -> Self {
+        Self {
+            upload_id: Some(upload_id),
+            ..self
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> UploadBlobBuilder<'a, C, Yes, Yes>
+where
+    C: Client,
+{
+    /// Uploads the content of `reader` in blocks of `block_size` bytes,
+    /// `parallelism` blocks at a time, and commits them as the content of
+    /// the blob. Throttling and server errors are retried by the pipeline of
+    /// the client; a block whose connection breaks off is uploaded again up
+    /// to `max_retries` times, waiting twice as long every time (up to a
+    /// minute). Nothing is committed if a block cannot be uploaded: the blob
+    /// keeps its previous content.
+    pub async fn finalize_from_reader<R>(
+        self,
+        reader: R,
+    ) -> Result<PutBlockListResponse, AzureError>
+    where
+        R: AsyncRead + Unpin,
+    {
+        instrument("UploadBlobBuilder::finalize", async move {
+            if self.block_size == 0 || self.block_size > MAX_BLOCK_SIZE {
+                return Err(AzureError::InputParametersError(format!(
+                    "the block size must be between 1 and {} bytes",
+                    MAX_BLOCK_SIZE
+                )));
+            }
+            if self.parallelism == 0 {
+                return Err(AzureError::InputParametersError(
+                    "the parallelism must be at least 1".to_owned(),
+                ));
+            }
+
+            // the blocks of a blob must have ids of the same length; the
+            // upload id keeps them apart from the uncommitted blocks of other
+            // uploads to the same blob
+            let random_id = Uuid::new_v4().to_string();
+            let upload_id = self.upload_id.unwrap_or(&random_id);
+            let block_size = self.block_size;
+            let blocks = stream::try_unfold((reader, 0), move |(mut reader, index)| async move {
+                let mut data = Vec::new();
+                (&mut reader)
+                    .take(block_size)
+                    .read_to_end(&mut data)
+                    .await?;
+                if data.is_empty() {
+                    return Ok(None);
+                }
+                if index == MAX_BLOCKS {
+                    return Err(AzureError::InputParametersError(format!(
+                        "the content does not fit in {} blocks of {} bytes",
+                        MAX_BLOCKS, block_size
+                    )));
+                }
+                let block_id = format!("{}-{:06}", upload_id, index).into_bytes();
+                Ok(Some(((block_id, data), (reader, index + 1))))
+            });
+
+            let block_ids: Vec<Vec<u8>> = blocks
+                .map_ok(|(block_id, data)| self.put_block(block_id, data))
+                .try_buffered(self.parallelism)
+                .try_collect()
+                .await?;
+            debug!("{} blocks uploaded", block_ids.len());
+
+            let block_list = BlockList {
+                blocks: block_ids.into_iter().map(BlobBlockType::Latest).collect(),
+            };
+            let mut request = PutBlockListBuilder::new(self.client())
+                .with_container_name(self.container_name())
+                .with_blob_name(self.blob_name())
+                .with_block_list(&block_list);
+            if let Some(timeout) = self.timeout {
+                request = request.with_timeout(timeout);
+            }
+            if let Some(content_type) = self.content_type {
+                request = request.with_content_type(content_type);
+            }
+            if let Some(content_encoding) = self.content_encoding {
+                request = request.with_content_encoding(content_encoding);
+            }
+            if let Some(content_language) = self.content_language {
+                request = request.with_content_language(content_language);
+            }
+            if let Some(cache_control) = self.cache_control {
+                request = request.with_cache_control(cache_control);
+            }
+            if let Some(content_disposition) = self.content_disposition {
+                request = request.with_content_disposition(content_disposition);
+            }
+            if let Some(metadata) = self.metadata {
+                request = request.with_metadata(metadata);
+            }
+            if let Some(access_tier) = self.access_tier {
+                request = request.with_access_tier(access_tier);
+            }
+            if let Some(lease_id) = self.lease_id {
+                request = request.with_lease_id(lease_id);
+            }
+            if let Some(client_request_id) = self.client_request_id {
+                request = request.with_client_request_id(client_request_id);
+            }
+            request.finalize().await
+        })
+        .await
+    }
+
+    /// Uploads the content of the file at `path`, see `finalize_from_reader`.
+    pub async fn finalize_from_file<P>(self, path: P) -> Result<PutBlockListResponse, AzureError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path).await?;
+        self.finalize_from_reader(file).await
+    }
+
+    async fn put_block(&self, block_id: Vec<u8>, data: Vec<u8>) -> Result<Vec<u8>, AzureError> {
+        let content_md5 = match self.block_checksum {
+            BlockChecksum::Md5 => Some(md5::compute(&data)),
+            _ => None,
+        };
+        let content_crc64 = match self.block_checksum {
+            BlockChecksum::Crc64 => Some(crc64::compute(&data)),
+            _ => None,
+        };
+
+        let mut retry = 0;
+        loop {
+            let mut request = PutBlockBuilder::new(self.client())
+                .with_container_name(self.container_name())
+                .with_blob_name(self.blob_name())
+                .with_body(&data)
+                .with_block_id(&block_id);
+            if let Some(content_md5) = &content_md5 {
+                request = request.with_content_md5(&content_md5[..]);
+            }
+            if let Some(content_crc64) = &content_crc64 {
+                request = request.with_content_crc64(content_crc64);
+            }
+            if let Some(timeout) = self.timeout {
+                request = request.with_timeout(timeout);
+            }
+            if let Some(lease_id) = self.lease_id {
+                request = request.with_lease_id(lease_id);
+            }
+            if let Some(client_request_id) = self.client_request_id {
+                request = request.with_client_request_id(client_request_id);
+            }
+
+            match request.finalize().await {
+                Ok(_) => return Ok(block_id),
+                Err(err) if retry < self.max_retries && is_transient(&err) => {
+                    warn!("block {:?} failed, retrying: {}", block_id, err);
+                    delay_for(retry_delay(retry)).await;
+                    retry += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+/// The largest block the service accepts in the API version in use.
+const MAX_BLOCK_SIZE: u64 = 100 * 1024 * 1024;
+/// The largest number of blocks a blob can have.
+const MAX_BLOCKS: usize = 50_000;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::with_access_key;
+    use azure_core::mock_transport::{ScriptedResponse, ScriptedTransport};
+    use hyper::StatusCode;
+    use std::sync::Arc;
+
+    fn created() -> ScriptedResponse {
+        ScriptedResponse::storage(StatusCode::CREATED, "")
+            .with_header("content-md5", "1B2M2Y8AsgTpgAmY7PhCfg==")
+            .with_header("etag", "\"0x8D880CC3A4D6F2B\"")
+            .with_header("last-modified", "Wed, 04 Nov 2020 14:56:02 GMT")
+            .with_header("x-ms-request-server-encrypted", "true")
+    }
+
+    #[tokio::test]
+    async fn splits_the_content_in_blocks() {
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            created(),
+            created(),
+            created(),
+            created(),
+        ]));
+        let client = with_access_key("account", &base64::encode("key"))
+            .with_pipeline(ScriptedTransport::pipeline(transport.clone()));
+        UploadBlobBuilder::new(&client)
+            .with_container_name("container")
+            .with_blob_name("blob")
+            .with_block_size(4)
+            .with_parallelism(1)
+            .with_upload_id("upload")
+            .finalize_from_reader(&b"0123456789"[..])
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 4);
+        let blocks: Vec<_> = requests[..3].iter().map(|r| &r.body()[..]).collect();
+        assert_eq!(blocks, vec![&b"0123"[..], b"4567", b"89"]);
+        for (request, block) in requests[..3].iter().zip(blocks) {
+            assert_eq!(
+                request.headers()["content-md5"],
+                base64::encode(&md5::compute(block)[..])
+            );
+        }
+
+        let block_list = std::str::from_utf8(requests[3].body()).unwrap();
+        let committed: Vec<_> = block_list
+            .split("<Latest>")
+            .skip(1)
+            .map(|latest| &latest[..latest.find("</Latest>").unwrap()])
+            .collect();
+        let block_ids: Vec<_> = ["upload-000000", "upload-000001", "upload-000002"]
+            .iter()
+            .map(base64::encode)
+            .collect();
+        assert_eq!(committed, block_ids);
+    }
+}
@@ -0,0 +1,10 @@
+use crate::blob::blob::Blob;
+use azure_core::range::Range;
+
+#[derive(Debug, Clone)]
+pub struct DownloadBlobResponse {
+    /// The properties of the blob, as returned with the first range.
+    pub blob: Blob,
+    /// The range of the blob downloaded.
+    pub range: Range,
+}
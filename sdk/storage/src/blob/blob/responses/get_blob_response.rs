@@ -1,8 +1,11 @@
 use crate::blob::blob::Blob;
 use azure_core::errors::AzureError;
 use azure_core::headers::{date_from_headers, request_id_from_headers};
+use azure_core::range::ContentRange;
+use azure_core::util::HeaderMapExt;
 use azure_core::RequestId;
 use chrono::{DateTime, Utc};
+use http::header::CONTENT_RANGE;
 use http::HeaderMap;
 
 #[derive(Debug, Clone)]
@@ -10,6 +13,8 @@ pub struct GetBlobResponse {
     pub blob: Blob,
     pub request_id: RequestId,
     pub data: Vec<u8>,
+    /// The range returned and the length of the blob, for a range request.
+    pub content_range: Option<ContentRange>,
     pub date: DateTime<Utc>,
 }
 
@@ -23,11 +28,16 @@ impl GetBlobResponse {
 
         let request_id = request_id_from_headers(headers)?;
         let date = date_from_headers(headers)?;
+        let content_range = match headers.get_as_str(CONTENT_RANGE) {
+            Some(content_range) => Some(content_range.parse()?),
+            None => None,
+        };
 
         Ok(GetBlobResponse {
             blob,
             request_id,
             data: body.to_vec(),
+            content_range,
             date,
         })
     }
@@ -1,8 +1,8 @@
 use crate::blob::blob::{incomplete_vector_from_response, Blob};
 use azure_core::errors::AzureError;
 use azure_core::headers::{date_from_headers, request_id_from_headers};
-use azure_core::incompletevector::IncompleteVector;
-use azure_core::RequestId;
+use azure_core::incompletevector::{ContinuationToken, IncompleteVector};
+use azure_core::{Page, RequestId};
 use chrono::{DateTime, Utc};
 use http::HeaderMap;
 
@@ -30,3 +30,15 @@ impl ListBlobsResponse {
         })
     }
 }
+
+impl Page for ListBlobsResponse {
+    type Item = Blob;
+
+    fn continuation(&self) -> Option<ContinuationToken> {
+        self.incomplete_vector.token.clone()
+    }
+
+    fn into_items(self) -> Vec<Blob> {
+        self.incomplete_vector.vector
+    }
+}
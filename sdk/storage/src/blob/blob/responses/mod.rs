@@ -32,3 +32,17 @@ mod copy_blob_from_url_response;
 pub use copy_blob_from_url_response::CopyBlobFromUrlResponse;
 mod copy_blob_response;
 pub use copy_blob_response::CopyBlobResponse;
+mod download_blob_response;
+pub use download_blob_response::DownloadBlobResponse;
+mod get_blob_properties_response;
+pub use get_blob_properties_response::GetBlobPropertiesResponse;
+mod set_blob_metadata_response;
+pub use set_blob_metadata_response::SetBlobMetadataResponse;
+mod set_blob_properties_response;
+pub use set_blob_properties_response::SetBlobPropertiesResponse;
+mod snapshot_blob_response;
+pub use snapshot_blob_response::SnapshotBlobResponse;
+mod undelete_blob_response;
+pub use undelete_blob_response::UndeleteBlobResponse;
+mod set_blob_tier_response;
+pub use set_blob_tier_response::SetBlobTierResponse;
@@ -0,0 +1,41 @@
+use azure_core::errors::AzureError;
+use azure_core::headers::{
+    date_from_headers, etag_from_headers, last_modified_from_headers, request_id_from_headers,
+    BLOB_SEQUENCE_NUMBER,
+};
+use azure_core::util::HeaderMapExt;
+use azure_core::RequestId;
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone)]
+pub struct SetBlobPropertiesResponse {
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    /// The sequence number of a page blob.
+    pub blob_sequence_number: Option<u64>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl SetBlobPropertiesResponse {
+    pub(crate) fn from_headers(
+        headers: &HeaderMap,
+    ) -> Result<SetBlobPropertiesResponse, AzureError> {
+        debug!("headers == {:#?}", headers);
+
+        let etag = etag_from_headers(headers)?;
+        let last_modified = last_modified_from_headers(headers)?;
+        let blob_sequence_number = headers.get_as_u64(BLOB_SEQUENCE_NUMBER);
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        Ok(SetBlobPropertiesResponse {
+            etag,
+            last_modified,
+            blob_sequence_number,
+            request_id,
+            date,
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use azure_core::errors::AzureError;
+use azure_core::headers::{date_from_headers, request_id_from_headers};
+use azure_core::RequestId;
+use chrono::{DateTime, Utc};
+use http::{HeaderMap, StatusCode};
+
+#[derive(Debug, Clone)]
+pub struct SetBlobTierResponse {
+    /// Whether the blob is being rehydrated from the archive tier. The
+    /// blob stays unreadable until its `archive_status` is cleared.
+    pub rehydrate_pending: bool,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl SetBlobTierResponse {
+    pub(crate) fn from_response(
+        status_code: StatusCode,
+        headers: &HeaderMap,
+    ) -> Result<SetBlobTierResponse, AzureError> {
+        debug!("headers == {:#?}", headers);
+
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        Ok(SetBlobTierResponse {
+            rehydrate_pending: status_code == StatusCode::ACCEPTED,
+            request_id,
+            date,
+        })
+    }
+}
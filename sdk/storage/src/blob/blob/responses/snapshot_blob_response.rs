@@ -0,0 +1,51 @@
+use crate::blob::headers::{SNAPSHOT, VERSION_ID};
+use azure_core::errors::AzureError;
+use azure_core::headers::{
+    date_from_headers, etag_from_headers, last_modified_from_headers, request_id_from_headers,
+    request_server_encrypted_from_headers,
+};
+use azure_core::util::HeaderMapExt;
+use azure_core::RequestId;
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone)]
+pub struct SnapshotBlobResponse {
+    /// The snapshot timestamp, to be passed to `with_snapshot` to address
+    /// the snapshot.
+    pub snapshot: DateTime<Utc>,
+    /// The version of the blob, if versioning is enabled on the account.
+    pub version_id: Option<String>,
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub request_server_encrypted: bool,
+}
+
+impl SnapshotBlobResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<SnapshotBlobResponse, AzureError> {
+        debug!("headers == {:#?}", headers);
+
+        let snapshot = headers
+            .get_as_str(SNAPSHOT)
+            .ok_or_else(|| AzureError::HeaderNotFound(SNAPSHOT.to_owned()))?;
+        let snapshot = DateTime::parse_from_rfc3339(snapshot)?.with_timezone(&Utc);
+        let version_id = headers.get_as_string(VERSION_ID);
+        let etag = etag_from_headers(headers)?;
+        let last_modified = last_modified_from_headers(headers)?;
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+        let request_server_encrypted = request_server_encrypted_from_headers(headers)?;
+
+        Ok(SnapshotBlobResponse {
+            snapshot,
+            version_id,
+            etag,
+            last_modified,
+            request_id,
+            date,
+            request_server_encrypted,
+        })
+    }
+}
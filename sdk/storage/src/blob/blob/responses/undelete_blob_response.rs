@@ -0,0 +1,8 @@
+use azure_core::headers::*;
+use azure_core::RequestId;
+use chrono::{DateTime, Utc};
+
+response_from_headers!(UndeleteBlobResponse ,
+               request_id_from_headers => request_id: RequestId,
+               date_from_headers => date: DateTime<Utc>
+);
@@ -2,6 +2,7 @@ use crate::container::responses::BreakLeaseResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_core::headers::LEASE_ACTION;
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -221,31 +222,35 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<BreakLeaseResponse, AzureError> {
-        let mut uri = format!(
-            "{}/{}?comp=lease&restype=container",
-            self.client().blob_uri(),
-            self.container_name()
-        );
-
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
+        instrument("BreakLeaseBuilder::finalize", async move {
+            let mut uri = format!(
+                "{}/{}?comp=lease&restype=container",
+                self.client().blob_uri(),
+                self.container_name()
+            );
+
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = request.header(LEASE_ACTION, "break");
+                    request = LeaseBreakPeriodOption::add_header(&self, request);
+                    request
+                },
+                Some(&[]),
+            )?;
 
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = ClientRequestIdOption::add_header(&self, request);
-                request = LeaseIdOption::add_header(&self, request);
-                request = request.header(LEASE_ACTION, "break");
-                request = LeaseBreakPeriodOption::add_header(&self, request);
-                request
-            },
-            Some(&[]),
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
-        BreakLeaseResponse::from_headers(&headers)
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED)
+                    .await?;
+            BreakLeaseResponse::from_headers(&headers)
+        })
+        .await
     }
 }
@@ -1,6 +1,7 @@
 use crate::container::{PublicAccess, PublicAccessRequired, PublicAccessSupport};
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use hyper::{Method, StatusCode};
@@ -253,29 +254,32 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<(), AzureError> {
-        let mut uri = format!(
-            "{}/{}?restype=container",
-            self.client().blob_uri(),
-            self.container_name()
-        );
+        instrument("CreateBuilder::finalize", async move {
+            let mut uri = format!(
+                "{}/{}?restype=container",
+                self.client().blob_uri(),
+                self.container_name()
+            );
 
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
 
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = PublicAccessRequired::add_header(&self, request);
-                request = ClientRequestIdOption::add_header(&self, request);
-                request = MetadataOption::add_header(&self, request);
-                request
-            },
-            Some(&[]),
-        )?;
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = PublicAccessRequired::add_header(&self, request);
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request = MetadataOption::add_header(&self, request);
+                    request
+                },
+                Some(&[]),
+            )?;
 
-        check_status_extract_body(future_response, StatusCode::CREATED).await?;
-        Ok(())
+            check_status_extract_body(future_response, StatusCode::CREATED).await?;
+            Ok(())
+        })
+        .await
     }
 }
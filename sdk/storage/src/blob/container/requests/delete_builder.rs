@@ -1,5 +1,6 @@
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -179,28 +180,31 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<(), AzureError> {
-        let mut uri = format!(
-            "{}/{}?restype=container",
-            self.client().blob_uri(),
-            self.container_name()
-        );
-
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::DELETE,
-            &|mut request| {
-                request = ClientRequestIdOption::add_header(&self, request);
-                request = LeaseIdOption::add_header(&self, request);
-                request
-            },
-            Some(&[]),
-        )?;
-
-        check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
-        Ok(())
+        instrument("DeleteBuilder::finalize", async move {
+            let mut uri = format!(
+                "{}/{}?restype=container",
+                self.client().blob_uri(),
+                self.container_name()
+            );
+
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::DELETE,
+                &|mut request| {
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request = LeaseIdOption::add_header(&self, request);
+                    request
+                },
+                Some(&[]),
+            )?;
+
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+            Ok(())
+        })
+        .await
     }
 }
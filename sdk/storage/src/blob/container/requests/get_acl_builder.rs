@@ -1,6 +1,7 @@
 use crate::container::responses::GetACLResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -180,30 +181,33 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<GetACLResponse, AzureError> {
-        let mut uri = format!(
-            "{}/{}?restype=container&comp=acl",
-            self.client().blob_uri(),
-            self.container_name()
-        );
-
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::GET,
-            &|mut request| {
-                request = ClientRequestIdOption::add_header(&self, request);
-                request = LeaseIdOption::add_header(&self, request);
-                request
-            },
-            None,
-        )?;
-
-        let (headers, body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
-        // todo: parse SAS policies
-        GetACLResponse::from_response(&body, &headers)
+        instrument("GetACLBuilder::finalize", async move {
+            let mut uri = format!(
+                "{}/{}?restype=container&comp=acl",
+                self.client().blob_uri(),
+                self.container_name()
+            );
+
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::GET,
+                &|mut request| {
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request = LeaseIdOption::add_header(&self, request);
+                    request
+                },
+                None,
+            )?;
+
+            let (headers, body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+            // todo: parse SAS policies
+            GetACLResponse::from_response(&body, &headers)
+        })
+        .await
     }
 }
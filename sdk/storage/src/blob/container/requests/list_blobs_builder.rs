@@ -2,9 +2,10 @@ use crate::blob::blob::responses::ListBlobsResponse;
 use crate::blob::container::generate_container_uri;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body_as_string, AzureError};
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
-use azure_core::{No, ToAssign, Yes};
-use futures::stream::{unfold, Stream};
+use azure_core::{No, Pageable, ToAssign, Yes};
 use hyper::{Method, StatusCode};
 use std::marker::PhantomData;
 
@@ -28,6 +29,7 @@ where
     include_uncommitted_blobs: bool,
     include_copy: bool,
     include_deleted: bool,
+    include_versions: bool,
 }
 
 impl<'a, C> ListBlobBuilder<'a, C, No>
@@ -51,6 +53,7 @@ where
             include_uncommitted_blobs: false,
             include_copy: false,
             include_deleted: false,
+            include_versions: false,
         }
     }
 }
@@ -201,6 +204,17 @@ where
     }
 }
 
+impl<'a, C, ContainerNameSet> IncludeVersionsOption for ListBlobBuilder<'a, C, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn include_versions(&self) -> bool {
+        self.include_versions
+    }
+}
+
 impl<'a, C> ContainerNameSupport<'a> for ListBlobBuilder<'a, C, No>
 where
     C: Client,
@@ -224,6 +238,7 @@ where
             include_uncommitted_blobs: self.include_uncommitted_blobs,
             include_copy: self.include_copy,
             include_deleted: self.include_deleted,
+            include_versions: self.include_versions,
         }
     }
 }
@@ -253,6 +268,7 @@ where
             include_uncommitted_blobs: self.include_uncommitted_blobs,
             include_copy: self.include_copy,
             include_deleted: self.include_deleted,
+            include_versions: self.include_versions,
         }
     }
 }
@@ -281,6 +297,7 @@ where
             include_uncommitted_blobs: self.include_uncommitted_blobs,
             include_copy: self.include_copy,
             include_deleted: self.include_deleted,
+            include_versions: self.include_versions,
         }
     }
 }
@@ -309,6 +326,7 @@ where
             include_uncommitted_blobs: self.include_uncommitted_blobs,
             include_copy: self.include_copy,
             include_deleted: self.include_deleted,
+            include_versions: self.include_versions,
         }
     }
 }
@@ -337,6 +355,7 @@ where
             include_uncommitted_blobs: self.include_uncommitted_blobs,
             include_copy: self.include_copy,
             include_deleted: self.include_deleted,
+            include_versions: self.include_versions,
         }
     }
 }
@@ -365,6 +384,7 @@ where
             include_uncommitted_blobs: self.include_uncommitted_blobs,
             include_copy: self.include_copy,
             include_deleted: self.include_deleted,
+            include_versions: self.include_versions,
         }
     }
 }
@@ -393,6 +413,7 @@ where
             include_uncommitted_blobs: self.include_uncommitted_blobs,
             include_copy: self.include_copy,
             include_deleted: self.include_deleted,
+            include_versions: self.include_versions,
         }
     }
 }
@@ -421,6 +442,7 @@ where
             include_uncommitted_blobs: self.include_uncommitted_blobs,
             include_copy: self.include_copy,
             include_deleted: self.include_deleted,
+            include_versions: self.include_versions,
         }
     }
 }
@@ -449,6 +471,7 @@ where
             include_uncommitted_blobs: self.include_uncommitted_blobs,
             include_copy: self.include_copy,
             include_deleted: self.include_deleted,
+            include_versions: self.include_versions,
         }
     }
 }
@@ -478,6 +501,7 @@ where
             include_uncommitted_blobs: true,
             include_copy: self.include_copy,
             include_deleted: self.include_deleted,
+            include_versions: self.include_versions,
         }
     }
 }
@@ -506,6 +530,7 @@ where
             include_uncommitted_blobs: self.include_uncommitted_blobs,
             include_copy: true,
             include_deleted: self.include_deleted,
+            include_versions: self.include_versions,
         }
     }
 }
@@ -534,6 +559,36 @@ where
             include_uncommitted_blobs: self.include_uncommitted_blobs,
             include_copy: self.include_copy,
             include_deleted: true,
+            include_versions: self.include_versions,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet> IncludeVersionsSupport for ListBlobBuilder<'a, C, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListBlobBuilder<'a, C, ContainerNameSet>;
+
+    #[inline]
+    fn with_include_versions(self) -> Self::O {
+        ListBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+            prefix: self.prefix,
+            delimiter: self.delimiter,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            include_snapshots: self.include_snapshots,
+            include_metadata: self.include_metadata,
+            include_uncommitted_blobs: self.include_uncommitted_blobs,
+            include_copy: self.include_copy,
+            include_deleted: self.include_deleted,
+            include_versions: true,
         }
     }
 }
@@ -545,43 +600,46 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<ListBlobsResponse, AzureError> {
-        // we create a copy to move into the future's closure.
-        // We need to do this since the closure only accepts
-        // 'static lifetimes.
-        let container_name = self.container_name().to_owned();
-
-        let mut uri = generate_container_uri(
-            self.client(),
-            self.container_name(),
-            Some("restype=container&comp=list"),
-        );
-
-        if let Some(mr) = MaxResultsOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, mr);
-        }
-        if let Some(mr) = NextMarkerOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, mr);
-        }
-        if let Some(mr) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, mr);
-        }
-        if let Some(mr) = PrefixOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, mr);
-        }
-        if let Some(mr) = IncludeListOptions::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, mr);
-        }
+        instrument("ListBlobBuilder::finalize", async move {
+            // we create a copy to move into the future's closure.
+            // We need to do this since the closure only accepts
+            // 'static lifetimes.
+            let container_name = self.container_name().to_owned();
+
+            let mut uri = generate_container_uri(
+                self.client(),
+                self.container_name(),
+                Some("restype=container&comp=list"),
+            );
+
+            if let Some(mr) = MaxResultsOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, mr);
+            }
+            if let Some(mr) = NextMarkerOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, mr);
+            }
+            if let Some(mr) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, mr);
+            }
+            if let Some(mr) = PrefixOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, mr);
+            }
+            if let Some(mr) = IncludeListOptions::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, mr);
+            }
 
-        trace!("list blob uri = {}", uri);
+            trace!("list blob uri = {}", uri);
 
-        let future_response =
-            self.client()
-                .perform_request(&uri, &Method::GET, &|request| request, None)?;
+            let future_response =
+                self.client()
+                    .perform_request(&uri, &Method::GET, &|request| request, None)?;
 
-        let (headers, body_as_str) =
-            check_status_extract_headers_and_body_as_string(future_response, StatusCode::OK)
-                .await?;
-        ListBlobsResponse::from_response(&container_name, &headers, &body_as_str)
+            let (headers, body_as_str) =
+                check_status_extract_headers_and_body_as_string(future_response, StatusCode::OK)
+                    .await?;
+            ListBlobsResponse::from_response(&container_name, &headers, &body_as_str)
+        })
+        .await
     }
 }
 
@@ -589,39 +647,85 @@ impl<'a, C> ListBlobBuilder<'a, C, Yes>
 where
     C: Client + Clone,
 {
-    pub fn stream(self) -> impl Stream<Item = Result<ListBlobsResponse, AzureError>> + 'a {
-        #[derive(Debug, Clone, PartialEq)]
-        enum States {
-            Init,
-            NextMarker(String),
-        };
-
-        unfold(Some(States::Init), move |next_marker: Option<States>| {
-            let req = self.clone();
-            async move {
-                debug!("next_marker == {:?}", &next_marker);
-                let response = match next_marker {
-                    Some(States::Init) => req.finalize().await,
-                    Some(States::NextMarker(next_marker)) => {
-                        req.with_next_marker(&next_marker).finalize().await
+    /// Lists every blob of the container, following the `NextMarker`s.
+    /// The listing starts at the builder's next marker, if any, and
+    /// restarts from the first page when given a `None` continuation token.
+    pub fn stream(self) -> Pageable<'a, ListBlobsResponse> {
+        let next_marker = self.next_marker.map(ToOwned::to_owned);
+        Pageable::new(
+            next_marker,
+            move |next_marker: Option<ContinuationToken>| {
+                let req = self.clone();
+                async move {
+                    debug!("next_marker == {:?}", &next_marker);
+                    match next_marker {
+                        Some(next_marker) => req.with_next_marker(&next_marker).finalize().await,
+                        None => {
+                            ListBlobBuilder {
+                                next_marker: None,
+                                ..req
+                            }
+                            .finalize()
+                            .await
+                        }
                     }
-                    None => return None,
-                };
-
-                // the ? operator does not work in async move (yet?)
-                // so we have to resort to this boilerplate
-                let response = match response {
-                    Ok(response) => response,
-                    Err(err) => return Some((Err(err), None)),
-                };
-
-                let next_marker = match response.incomplete_vector.token() {
-                    Some(ct) => Some(States::NextMarker(ct.to_owned())),
-                    None => None,
-                };
-
-                Some((Ok(response), next_marker))
-            }
-        })
+                }
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blob::prelude::*;
+    use crate::core::client::with_access_key;
+    use azure_core::mock_transport::{ScriptedResponse, ScriptedTransport};
+    use futures::StreamExt;
+    use std::sync::Arc;
+
+    fn listing(next_marker: &str) -> ScriptedResponse {
+        ScriptedResponse::storage(
+            StatusCode::OK,
+            format!(
+                "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+                <EnumerationResults ContainerName=\"container\"><Blobs /><NextMarker>{}</NextMarker>\
+                </EnumerationResults>",
+                next_marker
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn a_none_token_restarts_the_listing() {
+        let transport = Arc::new(ScriptedTransport::new(vec![
+            listing("second"),
+            listing(""),
+            listing(""),
+        ]));
+        let client = with_access_key("account", &base64::encode("key"))
+            .with_pipeline(ScriptedTransport::pipeline(transport.clone()));
+        let mut pages = client
+            .list_blobs()
+            .with_container_name("container")
+            .with_next_marker("first")
+            .stream();
+
+        pages.next().await.unwrap().unwrap();
+        assert_eq!(pages.continuation_token(), Some("second"));
+        pages.next().await.unwrap().unwrap();
+        assert!(pages.next().await.is_none());
+
+        let mut pages = pages.with_continuation_token(None);
+        pages.next().await.unwrap().unwrap();
+
+        let queries: Vec<String> = transport
+            .requests()
+            .iter()
+            .map(|request| request.uri().query().unwrap_or("").to_owned())
+            .collect();
+        assert!(queries[0].contains("marker=first"));
+        assert!(queries[1].contains("marker=second"));
+        assert!(!queries[2].contains("marker="));
     }
 }
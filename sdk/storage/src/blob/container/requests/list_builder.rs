@@ -3,7 +3,10 @@ use crate::container::responses::ListContainersResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_core::headers::request_id_from_headers;
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
+use azure_core::Pageable;
 use hyper::{Method, StatusCode};
 
 #[derive(Debug, Clone)]
@@ -237,42 +240,69 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<ListContainersResponse, AzureError> {
-        let mut uri = format!("{}?comp=list", self.client().blob_uri());
+        instrument("ListBuilder::finalize", async move {
+            let mut uri = format!("{}?comp=list", self.client().blob_uri());
 
-        if self.include_metadata() {
-            uri = format!("{}&include=metadata", uri);
-        }
+            if self.include_metadata() {
+                uri = format!("{}&include=metadata", uri);
+            }
 
-        if let Some(mr) = MaxResultsOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, mr);
-        }
-        if let Some(nm) = NextMarkerOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-        if let Some(nm) = PrefixOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
+            if let Some(mr) = MaxResultsOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, mr);
+            }
+            if let Some(nm) = NextMarkerOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+            if let Some(nm) = PrefixOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
 
-        debug!("generated uri = {}", uri);
+            debug!("generated uri = {}", uri);
 
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::GET,
-            &|request| ClientRequestIdOption::add_header(&self, request),
-            None,
-        )?;
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::GET,
+                &|request| ClientRequestIdOption::add_header(&self, request),
+                None,
+            )?;
 
-        let (headers, body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
-        let body = std::str::from_utf8(&body)?;
-        let incomplete_vector = incomplete_vector_from_container_response(&body)?;
-        let request_id = request_id_from_headers(&headers)?;
-        Ok(ListContainersResponse {
-            incomplete_vector,
-            request_id,
+            let (headers, body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+            let body = std::str::from_utf8(&body)?;
+            let incomplete_vector = incomplete_vector_from_container_response(&body)?;
+            let request_id = request_id_from_headers(&headers)?;
+            Ok(ListContainersResponse {
+                incomplete_vector,
+                request_id,
+            })
         })
+        .await
+    }
+}
+
+impl<'a, C> ListBuilder<'a, C>
+where
+    C: Client + Clone,
+{
+    /// Lists every container of the account, following the `NextMarker`s.
+    /// The listing starts at the builder's next marker, if any.
+    pub fn stream(self) -> Pageable<'a, ListContainersResponse> {
+        let next_marker = self.next_marker.map(ToOwned::to_owned);
+        Pageable::new(
+            next_marker,
+            move |next_marker: Option<ContinuationToken>| {
+                let req = self.clone();
+                async move {
+                    debug!("next_marker == {:?}", &next_marker);
+                    match next_marker {
+                        Some(next_marker) => req.with_next_marker(&next_marker).finalize().await,
+                        None => req.finalize().await,
+                    }
+                }
+            },
+        )
     }
 }
@@ -2,6 +2,7 @@ use crate::container::responses::RenewLeaseResponse;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_core::headers::LEASE_ACTION;
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
@@ -201,30 +202,33 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<RenewLeaseResponse, AzureError> {
-        let mut uri = format!(
-            "{}/{}?comp=lease&restype=container",
-            self.client().blob_uri(),
-            self.container_name()
-        );
-
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = ClientRequestIdOption::add_header(&self, request);
-                request = LeaseIdRequired::add_header(&self, request);
-                request = request.header(LEASE_ACTION, "renew");
-                request
-            },
-            Some(&[]),
-        )?;
-
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
-        RenewLeaseResponse::from_headers(&headers)
+        instrument("RenewLeaseBuilder::finalize", async move {
+            let mut uri = format!(
+                "{}/{}?comp=lease&restype=container",
+                self.client().blob_uri(),
+                self.container_name()
+            );
+
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request = LeaseIdRequired::add_header(&self, request);
+                    request = request.header(LEASE_ACTION, "renew");
+                    request
+                },
+                Some(&[]),
+            )?;
+
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+            RenewLeaseResponse::from_headers(&headers)
+        })
+        .await
     }
 }
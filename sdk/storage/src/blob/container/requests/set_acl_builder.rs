@@ -5,6 +5,7 @@ use crate::blob::prelude::{
 use crate::container::public_access_from_header;
 use crate::core::prelude::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::lease::LeaseId;
 use azure_core::prelude::*;
 use azure_core::{No, StoredAccessPolicyList, ToAssign, Yes};
@@ -295,41 +296,44 @@ where
     C: Client,
 {
     pub async fn finalize(self) -> Result<PublicAccess, AzureError> {
-        let mut uri = format!(
-            "{}/{}?restype=container&comp=acl",
-            self.client().blob_uri(),
-            self.container_name()
-        );
+        instrument("SetACLBuilder::finalize", async move {
+            let mut uri = format!(
+                "{}/{}?restype=container&comp=acl",
+                self.client().blob_uri(),
+                self.container_name()
+            );
 
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
 
-        let xml = if let Some(sapl) = self.stored_access_policy_list {
-            let xml = sapl.to_xml();
-            Some(xml)
-        } else {
-            None
-        };
+            let xml = if let Some(sapl) = self.stored_access_policy_list {
+                let xml = sapl.to_xml();
+                Some(xml)
+            } else {
+                None
+            };
 
-        let future_response = self.client().perform_request(
-            &uri,
-            &Method::PUT,
-            &|mut request| {
-                request = ClientRequestIdOption::add_header(&self, request);
-                request = LeaseIdOption::add_header(&self, request);
-                request = PublicAccessRequired::add_header(&self, request);
-                request
-            },
-            match xml {
-                Some(ref x) => Some(x.as_bytes()),
-                None => Some(&[]),
-            },
-        )?;
+            let future_response = self.client().perform_request(
+                &uri,
+                &Method::PUT,
+                &|mut request| {
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request = LeaseIdOption::add_header(&self, request);
+                    request = PublicAccessRequired::add_header(&self, request);
+                    request
+                },
+                match xml {
+                    Some(ref x) => Some(x.as_bytes()),
+                    None => Some(&[]),
+                },
+            )?;
 
-        let (headers, _body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+            let (headers, _body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
 
-        public_access_from_header(&headers)
+            public_access_from_header(&headers)
+        })
+        .await
     }
 }
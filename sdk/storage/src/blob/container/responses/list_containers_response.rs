@@ -1,6 +1,6 @@
 use crate::container::Container;
-use azure_core::incompletevector::IncompleteVector;
-use azure_core::RequestId;
+use azure_core::incompletevector::{ContinuationToken, IncompleteVector};
+use azure_core::{Page, RequestId};
 
 #[derive(Debug, Clone)]
 pub struct ListContainersResponse {
@@ -13,3 +13,15 @@ impl ListContainersResponse {
         self.incomplete_vector.is_complete()
     }
 }
+
+impl Page for ListContainersResponse {
+    type Item = Container;
+
+    fn continuation(&self) -> Option<ContinuationToken> {
+        self.incomplete_vector.token.clone()
+    }
+
+    fn into_items(self) -> Vec<Container> {
+        self.incomplete_vector.vector
+    }
+}
@@ -1 +1,12 @@
 pub const REHYDRATE_PRIORITY: &str = "x-ms-rehydrate-priority";
+pub const BLOB_CONTENT_TYPE: &str = "x-ms-blob-content-type";
+pub const BLOB_CONTENT_ENCODING: &str = "x-ms-blob-content-encoding";
+pub const BLOB_CONTENT_LANGUAGE: &str = "x-ms-blob-content-language";
+pub const BLOB_CONTENT_MD5: &str = "x-ms-blob-content-md5";
+pub const ACCESS_TIER_INFERRED: &str = "x-ms-access-tier-inferred";
+pub const ACCESS_TIER_CHANGE_TIME: &str = "x-ms-access-tier-change-time";
+pub const INCREMENTAL_COPY: &str = "x-ms-incremental-copy";
+pub const SNAPSHOT: &str = "x-ms-snapshot";
+pub const VERSION_ID: &str = "x-ms-version-id";
+pub const IS_CURRENT_VERSION: &str = "x-ms-is-current-version";
+pub const ARCHIVE_STATUS: &str = "x-ms-archive-status";
@@ -32,6 +32,15 @@ where
     C: Client,
 {
     fn get_blob<'a>(&'a self) -> blob::requests::GetBlobBuilder<'a, C, No, No>;
+    fn get_blob_properties<'a>(&'a self)
+        -> blob::requests::GetBlobPropertiesBuilder<'a, C, No, No>;
+    fn set_blob_metadata<'a>(&'a self) -> blob::requests::SetBlobMetadataBuilder<'a, C, No, No>;
+    fn set_blob_properties<'a>(&'a self)
+        -> blob::requests::SetBlobPropertiesBuilder<'a, C, No, No>;
+    fn snapshot_blob<'a>(&'a self) -> blob::requests::SnapshotBlobBuilder<'a, C, No, No>;
+    fn undelete_blob<'a>(&'a self) -> blob::requests::UndeleteBlobBuilder<'a, C, No, No>;
+    fn set_blob_tier<'a>(&'a self) -> blob::requests::SetBlobTierBuilder<'a, C, No, No, No>;
+    fn rehydrate_blob<'a>(&'a self) -> blob::requests::RehydrateBlobBuilder<'a, C, No, No, No>;
     fn put_block_blob<'a>(&'a self) -> blob::requests::PutBlockBlobBuilder<'a, C, No, No, No>;
     fn put_page_blob<'a>(&'a self) -> blob::requests::PutPageBlobBuilder<'a, C, No, No, No>;
     fn put_append_blob<'a>(&'a self) -> blob::requests::PutAppendBlobBuilder<'a, C, No, No>;
@@ -66,6 +75,8 @@ where
     fn generate_signed_blob_url<'a>(
         &'a self,
     ) -> blob::requests::SignedUrlBuilder<'a, C, No, No, No>;
+    fn upload_blob<'a>(&'a self) -> blob::requests::UploadBlobBuilder<'a, C, No, No>;
+    fn download_blob<'a>(&'a self) -> blob::requests::DownloadBlobBuilder<'a, C, No, No>;
 }
 
 pub trait Container<C>
@@ -100,6 +111,38 @@ where
         blob::requests::GetBlobBuilder::new(self)
     }
 
+    fn get_blob_properties<'a>(
+        &'a self,
+    ) -> blob::requests::GetBlobPropertiesBuilder<'a, C, No, No> {
+        blob::requests::GetBlobPropertiesBuilder::new(self)
+    }
+
+    fn set_blob_metadata<'a>(&'a self) -> blob::requests::SetBlobMetadataBuilder<'a, C, No, No> {
+        blob::requests::SetBlobMetadataBuilder::new(self)
+    }
+
+    fn set_blob_properties<'a>(
+        &'a self,
+    ) -> blob::requests::SetBlobPropertiesBuilder<'a, C, No, No> {
+        blob::requests::SetBlobPropertiesBuilder::new(self)
+    }
+
+    fn snapshot_blob<'a>(&'a self) -> blob::requests::SnapshotBlobBuilder<'a, C, No, No> {
+        blob::requests::SnapshotBlobBuilder::new(self)
+    }
+
+    fn undelete_blob<'a>(&'a self) -> blob::requests::UndeleteBlobBuilder<'a, C, No, No> {
+        blob::requests::UndeleteBlobBuilder::new(self)
+    }
+
+    fn set_blob_tier<'a>(&'a self) -> blob::requests::SetBlobTierBuilder<'a, C, No, No, No> {
+        blob::requests::SetBlobTierBuilder::new(self)
+    }
+
+    fn rehydrate_blob<'a>(&'a self) -> blob::requests::RehydrateBlobBuilder<'a, C, No, No, No> {
+        blob::requests::RehydrateBlobBuilder::new(self)
+    }
+
     fn put_block_blob<'a>(&'a self) -> blob::requests::PutBlockBlobBuilder<'a, C, No, No, No> {
         blob::requests::PutBlockBlobBuilder::new(self)
     }
@@ -193,6 +236,14 @@ where
     ) -> blob::requests::SignedUrlBuilder<'a, C, No, No, No> {
         blob::requests::SignedUrlBuilder::new(self)
     }
+
+    fn upload_blob<'a>(&'a self) -> blob::requests::UploadBlobBuilder<'a, C, No, No> {
+        blob::requests::UploadBlobBuilder::new(self)
+    }
+
+    fn download_blob<'a>(&'a self) -> blob::requests::DownloadBlobBuilder<'a, C, No, No> {
+        blob::requests::DownloadBlobBuilder::new(self)
+    }
 }
 
 impl<C> Container<C> for C
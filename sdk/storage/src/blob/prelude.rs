@@ -1,6 +1,8 @@
 pub use super::blob::{
-    BlobBlockType, BlockList, BlockListRequired, BlockListSupport, BlockListType,
-    BlockListTypeRequired, BlockListTypeSupport,
+    BlobBlockType, BlockChecksum, BlockChecksumOption, BlockChecksumSupport, BlockList,
+    BlockListRequired, BlockListSupport, BlockListType, BlockListTypeRequired,
+    BlockListTypeSupport, BlockSizeOption, BlockSizeSupport, MaxRetriesOption, MaxRetriesSupport,
+    ParallelismOption, ParallelismSupport, PollIntervalOption, PollIntervalSupport,
 };
 pub use super::container::{
     PublicAccess, PublicAccessRequired, PublicAccessSupport, StoredAccessPolicyListOption,
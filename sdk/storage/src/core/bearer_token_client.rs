@@ -1,17 +1,20 @@
 use super::prelude::*;
-use super::rest_client::{AZURE_VERSION, HEADER_DATE, HEADER_VERSION};
+use super::rest_client::{add_context_timeout, AZURE_VERSION, HEADER_DATE, HEADER_VERSION};
 use azure_core::errors::AzureError;
+use azure_core::pipeline::Pipeline;
 use azure_core::util::{format_header_value, RequestBuilderExt};
+use azure_core::AzureEnvironment;
+use azure_core::ResponseFuture;
+use bytes::Bytes;
 use http::request::Builder;
 use hyper::{header, Method};
-use hyper_rustls::HttpsConnector;
 use std::borrow::Cow;
 
 #[derive(Debug, Clone)]
 pub struct BearerTokenClient<'a> {
     account: Cow<'a, str>,
     bearer_token: Cow<'a, str>,
-    hc: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+    pipeline: Pipeline,
     blob_uri: String,
     table_uri: String,
     queue_uri: String,
@@ -23,7 +26,7 @@ impl<'a> BearerTokenClient<'a> {
     pub(crate) fn new(
         account: Cow<'a, str>,
         bearer_token: Cow<'a, str>,
-        hc: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+        pipeline: Pipeline,
     ) -> Self {
         let blob_uri = format!("https://{}.blob.core.windows.net", account);
         let table_uri = format!("https://{}.table.core.windows.net", account);
@@ -34,25 +37,43 @@ impl<'a> BearerTokenClient<'a> {
             account,
             bearer_token,
             queue_uri,
-            hc,
+            pipeline,
             blob_uri,
             table_uri,
             filesystem_uri,
         }
     }
 
+    /// Replaces the pipeline the requests of this client are sent through.
+    pub fn with_pipeline(self, pipeline: Pipeline) -> Self {
+        Self { pipeline, ..self }
+    }
+
+    /// Points the client to the storage endpoints of another Azure cloud.
+    pub fn with_environment(self, environment: &AzureEnvironment) -> Self {
+        let suffix = environment.storage_endpoint_suffix();
+        Self {
+            blob_uri: format!("https://{}.blob.{}", self.account, suffix),
+            table_uri: format!("https://{}.table.{}", self.account, suffix),
+            queue_uri: format!("https://{}.queue.{}", self.account, suffix),
+            filesystem_uri: format!("https://{}.dfs.{}", self.account, suffix),
+            ..self
+        }
+    }
+
     fn perform_request_internal(
         &self,
         uri: &str,
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         let dt = chrono::Utc::now();
         let time = format!("{}", dt.format("%a, %d %h %Y %T GMT"));
 
+        let uri = add_context_timeout(uri);
         let mut request = hyper::Request::builder();
-        request = request.method(method).uri(uri);
+        request = request.method(method).uri(uri.as_ref());
 
         // let's add content length to avoid "chunking" errors.
         match request_body {
@@ -71,9 +92,7 @@ impl<'a> BearerTokenClient<'a> {
             .header_bytes(HEADER_DATE, time)
             .header_static(HEADER_VERSION, AZURE_VERSION);
 
-        let b = request_body
-            .map(|v| Vec::from(v).into())
-            .unwrap_or_else(hyper::Body::empty);
+        let b = request_body.map(Bytes::copy_from_slice).unwrap_or_default();
         let mut request = request.body(b)?;
 
         request.headers_mut().insert(
@@ -81,7 +100,8 @@ impl<'a> BearerTokenClient<'a> {
             format_header_value(format!("Bearer {}", self.bearer_token))?,
         );
 
-        Ok(self.hc.request(request))
+        let pipeline = self.pipeline.clone();
+        Ok(Box::pin(async move { pipeline.execute(request).await }))
     }
 }
 
@@ -113,7 +133,7 @@ impl<'a> Client for BearerTokenClient<'a> {
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         self.perform_request_internal(uri, method, http_header_adder, request_body)
     }
 
@@ -124,7 +144,7 @@ impl<'a> Client for BearerTokenClient<'a> {
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         self.perform_request_internal(segment, method, http_header_adder, request_body)
     }
 }
@@ -3,10 +3,13 @@ use crate::core::key_client::get_sas_token_parms;
 use crate::core::rest_client::ServiceType;
 use crate::core::{ConnectionString, KeyClient};
 use azure_core::errors::AzureError;
+use azure_core::pipeline::Pipeline;
+use azure_core::policies::{RetryOptions, TransportPolicy};
+use azure_core::ResponseFuture;
 use http::request::Builder;
-use hyper::{self, Method};
-use hyper_rustls::HttpsConnector;
+use hyper::Method;
 use std::borrow::Cow;
+use std::sync::Arc;
 use url::Url;
 
 pub trait HttpHeaderAdder {
@@ -34,7 +37,7 @@ pub trait Client: std::fmt::Debug + Send + Sync {
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError>;
+    ) -> Result<ResponseFuture, AzureError>;
 
     fn perform_table_request(
         &self,
@@ -42,7 +45,7 @@ pub trait Client: std::fmt::Debug + Send + Sync {
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_str: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError>;
+    ) -> Result<ResponseFuture, AzureError>;
 }
 
 impl<C> Client for Box<C>
@@ -68,7 +71,7 @@ where
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         self.as_ref()
             .perform_request(uri, method, http_header_adder, request_body)
     }
@@ -79,7 +82,7 @@ where
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_str: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         self.as_ref()
             .perform_table_request(segment, method, http_header_adder, request_str)
     }
@@ -108,7 +111,7 @@ where
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         self.as_ref()
             .perform_request(uri, method, http_header_adder, request_body)
     }
@@ -119,7 +122,7 @@ where
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_str: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         self.as_ref()
             .perform_table_request(segment, method, http_header_adder, request_str)
     }
@@ -128,6 +131,19 @@ where
 //
 // def impl
 //
+/// The pipeline used by the storage clients unless one is supplied with
+/// `with_pipeline`.
+pub fn default_pipeline() -> Pipeline {
+    Pipeline::new(
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        Vec::new(),
+        RetryOptions::default(),
+        Vec::new(),
+        Arc::new(TransportPolicy::default()),
+    )
+}
+
 #[deprecated(
     since = "0.44.0",
     note = "Please use the with_access_key function instead"
@@ -137,14 +153,14 @@ pub fn new(account: &str, key: &str) -> KeyClient {
 }
 
 pub fn with_azure_sas(account: &str, sas_token: &str) -> KeyClient {
-    let client = hyper::Client::builder().build(HttpsConnector::new());
+    let pipeline = default_pipeline();
     let params = get_sas_token_parms(sas_token);
 
     KeyClient::new(
         account.to_owned(),
         String::new(),
         Some(params),
-        client,
+        pipeline,
         format!("https://{}.blob.core.windows.net", account),
         format!("https://{}.table.core.windows.net", account),
         format!("https://{}.queue.core.windows.net", account),
@@ -153,13 +169,13 @@ pub fn with_azure_sas(account: &str, sas_token: &str) -> KeyClient {
 }
 
 pub fn with_access_key(account: &str, key: &str) -> KeyClient {
-    let client = hyper::Client::builder().build(HttpsConnector::new());
+    let pipeline = default_pipeline();
 
     KeyClient::new(
         account.to_owned(),
         key.to_owned(),
         None,
-        client,
+        pipeline,
         format!("https://{}.blob.core.windows.net", account),
         format!("https://{}.table.core.windows.net", account),
         format!("https://{}.queue.core.windows.net", account),
@@ -168,7 +184,7 @@ pub fn with_access_key(account: &str, key: &str) -> KeyClient {
 }
 
 pub fn from_connection_string(connection_string: &str) -> Result<KeyClient, AzureError> {
-    let client = hyper::Client::builder().build(HttpsConnector::new());
+    let pipeline = default_pipeline();
 
     match ConnectionString::new(connection_string)? {
             ConnectionString {
@@ -182,7 +198,7 @@ pub fn from_connection_string(connection_string: &str) -> Result<KeyClient, Azur
                     account.to_owned(),
                     String::new(),
                     Some(get_sas_token_parms(sas_token)),
-                    client,
+                    pipeline,
                     format!("https://{}.blob.core.windows.net", account),
                     format!("https://{}.table.core.windows.net", account),
                     format!("https://{}.queue.core.windows.net", account),
@@ -197,7 +213,7 @@ pub fn from_connection_string(connection_string: &str) -> Result<KeyClient, Azur
                 account.to_owned(),
                 String::new(),
                 Some(get_sas_token_parms(sas_token)),
-                client,
+                pipeline,
                 format!("https://{}.blob.core.windows.net", account),
                 format!("https://{}.table.core.windows.net", account),
                 format!("https://{}.queue.core.windows.net", account),
@@ -211,7 +227,7 @@ pub fn from_connection_string(connection_string: &str) -> Result<KeyClient, Azur
                 account.to_owned(),
                 key.to_owned(),
                 None,
-                client,
+                pipeline,
                 format!("https://{}.blob.core.windows.net", account),
                 format!("https://{}.table.core.windows.net", account),
                 format!("https://{}.queue.core.windows.net", account),
@@ -231,13 +247,13 @@ where
     A: Into<Cow<'a, str>>,
     BT: Into<Cow<'a, str>>,
 {
-    let client = hyper::Client::builder().build(HttpsConnector::new());
+    let pipeline = default_pipeline();
 
-    BearerTokenClient::new(account.into(), bearer_token.into(), client)
+    BearerTokenClient::new(account.into(), bearer_token.into(), pipeline)
 }
 
 pub fn with_emulator(blob_storage_url: &Url, table_storage_url: &Url) -> KeyClient {
-    let client = hyper::Client::builder().build(HttpsConnector::new());
+    let pipeline = default_pipeline();
 
     let blob_uri = format!("{}devstoreaccount1", blob_storage_url.as_str());
     debug!("blob_uri == {}", blob_uri);
@@ -253,7 +269,7 @@ pub fn with_emulator(blob_storage_url: &Url, table_storage_url: &Url) -> KeyClie
         "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw=="
             .to_owned(),
         None,
-        client,
+        pipeline,
         blob_uri,
         table_uri,
         queue_uri,
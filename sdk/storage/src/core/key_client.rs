@@ -1,9 +1,11 @@
 use crate::core::rest_client::{perform_request, ServiceType};
-use crate::core::{Client, ClientEndpoint, HyperClientEndpoint};
+use crate::core::{Client, ClientEndpoint, PipelineEndpoint};
 use azure_core::errors::AzureError;
+use azure_core::pipeline::Pipeline;
+use azure_core::AzureEnvironment;
+use azure_core::ResponseFuture;
 use http::request::Builder;
-use hyper::{self, Method};
-use hyper_rustls::HttpsConnector;
+use hyper::Method;
 use url::Url;
 
 #[derive(Debug, Clone)]
@@ -11,7 +13,7 @@ pub struct KeyClient {
     account: String,
     key: String,
     sas_token: Option<Vec<(String, String)>>,
-    hc: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+    pipeline: Pipeline,
     blob_uri: String,
     table_uri: String,
     queue_uri: String,
@@ -36,7 +38,7 @@ impl KeyClient {
         account: String,
         key: String,
         sas_token: Option<Vec<(String, String)>>,
-        hc: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+        pipeline: Pipeline,
         blob_uri: String,
         table_uri: String,
         queue_uri: String,
@@ -46,7 +48,7 @@ impl KeyClient {
             account,
             key,
             sas_token,
-            hc,
+            pipeline,
             blob_uri,
             table_uri,
             queue_uri,
@@ -54,6 +56,23 @@ impl KeyClient {
         }
     }
 
+    /// Replaces the pipeline the requests of this client are sent through.
+    pub fn with_pipeline(self, pipeline: Pipeline) -> Self {
+        Self { pipeline, ..self }
+    }
+
+    /// Points the client to the storage endpoints of another Azure cloud.
+    pub fn with_environment(self, environment: &AzureEnvironment) -> Self {
+        let suffix = environment.storage_endpoint_suffix();
+        Self {
+            blob_uri: format!("https://{}.blob.{}", self.account, suffix),
+            table_uri: format!("https://{}.table.{}", self.account, suffix),
+            queue_uri: format!("https://{}.queue.{}", self.account, suffix),
+            filesystem_uri: format!("https://{}.dfs.{}", self.account, suffix),
+            ..self
+        }
+    }
+
     fn add_sas_token_to_uri(&self, uri: &str) -> String {
         match &self.sas_token {
             Some(token) => Url::parse_with_params(uri, token).unwrap().to_string(),
@@ -89,7 +108,7 @@ impl Client for KeyClient {
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         let uri = self.add_sas_token_to_uri(uri);
 
         perform_request(
@@ -108,7 +127,7 @@ impl Client for KeyClient {
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_str: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         debug!("segment: {}, method: {:?}", segment, method,);
 
         let uri =
@@ -135,8 +154,8 @@ impl ClientEndpoint for KeyClient {
     }
 }
 
-impl HyperClientEndpoint for KeyClient {
-    fn hyper_client(&self) -> &hyper::Client<HttpsConnector<hyper::client::HttpConnector>> {
-        &self.hc
+impl PipelineEndpoint for KeyClient {
+    fn pipeline(&self) -> &Pipeline {
+        &self.pipeline
     }
 }
@@ -5,9 +5,9 @@ mod client_endpoint;
 mod connection_string;
 mod connection_string_builder;
 mod container_sas_builder;
-mod hyper_client_endpoint;
 mod into_azure_path;
 pub mod key_client;
+mod pipeline_endpoint;
 pub mod prelude;
 mod rest_client;
 pub mod shared_access_signature;
@@ -25,7 +25,7 @@ use azure_core::util::HeaderMapExt;
 pub use client::Client;
 pub use client_endpoint::ClientEndpoint;
 use http::HeaderMap;
-pub use hyper_client_endpoint::HyperClientEndpoint;
+pub use pipeline_endpoint::PipelineEndpoint;
 
 pub trait ClientRequired<'a, C>
 where
@@ -0,0 +1,6 @@
+use crate::ClientEndpoint;
+use azure_core::pipeline::Pipeline;
+
+pub trait PipelineEndpoint: ClientEndpoint {
+    fn pipeline(&self) -> &Pipeline;
+}
@@ -1,12 +1,15 @@
 use crate::IPRange;
-use crate::{ClientEndpoint, HyperClientEndpoint};
+use crate::{ClientEndpoint, PipelineEndpoint};
 use azure_core::errors::AzureError;
 use azure_core::headers;
 use azure_core::util::{format_header_value, HeaderMapExt, RequestBuilderExt};
+use azure_core::{Context, ResponseFuture};
+use bytes::Bytes;
 use chrono::{DateTime, Utc};
 use http::request::Builder;
 use hyper::{self, header, HeaderMap, Method};
 use ring::hmac;
+use std::borrow::Cow;
 use std::fmt::Write;
 use url::form_urlencoded;
 
@@ -18,7 +21,7 @@ pub enum ServiceType {
     Table,
 }
 
-pub(crate) const AZURE_VERSION: &str = "2019-07-07";
+pub(crate) const AZURE_VERSION: &str = "2019-12-12";
 pub(crate) const SAS_VERSION: &str = "2019-02-02";
 
 pub(crate) const HEADER_VERSION: &str = "x-ms-version"; //=> [String] }
@@ -492,18 +495,44 @@ fn lexy_sort(vec: &url::form_urlencoded::Parse, query_param: &str) -> Vec<String
     v_values
 }
 
+/// Adds the time left before the deadline of the current [`Context`] as the
+/// `timeout` query parameter, unless the caller already set one, so the
+/// service gives up on the request when the caller does.
+pub(crate) fn add_context_timeout(uri: &str) -> Cow<'_, str> {
+    let remaining = match Context::current().and_then(|ctx| ctx.remaining()) {
+        Some(remaining) => remaining,
+        None => return Cow::Borrowed(uri),
+    };
+    let has_timeout = url::Url::parse(uri)
+        .map(|url| url.query_pairs().any(|(name, _)| name == "timeout"))
+        .unwrap_or(false);
+    if has_timeout {
+        return Cow::Borrowed(uri);
+    }
+
+    // the service wants whole seconds and rejects 0
+    let seconds = std::cmp::max(
+        1,
+        remaining.as_secs() + (remaining.subsec_nanos() > 0) as u64,
+    );
+    let separator = if uri.contains('?') { '&' } else { '?' };
+    Cow::Owned(format!("{}{}timeout={}", uri, separator, seconds))
+}
+
 #[allow(unknown_lints)]
-pub fn perform_request<HCE: HyperClientEndpoint>(
-    hyper_client_endpoint: &HCE,
+pub fn perform_request<PE: PipelineEndpoint>(
+    pipeline_endpoint: &PE,
     uri: &str,
     http_method: &Method,
     http_header_adder: &dyn Fn(Builder) -> Builder,
     request_body: Option<&[u8]>,
     service_type: ServiceType,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     let dt = chrono::Utc::now();
     let time = format!("{}", dt.format("%a, %d %h %Y %T GMT"));
 
+    let uri = add_context_timeout(uri);
+    let uri = uri.as_ref();
     let url = url::Url::parse(uri)?;
 
     // for header in additional_headers.iter() {
@@ -530,16 +559,14 @@ pub fn perform_request<HCE: HyperClientEndpoint>(
         .header_bytes(HEADER_DATE, time)
         .header_static(HEADER_VERSION, AZURE_VERSION);
 
-    let b = request_body
-        .map(|v| Vec::from(v).into())
-        .unwrap_or_else(hyper::Body::empty);
+    let b = request_body.map(Bytes::copy_from_slice).unwrap_or_default();
     let mut request = request.body(b)?;
 
     // We sign the request only if it is not already signed (with the signature of an
     // SAS token for example)
     if url.query_pairs().find(|p| p.0 == "sig").is_none() {
         let auth = generate_authorization(
-            hyper_client_endpoint,
+            pipeline_endpoint,
             request.headers(),
             &url,
             http_method,
@@ -550,7 +577,8 @@ pub fn perform_request<HCE: HyperClientEndpoint>(
             .insert(header::AUTHORIZATION, format_header_value(auth)?);
     }
 
-    Ok(hyper_client_endpoint.hyper_client().request(request))
+    let pipeline = pipeline_endpoint.pipeline().clone();
+    Ok(Box::pin(async move { pipeline.execute(request).await }))
 }
 
 #[inline]
@@ -769,4 +797,30 @@ Wed, 03 May 2017 14:04:56 GMT
             "YuKoXELO9M9HXeeGaSXBr4Nk+CgPAEQhcwJ6tVtBRCw=".to_owned()
         );
     }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn context_deadline_becomes_the_timeout() {
+        let uri = "https://account.blob.core.windows.net/container?restype=container";
+        assert_eq!(add_context_timeout(uri), uri);
+
+        let ctx = Context::new().with_timeout(std::time::Duration::from_millis(29_500));
+        let uris = ctx
+            .run(async {
+                Ok::<_, AzureError>((
+                    add_context_timeout(uri).into_owned(),
+                    add_context_timeout("https://account.blob.core.windows.net/c/b").into_owned(),
+                    add_context_timeout(&format!("{}&timeout=5", uri)).into_owned(),
+                ))
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(uris.0, format!("{}&timeout=30", uri));
+        assert_eq!(
+            uris.1,
+            "https://account.blob.core.windows.net/c/b?timeout=30"
+        );
+        assert_eq!(uris.2, format!("{}&timeout=5", uri));
+    }
 }
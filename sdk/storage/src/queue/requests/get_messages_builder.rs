@@ -2,6 +2,7 @@ use crate::core::prelude::*;
 use crate::queue::prelude::*;
 use crate::responses::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -166,41 +167,44 @@ where
     C: Client,
 {
     pub async fn execute(self) -> Result<GetMessagesResponse, AzureError> {
-        let mut uri = format!(
-            "{}/{}/messages",
-            self.queue_name_service.storage_client().queue_uri(),
-            self.queue_name_service.queue_name()
-        );
-
-        let mut concatenation_char = '?';
-
-        if let Some(nm) = VisibilityTimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}{}{}", uri, concatenation_char, nm);
-            concatenation_char = '&';
-        }
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}{}{}", uri, concatenation_char, nm);
-            concatenation_char = '&';
-        }
-        if let Some(nm) = NumberOfMessagesOption::to_uri_parameter(&self) {
-            uri = format!("{}{}{}", uri, concatenation_char, nm);
-        }
-
-        debug!("uri == {}", uri);
-
-        let future_response = self.queue_name_service.storage_client().perform_request(
-            &uri,
-            &http::Method::GET,
-            &|mut request| {
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            Some(&[]),
-        )?;
-
-        let (headers, body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
-
-        (&headers, &body as &[u8]).try_into()
+        instrument("GetMessagesBuilder::execute", async move {
+            let mut uri = format!(
+                "{}/{}/messages",
+                self.queue_name_service.storage_client().queue_uri(),
+                self.queue_name_service.queue_name()
+            );
+
+            let mut concatenation_char = '?';
+
+            if let Some(nm) = VisibilityTimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}{}{}", uri, concatenation_char, nm);
+                concatenation_char = '&';
+            }
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}{}{}", uri, concatenation_char, nm);
+                concatenation_char = '&';
+            }
+            if let Some(nm) = NumberOfMessagesOption::to_uri_parameter(&self) {
+                uri = format!("{}{}{}", uri, concatenation_char, nm);
+            }
+
+            debug!("uri == {}", uri);
+
+            let future_response = self.queue_name_service.storage_client().perform_request(
+                &uri,
+                &http::Method::GET,
+                &|mut request| {
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                Some(&[]),
+            )?;
+
+            let (headers, body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+
+            (&headers, &body as &[u8]).try_into()
+        })
+        .await
     }
 }
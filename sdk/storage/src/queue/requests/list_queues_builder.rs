@@ -2,6 +2,7 @@ use crate::core::prelude::*;
 use crate::queue::prelude::*;
 use crate::queue::responses::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -237,42 +238,45 @@ where
     C: Client,
 {
     pub async fn execute(self) -> Result<ListQueuesResponse, AzureError> {
-        let mut uri = format!(
-            "{}?comp=list",
-            self.queue_service.storage_client().queue_uri()
-        );
+        instrument("ListQueuesBuilder::execute", async move {
+            let mut uri = format!(
+                "{}?comp=list",
+                self.queue_service.storage_client().queue_uri()
+            );
 
-        if let Some(nm) = IncludeMetadataOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-        if let Some(nm) = MaxResultsOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-        if let Some(nm) = NextMarkerOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-        if let Some(nm) = PrefixOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
+            if let Some(nm) = IncludeMetadataOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+            if let Some(nm) = MaxResultsOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+            if let Some(nm) = NextMarkerOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+            if let Some(nm) = PrefixOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
 
-        debug!("uri == {}", uri);
+            debug!("uri == {}", uri);
 
-        let future_response = self.queue_service.storage_client().perform_request(
-            &uri,
-            &http::Method::GET,
-            &|mut request| {
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            Some(&[]),
-        )?;
+            let future_response = self.queue_service.storage_client().perform_request(
+                &uri,
+                &http::Method::GET,
+                &|mut request| {
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                Some(&[]),
+            )?;
 
-        let (headers, body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+            let (headers, body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
 
-        (&headers, &body as &[u8]).try_into()
+            (&headers, &body as &[u8]).try_into()
+        })
+        .await
     }
 }
@@ -1,6 +1,7 @@
 use crate::queue::*;
 use crate::responses::*;
 use azure_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_core::instrumentation::instrument;
 use azure_core::prelude::*;
 use azure_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -224,45 +225,48 @@ where
     C: Client,
 {
     pub async fn execute(self) -> Result<PutMessageResponse, AzureError> {
-        let mut uri = format!(
-            "{}/{}/messages",
-            self.queue_name_service.storage_client().queue_uri(),
-            self.queue_name_service.queue_name()
-        );
+        instrument("PutMessageBuilder::execute", async move {
+            let mut uri = format!(
+                "{}/{}/messages",
+                self.queue_name_service.storage_client().queue_uri(),
+                self.queue_name_service.queue_name()
+            );
 
-        uri = format!("{}?{}", uri, MessageTTLRequired::to_uri_parameter(&self));
-        if let Some(nm) = VisibilityTimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
-        }
+            uri = format!("{}?{}", uri, MessageTTLRequired::to_uri_parameter(&self));
+            if let Some(nm) = VisibilityTimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
+            if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+                uri = format!("{}&{}", uri, nm);
+            }
 
-        debug!("uri == {}", uri);
+            debug!("uri == {}", uri);
 
-        // since the format is fixed we just decorate the message with the tags.
-        // This could be made optional in the future and/or more
-        // stringent.
-        let message = format!(
-            "<QueueMessage><MessageText>{}</MessageText></QueueMessage>",
-            self.message_body()
-        );
+            // since the format is fixed we just decorate the message with the tags.
+            // This could be made optional in the future and/or more
+            // stringent.
+            let message = format!(
+                "<QueueMessage><MessageText>{}</MessageText></QueueMessage>",
+                self.message_body()
+            );
 
-        debug!("message about to be posted == {}", message);
+            debug!("message about to be posted == {}", message);
 
-        let future_response = self.queue_name_service.storage_client().perform_request(
-            &uri,
-            &http::Method::POST,
-            &|mut request| {
-                request = ClientRequestIdOption::add_header(&self, request);
-                request
-            },
-            Some(message.as_bytes()),
-        )?;
+            let future_response = self.queue_name_service.storage_client().perform_request(
+                &uri,
+                &http::Method::POST,
+                &|mut request| {
+                    request = ClientRequestIdOption::add_header(&self, request);
+                    request
+                },
+                Some(message.as_bytes()),
+            )?;
 
-        let (headers, body) =
-            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+            let (headers, body) =
+                check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
 
-        (&headers, &body as &[u8]).try_into()
+            (&headers, &body as &[u8]).try_into()
+        })
+        .await
     }
 }
@@ -3,9 +3,10 @@ use crate::{
     entity_path, get_batch_mime, Batch, Continuation, MetadataDetail, TableClient, TableEntity,
 };
 use azure_core::errors::{
-    check_status_extract_body, check_status_extract_headers_and_body, AzureError,
+    check_status_extract_body, check_status_extract_headers_and_body, AzureError, ErrorKind,
 };
-use futures::stream::Stream;
+use azure_core::incompletevector::ContinuationToken;
+use azure_core::{Page, Pageable};
 use hyper::{header, Method, StatusCode};
 use serde::{de::DeserializeOwned, Serialize};
 use std::convert::TryFrom;
@@ -40,7 +41,7 @@ where
     /// Creates the table in the storage service using default request options if it does not already exist.
     pub async fn create_if_not_exists(&self) -> Result<(), AzureError> {
         self.create().await.or_else(|err| match err {
-            AzureError::UnexpectedHTTPResult(e) if e.status_code() == 409 => Ok(()),
+            AzureError::HttpError(e) if e.kind() == ErrorKind::Conflict => Ok(()),
             e => Err(e),
         })
     }
@@ -69,7 +70,7 @@ where
         )?;
         let (headers, body) =
             match check_status_extract_headers_and_body(future_response, StatusCode::OK).await {
-                Err(AzureError::UnexpectedHTTPResult(e)) if e.status_code() == 404 => {
+                Err(AzureError::HttpError(e)) if e.kind() == ErrorKind::NotFound => {
                     return Ok(None)
                 }
                 x => x,
@@ -294,20 +295,29 @@ where
         Ok(Some(entities.value))
     }
 
+    /// Runs the query page by page. `Pageable::with_continuation_token`
+    /// resumes it from the token of a `Continuation`.
     pub fn stream_query<'a, T>(
         &'a self,
         query: Option<&'a str>,
-    ) -> impl Stream<Item = Result<Vec<TableEntity<T>>, AzureError>> + 'a
+    ) -> Pageable<'a, QueryEntitiesResponse<T>>
     where
-        T: Serialize + DeserializeOwned + 'a,
+        T: Serialize + DeserializeOwned + Send + 'a,
     {
-        futures::stream::unfold(Continuation::start(), move |mut cont| async move {
-            log::debug!("cont == {:?}", cont);
-            match self.execute_query::<T>(query, &mut cont).await {
-                Ok(Some(segment)) => Some((Ok(segment), cont)),
-                Ok(None) => None,
-                Err(err) => Some((Err(err), cont)),
-            }
+        Pageable::new(None, move |token: Option<ContinuationToken>| async move {
+            let mut continuation = match token {
+                Some(token) => Continuation::from_token(&token)?,
+                None => Continuation::start(),
+            };
+            log::debug!("continuation == {:?}", continuation);
+            let entities = self
+                .execute_query::<T>(query, &mut continuation)
+                .await?
+                .unwrap_or_default();
+            Ok(QueryEntitiesResponse {
+                entities,
+                continuation,
+            })
         })
     }
 
@@ -330,6 +340,25 @@ where
     }
 }
 
+/// A page of the entities matching a query.
+#[derive(Debug, Clone)]
+pub struct QueryEntitiesResponse<T> {
+    pub entities: Vec<TableEntity<T>>,
+    pub continuation: Continuation,
+}
+
+impl<T> Page for QueryEntitiesResponse<T> {
+    type Item = TableEntity<T>;
+
+    fn continuation(&self) -> Option<ContinuationToken> {
+        self.continuation.token()
+    }
+
+    fn into_items(self) -> Vec<TableEntity<T>> {
+        self.entities
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct EntityCollection<T> {
     value: Vec<TableEntity<T>>,
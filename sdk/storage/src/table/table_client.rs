@@ -5,11 +5,9 @@ use crate::core::{
     ConnectionString, ServiceType,
 };
 use azure_core::errors::{check_status_extract_body, AzureError};
+use azure_core::ResponseFuture;
 use http::request::Builder;
-use hyper::{
-    client::ResponseFuture,
-    header::{self, HeaderValue},
-};
+use hyper::header::{self, HeaderValue};
 use hyper::{Method, StatusCode};
 use log;
 use serde_json;
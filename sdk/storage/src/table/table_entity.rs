@@ -1,5 +1,6 @@
 use crate::de;
 use azure_core::errors::AzureError;
+use azure_core::incompletevector::ContinuationToken;
 use chrono::{DateTime, Utc};
 use http::header;
 use http::HeaderMap;
@@ -52,7 +53,7 @@ where
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub(crate) struct ContinuationCursor {
     pub(crate) partition_key: String,
     pub(crate) row_key: String,
@@ -71,6 +72,22 @@ impl Continuation {
             next: None,
         }
     }
+
+    /// Resumes a query from the token returned by `token`.
+    pub fn from_token(token: &str) -> Result<Self, AzureError> {
+        Ok(Continuation {
+            fused: false,
+            next: Some(serde_json::from_str(token)?),
+        })
+    }
+
+    /// The `NextPartitionKey` and `NextRowKey` of the query as a single
+    /// token, `None` once every entity has been read.
+    pub fn token(&self) -> Option<ContinuationToken> {
+        self.next
+            .as_ref()
+            .map(|next| serde_json::to_string(next).expect("two strings always serialize"))
+    }
 }
 
 impl std::convert::TryFrom<&HeaderMap> for Continuation {
@@ -2,6 +2,7 @@
 #[macro_use]
 extern crate log;
 
+use azure_core::mock_transport::{self, TestMode};
 use azure_core::prelude::*;
 use azure_core::{Consistency, DeleteSnapshotsMethod};
 use azure_storage::blob::{
@@ -10,16 +11,17 @@ use azure_storage::blob::{
     prelude::*,
 };
 use azure_storage::core::prelude::*;
+use azure_storage::key_client::KeyClient;
 use chrono::{Duration, FixedOffset, Utc};
+use std::collections::HashMap;
 use std::ops::Add;
 use std::ops::Deref;
-use uuid::Uuid;
 
 #[tokio::test]
 async fn create_and_delete_container() {
     let name: &'static str = "azuresdkrustetoets";
 
-    let client = initialize();
+    let client = initialize_recorded("create_and_delete_container");
     client
         .create_container()
         .with_container_name(name)
@@ -92,7 +94,7 @@ async fn create_and_delete_container() {
     let cont_list: Vec<&Container> = list
         .incomplete_vector
         .deref()
-        .into_iter()
+        .iter()
         .filter(|e| e.name == name)
         .collect();
 
@@ -128,11 +130,10 @@ async fn create_and_delete_container() {
 
 #[tokio::test]
 async fn put_and_get_block_list() {
-    let u = Uuid::new_v4();
-    let container = Container::new(&format!("sdkrust{}", u));
+    let container = Container::new("sdkrustputblocklist");
     let name = "asd - ()krustputblock.txt";
 
-    let client = initialize();
+    let client = initialize_recorded("put_and_get_block_list");
 
     client
         .create_container()
@@ -152,7 +153,7 @@ async fn put_and_get_block_list() {
         .put_block()
         .with_container_name(&container.name)
         .with_blob_name(name)
-        .with_body(&contents1.as_bytes())
+        .with_body(contents1.as_bytes())
         .with_block_id(b"block1")
         .finalize()
         .await
@@ -167,7 +168,7 @@ async fn put_and_get_block_list() {
         .put_block()
         .with_container_name(&container.name)
         .with_blob_name(name)
-        .with_body(&contents2.as_bytes())
+        .with_body(contents2.as_bytes())
         .with_block_id(b"block2")
         .finalize()
         .await
@@ -177,7 +178,7 @@ async fn put_and_get_block_list() {
         .put_block()
         .with_container_name(&container.name)
         .with_blob_name(name)
-        .with_body(&contents3.as_bytes())
+        .with_body(contents3.as_bytes())
         .with_block_id(b"block3")
         .with_content_md5(&digest3[..])
         .finalize()
@@ -271,7 +272,7 @@ async fn put_and_get_block_list() {
 
 #[tokio::test]
 async fn list_containers() {
-    let client = initialize();
+    let client = initialize_recorded("list_containers");
 
     trace!("running list_containers");
 
@@ -298,7 +299,7 @@ async fn list_containers() {
 
 #[tokio::test]
 async fn put_block_blob() {
-    let client = initialize();
+    let client = initialize_recorded("put_block_blob");
 
     let blob_name: &'static str = "m1";
     let container_name: &'static str = "rust-upload-test";
@@ -328,8 +329,8 @@ async fn put_block_blob() {
 
     client
         .put_block_blob()
-        .with_container_name(&container_name)
-        .with_blob_name(&blob_name)
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
         .with_content_type("text/plain")
         .with_body(&data[..])
         .with_content_md5(&digest[..])
@@ -340,9 +341,386 @@ async fn put_block_blob() {
     trace!("created {:?}", blob_name);
 }
 
+#[tokio::test]
+async fn upload_blob() {
+    let client = initialize_recorded("upload_blob");
+
+    let blob_name: &'static str = "uploaded";
+    let container_name: &'static str = "rust-upload-test";
+    let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+
+    if client
+        .list_containers()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector
+        .iter()
+        .find(|x| x.name == container_name)
+        .is_none()
+    {
+        client
+            .create_container()
+            .with_container_name(container_name)
+            .with_public_access(PublicAccess::Blob)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    client
+        .upload_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_block_size(16 * 1024)
+        .with_parallelism(3)
+        .with_block_checksum(BlockChecksum::Crc64)
+        .with_content_type("application/octet-stream")
+        .finalize_from_reader(&data[..])
+        .await
+        .unwrap();
+
+    let block_list = client
+        .get_block_list()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_block_list_type(BlockListType::Committed)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(block_list.block_with_size_list.blocks.len(), 7);
+
+    let blob = client
+        .get_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(blob.data, data);
+    assert_eq!(
+        blob.blob.content_type.as_deref(),
+        Some("application/octet-stream")
+    );
+}
+
+#[tokio::test]
+async fn download_blob() {
+    let client = initialize_recorded("download_blob");
+
+    let blob_name: &'static str = "downloaded";
+    let container_name: &'static str = "rust-upload-test";
+    let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+
+    if client
+        .list_containers()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector
+        .iter()
+        .find(|x| x.name == container_name)
+        .is_none()
+    {
+        client
+            .create_container()
+            .with_container_name(container_name)
+            .with_public_access(PublicAccess::Blob)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    client
+        .put_block_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_content_type("application/octet-stream")
+        .with_body(&data[..])
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut downloaded = Vec::new();
+    let response = client
+        .download_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_chunk_size(16 * 1024)
+        .with_parallelism(3)
+        .finalize_to_writer(&mut downloaded)
+        .await
+        .unwrap();
+    assert_eq!(response.blob.content_length, data.len() as u64);
+    assert_eq!(downloaded, data);
+
+    let range = Range::new(50_000, 70_000);
+    let mut downloaded = Vec::new();
+    client
+        .download_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_range(&range)
+        .with_chunk_size(16 * 1024)
+        .finalize_to_writer(&mut downloaded)
+        .await
+        .unwrap();
+    assert_eq!(downloaded, &data[50_000..70_000]);
+}
+
+#[tokio::test]
+async fn blob_properties_and_metadata() {
+    let client = initialize_recorded("blob_properties_and_metadata");
+
+    let blob_name: &'static str = "properties";
+    let container_name: &'static str = "rust-upload-test";
+    let data = b"abcdef";
+
+    if client
+        .list_containers()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector
+        .iter()
+        .find(|x| x.name == container_name)
+        .is_none()
+    {
+        client
+            .create_container()
+            .with_container_name(container_name)
+            .with_public_access(PublicAccess::Blob)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    client
+        .put_block_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_content_type("text/plain")
+        .with_body(&data[..])
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut metadata = HashMap::new();
+    metadata.insert("pollo", "arrosto");
+    metadata.insert("milk", "shake");
+    client
+        .set_blob_metadata()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_metadata(&metadata)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .set_blob_properties()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_content_type("application/json")
+        .with_cache_control("no-cache")
+        .finalize()
+        .await
+        .unwrap();
+
+    let blob = client
+        .get_blob_properties()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap()
+        .blob;
+    assert_eq!(blob.content_length, data.len() as u64);
+    assert_eq!(blob.content_type.as_deref(), Some("application/json"));
+    assert_eq!(blob.cache_control.as_deref(), Some("no-cache"));
+    assert_eq!(blob.metadata.len(), 2);
+    assert_eq!(blob.metadata["pollo"], "arrosto");
+    assert_eq!(blob.metadata["milk"], "shake");
+
+    client
+        .set_blob_metadata()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap();
+
+    let blob = client
+        .get_blob_properties()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap()
+        .blob;
+    assert!(blob.metadata.is_empty());
+}
+
+#[tokio::test]
+async fn snapshot_blob() {
+    let client = initialize_recorded("snapshot_blob");
+
+    let blob_name: &'static str = "snapshotted";
+    let container_name: &'static str = "rust-upload-test";
+
+    if client
+        .list_containers()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector
+        .iter()
+        .find(|x| x.name == container_name)
+        .is_none()
+    {
+        client
+            .create_container()
+            .with_container_name(container_name)
+            .with_public_access(PublicAccess::Blob)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    client
+        .put_block_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_body(b"first")
+        .finalize()
+        .await
+        .unwrap();
+
+    let snapshot = client
+        .snapshot_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap()
+        .snapshot;
+
+    client
+        .put_block_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_body(b"second")
+        .finalize()
+        .await
+        .unwrap();
+
+    let data = client
+        .get_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_snapshot(snapshot)
+        .finalize()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(data, b"first");
+
+    let blobs = client
+        .list_blobs()
+        .with_container_name(container_name)
+        .with_prefix(blob_name)
+        .with_include_snapshots()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector;
+    assert!(blobs
+        .iter()
+        .any(|blob| blob.name == blob_name && blob.snapshot_time == Some(snapshot)));
+
+    client
+        .delete_blob_snapshot()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_snapshot(snapshot)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn set_blob_tier() {
+    let client = initialize_recorded("set_blob_tier");
+
+    let blob_name: &'static str = "tiered";
+    let container_name: &'static str = "rust-upload-test";
+
+    if client
+        .list_containers()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector
+        .iter()
+        .find(|x| x.name == container_name)
+        .is_none()
+    {
+        client
+            .create_container()
+            .with_container_name(container_name)
+            .with_public_access(PublicAccess::Blob)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    client
+        .put_block_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_body(b"tiered")
+        .with_access_tier(AccessTier::Cool)
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .set_blob_tier()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_access_tier(AccessTier::Hot)
+        .finalize()
+        .await
+        .unwrap();
+    assert!(!response.rehydrate_pending);
+
+    let blob = client
+        .get_blob_properties()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap()
+        .blob;
+    assert_eq!(blob.access_tier.as_deref(), Some("Hot"));
+
+    // a blob that is not archived is readable right away
+    let blob = client
+        .rehydrate_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_access_tier(AccessTier::Cool)
+        .finalize()
+        .await
+        .unwrap()
+        .blob;
+    assert_eq!(blob.access_tier.as_deref(), Some("Cool"));
+    assert_eq!(blob.archive_status, None);
+}
+
 #[tokio::test]
 async fn copy_blob() {
-    let client = initialize();
+    let client = initialize_recorded("copy_blob");
 
     let blob_name: &'static str = "copysrc";
     let container_name: &'static str = "rust-upload-test";
@@ -372,8 +750,8 @@ async fn copy_blob() {
 
     client
         .put_block_blob()
-        .with_container_name(&container_name)
-        .with_blob_name(&blob_name)
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
         .with_content_type("text/plain")
         .with_body(&data[..])
         .with_content_md5(&digest[..])
@@ -386,23 +764,42 @@ async fn copy_blob() {
     client
         .copy_blob()
         .with_source_url(&format!(
-            "https://{}.blob.core.windows.net/{}/{}",
-            &std::env::var("STORAGE_ACCOUNT").unwrap(),
+            "{}/{}/{}",
+            client.blob_uri(),
             &container_name,
             &blob_name
         ))
-        .with_container_name(&container_name)
+        .with_container_name(container_name)
         .with_blob_name("cloned_blob")
         .finalize()
         .await
         .unwrap();
 }
 
-fn initialize() -> Box<dyn Client> {
+fn initialize() -> KeyClient {
     let account =
         std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
     let master_key =
         std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
 
-    Box::new(client::with_access_key(&account, &master_key))
+    client::with_access_key(&account, &master_key)
+}
+
+/// Creates a client for the test environment, or one that records its traffic
+/// or plays it back, according to `AZURE_TEST_MODE` (see
+/// `azure_core::mock_transport`).
+///
+/// `tests/fixtures/<recording_name>.json` are hand-written fixtures, not
+/// captures of the service: `AZURE_TEST_MODE=playback
+/// AZURE_TEST_RECORDINGS=tests/fixtures` only checks that the client can
+/// replay them. The live run is the coverage of these tests.
+fn initialize_recorded(recording_name: &str) -> Box<dyn Client> {
+    let (client, pipeline) = mock_transport::initialize_recorded(
+        TestMode::from_env().unwrap(),
+        recording_name,
+        || Ok(initialize()),
+        || Ok(client::with_access_key("playback", &base64::encode("key"))),
+    )
+    .unwrap();
+    Box::new(client.with_pipeline(pipeline))
 }
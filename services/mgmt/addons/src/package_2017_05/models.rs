@@ -6,6 +6,9 @@ use serde::{Deserialize, Serialize};
 pub struct CanonicalSupportPlanProperties {
     #[serde(rename = "provisioningState", skip_serializing_if = "Option::is_none")]
     pub provisioning_state: Option<canonical_support_plan_properties::ProvisioningState>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod canonical_support_plan_properties {
     use super::*;
@@ -18,6 +21,8 @@ pub mod canonical_support_plan_properties {
         Downgrading,
         Cancelling,
         Upgrading,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -29,6 +34,9 @@ pub struct CanonicalSupportPlanResponseEnvelope {
     #[serde(rename = "type", skip_serializing)]
     pub type_: Option<String>,
     pub properties: CanonicalSupportPlanProperties,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub type CanonicalSupportPlanStatus = Vec<serde_json::Value>;
 pub type OperationList = Vec<OperationsDefinition>;
@@ -38,6 +46,9 @@ pub struct OperationsDefinition {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display: Option<OperationsDisplayDefinition>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationsDisplayDefinition {
@@ -49,9 +60,15 @@ pub struct OperationsDisplayDefinition {
     pub operation: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorDefinition {
     pub message: String,
     pub code: i64,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
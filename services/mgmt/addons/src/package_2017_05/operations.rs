@@ -2,76 +2,30 @@
 #![allow(unused_mut)]
 #![allow(unused_variables)]
 #![allow(unused_imports)]
-use crate::models::*;
+use crate::package_2017_05::models::*;
 use reqwest::StatusCode;
 use snafu::{ResultExt, Snafu};
 pub mod operations {
-    use crate::models::*;
+    use crate::package_2017_05::models::*;
     use reqwest::StatusCode;
     use snafu::{ResultExt, Snafu};
     pub async fn list(operation_config: &crate::OperationConfig) -> std::result::Result<OperationList, list::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!("{}/providers/Microsoft.Addons/operations", &operation_config.base_path,);
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2017_05::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: OperationList = serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: ErrorDefinition = serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                list::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod list {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorDefinition,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
-        }
+        use crate::package_2017_05::{models, models::*};
+        pub type Error = azure_mgmt_core::Error<models::ErrorDefinition>;
     }
 }
 pub mod support_plan_types {
-    use crate::models::*;
+    use crate::package_2017_05::models::*;
     use reqwest::StatusCode;
     use snafu::{ResultExt, Snafu};
     pub async fn get(
@@ -93,19 +47,19 @@ pub mod support_plan_types {
                 .context(get::GetTokenError)?;
             req_builder = req_builder.bearer_auth(token_response.token.secret());
         }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        req_builder = req_builder.query(&[("api-version", crate::package_2017_05::API_VERSION)]);
         let req = req_builder.build().context(get::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(get::ExecuteRequestError)?;
+        let rsp = operation_config.pipeline.execute(req).await.context(get::ExecuteRequestError)?;
         match rsp.status() {
             StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
+                let body: bytes::Bytes = rsp.into_body().await.context(get::ResponseBytesError)?;
                 let rsp_value: CanonicalSupportPlanResponseEnvelope =
                     serde_json::from_slice(&body).context(get::DeserializeError { body })?;
                 Ok(rsp_value)
             }
             StatusCode::NOT_FOUND => get::NotFound404 {}.fail(),
             status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
+                let body: bytes::Bytes = rsp.into_body().await.context(get::ResponseBytesError)?;
                 let rsp_value: ErrorDefinition = serde_json::from_slice(&body).context(get::DeserializeError { body })?;
                 get::DefaultResponse {
                     status_code,
@@ -116,7 +70,7 @@ pub mod support_plan_types {
         }
     }
     pub mod get {
-        use crate::{models, models::*};
+        use crate::package_2017_05::{models, models::*};
         use reqwest::StatusCode;
         use snafu::Snafu;
         #[derive(Debug, Snafu)]
@@ -131,10 +85,10 @@ pub mod support_plan_types {
                 source: reqwest::Error,
             },
             ExecuteRequestError {
-                source: reqwest::Error,
+                source: azure_core::errors::AzureError,
             },
             ResponseBytesError {
-                source: reqwest::Error,
+                source: azure_core::errors::AzureError,
             },
             DeserializeError {
                 source: serde_json::Error,
@@ -164,25 +118,29 @@ pub mod support_plan_types {
                 .context(create_or_update::GetTokenError)?;
             req_builder = req_builder.bearer_auth(token_response.token.secret());
         }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        req_builder = req_builder.query(&[("api-version", crate::package_2017_05::API_VERSION)]);
         let req = req_builder.build().context(create_or_update::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(create_or_update::ExecuteRequestError)?;
+        let rsp = operation_config
+            .pipeline
+            .execute(req)
+            .await
+            .context(create_or_update::ExecuteRequestError)?;
         match rsp.status() {
             StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_or_update::ResponseBytesError)?;
+                let body: bytes::Bytes = rsp.into_body().await.context(create_or_update::ResponseBytesError)?;
                 let rsp_value: CanonicalSupportPlanResponseEnvelope =
                     serde_json::from_slice(&body).context(create_or_update::DeserializeError { body })?;
                 Ok(create_or_update::Response::Ok200(rsp_value))
             }
             StatusCode::CREATED => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_or_update::ResponseBytesError)?;
+                let body: bytes::Bytes = rsp.into_body().await.context(create_or_update::ResponseBytesError)?;
                 let rsp_value: CanonicalSupportPlanResponseEnvelope =
                     serde_json::from_slice(&body).context(create_or_update::DeserializeError { body })?;
                 Ok(create_or_update::Response::Created201(rsp_value))
             }
             StatusCode::NOT_FOUND => create_or_update::NotFound404 {}.fail(),
             status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_or_update::ResponseBytesError)?;
+                let body: bytes::Bytes = rsp.into_body().await.context(create_or_update::ResponseBytesError)?;
                 let rsp_value: ErrorDefinition = serde_json::from_slice(&body).context(create_or_update::DeserializeError { body })?;
                 create_or_update::DefaultResponse {
                     status_code,
@@ -193,7 +151,7 @@ pub mod support_plan_types {
         }
     }
     pub mod create_or_update {
-        use crate::{models, models::*};
+        use crate::package_2017_05::{models, models::*};
         use reqwest::StatusCode;
         use snafu::Snafu;
         #[derive(Debug)]
@@ -213,10 +171,10 @@ pub mod support_plan_types {
                 source: reqwest::Error,
             },
             ExecuteRequestError {
-                source: reqwest::Error,
+                source: azure_core::errors::AzureError,
             },
             ResponseBytesError {
-                source: reqwest::Error,
+                source: azure_core::errors::AzureError,
             },
             DeserializeError {
                 source: serde_json::Error,
@@ -233,78 +191,37 @@ pub mod support_plan_types {
         provider_name: &str,
         plan_type_name: &str,
     ) -> std::result::Result<delete::Response, delete::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.Addons/supportProviders/{}/supportPlanTypes/{}",
             &operation_config.base_path, subscription_id, provider_name, plan_type_name
         );
-        let mut req_builder = client.delete(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(delete::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(delete::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(delete::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.delete(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2017_05::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::ACCEPTED => {
-                let body: bytes::Bytes = rsp.bytes().await.context(delete::ResponseBytesError)?;
-                let rsp_value: CanonicalSupportPlanResponseEnvelope =
-                    serde_json::from_slice(&body).context(delete::DeserializeError { body })?;
-                Ok(delete::Response::Accepted202(rsp_value))
-            }
-            StatusCode::NO_CONTENT => Ok(delete::Response::NoContent204),
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(delete::ResponseBytesError)?;
-                let rsp_value: ErrorDefinition = serde_json::from_slice(&body).context(delete::DeserializeError { body })?;
-                delete::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::ACCEPTED => Ok(delete::Response::Accepted202(
+                rsp.into_poller(reqwest::Method::DELETE, uri_str, crate::package_2017_05::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::NO_CONTENT => Ok(delete::Response::NoContent204(
+                rsp.into_poller(reqwest::Method::DELETE, uri_str, crate::package_2017_05::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod delete {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2017_05::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Accepted202(CanonicalSupportPlanResponseEnvelope),
-            NoContent204,
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorDefinition,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+            Accepted202(azure_core::lro::Poller<CanonicalSupportPlanResponseEnvelope>),
+            NoContent204(azure_core::lro::Poller<()>),
         }
+        pub type Error = azure_mgmt_core::Error<models::ErrorDefinition>;
     }
 }
 pub mod canonical_support_plan_types {
-    use crate::models::*;
+    use crate::package_2017_05::models::*;
     use reqwest::StatusCode;
     use snafu::{ResultExt, Snafu};
     pub async fn get(
@@ -312,66 +229,20 @@ pub mod canonical_support_plan_types {
         subscription_id: &str,
         provider_name: &str,
     ) -> std::result::Result<CanonicalSupportPlanStatus, get::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.Addons/supportProviders/{}/supportPlanTypes",
             &operation_config.base_path, subscription_id, provider_name
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(get::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(get::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(get::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2017_05::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
-                let rsp_value: CanonicalSupportPlanStatus = serde_json::from_slice(&body).context(get::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
-                let rsp_value: ErrorDefinition = serde_json::from_slice(&body).context(get::DeserializeError { body })?;
-                get::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod get {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorDefinition,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
-        }
+        use crate::package_2017_05::{models, models::*};
+        pub type Error = azure_mgmt_core::Error<models::ErrorDefinition>;
     }
 }
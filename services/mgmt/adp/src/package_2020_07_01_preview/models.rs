@@ -22,6 +22,9 @@ pub struct AccountPatch {
     pub tags: Tags,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<AccountProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AccountProperties {
@@ -29,6 +32,9 @@ pub struct AccountProperties {
     pub account_id: Option<String>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<account_properties::ProvisioningState>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod account_properties {
     use super::*;
@@ -40,14 +46,28 @@ pub mod account_properties {
         Accepted,
         Provisioning,
         Deleting,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AccountList {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Account>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for AccountList {
+    type Item = Account;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Account> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataPool {
@@ -55,6 +75,9 @@ pub struct DataPool {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<DataPoolProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataPoolPatch {
@@ -62,6 +85,9 @@ pub struct DataPoolPatch {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<DataPoolBaseProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataPoolBaseProperties {
@@ -69,7 +95,7 @@ pub struct DataPoolBaseProperties {
     pub data_pool_id: Option<String>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<data_pool_base_properties::ProvisioningState>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub locations: Vec<DataPoolLocation>,
 }
 pub mod data_pool_base_properties {
@@ -82,23 +108,43 @@ pub mod data_pool_base_properties {
         Accepted,
         Provisioning,
         Deleting,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataPoolProperties {
     #[serde(flatten)]
     pub data_pool_base_properties: DataPoolBaseProperties,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataPoolLocation {
     pub name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataPoolList {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<DataPool>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for DataPoolList {
+    type Item = DataPool;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<DataPool> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
@@ -112,6 +158,9 @@ pub struct Operation {
     pub origin: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<OperationProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationDisplay {
@@ -123,18 +172,27 @@ pub struct OperationDisplay {
     pub operation: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationProperties {
     #[serde(rename = "serviceSpecification", skip_serializing_if = "Option::is_none")]
     pub service_specification: Option<OperationServiceSpecification>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationServiceSpecification {
-    #[serde(rename = "logSpecifications", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "logSpecifications", default, skip_serializing_if = "Vec::is_empty")]
     pub log_specifications: Vec<OperationLogSpecification>,
-    #[serde(rename = "metricSpecifications", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "metricSpecifications", default, skip_serializing_if = "Vec::is_empty")]
     pub metric_specifications: Vec<OperationMetricSpecification>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationLogSpecification {
@@ -144,6 +202,9 @@ pub struct OperationLogSpecification {
     pub display_name: Option<String>,
     #[serde(rename = "blobDuration", skip_serializing_if = "Option::is_none")]
     pub blob_duration: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationMetricSpecification {
@@ -163,8 +224,11 @@ pub struct OperationMetricSpecification {
     pub source_mdm_account: Option<String>,
     #[serde(rename = "sourceMdmNamespace", skip_serializing_if = "Option::is_none")]
     pub source_mdm_namespace: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub availabilities: Vec<OperationMetricAvailability>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationMetricAvailability {
@@ -172,18 +236,36 @@ pub struct OperationMetricAvailability {
     pub time_grain: Option<String>,
     #[serde(rename = "blobDuration", skip_serializing_if = "Option::is_none")]
     pub blob_duration: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Operation>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationListResult {
+    type Item = Operation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Operation> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ErrorDefinition>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorDefinition {
@@ -191,8 +273,11 @@ pub struct ErrorDefinition {
     pub code: Option<String>,
     #[serde(skip_serializing)]
     pub message: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub details: Vec<ErrorDefinition>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SystemData {
@@ -208,6 +293,9 @@ pub struct SystemData {
     pub last_modified_by_type: Option<system_data::LastModifiedByType>,
     #[serde(rename = "lastModifiedAt", skip_serializing_if = "Option::is_none")]
     pub last_modified_at: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod system_data {
     use super::*;
@@ -217,6 +305,8 @@ pub mod system_data {
         Application,
         ManagedIdentity,
         Key,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum LastModifiedByType {
@@ -224,6 +314,8 @@ pub mod system_data {
         Application,
         ManagedIdentity,
         Key,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
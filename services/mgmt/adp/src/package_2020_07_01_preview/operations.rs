@@ -2,278 +2,155 @@
 #![allow(unused_mut)]
 #![allow(unused_variables)]
 #![allow(unused_imports)]
-use crate::models::*;
+use crate::package_2020_07_01_preview::models::*;
 use reqwest::StatusCode;
-use snafu::{ResultExt, Snafu};
 pub mod operations {
-    use crate::models::*;
+    use crate::package_2020_07_01_preview::models::*;
     use reqwest::StatusCode;
-    use snafu::{ResultExt, Snafu};
     pub async fn list(operation_config: &crate::OperationConfig) -> std::result::Result<OperationListResult, list::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/providers/Microsoft.AutonomousDevelopmentPlatform/operations",
             &operation_config.base_path,
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_07_01_preview::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: OperationListResult = serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                list::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod list {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+        use crate::package_2020_07_01_preview::{models, models::*};
+        pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
+    }
+    pub async fn list_next(
+        operation_config: &crate::OperationConfig,
+        next_link: &str,
+    ) -> std::result::Result<OperationListResult, list::Error> {
+        let uri_str = next_link;
+        let mut req_builder = operation_config.client.get(uri_str);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
+        match rsp.status() {
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
+    pub fn list_stream<'a>(operation_config: &'a crate::OperationConfig) -> azure_core::Pageable<'a, OperationListResult, list::Error> {
+        azure_core::Pageable::new(None, move |next_link: Option<String>| async move {
+            match next_link {
+                Some(next_link) => list_next(operation_config, &next_link).await,
+                None => list(operation_config).await,
+            }
+        })
+    }
 }
 pub mod accounts {
-    use crate::models::*;
+    use crate::package_2020_07_01_preview::models::*;
     use reqwest::StatusCode;
-    use snafu::{ResultExt, Snafu};
     pub async fn list(operation_config: &crate::OperationConfig, subscription_id: &str) -> std::result::Result<AccountList, list::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.AutonomousDevelopmentPlatform/accounts",
             &operation_config.base_path, subscription_id
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_07_01_preview::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: AccountList = serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                list::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod list {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+        use crate::package_2020_07_01_preview::{models, models::*};
+        pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
+    }
+    pub async fn list_next(operation_config: &crate::OperationConfig, next_link: &str) -> std::result::Result<AccountList, list::Error> {
+        let uri_str = next_link;
+        let mut req_builder = operation_config.client.get(uri_str);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
+        match rsp.status() {
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
+    pub fn list_stream<'a>(
+        operation_config: &'a crate::OperationConfig,
+        subscription_id: &'a str,
+    ) -> azure_core::Pageable<'a, AccountList, list::Error> {
+        azure_core::Pageable::new(None, move |next_link: Option<String>| async move {
+            match next_link {
+                Some(next_link) => list_next(operation_config, &next_link).await,
+                None => list(operation_config, subscription_id).await,
+            }
+        })
+    }
     pub async fn list_by_resource_group(
         operation_config: &crate::OperationConfig,
         subscription_id: &str,
         resource_group_name: &str,
     ) -> std::result::Result<AccountList, list_by_resource_group::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AutonomousDevelopmentPlatform/accounts",
             &operation_config.base_path, subscription_id, resource_group_name
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list_by_resource_group::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list_by_resource_group::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list_by_resource_group::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_07_01_preview::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_by_resource_group::ResponseBytesError)?;
-                let rsp_value: AccountList = serde_json::from_slice(&body).context(list_by_resource_group::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_by_resource_group::ResponseBytesError)?;
-                let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(list_by_resource_group::DeserializeError { body })?;
-                list_by_resource_group::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod list_by_resource_group {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+        use crate::package_2020_07_01_preview::{models, models::*};
+        pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
+    }
+    pub async fn list_by_resource_group_next(
+        operation_config: &crate::OperationConfig,
+        next_link: &str,
+    ) -> std::result::Result<AccountList, list_by_resource_group::Error> {
+        let uri_str = next_link;
+        let mut req_builder = operation_config.client.get(uri_str);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
+        match rsp.status() {
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
+    pub fn list_by_resource_group_stream<'a>(
+        operation_config: &'a crate::OperationConfig,
+        subscription_id: &'a str,
+        resource_group_name: &'a str,
+    ) -> azure_core::Pageable<'a, AccountList, list_by_resource_group::Error> {
+        azure_core::Pageable::new(None, move |next_link: Option<String>| async move {
+            match next_link {
+                Some(next_link) => list_by_resource_group_next(operation_config, &next_link).await,
+                None => list_by_resource_group(operation_config, subscription_id, resource_group_name).await,
+            }
+        })
+    }
     pub async fn get(
         operation_config: &crate::OperationConfig,
         subscription_id: &str,
         resource_group_name: &str,
         account_name: &str,
     ) -> std::result::Result<Account, get::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AutonomousDevelopmentPlatform/accounts/{}",
             &operation_config.base_path, subscription_id, resource_group_name, account_name
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(get::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(get::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(get::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_07_01_preview::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
-                let rsp_value: Account = serde_json::from_slice(&body).context(get::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
-                let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(get::DeserializeError { body })?;
-                get::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod get {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
-        }
+        use crate::package_2020_07_01_preview::{models, models::*};
+        pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
     }
     pub async fn create_or_update(
         operation_config: &crate::OperationConfig,
@@ -282,80 +159,36 @@ pub mod accounts {
         account_name: &str,
         parameters: Option<&Account>,
     ) -> std::result::Result<create_or_update::Response, create_or_update::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AutonomousDevelopmentPlatform/accounts/{}",
             &operation_config.base_path, subscription_id, resource_group_name, account_name
         );
-        let mut req_builder = client.put(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(create_or_update::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.put(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_07_01_preview::API_VERSION)]);
         if let Some(parameters) = parameters {
             req_builder = req_builder.json(parameters);
         }
-        let req = req_builder.build().context(create_or_update::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(create_or_update::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_or_update::ResponseBytesError)?;
-                let rsp_value: Account = serde_json::from_slice(&body).context(create_or_update::DeserializeError { body })?;
-                Ok(create_or_update::Response::Ok200(rsp_value))
-            }
-            StatusCode::CREATED => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_or_update::ResponseBytesError)?;
-                let rsp_value: Account = serde_json::from_slice(&body).context(create_or_update::DeserializeError { body })?;
-                Ok(create_or_update::Response::Created201(rsp_value))
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_or_update::ResponseBytesError)?;
-                let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(create_or_update::DeserializeError { body })?;
-                create_or_update::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(create_or_update::Response::Ok200(
+                rsp.into_poller(reqwest::Method::PUT, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::CREATED => Ok(create_or_update::Response::Created201(
+                rsp.into_poller(reqwest::Method::PUT, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod create_or_update {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2020_07_01_preview::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200(Account),
-            Created201(Account),
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+            Ok200(azure_core::lro::Poller<Account>),
+            Created201(azure_core::lro::Poller<Account>),
         }
+        pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
     }
     pub async fn update(
         operation_config: &crate::OperationConfig,
@@ -364,80 +197,36 @@ pub mod accounts {
         account_name: &str,
         parameters: Option<&AccountPatch>,
     ) -> std::result::Result<update::Response, update::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AutonomousDevelopmentPlatform/accounts/{}",
             &operation_config.base_path, subscription_id, resource_group_name, account_name
         );
-        let mut req_builder = client.patch(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(update::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.patch(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_07_01_preview::API_VERSION)]);
         if let Some(parameters) = parameters {
             req_builder = req_builder.json(parameters);
         }
-        let req = req_builder.build().context(update::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(update::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(update::ResponseBytesError)?;
-                let rsp_value: Account = serde_json::from_slice(&body).context(update::DeserializeError { body })?;
-                Ok(update::Response::Ok200(rsp_value))
-            }
-            StatusCode::CREATED => {
-                let body: bytes::Bytes = rsp.bytes().await.context(update::ResponseBytesError)?;
-                let rsp_value: Account = serde_json::from_slice(&body).context(update::DeserializeError { body })?;
-                Ok(update::Response::Created201(rsp_value))
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(update::ResponseBytesError)?;
-                let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(update::DeserializeError { body })?;
-                update::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(update::Response::Ok200(
+                rsp.into_poller(reqwest::Method::PATCH, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::CREATED => Ok(update::Response::Created201(
+                rsp.into_poller(reqwest::Method::PATCH, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod update {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2020_07_01_preview::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200(Account),
-            Created201(Account),
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+            Ok200(azure_core::lro::Poller<Account>),
+            Created201(azure_core::lro::Poller<Account>),
         }
+        pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
     }
     pub async fn delete(
         operation_config: &crate::OperationConfig,
@@ -445,145 +234,87 @@ pub mod accounts {
         resource_group_name: &str,
         account_name: &str,
     ) -> std::result::Result<delete::Response, delete::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AutonomousDevelopmentPlatform/accounts/{}",
             &operation_config.base_path, subscription_id, resource_group_name, account_name
         );
-        let mut req_builder = client.delete(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(delete::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(delete::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(delete::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.delete(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_07_01_preview::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => Ok(delete::Response::Ok200),
-            StatusCode::ACCEPTED => Ok(delete::Response::Accepted202),
-            StatusCode::NO_CONTENT => Ok(delete::Response::NoContent204),
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(delete::ResponseBytesError)?;
-                let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(delete::DeserializeError { body })?;
-                delete::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(delete::Response::Ok200(
+                rsp.into_poller(reqwest::Method::DELETE, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::ACCEPTED => Ok(delete::Response::Accepted202(
+                rsp.into_poller(reqwest::Method::DELETE, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::NO_CONTENT => Ok(delete::Response::NoContent204(
+                rsp.into_poller(reqwest::Method::DELETE, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod delete {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2020_07_01_preview::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200,
-            Accepted202,
-            NoContent204,
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+            Ok200(azure_core::lro::Poller<()>),
+            Accepted202(azure_core::lro::Poller<()>),
+            NoContent204(azure_core::lro::Poller<()>),
         }
+        pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
     }
 }
 pub mod data_pools {
-    use crate::models::*;
+    use crate::package_2020_07_01_preview::models::*;
     use reqwest::StatusCode;
-    use snafu::{ResultExt, Snafu};
     pub async fn list(
         operation_config: &crate::OperationConfig,
         subscription_id: &str,
         resource_group_name: &str,
         account_name: &str,
     ) -> std::result::Result<DataPoolList, list::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AutonomousDevelopmentPlatform/accounts/{}/dataPools",
             &operation_config.base_path, subscription_id, resource_group_name, account_name
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_07_01_preview::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: DataPoolList = serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                list::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod list {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+        use crate::package_2020_07_01_preview::{models, models::*};
+        pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
+    }
+    pub async fn list_next(operation_config: &crate::OperationConfig, next_link: &str) -> std::result::Result<DataPoolList, list::Error> {
+        let uri_str = next_link;
+        let mut req_builder = operation_config.client.get(uri_str);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
+        match rsp.status() {
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
+    pub fn list_stream<'a>(
+        operation_config: &'a crate::OperationConfig,
+        subscription_id: &'a str,
+        resource_group_name: &'a str,
+        account_name: &'a str,
+    ) -> azure_core::Pageable<'a, DataPoolList, list::Error> {
+        azure_core::Pageable::new(None, move |next_link: Option<String>| async move {
+            match next_link {
+                Some(next_link) => list_next(operation_config, &next_link).await,
+                None => list(operation_config, subscription_id, resource_group_name, account_name).await,
+            }
+        })
+    }
     pub async fn get(
         operation_config: &crate::OperationConfig,
         subscription_id: &str,
@@ -591,67 +322,21 @@ pub mod data_pools {
         account_name: &str,
         data_pool_name: &str,
     ) -> std::result::Result<DataPool, get::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AutonomousDevelopmentPlatform/accounts/{}/dataPools/{}",
             &operation_config.base_path, subscription_id, resource_group_name, account_name, data_pool_name
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(get::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(get::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(get::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_07_01_preview::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
-                let rsp_value: DataPool = serde_json::from_slice(&body).context(get::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
-                let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(get::DeserializeError { body })?;
-                get::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod get {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
-        }
+        use crate::package_2020_07_01_preview::{models, models::*};
+        pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
     }
     pub async fn create_or_update(
         operation_config: &crate::OperationConfig,
@@ -661,80 +346,36 @@ pub mod data_pools {
         data_pool_name: &str,
         parameters: Option<&DataPool>,
     ) -> std::result::Result<create_or_update::Response, create_or_update::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AutonomousDevelopmentPlatform/accounts/{}/dataPools/{}",
             &operation_config.base_path, subscription_id, resource_group_name, account_name, data_pool_name
         );
-        let mut req_builder = client.put(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(create_or_update::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.put(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_07_01_preview::API_VERSION)]);
         if let Some(parameters) = parameters {
             req_builder = req_builder.json(parameters);
         }
-        let req = req_builder.build().context(create_or_update::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(create_or_update::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_or_update::ResponseBytesError)?;
-                let rsp_value: DataPool = serde_json::from_slice(&body).context(create_or_update::DeserializeError { body })?;
-                Ok(create_or_update::Response::Ok200(rsp_value))
-            }
-            StatusCode::CREATED => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_or_update::ResponseBytesError)?;
-                let rsp_value: DataPool = serde_json::from_slice(&body).context(create_or_update::DeserializeError { body })?;
-                Ok(create_or_update::Response::Created201(rsp_value))
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_or_update::ResponseBytesError)?;
-                let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(create_or_update::DeserializeError { body })?;
-                create_or_update::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(create_or_update::Response::Ok200(
+                rsp.into_poller(reqwest::Method::PUT, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::CREATED => Ok(create_or_update::Response::Created201(
+                rsp.into_poller(reqwest::Method::PUT, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod create_or_update {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2020_07_01_preview::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200(DataPool),
-            Created201(DataPool),
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+            Ok200(azure_core::lro::Poller<DataPool>),
+            Created201(azure_core::lro::Poller<DataPool>),
         }
+        pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
     }
     pub async fn update(
         operation_config: &crate::OperationConfig,
@@ -744,80 +385,36 @@ pub mod data_pools {
         data_pool_name: &str,
         parameters: Option<&DataPoolPatch>,
     ) -> std::result::Result<update::Response, update::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AutonomousDevelopmentPlatform/accounts/{}/dataPools/{}",
             &operation_config.base_path, subscription_id, resource_group_name, account_name, data_pool_name
         );
-        let mut req_builder = client.patch(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(update::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.patch(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_07_01_preview::API_VERSION)]);
         if let Some(parameters) = parameters {
             req_builder = req_builder.json(parameters);
         }
-        let req = req_builder.build().context(update::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(update::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(update::ResponseBytesError)?;
-                let rsp_value: DataPool = serde_json::from_slice(&body).context(update::DeserializeError { body })?;
-                Ok(update::Response::Ok200(rsp_value))
-            }
-            StatusCode::CREATED => {
-                let body: bytes::Bytes = rsp.bytes().await.context(update::ResponseBytesError)?;
-                let rsp_value: DataPool = serde_json::from_slice(&body).context(update::DeserializeError { body })?;
-                Ok(update::Response::Created201(rsp_value))
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(update::ResponseBytesError)?;
-                let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(update::DeserializeError { body })?;
-                update::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(update::Response::Ok200(
+                rsp.into_poller(reqwest::Method::PATCH, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::CREATED => Ok(update::Response::Created201(
+                rsp.into_poller(reqwest::Method::PATCH, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod update {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2020_07_01_preview::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200(DataPool),
-            Created201(DataPool),
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+            Ok200(azure_core::lro::Poller<DataPool>),
+            Created201(azure_core::lro::Poller<DataPool>),
         }
+        pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
     }
     pub async fn delete(
         operation_config: &crate::OperationConfig,
@@ -826,70 +423,37 @@ pub mod data_pools {
         account_name: &str,
         data_pool_name: &str,
     ) -> std::result::Result<delete::Response, delete::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AutonomousDevelopmentPlatform/accounts/{}/dataPools/{}",
             &operation_config.base_path, subscription_id, resource_group_name, account_name, data_pool_name
         );
-        let mut req_builder = client.delete(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(delete::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(delete::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(delete::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.delete(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_07_01_preview::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => Ok(delete::Response::Ok200),
-            StatusCode::ACCEPTED => Ok(delete::Response::Accepted202),
-            StatusCode::NO_CONTENT => Ok(delete::Response::NoContent204),
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(delete::ResponseBytesError)?;
-                let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(delete::DeserializeError { body })?;
-                delete::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(delete::Response::Ok200(
+                rsp.into_poller(reqwest::Method::DELETE, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::ACCEPTED => Ok(delete::Response::Accepted202(
+                rsp.into_poller(reqwest::Method::DELETE, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::NO_CONTENT => Ok(delete::Response::NoContent204(
+                rsp.into_poller(reqwest::Method::DELETE, uri_str, crate::package_2020_07_01_preview::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod delete {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2020_07_01_preview::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200,
-            Accepted202,
-            NoContent204,
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+            Ok200(azure_core::lro::Poller<()>),
+            Accepted202(azure_core::lro::Poller<()>),
+            NoContent204(azure_core::lro::Poller<()>),
         }
+        pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
     }
 }
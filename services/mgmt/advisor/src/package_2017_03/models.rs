@@ -6,8 +6,20 @@ use serde::{Deserialize, Serialize};
 pub struct ResourceRecommendationBaseListResult {
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<ResourceRecommendationBase>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ResourceRecommendationBaseListResult {
+    type Item = ResourceRecommendationBase;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<ResourceRecommendationBase> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceRecommendationBase {
@@ -15,6 +27,9 @@ pub struct ResourceRecommendationBase {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<RecommendationProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RecommendationProperties {
@@ -36,8 +51,11 @@ pub struct RecommendationProperties {
     pub risk: Option<recommendation_properties::Risk>,
     #[serde(rename = "shortDescription", skip_serializing_if = "Option::is_none")]
     pub short_description: Option<ShortDescription>,
-    #[serde(rename = "suppressionIds", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "suppressionIds", default, skip_serializing_if = "Vec::is_empty")]
     pub suppression_ids: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod recommendation_properties {
     use super::*;
@@ -48,18 +66,24 @@ pub mod recommendation_properties {
         Performance,
         Cost,
         OperationalExcellence,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Impact {
         High,
         Medium,
         Low,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Risk {
         Error,
         Warning,
         None,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -77,13 +101,28 @@ pub struct ShortDescription {
     pub problem: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub solution: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationEntityListResult {
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<OperationEntity>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationEntityListResult {
+    type Item = OperationEntity;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<OperationEntity> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationEntity {
@@ -91,6 +130,9 @@ pub struct OperationEntity {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display: Option<OperationDisplayInfo>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationDisplayInfo {
@@ -102,6 +144,9 @@ pub struct OperationDisplayInfo {
     pub provider: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SuppressionContract {
@@ -109,6 +154,9 @@ pub struct SuppressionContract {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<SuppressionProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SuppressionProperties {
@@ -116,4 +164,7 @@ pub struct SuppressionProperties {
     pub suppression_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ttl: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
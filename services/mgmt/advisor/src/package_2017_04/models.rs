@@ -4,10 +4,22 @@
 use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MetadataEntityListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<MetadataEntity>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for MetadataEntityListResult {
+    type Item = MetadataEntity;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<MetadataEntity> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MetadataEntity {
@@ -19,17 +31,23 @@ pub struct MetadataEntity {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<MetadataEntityProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MetadataEntityProperties {
     #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
-    #[serde(rename = "dependsOn", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "dependsOn", default, skip_serializing_if = "Vec::is_empty")]
     pub depends_on: Vec<String>,
-    #[serde(rename = "applicableScenarios", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "applicableScenarios", default, skip_serializing_if = "Vec::is_empty")]
     pub applicable_scenarios: Vec<String>,
-    #[serde(rename = "supportedValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "supportedValues", default, skip_serializing_if = "Vec::is_empty")]
     pub supported_values: Vec<MetadataSupportedValueDetail>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MetadataSupportedValueDetail {
@@ -37,13 +55,28 @@ pub struct MetadataSupportedValueDetail {
     pub id: Option<String>,
     #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<ConfigData>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ConfigurationListResult {
+    type Item = ConfigData;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<ConfigData> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigData {
@@ -55,6 +88,9 @@ pub struct ConfigData {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<config_data::Properties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod config_data {
     use super::*;
@@ -64,6 +100,9 @@ pub mod config_data {
         pub exclude: Option<bool>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub low_cpu_threshold: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -72,13 +111,28 @@ pub struct ArmErrorResponseBody {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceRecommendationBaseListResult {
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<ResourceRecommendationBase>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ResourceRecommendationBaseListResult {
+    type Item = ResourceRecommendationBase;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<ResourceRecommendationBase> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceRecommendationBase {
@@ -86,6 +140,9 @@ pub struct ResourceRecommendationBase {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<RecommendationProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RecommendationProperties {
@@ -107,10 +164,13 @@ pub struct RecommendationProperties {
     pub risk: Option<recommendation_properties::Risk>,
     #[serde(rename = "shortDescription", skip_serializing_if = "Option::is_none")]
     pub short_description: Option<ShortDescription>,
-    #[serde(rename = "suppressionIds", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "suppressionIds", default, skip_serializing_if = "Vec::is_empty")]
     pub suppression_ids: Vec<String>,
     #[serde(rename = "extendedProperties", skip_serializing_if = "Option::is_none")]
     pub extended_properties: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod recommendation_properties {
     use super::*;
@@ -121,18 +181,24 @@ pub mod recommendation_properties {
         Performance,
         Cost,
         OperationalExcellence,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Impact {
         High,
         Medium,
         Low,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Risk {
         Error,
         Warning,
         None,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -150,13 +216,28 @@ pub struct ShortDescription {
     pub problem: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub solution: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationEntityListResult {
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<OperationEntity>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationEntityListResult {
+    type Item = OperationEntity;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<OperationEntity> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationEntity {
@@ -164,6 +245,9 @@ pub struct OperationEntity {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display: Option<OperationDisplayInfo>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationDisplayInfo {
@@ -175,6 +259,9 @@ pub struct OperationDisplayInfo {
     pub provider: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SuppressionContract {
@@ -182,6 +269,9 @@ pub struct SuppressionContract {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<SuppressionProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SuppressionProperties {
@@ -189,11 +279,26 @@ pub struct SuppressionProperties {
     pub suppression_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ttl: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SuppressionContractListResult {
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<SuppressionContract>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for SuppressionContractListResult {
+    type Item = SuppressionContract;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<SuppressionContract> {
+        self.value
+    }
 }
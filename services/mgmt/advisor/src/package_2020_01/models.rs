@@ -4,10 +4,22 @@
 use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MetadataEntityListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<MetadataEntity>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for MetadataEntityListResult {
+    type Item = MetadataEntity;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<MetadataEntity> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MetadataEntity {
@@ -19,17 +31,23 @@ pub struct MetadataEntity {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<MetadataEntityProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MetadataEntityProperties {
     #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
-    #[serde(rename = "dependsOn", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "dependsOn", default, skip_serializing_if = "Vec::is_empty")]
     pub depends_on: Vec<String>,
-    #[serde(rename = "applicableScenarios", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "applicableScenarios", default, skip_serializing_if = "Vec::is_empty")]
     pub applicable_scenarios: Vec<String>,
-    #[serde(rename = "supportedValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "supportedValues", default, skip_serializing_if = "Vec::is_empty")]
     pub supported_values: Vec<MetadataSupportedValueDetail>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MetadataSupportedValueDetail {
@@ -37,13 +55,28 @@ pub struct MetadataSupportedValueDetail {
     pub id: Option<String>,
     #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<ConfigData>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ConfigurationListResult {
+    type Item = ConfigData;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<ConfigData> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigData {
@@ -51,6 +84,9 @@ pub struct ConfigData {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ConfigDataProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigDataProperties {
@@ -58,8 +94,11 @@ pub struct ConfigDataProperties {
     pub exclude: Option<bool>,
     #[serde(rename = "lowCpuThreshold", skip_serializing_if = "Option::is_none")]
     pub low_cpu_threshold: Option<config_data_properties::LowCpuThreshold>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub digests: Vec<DigestConfig>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod config_data_properties {
     use super::*;
@@ -73,6 +112,8 @@ pub mod config_data_properties {
         _15,
         #[serde(rename = "20")]
         _20,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -83,12 +124,15 @@ pub struct DigestConfig {
     pub action_group_resource_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub frequency: Option<i32>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub categories: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<digest_config::State>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod digest_config {
     use super::*;
@@ -96,12 +140,17 @@ pub mod digest_config {
     pub enum State {
         Active,
         Disabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ArmErrorResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ArmErrorResponseBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ArmErrorResponseBody {
@@ -109,13 +158,28 @@ pub struct ArmErrorResponseBody {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceRecommendationBaseListResult {
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<ResourceRecommendationBase>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ResourceRecommendationBaseListResult {
+    type Item = ResourceRecommendationBase;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<ResourceRecommendationBase> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceRecommendationBase {
@@ -123,6 +187,9 @@ pub struct ResourceRecommendationBase {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<RecommendationProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RecommendationProperties {
@@ -144,12 +211,15 @@ pub struct RecommendationProperties {
     pub risk: Option<recommendation_properties::Risk>,
     #[serde(rename = "shortDescription", skip_serializing_if = "Option::is_none")]
     pub short_description: Option<ShortDescription>,
-    #[serde(rename = "suppressionIds", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "suppressionIds", default, skip_serializing_if = "Vec::is_empty")]
     pub suppression_ids: Vec<String>,
     #[serde(rename = "extendedProperties", skip_serializing_if = "Option::is_none")]
     pub extended_properties: Option<serde_json::Value>,
     #[serde(rename = "resourceMetadata", skip_serializing_if = "Option::is_none")]
     pub resource_metadata: Option<ResourceMetadata>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod recommendation_properties {
     use super::*;
@@ -160,18 +230,24 @@ pub mod recommendation_properties {
         Performance,
         Cost,
         OperationalExcellence,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Impact {
         High,
         Medium,
         Low,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Risk {
         Error,
         Warning,
         None,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -189,6 +265,9 @@ pub struct ShortDescription {
     pub problem: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub solution: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceMetadata {
@@ -196,13 +275,28 @@ pub struct ResourceMetadata {
     pub resource_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationEntityListResult {
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<OperationEntity>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationEntityListResult {
+    type Item = OperationEntity;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<OperationEntity> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationEntity {
@@ -210,6 +304,9 @@ pub struct OperationEntity {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display: Option<OperationDisplayInfo>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationDisplayInfo {
@@ -221,6 +318,9 @@ pub struct OperationDisplayInfo {
     pub provider: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SuppressionContract {
@@ -228,6 +328,9 @@ pub struct SuppressionContract {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<SuppressionProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SuppressionProperties {
@@ -235,11 +338,26 @@ pub struct SuppressionProperties {
     pub suppression_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ttl: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SuppressionContractListResult {
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<SuppressionContract>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for SuppressionContractListResult {
+    type Item = SuppressionContract;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<SuppressionContract> {
+        self.value
+    }
 }
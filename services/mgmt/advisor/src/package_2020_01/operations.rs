@@ -2,11 +2,11 @@
 #![allow(unused_mut)]
 #![allow(unused_variables)]
 #![allow(unused_imports)]
-use crate::models::*;
+use crate::package_2020_01::models::*;
 use reqwest::StatusCode;
 use snafu::{ResultExt, Snafu};
 pub mod recommendation_metadata {
-    use crate::models::*;
+    use crate::package_2020_01::models::*;
     use reqwest::StatusCode;
     use snafu::{ResultExt, Snafu};
     pub async fn get(operation_config: &crate::OperationConfig, name: &str) -> std::result::Result<MetadataEntity, get::Error> {
@@ -20,294 +20,216 @@ pub mod recommendation_metadata {
                 .context(get::GetTokenError)?;
             req_builder = req_builder.bearer_auth(token_response.token.secret());
         }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
         let req = req_builder.build().context(get::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(get::ExecuteRequestError)?;
+        let rsp = operation_config.pipeline.execute(req).await.context(get::ExecuteRequestError)?;
         match rsp.status() {
             StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
+                let body: bytes::Bytes = rsp.into_body().await.context(get::ResponseBytesError)?;
                 let rsp_value: MetadataEntity = serde_json::from_slice(&body).context(get::DeserializeError { body })?;
                 Ok(rsp_value)
             }
             StatusCode::NOT_FOUND => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
+                let body: bytes::Bytes = rsp.into_body().await.context(get::ResponseBytesError)?;
                 let rsp_value: ArmErrorResponseBody = serde_json::from_slice(&body).context(get::DeserializeError { body })?;
                 get::NotFound404 { value: rsp_value }.fail()
             }
             status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
-                get::UnexpectedResponse { status_code, body: body }.fail()
+                let body: bytes::Bytes = rsp.into_body().await.context(get::ResponseBytesError)?;
+                get::UnexpectedResponse {
+                    status_code,
+                    error: azure_core::ArmError::from_body(&body),
+                    body: body,
+                }
+                .fail()
             }
         }
     }
     pub mod get {
-        use crate::{models, models::*};
+        use crate::package_2020_01::{models, models::*};
         use reqwest::StatusCode;
         use snafu::Snafu;
         #[derive(Debug, Snafu)]
         #[snafu(visibility(pub(crate)))]
         pub enum Error {
-            NotFound404 { value: models::ArmErrorResponseBody },
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+            NotFound404 {
+                value: models::ArmErrorResponseBody,
+            },
+            UnexpectedResponse {
+                status_code: StatusCode,
+                body: bytes::Bytes,
+                error: Option<azure_core::ArmError>,
+            },
+            BuildRequestError {
+                source: reqwest::Error,
+            },
+            ExecuteRequestError {
+                source: azure_core::errors::AzureError,
+            },
+            ResponseBytesError {
+                source: azure_core::errors::AzureError,
+            },
+            DeserializeError {
+                source: serde_json::Error,
+                body: bytes::Bytes,
+            },
+            GetTokenError {
+                source: azure_core::errors::AzureError,
+            },
         }
     }
     pub async fn list(operation_config: &crate::OperationConfig) -> std::result::Result<MetadataEntityListResult, list::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!("{}/providers/Microsoft.Advisor/metadata", &operation_config.base_path,);
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: MetadataEntityListResult = serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                list::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod list {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
+    }
+    pub async fn list_next(
+        operation_config: &crate::OperationConfig,
+        next_link: &str,
+    ) -> std::result::Result<MetadataEntityListResult, list::Error> {
+        let uri_str = next_link;
+        let mut req_builder = operation_config.client.get(uri_str);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
+        match rsp.status() {
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
+    pub fn list_stream<'a>(
+        operation_config: &'a crate::OperationConfig,
+    ) -> azure_core::Pageable<'a, MetadataEntityListResult, list::Error> {
+        azure_core::Pageable::new(None, move |next_link: Option<String>| async move {
+            match next_link {
+                Some(next_link) => list_next(operation_config, &next_link).await,
+                None => list(operation_config).await,
+            }
+        })
+    }
 }
 pub mod configurations {
-    use crate::models::*;
+    use crate::package_2020_01::models::*;
     use reqwest::StatusCode;
     use snafu::{ResultExt, Snafu};
     pub async fn list_by_subscription(
         operation_config: &crate::OperationConfig,
         subscription_id: &str,
     ) -> std::result::Result<ConfigurationListResult, list_by_subscription::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.Advisor/configurations",
             &operation_config.base_path, subscription_id
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list_by_subscription::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list_by_subscription::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list_by_subscription::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_by_subscription::ResponseBytesError)?;
-                let rsp_value: ConfigurationListResult =
-                    serde_json::from_slice(&body).context(list_by_subscription::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_by_subscription::ResponseBytesError)?;
-                let rsp_value: ArmErrorResponse = serde_json::from_slice(&body).context(list_by_subscription::DeserializeError { body })?;
-                list_by_subscription::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod list_by_subscription {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ArmErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error<models::ArmErrorResponse>;
+    }
+    pub async fn list_by_subscription_next(
+        operation_config: &crate::OperationConfig,
+        next_link: &str,
+    ) -> std::result::Result<ConfigurationListResult, list_by_subscription::Error> {
+        let uri_str = next_link;
+        let mut req_builder = operation_config.client.get(uri_str);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
+        match rsp.status() {
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
+    pub fn list_by_subscription_stream<'a>(
+        operation_config: &'a crate::OperationConfig,
+        subscription_id: &'a str,
+    ) -> azure_core::Pageable<'a, ConfigurationListResult, list_by_subscription::Error> {
+        azure_core::Pageable::new(None, move |next_link: Option<String>| async move {
+            match next_link {
+                Some(next_link) => list_by_subscription_next(operation_config, &next_link).await,
+                None => list_by_subscription(operation_config, subscription_id).await,
+            }
+        })
+    }
     pub async fn create_in_subscription(
         operation_config: &crate::OperationConfig,
         config_contract: &ConfigData,
         subscription_id: &str,
         configuration_name: &str,
     ) -> std::result::Result<ConfigData, create_in_subscription::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.Advisor/configurations/{}",
             &operation_config.base_path, subscription_id, configuration_name
         );
-        let mut req_builder = client.put(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(create_in_subscription::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.put(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
         req_builder = req_builder.json(config_contract);
-        let req = req_builder.build().context(create_in_subscription::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(create_in_subscription::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_in_subscription::ResponseBytesError)?;
-                let rsp_value: ConfigData = serde_json::from_slice(&body).context(create_in_subscription::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_in_subscription::ResponseBytesError)?;
-                let rsp_value: ArmErrorResponse =
-                    serde_json::from_slice(&body).context(create_in_subscription::DeserializeError { body })?;
-                create_in_subscription::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod create_in_subscription {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ArmErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
-        }
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error<models::ArmErrorResponse>;
     }
     pub async fn list_by_resource_group(
         operation_config: &crate::OperationConfig,
         subscription_id: &str,
         resource_group: &str,
     ) -> std::result::Result<ConfigurationListResult, list_by_resource_group::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.Advisor/configurations",
             &operation_config.base_path, subscription_id, resource_group
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list_by_resource_group::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list_by_resource_group::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list_by_resource_group::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_by_resource_group::ResponseBytesError)?;
-                let rsp_value: ConfigurationListResult =
-                    serde_json::from_slice(&body).context(list_by_resource_group::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_by_resource_group::ResponseBytesError)?;
-                let rsp_value: ArmErrorResponse =
-                    serde_json::from_slice(&body).context(list_by_resource_group::DeserializeError { body })?;
-                list_by_resource_group::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod list_by_resource_group {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ArmErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error<models::ArmErrorResponse>;
+    }
+    pub async fn list_by_resource_group_next(
+        operation_config: &crate::OperationConfig,
+        next_link: &str,
+    ) -> std::result::Result<ConfigurationListResult, list_by_resource_group::Error> {
+        let uri_str = next_link;
+        let mut req_builder = operation_config.client.get(uri_str);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
+        match rsp.status() {
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
+    pub fn list_by_resource_group_stream<'a>(
+        operation_config: &'a crate::OperationConfig,
+        subscription_id: &'a str,
+        resource_group: &'a str,
+    ) -> azure_core::Pageable<'a, ConfigurationListResult, list_by_resource_group::Error> {
+        azure_core::Pageable::new(None, move |next_link: Option<String>| async move {
+            match next_link {
+                Some(next_link) => list_by_resource_group_next(operation_config, &next_link).await,
+                None => list_by_resource_group(operation_config, subscription_id, resource_group).await,
+            }
+        })
+    }
     pub async fn create_in_resource_group(
         operation_config: &crate::OperationConfig,
         config_contract: &ConfigData,
@@ -315,165 +237,78 @@ pub mod configurations {
         configuration_name: &str,
         resource_group: &str,
     ) -> std::result::Result<ConfigData, create_in_resource_group::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.Advisor/configurations/{}",
             &operation_config.base_path, subscription_id, resource_group, configuration_name
         );
-        let mut req_builder = client.put(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(create_in_resource_group::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.put(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
         req_builder = req_builder.json(config_contract);
-        let req = req_builder.build().context(create_in_resource_group::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(create_in_resource_group::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_in_resource_group::ResponseBytesError)?;
-                let rsp_value: ConfigData = serde_json::from_slice(&body).context(create_in_resource_group::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create_in_resource_group::ResponseBytesError)?;
-                let rsp_value: ArmErrorResponse =
-                    serde_json::from_slice(&body).context(create_in_resource_group::DeserializeError { body })?;
-                create_in_resource_group::DefaultResponse {
-                    status_code,
-                    value: rsp_value,
-                }
-                .fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.default_response().await),
         }
     }
     pub mod create_in_resource_group {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            DefaultResponse {
-                status_code: StatusCode,
-                value: models::ArmErrorResponse,
-            },
-            BuildRequestError {
-                source: reqwest::Error,
-            },
-            ExecuteRequestError {
-                source: reqwest::Error,
-            },
-            ResponseBytesError {
-                source: reqwest::Error,
-            },
-            DeserializeError {
-                source: serde_json::Error,
-                body: bytes::Bytes,
-            },
-            GetTokenError {
-                source: azure_core::errors::AzureError,
-            },
-        }
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error<models::ArmErrorResponse>;
     }
 }
 pub mod recommendations {
-    use crate::models::*;
+    use crate::package_2020_01::models::*;
     use reqwest::StatusCode;
     use snafu::{ResultExt, Snafu};
     pub async fn generate(operation_config: &crate::OperationConfig, subscription_id: &str) -> std::result::Result<(), generate::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.Advisor/generateRecommendations",
             &operation_config.base_path, subscription_id
         );
-        let mut req_builder = client.post(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(generate::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.post(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
         req_builder = req_builder.header(reqwest::header::CONTENT_LENGTH, 0);
-        let req = req_builder.build().context(generate::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(generate::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
             StatusCode::ACCEPTED => Ok(()),
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(generate::ResponseBytesError)?;
-                generate::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod generate {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
-        }
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn get_generate_status(
         operation_config: &crate::OperationConfig,
         subscription_id: &str,
         operation_id: &str,
     ) -> std::result::Result<get_generate_status::Response, get_generate_status::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.Advisor/generateRecommendations/{}",
             &operation_config.base_path, subscription_id, operation_id
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(get_generate_status::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(get_generate_status::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(get_generate_status::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::ACCEPTED => Ok(get_generate_status::Response::Accepted202),
-            StatusCode::NO_CONTENT => Ok(get_generate_status::Response::NoContent204),
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get_generate_status::ResponseBytesError)?;
-                get_generate_status::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::ACCEPTED => Ok(get_generate_status::Response::Accepted202(
+                rsp.into_poller(reqwest::Method::GET, uri_str, crate::package_2020_01::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::NO_CONTENT => Ok(get_generate_status::Response::NoContent204(
+                rsp.into_poller(reqwest::Method::GET, uri_str, crate::package_2020_01::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod get_generate_status {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2020_01::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Accepted202,
-            NoContent204,
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+            Accepted202(azure_core::lro::Poller<()>),
+            NoContent204(azure_core::lro::Poller<()>),
         }
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn list(
         operation_config: &crate::OperationConfig,
@@ -482,20 +317,12 @@ pub mod recommendations {
         top: Option<i64>,
         skip_token: Option<&str>,
     ) -> std::result::Result<ResourceRecommendationBaseListResult, list::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.Advisor/recommendations",
             &operation_config.base_path, subscription_id
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
         if let Some(filter) = filter {
             req_builder = req_builder.query(&[("$filter", filter)]);
         }
@@ -505,133 +332,107 @@ pub mod recommendations {
         if let Some(skip_token) = skip_token {
             req_builder = req_builder.query(&[("$skipToken", skip_token)]);
         }
-        let req = req_builder.build().context(list::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: ResourceRecommendationBaseListResult =
-                    serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                list::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod list {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
+    }
+    pub async fn list_next(
+        operation_config: &crate::OperationConfig,
+        next_link: &str,
+    ) -> std::result::Result<ResourceRecommendationBaseListResult, list::Error> {
+        let uri_str = next_link;
+        let mut req_builder = operation_config.client.get(uri_str);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
+        match rsp.status() {
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
+    pub fn list_stream<'a>(
+        operation_config: &'a crate::OperationConfig,
+        subscription_id: &'a str,
+        filter: Option<&'a str>,
+        top: Option<i64>,
+        skip_token: Option<&'a str>,
+    ) -> azure_core::Pageable<'a, ResourceRecommendationBaseListResult, list::Error> {
+        azure_core::Pageable::new(None, move |next_link: Option<String>| async move {
+            match next_link {
+                Some(next_link) => list_next(operation_config, &next_link).await,
+                None => list(operation_config, subscription_id, filter, top, skip_token).await,
+            }
+        })
+    }
     pub async fn get(
         operation_config: &crate::OperationConfig,
         resource_uri: &str,
         recommendation_id: &str,
     ) -> std::result::Result<ResourceRecommendationBase, get::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/{}/providers/Microsoft.Advisor/recommendations/{}",
             &operation_config.base_path, resource_uri, recommendation_id
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(get::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(get::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(get::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
-                let rsp_value: ResourceRecommendationBase = serde_json::from_slice(&body).context(get::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
-                get::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod get {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
-        }
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
     }
 }
 pub mod operations {
-    use crate::models::*;
+    use crate::package_2020_01::models::*;
     use reqwest::StatusCode;
     use snafu::{ResultExt, Snafu};
     pub async fn list(operation_config: &crate::OperationConfig) -> std::result::Result<OperationEntityListResult, list::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!("{}/providers/Microsoft.Advisor/operations", &operation_config.base_path,);
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: OperationEntityListResult = serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                list::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod list {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
+    }
+    pub async fn list_next(
+        operation_config: &crate::OperationConfig,
+        next_link: &str,
+    ) -> std::result::Result<OperationEntityListResult, list::Error> {
+        let uri_str = next_link;
+        let mut req_builder = operation_config.client.get(uri_str);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
+        match rsp.status() {
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
+    pub fn list_stream<'a>(
+        operation_config: &'a crate::OperationConfig,
+    ) -> azure_core::Pageable<'a, OperationEntityListResult, list::Error> {
+        azure_core::Pageable::new(None, move |next_link: Option<String>| async move {
+            match next_link {
+                Some(next_link) => list_next(operation_config, &next_link).await,
+                None => list(operation_config).await,
+            }
+        })
+    }
 }
 pub mod suppressions {
-    use crate::models::*;
+    use crate::package_2020_01::models::*;
     use reqwest::StatusCode;
     use snafu::{ResultExt, Snafu};
     pub async fn get(
@@ -640,48 +441,21 @@ pub mod suppressions {
         recommendation_id: &str,
         name: &str,
     ) -> std::result::Result<SuppressionContract, get::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/{}/providers/Microsoft.Advisor/recommendations/{}/suppressions/{}",
             &operation_config.base_path, resource_uri, recommendation_id, name
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(get::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(get::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(get::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
-                let rsp_value: SuppressionContract = serde_json::from_slice(&body).context(get::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get::ResponseBytesError)?;
-                get::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod get {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
-        }
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn create(
         operation_config: &crate::OperationConfig,
@@ -690,49 +464,22 @@ pub mod suppressions {
         name: &str,
         suppression_contract: &SuppressionContract,
     ) -> std::result::Result<SuppressionContract, create::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/{}/providers/Microsoft.Advisor/recommendations/{}/suppressions/{}",
             &operation_config.base_path, resource_uri, recommendation_id, name
         );
-        let mut req_builder = client.put(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(create::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.put(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
         req_builder = req_builder.json(suppression_contract);
-        let req = req_builder.build().context(create::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(create::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create::ResponseBytesError)?;
-                let rsp_value: SuppressionContract = serde_json::from_slice(&body).context(create::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create::ResponseBytesError)?;
-                create::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod create {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
-        }
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn delete(
         operation_config: &crate::OperationConfig,
@@ -740,44 +487,21 @@ pub mod suppressions {
         recommendation_id: &str,
         name: &str,
     ) -> std::result::Result<(), delete::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/{}/providers/Microsoft.Advisor/recommendations/{}/suppressions/{}",
             &operation_config.base_path, resource_uri, recommendation_id, name
         );
-        let mut req_builder = client.delete(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(delete::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(delete::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(delete::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.delete(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
             StatusCode::NO_CONTENT => Ok(()),
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(delete::ResponseBytesError)?;
-                delete::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod delete {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
-        }
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn list(
         operation_config: &crate::OperationConfig,
@@ -785,53 +509,51 @@ pub mod suppressions {
         top: Option<i32>,
         skip_token: Option<&str>,
     ) -> std::result::Result<SuppressionContractListResult, list::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.Advisor/suppressions",
             &operation_config.base_path, subscription_id
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2020_01::API_VERSION)]);
         if let Some(top) = top {
             req_builder = req_builder.query(&[("$top", top)]);
         }
         if let Some(skip_token) = skip_token {
             req_builder = req_builder.query(&[("$skipToken", skip_token)]);
         }
-        let req = req_builder.build().context(list::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: SuppressionContractListResult = serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                list::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod list {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+        use crate::package_2020_01::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
+    }
+    pub async fn list_next(
+        operation_config: &crate::OperationConfig,
+        next_link: &str,
+    ) -> std::result::Result<SuppressionContractListResult, list::Error> {
+        let uri_str = next_link;
+        let mut req_builder = operation_config.client.get(uri_str);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
+        match rsp.status() {
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
+    pub fn list_stream<'a>(
+        operation_config: &'a crate::OperationConfig,
+        subscription_id: &'a str,
+        top: Option<i32>,
+        skip_token: Option<&'a str>,
+    ) -> azure_core::Pageable<'a, SuppressionContractListResult, list::Error> {
+        azure_core::Pageable::new(None, move |next_link: Option<String>| async move {
+            match next_link {
+                Some(next_link) => list_next(operation_config, &next_link).await,
+                None => list(operation_config, subscription_id, top, skip_token).await,
+            }
+        })
+    }
 }
@@ -6,6 +6,9 @@ use serde::{Deserialize, Serialize};
 pub struct ArmErrorResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ArmErrorResponseBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ArmErrorResponseBody {
@@ -13,13 +16,28 @@ pub struct ArmErrorResponseBody {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationEntityListResult {
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<OperationEntity>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationEntityListResult {
+    type Item = OperationEntity;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<OperationEntity> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationEntity {
@@ -27,6 +45,9 @@ pub struct OperationEntity {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display: Option<OperationDisplayInfo>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationDisplayInfo {
@@ -38,6 +59,9 @@ pub struct OperationDisplayInfo {
     pub provider: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ScoreEntity {
@@ -51,6 +75,9 @@ pub struct ScoreEntity {
     pub impacted_resource_count: Option<f64>,
     #[serde(rename = "potentialScoreIncrease", skip_serializing_if = "Option::is_none")]
     pub potential_score_increase: Option<f64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub type TimeSeriesEntity = Vec<serde_json::Value>;
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -63,6 +90,9 @@ pub struct AdvisorScoreEntity {
     pub type_: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<advisor_score_entity::Properties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod advisor_score_entity {
     use super::*;
@@ -72,5 +102,8 @@ pub mod advisor_score_entity {
         pub last_refreshed_score: Option<ScoreEntity>,
         #[serde(rename = "timeSeries", skip_serializing_if = "Option::is_none")]
         pub time_series: Option<TimeSeriesEntity>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
@@ -2,61 +2,32 @@
 #![allow(unused_mut)]
 #![allow(unused_variables)]
 #![allow(unused_imports)]
-use crate::models::*;
+use crate::package_2016_05::models::*;
 use reqwest::StatusCode;
-use snafu::{ResultExt, Snafu};
 pub mod servers {
-    use crate::models::*;
+    use crate::package_2016_05::models::*;
     use reqwest::StatusCode;
-    use snafu::{ResultExt, Snafu};
     pub async fn get_details(
         operation_config: &crate::OperationConfig,
         resource_group_name: &str,
         server_name: &str,
         subscription_id: &str,
     ) -> std::result::Result<AnalysisServicesServer, get_details::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AnalysisServices/servers/{}",
             &operation_config.base_path, subscription_id, resource_group_name, server_name
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(get_details::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(get_details::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(get_details::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get_details::ResponseBytesError)?;
-                let rsp_value: AnalysisServicesServer = serde_json::from_slice(&body).context(get_details::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(get_details::ResponseBytesError)?;
-                get_details::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod get_details {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
-        }
+        use crate::package_2016_05::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn create(
         operation_config: &crate::OperationConfig,
@@ -65,59 +36,34 @@ pub mod servers {
         server_parameters: &AnalysisServicesServer,
         subscription_id: &str,
     ) -> std::result::Result<create::Response, create::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AnalysisServices/servers/{}",
             &operation_config.base_path, subscription_id, resource_group_name, server_name
         );
-        let mut req_builder = client.put(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(create::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.put(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
         req_builder = req_builder.json(server_parameters);
-        let req = req_builder.build().context(create::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(create::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create::ResponseBytesError)?;
-                let rsp_value: AnalysisServicesServer = serde_json::from_slice(&body).context(create::DeserializeError { body })?;
-                Ok(create::Response::Ok200(rsp_value))
-            }
-            StatusCode::CREATED => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create::ResponseBytesError)?;
-                let rsp_value: AnalysisServicesServer = serde_json::from_slice(&body).context(create::DeserializeError { body })?;
-                Ok(create::Response::Created201(rsp_value))
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(create::ResponseBytesError)?;
-                create::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(create::Response::Ok200(
+                rsp.into_poller(reqwest::Method::PUT, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::CREATED => Ok(create::Response::Created201(
+                rsp.into_poller(reqwest::Method::PUT, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod create {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2016_05::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200(AnalysisServicesServer),
-            Created201(AnalysisServicesServer),
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+            Ok200(azure_core::lro::Poller<AnalysisServicesServer>),
+            Created201(azure_core::lro::Poller<AnalysisServicesServer>),
         }
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn update(
         operation_config: &crate::OperationConfig,
@@ -126,59 +72,34 @@ pub mod servers {
         server_update_parameters: &AnalysisServicesServerUpdateParameters,
         subscription_id: &str,
     ) -> std::result::Result<update::Response, update::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AnalysisServices/servers/{}",
             &operation_config.base_path, subscription_id, resource_group_name, server_name
         );
-        let mut req_builder = client.patch(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(update::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.patch(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
         req_builder = req_builder.json(server_update_parameters);
-        let req = req_builder.build().context(update::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(update::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(update::ResponseBytesError)?;
-                let rsp_value: AnalysisServicesServer = serde_json::from_slice(&body).context(update::DeserializeError { body })?;
-                Ok(update::Response::Ok200(rsp_value))
-            }
-            StatusCode::ACCEPTED => {
-                let body: bytes::Bytes = rsp.bytes().await.context(update::ResponseBytesError)?;
-                let rsp_value: AnalysisServicesServer = serde_json::from_slice(&body).context(update::DeserializeError { body })?;
-                Ok(update::Response::Accepted202(rsp_value))
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(update::ResponseBytesError)?;
-                update::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(update::Response::Ok200(
+                rsp.into_poller(reqwest::Method::PATCH, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::ACCEPTED => Ok(update::Response::Accepted202(
+                rsp.into_poller(reqwest::Method::PATCH, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod update {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2016_05::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200(AnalysisServicesServer),
-            Accepted202(AnalysisServicesServer),
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+            Ok200(azure_core::lro::Poller<AnalysisServicesServer>),
+            Accepted202(azure_core::lro::Poller<AnalysisServicesServer>),
         }
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn delete(
         operation_config: &crate::OperationConfig,
@@ -186,52 +107,38 @@ pub mod servers {
         server_name: &str,
         subscription_id: &str,
     ) -> std::result::Result<delete::Response, delete::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AnalysisServices/servers/{}",
             &operation_config.base_path, subscription_id, resource_group_name, server_name
         );
-        let mut req_builder = client.delete(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(delete::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(delete::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(delete::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.delete(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => Ok(delete::Response::Ok200),
-            StatusCode::NO_CONTENT => Ok(delete::Response::NoContent204),
-            StatusCode::ACCEPTED => Ok(delete::Response::Accepted202),
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(delete::ResponseBytesError)?;
-                delete::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(delete::Response::Ok200(
+                rsp.into_poller(reqwest::Method::DELETE, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::NO_CONTENT => Ok(delete::Response::NoContent204(
+                rsp.into_poller(reqwest::Method::DELETE, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::ACCEPTED => Ok(delete::Response::Accepted202(
+                rsp.into_poller(reqwest::Method::DELETE, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod delete {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2016_05::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200,
-            NoContent204,
-            Accepted202,
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+            Ok200(azure_core::lro::Poller<()>),
+            NoContent204(azure_core::lro::Poller<()>),
+            Accepted202(azure_core::lro::Poller<()>),
         }
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn suspend(
         operation_config: &crate::OperationConfig,
@@ -239,51 +146,34 @@ pub mod servers {
         server_name: &str,
         subscription_id: &str,
     ) -> std::result::Result<suspend::Response, suspend::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AnalysisServices/servers/{}/suspend",
             &operation_config.base_path, subscription_id, resource_group_name, server_name
         );
-        let mut req_builder = client.post(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(suspend::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.post(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
         req_builder = req_builder.header(reqwest::header::CONTENT_LENGTH, 0);
-        let req = req_builder.build().context(suspend::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(suspend::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => Ok(suspend::Response::Ok200),
-            StatusCode::ACCEPTED => Ok(suspend::Response::Accepted202),
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(suspend::ResponseBytesError)?;
-                suspend::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(suspend::Response::Ok200(
+                rsp.into_poller(reqwest::Method::POST, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::ACCEPTED => Ok(suspend::Response::Accepted202(
+                rsp.into_poller(reqwest::Method::POST, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod suspend {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2016_05::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200,
-            Accepted202,
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+            Ok200(azure_core::lro::Poller<()>),
+            Accepted202(azure_core::lro::Poller<()>),
         }
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn resume(
         operation_config: &crate::OperationConfig,
@@ -291,195 +181,95 @@ pub mod servers {
         server_name: &str,
         subscription_id: &str,
     ) -> std::result::Result<resume::Response, resume::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AnalysisServices/servers/{}/resume",
             &operation_config.base_path, subscription_id, resource_group_name, server_name
         );
-        let mut req_builder = client.post(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(resume::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.post(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
         req_builder = req_builder.header(reqwest::header::CONTENT_LENGTH, 0);
-        let req = req_builder.build().context(resume::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(resume::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => Ok(resume::Response::Ok200),
-            StatusCode::ACCEPTED => Ok(resume::Response::Accepted202),
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(resume::ResponseBytesError)?;
-                resume::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(resume::Response::Ok200(
+                rsp.into_poller(reqwest::Method::POST, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::ACCEPTED => Ok(resume::Response::Accepted202(
+                rsp.into_poller(reqwest::Method::POST, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod resume {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2016_05::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200,
-            Accepted202,
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+            Ok200(azure_core::lro::Poller<()>),
+            Accepted202(azure_core::lro::Poller<()>),
         }
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn list_by_resource_group(
         operation_config: &crate::OperationConfig,
         resource_group_name: &str,
         subscription_id: &str,
     ) -> std::result::Result<AnalysisServicesServers, list_by_resource_group::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AnalysisServices/servers",
             &operation_config.base_path, subscription_id, resource_group_name
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list_by_resource_group::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list_by_resource_group::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list_by_resource_group::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_by_resource_group::ResponseBytesError)?;
-                let rsp_value: AnalysisServicesServers =
-                    serde_json::from_slice(&body).context(list_by_resource_group::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_by_resource_group::ResponseBytesError)?;
-                list_by_resource_group::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod list_by_resource_group {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
-        }
+        use crate::package_2016_05::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn list(
         operation_config: &crate::OperationConfig,
         subscription_id: &str,
     ) -> std::result::Result<AnalysisServicesServers, list::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.AnalysisServices/servers",
             &operation_config.base_path, subscription_id
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                let rsp_value: AnalysisServicesServers = serde_json::from_slice(&body).context(list::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list::ResponseBytesError)?;
-                list::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod list {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
-        }
+        use crate::package_2016_05::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn list_skus_for_new(
         operation_config: &crate::OperationConfig,
         subscription_id: &str,
     ) -> std::result::Result<SkuEnumerationForNewResourceResult, list_skus_for_new::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.AnalysisServices/skus",
             &operation_config.base_path, subscription_id
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list_skus_for_new::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list_skus_for_new::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list_skus_for_new::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_skus_for_new::ResponseBytesError)?;
-                let rsp_value: SkuEnumerationForNewResourceResult =
-                    serde_json::from_slice(&body).context(list_skus_for_new::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_skus_for_new::ResponseBytesError)?;
-                list_skus_for_new::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod list_skus_for_new {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
-        }
+        use crate::package_2016_05::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn list_skus_for_existing(
         operation_config: &crate::OperationConfig,
@@ -487,49 +277,21 @@ pub mod servers {
         server_name: &str,
         subscription_id: &str,
     ) -> std::result::Result<SkuEnumerationForExistingResourceResult, list_skus_for_existing::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.AnalysisServices/servers/{}/skus",
             &operation_config.base_path, subscription_id, resource_group_name, server_name
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list_skus_for_existing::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list_skus_for_existing::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list_skus_for_existing::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_skus_for_existing::ResponseBytesError)?;
-                let rsp_value: SkuEnumerationForExistingResourceResult =
-                    serde_json::from_slice(&body).context(list_skus_for_existing::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_skus_for_existing::ResponseBytesError)?;
-                list_skus_for_existing::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod list_skus_for_existing {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
-        }
+        use crate::package_2016_05::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn check_name_availability(
         operation_config: &crate::OperationConfig,
@@ -537,50 +299,22 @@ pub mod servers {
         server_parameters: &CheckServerNameAvailabilityParameters,
         subscription_id: &str,
     ) -> std::result::Result<CheckServerNameAvailabilityResult, check_name_availability::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.AnalysisServices/locations/{}/checkNameAvailability",
             &operation_config.base_path, subscription_id, location
         );
-        let mut req_builder = client.post(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(check_name_availability::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+        let mut req_builder = operation_config.client.post(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
         req_builder = req_builder.json(server_parameters);
-        let req = req_builder.build().context(check_name_availability::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(check_name_availability::ExecuteRequestError)?;
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(check_name_availability::ResponseBytesError)?;
-                let rsp_value: CheckServerNameAvailabilityResult =
-                    serde_json::from_slice(&body).context(check_name_availability::DeserializeError { body })?;
-                Ok(rsp_value)
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(check_name_availability::ResponseBytesError)?;
-                check_name_availability::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(rsp.json().await?),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod check_name_availability {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
-        }
+        use crate::package_2016_05::{models, models::*};
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn list_operation_results(
         operation_config: &crate::OperationConfig,
@@ -588,50 +322,33 @@ pub mod servers {
         operation_id: &str,
         subscription_id: &str,
     ) -> std::result::Result<list_operation_results::Response, list_operation_results::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.AnalysisServices/locations/{}/operationresults/{}",
             &operation_config.base_path, subscription_id, location, operation_id
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list_operation_results::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list_operation_results::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list_operation_results::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => Ok(list_operation_results::Response::Ok200),
-            StatusCode::ACCEPTED => Ok(list_operation_results::Response::Accepted202),
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_operation_results::ResponseBytesError)?;
-                list_operation_results::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(list_operation_results::Response::Ok200(
+                rsp.into_poller(reqwest::Method::GET, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::ACCEPTED => Ok(list_operation_results::Response::Accepted202(
+                rsp.into_poller(reqwest::Method::GET, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod list_operation_results {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2016_05::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200,
-            Accepted202,
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+            Ok200(azure_core::lro::Poller<()>),
+            Accepted202(azure_core::lro::Poller<()>),
         }
+        pub type Error = azure_mgmt_core::Error;
     }
     pub async fn list_operation_statuses(
         operation_config: &crate::OperationConfig,
@@ -639,59 +356,32 @@ pub mod servers {
         operation_id: &str,
         subscription_id: &str,
     ) -> std::result::Result<list_operation_statuses::Response, list_operation_statuses::Error> {
-        let client = &operation_config.client;
         let uri_str = &format!(
             "{}/subscriptions/{}/providers/Microsoft.AnalysisServices/locations/{}/operationstatuses/{}",
             &operation_config.base_path, subscription_id, location, operation_id
         );
-        let mut req_builder = client.get(uri_str);
-        if let Some(token_credential) = &operation_config.token_credential {
-            let token_response = token_credential
-                .get_token(&operation_config.token_credential_resource)
-                .await
-                .context(list_operation_statuses::GetTokenError)?;
-            req_builder = req_builder.bearer_auth(token_response.token.secret());
-        }
-        req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-        let req = req_builder.build().context(list_operation_statuses::BuildRequestError)?;
-        let rsp = client.execute(req).await.context(list_operation_statuses::ExecuteRequestError)?;
+        let mut req_builder = operation_config.client.get(uri_str);
+        req_builder = req_builder.query(&[("api-version", crate::package_2016_05::API_VERSION)]);
+        let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
         match rsp.status() {
-            StatusCode::OK => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_operation_statuses::ResponseBytesError)?;
-                let rsp_value: OperationStatus =
-                    serde_json::from_slice(&body).context(list_operation_statuses::DeserializeError { body })?;
-                Ok(list_operation_statuses::Response::Ok200(rsp_value))
-            }
-            StatusCode::ACCEPTED => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_operation_statuses::ResponseBytesError)?;
-                let rsp_value: OperationStatus =
-                    serde_json::from_slice(&body).context(list_operation_statuses::DeserializeError { body })?;
-                Ok(list_operation_statuses::Response::Accepted202(rsp_value))
-            }
-            status_code => {
-                let body: bytes::Bytes = rsp.bytes().await.context(list_operation_statuses::ResponseBytesError)?;
-                list_operation_statuses::UnexpectedResponse { status_code, body: body }.fail()
-            }
+            StatusCode::OK => Ok(list_operation_statuses::Response::Ok200(
+                rsp.into_poller(reqwest::Method::GET, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            StatusCode::ACCEPTED => Ok(list_operation_statuses::Response::Accepted202(
+                rsp.into_poller(reqwest::Method::GET, uri_str, crate::package_2016_05::API_VERSION)
+                    .await?,
+            )),
+            _ => Err(rsp.unexpected_response().await),
         }
     }
     pub mod list_operation_statuses {
-        use crate::{models, models::*};
-        use reqwest::StatusCode;
-        use snafu::Snafu;
+        use crate::package_2016_05::{models, models::*};
         #[derive(Debug)]
         pub enum Response {
-            Ok200(OperationStatus),
-            Accepted202(OperationStatus),
-        }
-        #[derive(Debug, Snafu)]
-        #[snafu(visibility(pub(crate)))]
-        pub enum Error {
-            UnexpectedResponse { status_code: StatusCode, body: bytes::Bytes },
-            BuildRequestError { source: reqwest::Error },
-            ExecuteRequestError { source: reqwest::Error },
-            ResponseBytesError { source: reqwest::Error },
-            DeserializeError { source: serde_json::Error, body: bytes::Bytes },
-            GetTokenError { source: azure_core::errors::AzureError },
+            Ok200(azure_core::lro::Poller<OperationStatus>),
+            Accepted202(azure_core::lro::Poller<OperationStatus>),
         }
+        pub type Error = azure_mgmt_core::Error;
     }
 }
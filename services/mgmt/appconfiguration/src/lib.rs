@@ -1,47 +1,24 @@
 #![doc = "generated by AutoRust 0.1.0"]
-#[cfg(feature = "package-2020-07-01-preview")]
-mod package_2020_07_01_preview;
-#[cfg(feature = "package-2020-07-01-preview")]
-pub use package_2020_07_01_preview::{models, operations, API_VERSION};
 #[cfg(feature = "package-2020-06-01")]
-mod package_2020_06_01;
+pub mod package_2020_06_01;
+#[cfg(feature = "package-2020-06-01")]
+pub use package_2020_06_01 as v2020_06_01;
+#[cfg(feature = "package-2020-07-01-preview")]
+pub mod package_2020_07_01_preview;
 #[cfg(feature = "package-2020-06-01")]
 pub use package_2020_06_01::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-11-01-preview")]
-mod package_2019_11_01_preview;
-#[cfg(feature = "package-2019-11-01-preview")]
-pub use package_2019_11_01_preview::{models, operations, API_VERSION};
+#[cfg(feature = "package-2020-07-01-preview")]
+pub use package_2020_07_01_preview as v2020_07_01_preview;
 #[cfg(feature = "package-2019-02-01-preview")]
-mod package_2019_02_01_preview;
+pub mod package_2019_02_01_preview;
 #[cfg(feature = "package-2019-02-01-preview")]
-pub use package_2019_02_01_preview::{models, operations, API_VERSION};
+pub use package_2019_02_01_preview as v2019_02_01_preview;
 #[cfg(feature = "package-2019-10-01")]
-mod package_2019_10_01;
+pub mod package_2019_10_01;
 #[cfg(feature = "package-2019-10-01")]
-pub use package_2019_10_01::{models, operations, API_VERSION};
-pub struct OperationConfig {
-    pub api_version: String,
-    pub client: reqwest::Client,
-    pub base_path: String,
-    pub token_credential: Option<Box<dyn azure_core::TokenCredential>>,
-    pub token_credential_resource: String,
-}
-impl OperationConfig {
-    pub fn new(token_credential: Box<dyn azure_core::TokenCredential>) -> Self {
-        Self {
-            token_credential: Some(token_credential),
-            ..Default::default()
-        }
-    }
-}
-impl Default for OperationConfig {
-    fn default() -> Self {
-        Self {
-            api_version: API_VERSION.to_owned(),
-            client: reqwest::Client::new(),
-            base_path: "https://management.azure.com".to_owned(),
-            token_credential: None,
-            token_credential_resource: "https://management.azure.com/".to_owned(),
-        }
-    }
-}
+pub use package_2019_10_01 as v2019_10_01;
+#[cfg(feature = "package-2019-11-01-preview")]
+pub mod package_2019_11_01_preview;
+pub use azure_mgmt_core::OperationConfig;
+#[cfg(feature = "package-2019-11-01-preview")]
+pub use package_2019_11_01_preview as v2019_11_01_preview;
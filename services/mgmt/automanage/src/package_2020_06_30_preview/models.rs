@@ -15,6 +15,9 @@ pub struct AccountUpdate {
     pub update_resource: UpdateResource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub identity: Option<AccountIdentity>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AccountIdentity {
@@ -24,6 +27,9 @@ pub struct AccountIdentity {
     pub tenant_id: Option<String>,
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub type_: Option<account_identity::Type>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod account_identity {
     use super::*;
@@ -31,17 +37,25 @@ pub mod account_identity {
     pub enum Type {
         SystemAssigned,
         None,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AccountList {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Account>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationProfileAssignmentList {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<ConfigurationProfileAssignment>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationProfileAssignment {
@@ -64,6 +78,9 @@ pub struct ConfigurationProfileAssignmentProperties {
     pub provisioning_state: Option<configuration_profile_assignment_properties::ProvisioningState>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compliance: Option<ConfigurationProfileAssignmentCompliance>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod configuration_profile_assignment_properties {
     use super::*;
@@ -73,18 +90,25 @@ pub mod configuration_profile_assignment_properties {
         AzureVirtualMachineBestPracticesDevTest,
         #[serde(rename = "Azure virtual machine best practices – Production")]
         AzureVirtualMachineBestPracticesProduction,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProvisioningState {
         Succeeded,
         Failed,
         Created,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationProfileAssignmentCompliance {
     #[serde(rename = "updateStatus", skip_serializing)]
     pub update_status: Option<configuration_profile_assignment_compliance::UpdateStatus>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod configuration_profile_assignment_compliance {
     use super::*;
@@ -93,6 +117,8 @@ pub mod configuration_profile_assignment_compliance {
         Succeeded,
         Failed,
         Created,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -108,6 +134,9 @@ pub struct ConfigurationProfilePreferenceUpdate {
     pub update_resource: UpdateResource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ConfigurationProfilePreferenceProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationProfilePreferenceProperties {
@@ -115,6 +144,9 @@ pub struct ConfigurationProfilePreferenceProperties {
     pub vm_backup: Option<ConfigurationProfilePreferenceVmBackup>,
     #[serde(rename = "antiMalware", skip_serializing_if = "Option::is_none")]
     pub anti_malware: Option<ConfigurationProfilePreferenceAntiMalware>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationProfilePreferenceVmBackup {
@@ -126,6 +158,9 @@ pub struct ConfigurationProfilePreferenceVmBackup {
     pub retention_policy: Option<String>,
     #[serde(rename = "schedulePolicy", skip_serializing_if = "Option::is_none")]
     pub schedule_policy: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationProfilePreferenceAntiMalware {
@@ -141,6 +176,9 @@ pub struct ConfigurationProfilePreferenceAntiMalware {
     pub scan_day: Option<String>,
     #[serde(rename = "scanTimeInMinutes", skip_serializing_if = "Option::is_none")]
     pub scan_time_in_minutes: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod configuration_profile_preference_anti_malware {
     use super::*;
@@ -148,22 +186,31 @@ pub mod configuration_profile_preference_anti_malware {
     pub enum EnableRealTimeProtection {
         True,
         False,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum RunScheduledScan {
         True,
         False,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ScanType {
         Quick,
         Full,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationProfilePreferenceList {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<ConfigurationProfilePreference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
@@ -175,6 +222,9 @@ pub struct Operation {
     pub display: Option<operation::Display>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<operation::Properties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod operation {
     use super::*;
@@ -188,17 +238,26 @@ pub mod operation {
         pub operation: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub struct Properties {
         #[serde(rename = "statusCode", skip_serializing_if = "Option::is_none")]
         pub status_code: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationList {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Operation>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UpdateResource {
@@ -209,6 +268,9 @@ pub struct UpdateResource {
 pub struct ErrorResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ErrorDetail>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorDetail {
@@ -218,10 +280,13 @@ pub struct ErrorDetail {
     pub message: Option<String>,
     #[serde(skip_serializing)]
     pub target: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub details: Vec<ErrorDetail>,
-    #[serde(rename = "additionalInfo", skip_serializing)]
+    #[serde(rename = "additionalInfo", default, skip_serializing)]
     pub additional_info: Vec<ErrorAdditionalInfo>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorAdditionalInfo {
@@ -229,6 +294,9 @@ pub struct ErrorAdditionalInfo {
     pub type_: Option<String>,
     #[serde(skip_serializing)]
     pub info: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TrackedResource {
@@ -1,83 +1,60 @@
 #![doc = "generated by AutoRust 0.1.0"]
 #[cfg(feature = "package-2020-09-18")]
-mod package_2020_09_18;
+pub mod package_2020_09_18;
+#[cfg(feature = "package-2020-09-18")]
+pub use package_2020_09_18 as v2020_09_18;
 #[cfg(feature = "package-2020-09-18")]
 pub use package_2020_09_18::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-06-14")]
-mod package_2020_06_14;
-#[cfg(feature = "package-2020-06-14")]
-pub use package_2020_06_14::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-02-15")]
-mod package_2020_02_15;
-#[cfg(feature = "package-2020-02-15")]
-pub use package_2020_02_15::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-11-09")]
-mod package_2019_11_09;
-#[cfg(feature = "package-2019-11-09")]
-pub use package_2019_11_09::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-09-07")]
-mod package_2019_09_07;
-#[cfg(feature = "package-2019-09-07")]
-pub use package_2019_09_07::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-05-15")]
-mod package_2019_05_15;
-#[cfg(feature = "package-2019-05-15")]
-pub use package_2019_05_15::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-01-21")]
-mod package_2019_01_21;
-#[cfg(feature = "package-2019-01-21")]
-pub use package_2019_01_21::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-09-07-preview")]
-mod package_2018_09_07_preview;
-#[cfg(feature = "package-2018-09-07-preview")]
-pub use package_2018_09_07_preview::{models, operations, API_VERSION};
 #[cfg(feature = "package-2017-09-07-privatepreview")]
-mod package_2017_09_07_privatepreview;
+pub mod package_2017_09_07_privatepreview;
 #[cfg(feature = "package-2017-09-07-privatepreview")]
-pub use package_2017_09_07_privatepreview::{models, operations, API_VERSION};
-#[cfg(feature = "schema-2019-09-07")]
-mod schema_2019_09_07;
-#[cfg(feature = "schema-2019-09-07")]
-pub use schema_2019_09_07::{models, operations, API_VERSION};
-#[cfg(feature = "schema-2019-05-15")]
-mod schema_2019_05_15;
-#[cfg(feature = "schema-2019-05-15")]
-pub use schema_2019_05_15::{models, operations, API_VERSION};
-#[cfg(feature = "schema-2019-01-21")]
-mod schema_2019_01_21;
-#[cfg(feature = "schema-2019-01-21")]
-pub use schema_2019_01_21::{models, operations, API_VERSION};
-#[cfg(feature = "schema-2018-09-07-preview")]
-mod schema_2018_09_07_preview;
-#[cfg(feature = "schema-2018-09-07-preview")]
-pub use schema_2018_09_07_preview::{models, operations, API_VERSION};
+pub use package_2017_09_07_privatepreview as v2017_09_07_privatepreview;
+#[cfg(feature = "package-2018-09-07-preview")]
+pub mod package_2018_09_07_preview;
+#[cfg(feature = "package-2018-09-07-preview")]
+pub use package_2018_09_07_preview as v2018_09_07_preview;
+#[cfg(feature = "package-2019-01-21")]
+pub mod package_2019_01_21;
+#[cfg(feature = "package-2019-01-21")]
+pub use package_2019_01_21 as v2019_01_21;
+#[cfg(feature = "package-2019-05-15")]
+pub mod package_2019_05_15;
+#[cfg(feature = "package-2019-05-15")]
+pub use package_2019_05_15 as v2019_05_15;
+#[cfg(feature = "package-2019-09-07")]
+pub mod package_2019_09_07;
+#[cfg(feature = "package-2019-09-07")]
+pub use package_2019_09_07 as v2019_09_07;
+#[cfg(feature = "package-2019-11-09")]
+pub mod package_2019_11_09;
+#[cfg(feature = "package-2019-11-09")]
+pub use package_2019_11_09 as v2019_11_09;
+#[cfg(feature = "package-2020-02-15")]
+pub mod package_2020_02_15;
+#[cfg(feature = "package-2020-02-15")]
+pub use package_2020_02_15 as v2020_02_15;
+#[cfg(feature = "package-2020-06-14")]
+pub mod package_2020_06_14;
+#[cfg(feature = "package-2020-06-14")]
+pub use package_2020_06_14 as v2020_06_14;
 #[cfg(feature = "schema-2017-09-07-privatepreview")]
-mod schema_2017_09_07_privatepreview;
+pub mod schema_2017_09_07_privatepreview;
 #[cfg(feature = "schema-2017-09-07-privatepreview")]
-pub use schema_2017_09_07_privatepreview::{models, operations, API_VERSION};
-pub struct OperationConfig {
-    pub api_version: String,
-    pub client: reqwest::Client,
-    pub base_path: String,
-    pub token_credential: Option<Box<dyn azure_core::TokenCredential>>,
-    pub token_credential_resource: String,
-}
-impl OperationConfig {
-    pub fn new(token_credential: Box<dyn azure_core::TokenCredential>) -> Self {
-        Self {
-            token_credential: Some(token_credential),
-            ..Default::default()
-        }
-    }
-}
-impl Default for OperationConfig {
-    fn default() -> Self {
-        Self {
-            api_version: API_VERSION.to_owned(),
-            client: reqwest::Client::new(),
-            base_path: "https://management.azure.com".to_owned(),
-            token_credential: None,
-            token_credential_resource: "https://management.azure.com/".to_owned(),
-        }
-    }
-}
+pub use schema_2017_09_07_privatepreview as schema_v2017_09_07_privatepreview;
+#[cfg(feature = "schema-2018-09-07-preview")]
+pub mod schema_2018_09_07_preview;
+#[cfg(feature = "schema-2018-09-07-preview")]
+pub use schema_2018_09_07_preview as schema_v2018_09_07_preview;
+#[cfg(feature = "schema-2019-01-21")]
+pub mod schema_2019_01_21;
+#[cfg(feature = "schema-2019-01-21")]
+pub use schema_2019_01_21 as schema_v2019_01_21;
+#[cfg(feature = "schema-2019-05-15")]
+pub mod schema_2019_05_15;
+#[cfg(feature = "schema-2019-05-15")]
+pub use schema_2019_05_15 as schema_v2019_05_15;
+#[cfg(feature = "schema-2019-09-07")]
+pub mod schema_2019_09_07;
+pub use azure_mgmt_core::OperationConfig;
+#[cfg(feature = "schema-2019-09-07")]
+pub use schema_2019_09_07 as schema_v2019_09_07;
@@ -12,7 +12,7 @@ pub struct ClusterProperties {
     pub uri: Option<String>,
     #[serde(rename = "dataIngestionUri", skip_serializing)]
     pub data_ingestion_uri: Option<String>,
-    #[serde(rename = "trustedExternalTenants", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "trustedExternalTenants", default, skip_serializing_if = "Vec::is_empty")]
     pub trusted_external_tenants: Vec<TrustedExternalTenant>,
     #[serde(rename = "optimizedAutoscale", skip_serializing_if = "Option::is_none")]
     pub optimized_autoscale: Option<OptimizedAutoscale>,
@@ -22,6 +22,9 @@ pub struct ClusterProperties {
     pub enable_streaming_ingest: Option<bool>,
     #[serde(rename = "virtualNetworkConfiguration", skip_serializing_if = "Option::is_none")]
     pub virtual_network_configuration: Option<VirtualNetworkConfiguration>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_properties {
     use super::*;
@@ -36,6 +39,8 @@ pub mod cluster_properties {
         Stopped,
         Starting,
         Updating,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProvisioningState {
@@ -45,12 +50,17 @@ pub mod cluster_properties {
         Succeeded,
         Failed,
         Moving,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TrustedExternalTenant {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureResourceSku {
@@ -60,6 +70,9 @@ pub struct AzureResourceSku {
     pub sku: Option<AzureSku>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub capacity: Option<AzureCapacity>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureCapacity {
@@ -68,6 +81,9 @@ pub struct AzureCapacity {
     pub minimum: i64,
     pub maximum: i64,
     pub default: i64,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod azure_capacity {
     use super::*;
@@ -79,12 +95,17 @@ pub mod azure_capacity {
         Manual,
         #[serde(rename = "none")]
         None,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SkuDescriptionList {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<SkuDescription>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SkuDescription {
@@ -94,18 +115,24 @@ pub struct SkuDescription {
     pub name: Option<String>,
     #[serde(skip_serializing)]
     pub tier: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub locations: Vec<String>,
-    #[serde(rename = "locationInfo", skip_serializing)]
+    #[serde(rename = "locationInfo", default, skip_serializing)]
     pub location_info: Vec<SkuLocationInfoItem>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub restrictions: Vec<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SkuLocationInfoItem {
     pub location: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub zones: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureSku {
@@ -113,6 +140,9 @@ pub struct AzureSku {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub capacity: Option<i64>,
     pub tier: azure_sku::Tier,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod azure_sku {
     use super::*;
@@ -142,11 +172,15 @@ pub mod azure_sku {
         StandardL4s,
         #[serde(rename = "Dev(No SLA)_Standard_D11_v2")]
         DevNoSlaStandardD11V2,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Tier {
         Basic,
         Standard,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 pub type Zones = Vec<String>;
@@ -157,6 +191,9 @@ pub struct OptimizedAutoscale {
     pub is_enabled: bool,
     pub minimum: i64,
     pub maximum: i64,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VirtualNetworkConfiguration {
@@ -166,11 +203,17 @@ pub struct VirtualNetworkConfiguration {
     pub engine_public_ip_id: String,
     #[serde(rename = "dataManagementPublicIpId")]
     pub data_management_public_ip_id: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabaseStatistics {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size: Option<f64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabaseProperties {
@@ -182,6 +225,9 @@ pub struct DatabaseProperties {
     pub hot_cache_period: Option<String>,
     #[serde(skip_serializing)]
     pub statistics: Option<DatabaseStatistics>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod database_properties {
     use super::*;
@@ -193,6 +239,8 @@ pub mod database_properties {
         Succeeded,
         Failed,
         Moving,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -207,8 +255,11 @@ pub struct EventHubConnectionProperties {
     pub mapping_rule_name: Option<String>,
     #[serde(rename = "dataFormat", skip_serializing_if = "Option::is_none")]
     pub data_format: Option<DataFormat>,
-    #[serde(rename = "eventSystemProperties", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "eventSystemProperties", default, skip_serializing_if = "Vec::is_empty")]
     pub event_system_properties: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct IotHubConnectionProperties {
@@ -222,10 +273,13 @@ pub struct IotHubConnectionProperties {
     pub mapping_rule_name: Option<String>,
     #[serde(rename = "dataFormat", skip_serializing_if = "Option::is_none")]
     pub data_format: Option<DataFormat>,
-    #[serde(rename = "eventSystemProperties", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "eventSystemProperties", default, skip_serializing_if = "Vec::is_empty")]
     pub event_system_properties: Vec<String>,
     #[serde(rename = "sharedAccessPolicyName")]
     pub shared_access_policy_name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum DataFormat {
@@ -253,6 +307,8 @@ pub enum DataFormat {
     Avro,
     #[serde(rename = "TSVE")]
     Tsve,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EventGridConnectionProperties {
@@ -268,6 +324,9 @@ pub struct EventGridConnectionProperties {
     pub mapping_rule_name: Option<String>,
     #[serde(rename = "dataFormat")]
     pub data_format: DataFormat,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Cluster {
@@ -291,6 +350,9 @@ pub struct ClusterUpdate {
     pub sku: Option<AzureSku>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ClusterProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Database {
@@ -309,6 +371,9 @@ pub struct DatabaseUpdate {
     pub location: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<DatabaseProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabasePrincipal {
@@ -324,6 +389,9 @@ pub struct DatabasePrincipal {
     pub app_id: Option<String>,
     #[serde(rename = "tenantName", skip_serializing)]
     pub tenant_name: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod database_principal {
     use super::*;
@@ -335,28 +403,41 @@ pub mod database_principal {
         User,
         UnrestrictedViewers,
         Viewer,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         App,
         Group,
         User,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Cluster>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabaseListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Database>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabasePrincipalListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DatabasePrincipal>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnection {
@@ -373,17 +454,25 @@ pub mod data_connection {
         EventHub,
         EventGrid,
         IotHub,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnectionValidationResult {
     #[serde(rename = "errorMessage", skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabasePrincipalListRequest {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DatabasePrincipal>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnectionValidation {
@@ -391,6 +480,9 @@ pub struct DataConnectionValidation {
     pub data_connection_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<DataConnection>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EventHubDataConnection {
@@ -415,24 +507,36 @@ pub struct EventGridDataConnection {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnectionValidationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DataConnectionValidationResult>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnectionListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DataConnection>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudError {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterCheckNameRequest {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: cluster_check_name_request::Type,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_check_name_request {
     use super::*;
@@ -440,6 +544,8 @@ pub mod cluster_check_name_request {
     pub enum Type {
         #[serde(rename = "Microsoft.Kusto/clusters")]
         MicrosoftKustoClusters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -447,6 +553,9 @@ pub struct DatabaseCheckNameRequest {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: database_check_name_request::Type,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod database_check_name_request {
     use super::*;
@@ -454,6 +563,8 @@ pub mod database_check_name_request {
     pub enum Type {
         #[serde(rename = "Microsoft.Kusto/clusters/databases")]
         MicrosoftKustoClustersDatabases,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -461,6 +572,9 @@ pub struct DataConnectionCheckNameRequest {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: data_connection_check_name_request::Type,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod data_connection_check_name_request {
     use super::*;
@@ -468,6 +582,8 @@ pub mod data_connection_check_name_request {
     pub enum Type {
         #[serde(rename = "Microsoft.Kusto/clusters/databases/dataConnections")]
         MicrosoftKustoClustersDatabasesDataConnections,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -480,6 +596,9 @@ pub struct CheckNameResult {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<check_name_result::Reason>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod check_name_result {
     use super::*;
@@ -487,12 +606,17 @@ pub mod check_name_result {
     pub enum Reason {
         Invalid,
         AlreadyExists,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListResourceSkusResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<AzureResourceSku>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudErrorBody {
@@ -502,15 +626,30 @@ pub struct CloudErrorBody {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub details: Vec<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Operation>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationListResult {
+    type Item = Operation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Operation> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
@@ -522,6 +661,9 @@ pub struct Operation {
     pub origin: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod operation {
     use super::*;
@@ -535,6 +677,9 @@ pub mod operation {
         pub resource: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
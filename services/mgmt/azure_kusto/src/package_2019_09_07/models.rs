@@ -12,7 +12,7 @@ pub struct ClusterProperties {
     pub uri: Option<String>,
     #[serde(rename = "dataIngestionUri", skip_serializing)]
     pub data_ingestion_uri: Option<String>,
-    #[serde(rename = "trustedExternalTenants", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "trustedExternalTenants", default, skip_serializing_if = "Vec::is_empty")]
     pub trusted_external_tenants: Vec<TrustedExternalTenant>,
     #[serde(rename = "optimizedAutoscale", skip_serializing_if = "Option::is_none")]
     pub optimized_autoscale: Option<OptimizedAutoscale>,
@@ -24,6 +24,9 @@ pub struct ClusterProperties {
     pub virtual_network_configuration: Option<VirtualNetworkConfiguration>,
     #[serde(rename = "keyVaultProperties", skip_serializing_if = "Option::is_none")]
     pub key_vault_properties: Option<KeyVaultProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_properties {
     use super::*;
@@ -38,6 +41,8 @@ pub mod cluster_properties {
         Stopped,
         Starting,
         Updating,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProvisioningState {
@@ -47,12 +52,17 @@ pub mod cluster_properties {
         Succeeded,
         Failed,
         Moving,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TrustedExternalTenant {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureResourceSku {
@@ -62,6 +72,9 @@ pub struct AzureResourceSku {
     pub sku: Option<AzureSku>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub capacity: Option<AzureCapacity>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureCapacity {
@@ -70,6 +83,9 @@ pub struct AzureCapacity {
     pub minimum: i64,
     pub maximum: i64,
     pub default: i64,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod azure_capacity {
     use super::*;
@@ -81,12 +97,17 @@ pub mod azure_capacity {
         Manual,
         #[serde(rename = "none")]
         None,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SkuDescriptionList {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<SkuDescription>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SkuDescription {
@@ -96,18 +117,24 @@ pub struct SkuDescription {
     pub name: Option<String>,
     #[serde(skip_serializing)]
     pub tier: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub locations: Vec<String>,
-    #[serde(rename = "locationInfo", skip_serializing)]
+    #[serde(rename = "locationInfo", default, skip_serializing)]
     pub location_info: Vec<SkuLocationInfoItem>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub restrictions: Vec<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SkuLocationInfoItem {
     pub location: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub zones: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureSku {
@@ -115,6 +142,9 @@ pub struct AzureSku {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub capacity: Option<i64>,
     pub tier: azure_sku::Tier,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod azure_sku {
     use super::*;
@@ -144,11 +174,15 @@ pub mod azure_sku {
         StandardL4s,
         #[serde(rename = "Dev(No SLA)_Standard_D11_v2")]
         DevNoSlaStandardD11V2,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Tier {
         Basic,
         Standard,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 pub type Zones = Vec<String>;
@@ -159,6 +193,9 @@ pub struct OptimizedAutoscale {
     pub is_enabled: bool,
     pub minimum: i64,
     pub maximum: i64,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VirtualNetworkConfiguration {
@@ -168,11 +205,17 @@ pub struct VirtualNetworkConfiguration {
     pub engine_public_ip_id: String,
     #[serde(rename = "dataManagementPublicIpId")]
     pub data_management_public_ip_id: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabaseStatistics {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size: Option<f64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AttachedDatabaseConfigurationProperties {
@@ -182,10 +225,13 @@ pub struct AttachedDatabaseConfigurationProperties {
     pub database_name: String,
     #[serde(rename = "clusterResourceId")]
     pub cluster_resource_id: String,
-    #[serde(rename = "attachedDatabaseNames", skip_serializing)]
+    #[serde(rename = "attachedDatabaseNames", default, skip_serializing)]
     pub attached_database_names: Vec<String>,
     #[serde(rename = "defaultPrincipalsModificationKind")]
     pub default_principals_modification_kind: attached_database_configuration_properties::DefaultPrincipalsModificationKind,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod attached_database_configuration_properties {
     use super::*;
@@ -197,12 +243,16 @@ pub mod attached_database_configuration_properties {
         Succeeded,
         Failed,
         Moving,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum DefaultPrincipalsModificationKind {
         Union,
         Replace,
         None,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -215,6 +265,9 @@ pub struct ReadWriteDatabaseProperties {
     pub hot_cache_period: Option<String>,
     #[serde(skip_serializing)]
     pub statistics: Option<DatabaseStatistics>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod read_write_database_properties {
     use super::*;
@@ -226,6 +279,8 @@ pub mod read_write_database_properties {
         Succeeded,
         Failed,
         Moving,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -244,6 +299,9 @@ pub struct ReadOnlyFollowingDatabaseProperties {
     pub attached_database_configuration_name: Option<String>,
     #[serde(rename = "principalsModificationKind", skip_serializing)]
     pub principals_modification_kind: Option<read_only_following_database_properties::PrincipalsModificationKind>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod read_only_following_database_properties {
     use super::*;
@@ -255,12 +313,16 @@ pub mod read_only_following_database_properties {
         Succeeded,
         Failed,
         Moving,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum PrincipalsModificationKind {
         Union,
         Replace,
         None,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -275,8 +337,11 @@ pub struct EventHubConnectionProperties {
     pub mapping_rule_name: Option<String>,
     #[serde(rename = "dataFormat", skip_serializing_if = "Option::is_none")]
     pub data_format: Option<DataFormat>,
-    #[serde(rename = "eventSystemProperties", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "eventSystemProperties", default, skip_serializing_if = "Vec::is_empty")]
     pub event_system_properties: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct IotHubConnectionProperties {
@@ -290,10 +355,13 @@ pub struct IotHubConnectionProperties {
     pub mapping_rule_name: Option<String>,
     #[serde(rename = "dataFormat", skip_serializing_if = "Option::is_none")]
     pub data_format: Option<DataFormat>,
-    #[serde(rename = "eventSystemProperties", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "eventSystemProperties", default, skip_serializing_if = "Vec::is_empty")]
     pub event_system_properties: Vec<String>,
     #[serde(rename = "sharedAccessPolicyName")]
     pub shared_access_policy_name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum DataFormat {
@@ -321,6 +389,8 @@ pub enum DataFormat {
     Avro,
     #[serde(rename = "TSVE")]
     Tsve,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EventGridConnectionProperties {
@@ -336,6 +406,9 @@ pub struct EventGridConnectionProperties {
     pub mapping_rule_name: Option<String>,
     #[serde(rename = "dataFormat")]
     pub data_format: DataFormat,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Cluster {
@@ -363,11 +436,17 @@ pub struct ClusterUpdate {
     pub identity: Option<Identity>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ClusterProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AttachedDatabaseConfigurationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<AttachedDatabaseConfiguration>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AttachedDatabaseConfiguration {
@@ -393,6 +472,8 @@ pub mod database {
     pub enum Kind {
         ReadWrite,
         ReadOnlyFollowing,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -423,6 +504,9 @@ pub struct DatabasePrincipal {
     pub app_id: Option<String>,
     #[serde(rename = "tenantName", skip_serializing)]
     pub tenant_name: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod database_principal {
     use super::*;
@@ -434,33 +518,49 @@ pub mod database_principal {
         User,
         UnrestrictedViewers,
         Viewer,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         App,
         Group,
         User,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Cluster>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabaseListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Database>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabasePrincipalListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DatabasePrincipal>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FollowerDatabaseListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<FollowerDatabaseDefinition>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnection {
@@ -477,17 +577,25 @@ pub mod data_connection {
         EventHub,
         EventGrid,
         IotHub,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnectionValidationResult {
     #[serde(rename = "errorMessage", skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabasePrincipalListRequest {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DatabasePrincipal>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FollowerDatabaseDefinition {
@@ -497,6 +605,9 @@ pub struct FollowerDatabaseDefinition {
     pub attached_database_configuration_name: String,
     #[serde(rename = "databaseName", skip_serializing)]
     pub database_name: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnectionValidation {
@@ -504,6 +615,9 @@ pub struct DataConnectionValidation {
     pub data_connection_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<DataConnection>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EventHubDataConnection {
@@ -528,24 +642,36 @@ pub struct EventGridDataConnection {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnectionValidationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DataConnectionValidationResult>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnectionListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DataConnection>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudError {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterCheckNameRequest {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: cluster_check_name_request::Type,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_check_name_request {
     use super::*;
@@ -553,6 +679,8 @@ pub mod cluster_check_name_request {
     pub enum Type {
         #[serde(rename = "Microsoft.Kusto/clusters")]
         MicrosoftKustoClusters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -560,6 +688,9 @@ pub struct CheckNameRequest {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: check_name_request::Type,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod check_name_request {
     use super::*;
@@ -569,6 +700,8 @@ pub mod check_name_request {
         MicrosoftKustoClustersDatabases,
         #[serde(rename = "Microsoft.Kusto/clusters/attachedDatabaseConfigurations")]
         MicrosoftKustoClustersAttachedDatabaseConfigurations,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -576,6 +709,9 @@ pub struct DataConnectionCheckNameRequest {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: data_connection_check_name_request::Type,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod data_connection_check_name_request {
     use super::*;
@@ -583,6 +719,8 @@ pub mod data_connection_check_name_request {
     pub enum Type {
         #[serde(rename = "Microsoft.Kusto/clusters/databases/dataConnections")]
         MicrosoftKustoClustersDatabasesDataConnections,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -595,6 +733,9 @@ pub struct CheckNameResult {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<check_name_result::Reason>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod check_name_result {
     use super::*;
@@ -602,12 +743,17 @@ pub mod check_name_result {
     pub enum Reason {
         Invalid,
         AlreadyExists,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListResourceSkusResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<AzureResourceSku>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudErrorBody {
@@ -617,15 +763,30 @@ pub struct CloudErrorBody {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub details: Vec<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Operation>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationListResult {
+    type Item = Operation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Operation> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
@@ -637,6 +798,9 @@ pub struct Operation {
     pub origin: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod operation {
     use super::*;
@@ -650,6 +814,9 @@ pub mod operation {
         pub resource: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -662,6 +829,9 @@ pub struct Identity {
     pub type_: identity::Type,
     #[serde(rename = "userAssignedIdentities", skip_serializing_if = "Option::is_none")]
     pub user_assigned_identities: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod identity {
     use super::*;
@@ -669,6 +839,8 @@ pub mod identity {
     pub enum Type {
         None,
         SystemAssigned,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -679,6 +851,9 @@ pub struct KeyVaultProperties {
     pub key_version: String,
     #[serde(rename = "keyVaultUri")]
     pub key_vault_uri: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TrackedResource {
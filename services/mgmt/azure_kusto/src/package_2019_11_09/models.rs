@@ -14,7 +14,7 @@ pub struct ClusterProperties {
     pub data_ingestion_uri: Option<String>,
     #[serde(rename = "stateReason", skip_serializing)]
     pub state_reason: Option<String>,
-    #[serde(rename = "trustedExternalTenants", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "trustedExternalTenants", default, skip_serializing_if = "Vec::is_empty")]
     pub trusted_external_tenants: Vec<TrustedExternalTenant>,
     #[serde(rename = "optimizedAutoscale", skip_serializing_if = "Option::is_none")]
     pub optimized_autoscale: Option<OptimizedAutoscale>,
@@ -26,6 +26,9 @@ pub struct ClusterProperties {
     pub virtual_network_configuration: Option<VirtualNetworkConfiguration>,
     #[serde(rename = "keyVaultProperties", skip_serializing_if = "Option::is_none")]
     pub key_vault_properties: Option<KeyVaultProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_properties {
     use super::*;
@@ -40,6 +43,8 @@ pub mod cluster_properties {
         Stopped,
         Starting,
         Updating,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProvisioningState {
@@ -49,12 +54,17 @@ pub mod cluster_properties {
         Succeeded,
         Failed,
         Moving,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TrustedExternalTenant {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureResourceSku {
@@ -64,6 +74,9 @@ pub struct AzureResourceSku {
     pub sku: Option<AzureSku>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub capacity: Option<AzureCapacity>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureCapacity {
@@ -72,6 +85,9 @@ pub struct AzureCapacity {
     pub minimum: i64,
     pub maximum: i64,
     pub default: i64,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod azure_capacity {
     use super::*;
@@ -83,12 +99,17 @@ pub mod azure_capacity {
         Manual,
         #[serde(rename = "none")]
         None,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SkuDescriptionList {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<SkuDescription>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SkuDescription {
@@ -98,18 +119,24 @@ pub struct SkuDescription {
     pub name: Option<String>,
     #[serde(skip_serializing)]
     pub tier: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub locations: Vec<String>,
-    #[serde(rename = "locationInfo", skip_serializing)]
+    #[serde(rename = "locationInfo", default, skip_serializing)]
     pub location_info: Vec<SkuLocationInfoItem>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub restrictions: Vec<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SkuLocationInfoItem {
     pub location: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub zones: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureSku {
@@ -117,6 +144,9 @@ pub struct AzureSku {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub capacity: Option<i64>,
     pub tier: azure_sku::Tier,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod azure_sku {
     use super::*;
@@ -146,11 +176,15 @@ pub mod azure_sku {
         StandardL4s,
         #[serde(rename = "Dev(No SLA)_Standard_D11_v2")]
         DevNoSlaStandardD11V2,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Tier {
         Basic,
         Standard,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 pub type Zones = Vec<String>;
@@ -161,6 +195,9 @@ pub struct OptimizedAutoscale {
     pub is_enabled: bool,
     pub minimum: i64,
     pub maximum: i64,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VirtualNetworkConfiguration {
@@ -170,11 +207,17 @@ pub struct VirtualNetworkConfiguration {
     pub engine_public_ip_id: String,
     #[serde(rename = "dataManagementPublicIpId")]
     pub data_management_public_ip_id: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabaseStatistics {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size: Option<f64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AttachedDatabaseConfigurationProperties {
@@ -184,10 +227,13 @@ pub struct AttachedDatabaseConfigurationProperties {
     pub database_name: String,
     #[serde(rename = "clusterResourceId")]
     pub cluster_resource_id: String,
-    #[serde(rename = "attachedDatabaseNames", skip_serializing)]
+    #[serde(rename = "attachedDatabaseNames", default, skip_serializing)]
     pub attached_database_names: Vec<String>,
     #[serde(rename = "defaultPrincipalsModificationKind")]
     pub default_principals_modification_kind: attached_database_configuration_properties::DefaultPrincipalsModificationKind,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod attached_database_configuration_properties {
     use super::*;
@@ -199,12 +245,16 @@ pub mod attached_database_configuration_properties {
         Succeeded,
         Failed,
         Moving,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum DefaultPrincipalsModificationKind {
         Union,
         Replace,
         None,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -219,6 +269,9 @@ pub struct ReadWriteDatabaseProperties {
     pub statistics: Option<DatabaseStatistics>,
     #[serde(rename = "isFollowed", skip_serializing)]
     pub is_followed: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod read_write_database_properties {
     use super::*;
@@ -230,6 +283,8 @@ pub mod read_write_database_properties {
         Succeeded,
         Failed,
         Moving,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -248,6 +303,9 @@ pub struct ReadOnlyFollowingDatabaseProperties {
     pub attached_database_configuration_name: Option<String>,
     #[serde(rename = "principalsModificationKind", skip_serializing)]
     pub principals_modification_kind: Option<read_only_following_database_properties::PrincipalsModificationKind>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod read_only_following_database_properties {
     use super::*;
@@ -259,12 +317,16 @@ pub mod read_only_following_database_properties {
         Succeeded,
         Failed,
         Moving,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum PrincipalsModificationKind {
         Union,
         Replace,
         None,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -279,10 +341,13 @@ pub struct EventHubConnectionProperties {
     pub mapping_rule_name: Option<String>,
     #[serde(rename = "dataFormat", skip_serializing_if = "Option::is_none")]
     pub data_format: Option<EventHubDataFormat>,
-    #[serde(rename = "eventSystemProperties", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "eventSystemProperties", default, skip_serializing_if = "Vec::is_empty")]
     pub event_system_properties: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compression: Option<Compression>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct IotHubConnectionProperties {
@@ -296,10 +361,13 @@ pub struct IotHubConnectionProperties {
     pub mapping_rule_name: Option<String>,
     #[serde(rename = "dataFormat", skip_serializing_if = "Option::is_none")]
     pub data_format: Option<IotHubDataFormat>,
-    #[serde(rename = "eventSystemProperties", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "eventSystemProperties", default, skip_serializing_if = "Vec::is_empty")]
     pub event_system_properties: Vec<String>,
     #[serde(rename = "sharedAccessPolicyName")]
     pub shared_access_policy_name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum EventHubDataFormat {
@@ -331,6 +399,8 @@ pub enum EventHubDataFormat {
     Parquet,
     #[serde(rename = "ORC")]
     Orc,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum IotHubDataFormat {
@@ -362,6 +432,8 @@ pub enum IotHubDataFormat {
     Parquet,
     #[serde(rename = "ORC")]
     Orc,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum EventGridDataFormat {
@@ -393,11 +465,15 @@ pub enum EventGridDataFormat {
     Parquet,
     #[serde(rename = "ORC")]
     Orc,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Compression {
     None,
     GZip,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EventGridConnectionProperties {
@@ -413,6 +489,9 @@ pub struct EventGridConnectionProperties {
     pub mapping_rule_name: Option<String>,
     #[serde(rename = "dataFormat")]
     pub data_format: EventGridDataFormat,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Cluster {
@@ -440,11 +519,17 @@ pub struct ClusterUpdate {
     pub identity: Option<Identity>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ClusterProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AttachedDatabaseConfigurationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<AttachedDatabaseConfiguration>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AttachedDatabaseConfiguration {
@@ -469,6 +554,8 @@ pub mod database {
     pub enum Kind {
         ReadWrite,
         ReadOnlyFollowing,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -499,6 +586,9 @@ pub struct DatabasePrincipal {
     pub app_id: Option<String>,
     #[serde(rename = "tenantName", skip_serializing)]
     pub tenant_name: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod database_principal {
     use super::*;
@@ -510,12 +600,16 @@ pub mod database_principal {
         User,
         UnrestrictedViewers,
         Viewer,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         App,
         Group,
         User,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -533,6 +627,9 @@ pub struct DatabasePrincipalProperties {
     pub principal_name: Option<String>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<database_principal_properties::ProvisioningState>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod database_principal_properties {
     use super::*;
@@ -544,12 +641,16 @@ pub mod database_principal_properties {
         User,
         UnrestrictedViewers,
         Viewer,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum PrincipalType {
         App,
         Group,
         User,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProvisioningState {
@@ -559,6 +660,8 @@ pub mod database_principal_properties {
         Succeeded,
         Failed,
         Moving,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -576,6 +679,9 @@ pub struct ClusterPrincipalProperties {
     pub principal_name: Option<String>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<cluster_principal_properties::ProvisioningState>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_principal_properties {
     use super::*;
@@ -583,12 +689,16 @@ pub mod cluster_principal_properties {
     pub enum Role {
         AllDatabasesAdmin,
         AllDatabasesViewer,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum PrincipalType {
         App,
         Group,
         User,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProvisioningState {
@@ -598,6 +708,8 @@ pub mod cluster_principal_properties {
         Succeeded,
         Failed,
         Moving,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -616,33 +728,51 @@ pub struct ClusterPrincipalAssignment {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Cluster>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabaseListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Database>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabasePrincipalAssignmentListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DatabasePrincipalAssignment>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterPrincipalAssignmentListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<ClusterPrincipalAssignment>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabasePrincipalListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DatabasePrincipal>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FollowerDatabaseListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<FollowerDatabaseDefinition>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnection {
@@ -659,17 +789,25 @@ pub mod data_connection {
         EventHub,
         EventGrid,
         IotHub,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnectionValidationResult {
     #[serde(rename = "errorMessage", skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DatabasePrincipalListRequest {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DatabasePrincipal>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FollowerDatabaseDefinition {
@@ -679,11 +817,17 @@ pub struct FollowerDatabaseDefinition {
     pub attached_database_configuration_name: String,
     #[serde(rename = "databaseName", skip_serializing)]
     pub database_name: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DiagnoseVirtualNetworkResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub findings: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnectionValidation {
@@ -691,6 +835,9 @@ pub struct DataConnectionValidation {
     pub data_connection_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<DataConnection>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EventHubDataConnection {
@@ -715,24 +862,36 @@ pub struct EventGridDataConnection {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnectionValidationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DataConnectionValidationResult>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataConnectionListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<DataConnection>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudError {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterCheckNameRequest {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: cluster_check_name_request::Type,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_check_name_request {
     use super::*;
@@ -740,6 +899,8 @@ pub mod cluster_check_name_request {
     pub enum Type {
         #[serde(rename = "Microsoft.Kusto/clusters")]
         MicrosoftKustoClusters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -747,6 +908,9 @@ pub struct CheckNameRequest {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: check_name_request::Type,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod check_name_request {
     use super::*;
@@ -756,6 +920,8 @@ pub mod check_name_request {
         MicrosoftKustoClustersDatabases,
         #[serde(rename = "Microsoft.Kusto/clusters/attachedDatabaseConfigurations")]
         MicrosoftKustoClustersAttachedDatabaseConfigurations,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -763,6 +929,9 @@ pub struct ClusterPrincipalAssignmentCheckNameRequest {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: cluster_principal_assignment_check_name_request::Type,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_principal_assignment_check_name_request {
     use super::*;
@@ -770,6 +939,8 @@ pub mod cluster_principal_assignment_check_name_request {
     pub enum Type {
         #[serde(rename = "Microsoft.Kusto/clusters/principalAssignments")]
         MicrosoftKustoClustersPrincipalAssignments,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -777,6 +948,9 @@ pub struct DataConnectionCheckNameRequest {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: data_connection_check_name_request::Type,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod data_connection_check_name_request {
     use super::*;
@@ -784,6 +958,8 @@ pub mod data_connection_check_name_request {
     pub enum Type {
         #[serde(rename = "Microsoft.Kusto/clusters/databases/dataConnections")]
         MicrosoftKustoClustersDatabasesDataConnections,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -791,6 +967,9 @@ pub struct DatabasePrincipalAssignmentCheckNameRequest {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: database_principal_assignment_check_name_request::Type,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod database_principal_assignment_check_name_request {
     use super::*;
@@ -798,6 +977,8 @@ pub mod database_principal_assignment_check_name_request {
     pub enum Type {
         #[serde(rename = "Microsoft.Kusto/clusters/databases/principalAssignments")]
         MicrosoftKustoClustersDatabasesPrincipalAssignments,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -810,6 +991,9 @@ pub struct CheckNameResult {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<check_name_result::Reason>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod check_name_result {
     use super::*;
@@ -817,12 +1001,17 @@ pub mod check_name_result {
     pub enum Reason {
         Invalid,
         AlreadyExists,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListResourceSkusResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<AzureResourceSku>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudErrorBody {
@@ -832,15 +1021,30 @@ pub struct CloudErrorBody {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub details: Vec<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Operation>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationListResult {
+    type Item = Operation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Operation> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
@@ -852,6 +1056,9 @@ pub struct Operation {
     pub origin: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod operation {
     use super::*;
@@ -865,6 +1072,9 @@ pub mod operation {
         pub resource: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -877,6 +1087,9 @@ pub struct Identity {
     pub type_: identity::Type,
     #[serde(rename = "userAssignedIdentities", skip_serializing_if = "Option::is_none")]
     pub user_assigned_identities: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod identity {
     use super::*;
@@ -884,6 +1097,8 @@ pub mod identity {
     pub enum Type {
         None,
         SystemAssigned,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -894,6 +1109,9 @@ pub struct KeyVaultProperties {
     pub key_version: String,
     #[serde(rename = "keyVaultUri")]
     pub key_vault_uri: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TrackedResource {
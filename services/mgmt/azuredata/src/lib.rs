@@ -1,39 +1,16 @@
 #![doc = "generated by AutoRust 0.1.0"]
 #[cfg(feature = "package-preview-2020-09-08")]
-mod package_preview_2020_09_08;
+pub mod package_preview_2020_09_08;
+#[cfg(feature = "package-preview-2020-09-08")]
+pub use package_preview_2020_09_08 as preview_v2020_09_08;
 #[cfg(feature = "package-preview-2020-09-08")]
 pub use package_preview_2020_09_08::{models, operations, API_VERSION};
-#[cfg(feature = "package-preview-2019-07")]
-mod package_preview_2019_07;
-#[cfg(feature = "package-preview-2019-07")]
-pub use package_preview_2019_07::{models, operations, API_VERSION};
 #[cfg(feature = "package-2017-03-01-preview")]
-mod package_2017_03_01_preview;
+pub mod package_2017_03_01_preview;
 #[cfg(feature = "package-2017-03-01-preview")]
-pub use package_2017_03_01_preview::{models, operations, API_VERSION};
-pub struct OperationConfig {
-    pub api_version: String,
-    pub client: reqwest::Client,
-    pub base_path: String,
-    pub token_credential: Option<Box<dyn azure_core::TokenCredential>>,
-    pub token_credential_resource: String,
-}
-impl OperationConfig {
-    pub fn new(token_credential: Box<dyn azure_core::TokenCredential>) -> Self {
-        Self {
-            token_credential: Some(token_credential),
-            ..Default::default()
-        }
-    }
-}
-impl Default for OperationConfig {
-    fn default() -> Self {
-        Self {
-            api_version: API_VERSION.to_owned(),
-            client: reqwest::Client::new(),
-            base_path: "https://management.azure.com".to_owned(),
-            token_credential: None,
-            token_credential_resource: "https://management.azure.com/".to_owned(),
-        }
-    }
-}
+pub use package_2017_03_01_preview as v2017_03_01_preview;
+#[cfg(feature = "package-preview-2019-07")]
+pub mod package_preview_2019_07;
+pub use azure_mgmt_core::OperationConfig;
+#[cfg(feature = "package-preview-2019-07")]
+pub use package_preview_2019_07 as preview_v2019_07;
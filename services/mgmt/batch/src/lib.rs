@@ -1,55 +1,32 @@
 #![doc = "generated by AutoRust 0.1.0"]
 #[cfg(feature = "package-2020-09")]
-mod package_2020_09;
+pub mod package_2020_09;
+#[cfg(feature = "package-2020-09")]
+pub use package_2020_09 as v2020_09;
 #[cfg(feature = "package-2020-09")]
 pub use package_2020_09::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-05")]
-mod package_2020_05;
-#[cfg(feature = "package-2020-05")]
-pub use package_2020_05::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-03")]
-mod package_2020_03;
-#[cfg(feature = "package-2020-03")]
-pub use package_2020_03::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-08")]
-mod package_2019_08;
-#[cfg(feature = "package-2019-08")]
-pub use package_2019_08::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-04")]
-mod package_2019_04;
-#[cfg(feature = "package-2019-04")]
-pub use package_2019_04::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-12")]
-mod package_2018_12;
-#[cfg(feature = "package-2018-12")]
-pub use package_2018_12::{models, operations, API_VERSION};
 #[cfg(feature = "package-2017-09")]
-mod package_2017_09;
+pub mod package_2017_09;
 #[cfg(feature = "package-2017-09")]
-pub use package_2017_09::{models, operations, API_VERSION};
-pub struct OperationConfig {
-    pub api_version: String,
-    pub client: reqwest::Client,
-    pub base_path: String,
-    pub token_credential: Option<Box<dyn azure_core::TokenCredential>>,
-    pub token_credential_resource: String,
-}
-impl OperationConfig {
-    pub fn new(token_credential: Box<dyn azure_core::TokenCredential>) -> Self {
-        Self {
-            token_credential: Some(token_credential),
-            ..Default::default()
-        }
-    }
-}
-impl Default for OperationConfig {
-    fn default() -> Self {
-        Self {
-            api_version: API_VERSION.to_owned(),
-            client: reqwest::Client::new(),
-            base_path: "https://management.azure.com".to_owned(),
-            token_credential: None,
-            token_credential_resource: "https://management.azure.com/".to_owned(),
-        }
-    }
-}
+pub use package_2017_09 as v2017_09;
+#[cfg(feature = "package-2018-12")]
+pub mod package_2018_12;
+#[cfg(feature = "package-2018-12")]
+pub use package_2018_12 as v2018_12;
+#[cfg(feature = "package-2019-04")]
+pub mod package_2019_04;
+#[cfg(feature = "package-2019-04")]
+pub use package_2019_04 as v2019_04;
+#[cfg(feature = "package-2019-08")]
+pub mod package_2019_08;
+#[cfg(feature = "package-2019-08")]
+pub use package_2019_08 as v2019_08;
+#[cfg(feature = "package-2020-03")]
+pub mod package_2020_03;
+#[cfg(feature = "package-2020-03")]
+pub use package_2020_03 as v2020_03;
+#[cfg(feature = "package-2020-05")]
+pub mod package_2020_05;
+pub use azure_mgmt_core::OperationConfig;
+#[cfg(feature = "package-2020-05")]
+pub use package_2020_05 as v2020_05;
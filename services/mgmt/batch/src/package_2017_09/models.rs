@@ -11,6 +11,9 @@ pub struct AutoStorageBaseProperties {
 pub struct BatchAccountUpdateProperties {
     #[serde(rename = "autoStorage", skip_serializing_if = "Option::is_none")]
     pub auto_storage: Option<AutoStorageBaseProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchAccountCreateProperties {
@@ -20,6 +23,9 @@ pub struct BatchAccountCreateProperties {
     pub pool_allocation_mode: Option<PoolAllocationMode>,
     #[serde(rename = "keyVaultReference", skip_serializing_if = "Option::is_none")]
     pub key_vault_reference: Option<KeyVaultReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchAccountCreateParameters {
@@ -28,11 +34,17 @@ pub struct BatchAccountCreateParameters {
     pub tags: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<BatchAccountCreateProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct KeyVaultReference {
     pub id: String,
     pub url: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AutoStorageProperties {
@@ -40,6 +52,9 @@ pub struct AutoStorageProperties {
     pub auto_storage_base_properties: AutoStorageBaseProperties,
     #[serde(rename = "lastKeySync")]
     pub last_key_sync: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchAccountProperties {
@@ -61,6 +76,9 @@ pub struct BatchAccountProperties {
     pub pool_quota: Option<i32>,
     #[serde(rename = "activeJobAndJobScheduleQuota", skip_serializing)]
     pub active_job_and_job_schedule_quota: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod batch_account_properties {
     use super::*;
@@ -72,6 +90,8 @@ pub mod batch_account_properties {
         Succeeded,
         Failed,
         Cancelled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -80,6 +100,9 @@ pub struct BatchAccount {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<BatchAccountProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchAccountUpdateParameters {
@@ -87,18 +110,36 @@ pub struct BatchAccountUpdateParameters {
     pub tags: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<BatchAccountUpdateProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchAccountListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<BatchAccount>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for BatchAccountListResult {
+    type Item = BatchAccount;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<BatchAccount> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchAccountRegenerateKeyParameters {
     #[serde(rename = "keyName")]
     pub key_name: batch_account_regenerate_key_parameters::KeyName,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod batch_account_regenerate_key_parameters {
     use super::*;
@@ -106,6 +147,8 @@ pub mod batch_account_regenerate_key_parameters {
     pub enum KeyName {
         Primary,
         Secondary,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -116,10 +159,16 @@ pub struct BatchAccountKeys {
     pub primary: Option<String>,
     #[serde(skip_serializing)]
     pub secondary: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ActivateApplicationPackageParameters {
     pub format: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ApplicationCreateParameters {
@@ -127,6 +176,9 @@ pub struct ApplicationCreateParameters {
     pub allow_updates: Option<bool>,
     #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Application {
@@ -134,12 +186,15 @@ pub struct Application {
     pub id: Option<String>,
     #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub packages: Vec<ApplicationPackage>,
     #[serde(rename = "allowUpdates", skip_serializing_if = "Option::is_none")]
     pub allow_updates: Option<bool>,
     #[serde(rename = "defaultVersion", skip_serializing_if = "Option::is_none")]
     pub default_version: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ApplicationPackage {
@@ -157,6 +212,9 @@ pub struct ApplicationPackage {
     pub storage_url_expiry: Option<String>,
     #[serde(rename = "lastActivationTime", skip_serializing)]
     pub last_activation_time: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod application_package {
     use super::*;
@@ -165,14 +223,28 @@ pub mod application_package {
         Pending,
         Active,
         Unmapped,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListApplicationsResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Application>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ListApplicationsResult {
+    type Item = Application;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Application> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ApplicationUpdateParameters {
@@ -182,11 +254,17 @@ pub struct ApplicationUpdateParameters {
     pub default_version: Option<String>,
     #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchLocationQuota {
     #[serde(rename = "accountQuota", skip_serializing)]
     pub account_quota: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Resource {
@@ -216,6 +294,8 @@ pub struct ProxyResource {
 pub enum PoolAllocationMode {
     BatchService,
     UserSubscription,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CertificateBaseProperties {
@@ -232,6 +312,8 @@ pub mod certificate_base_properties {
     pub enum Format {
         Pfx,
         Cer,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -250,6 +332,9 @@ pub struct CertificateProperties {
     pub public_data: Option<String>,
     #[serde(rename = "deleteCertificateError", skip_serializing_if = "Option::is_none")]
     pub delete_certificate_error: Option<DeleteCertificateError>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod certificate_properties {
     use super::*;
@@ -258,12 +343,16 @@ pub mod certificate_properties {
         Succeeded,
         Deleting,
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum PreviousProvisioningState {
         Succeeded,
         Deleting,
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -273,6 +362,9 @@ pub struct CertificateCreateOrUpdateProperties {
     pub data: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Certificate {
@@ -280,6 +372,9 @@ pub struct Certificate {
     pub proxy_resource: ProxyResource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<CertificateProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CertificateCreateOrUpdateParameters {
@@ -287,13 +382,28 @@ pub struct CertificateCreateOrUpdateParameters {
     pub proxy_resource: ProxyResource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<CertificateCreateOrUpdateProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListCertificatesResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Certificate>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ListCertificatesResult {
+    type Item = Certificate;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Certificate> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeleteCertificateError {
@@ -301,8 +411,11 @@ pub struct DeleteCertificateError {
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub details: Vec<DeleteCertificateError>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Pool {
@@ -310,6 +423,9 @@ pub struct Pool {
     pub proxy_resource: ProxyResource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<PoolProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PoolProperties {
@@ -347,20 +463,23 @@ pub struct PoolProperties {
     pub max_tasks_per_node: Option<i32>,
     #[serde(rename = "taskSchedulingPolicy", skip_serializing_if = "Option::is_none")]
     pub task_scheduling_policy: Option<TaskSchedulingPolicy>,
-    #[serde(rename = "userAccounts", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "userAccounts", default, skip_serializing_if = "Vec::is_empty")]
     pub user_accounts: Vec<UserAccount>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub metadata: Vec<MetadataItem>,
     #[serde(rename = "startTask", skip_serializing_if = "Option::is_none")]
     pub start_task: Option<StartTask>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub certificates: Vec<CertificateReference>,
-    #[serde(rename = "applicationPackages", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "applicationPackages", default, skip_serializing_if = "Vec::is_empty")]
     pub application_packages: Vec<ApplicationPackageReference>,
-    #[serde(rename = "applicationLicenses", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "applicationLicenses", default, skip_serializing_if = "Vec::is_empty")]
     pub application_licenses: Vec<String>,
     #[serde(rename = "resizeOperationStatus", skip_serializing_if = "Option::is_none")]
     pub resize_operation_status: Option<ResizeOperationStatus>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod pool_properties {
     use super::*;
@@ -368,17 +487,23 @@ pub mod pool_properties {
     pub enum ProvisioningState {
         Succeeded,
         Deleting,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum AllocationState {
         Steady,
         Resizing,
         Stopping,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum InterNodeCommunication {
         Enabled,
         Disabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -387,6 +512,9 @@ pub struct DeploymentConfiguration {
     pub cloud_service_configuration: Option<CloudServiceConfiguration>,
     #[serde(rename = "virtualMachineConfiguration", skip_serializing_if = "Option::is_none")]
     pub virtual_machine_configuration: Option<VirtualMachineConfiguration>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ScaleSettings {
@@ -394,12 +522,18 @@ pub struct ScaleSettings {
     pub fixed_scale: Option<FixedScaleSettings>,
     #[serde(rename = "autoScale", skip_serializing_if = "Option::is_none")]
     pub auto_scale: Option<AutoScaleSettings>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AutoScaleSettings {
     pub formula: String,
     #[serde(rename = "evaluationInterval", skip_serializing_if = "Option::is_none")]
     pub evaluation_interval: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FixedScaleSettings {
@@ -411,6 +545,9 @@ pub struct FixedScaleSettings {
     pub target_low_priority_nodes: Option<i32>,
     #[serde(rename = "nodeDeallocationOption", skip_serializing_if = "Option::is_none")]
     pub node_deallocation_option: Option<ComputeNodeDeallocationOption>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ComputeNodeDeallocationOption {
@@ -418,6 +555,8 @@ pub enum ComputeNodeDeallocationOption {
     Terminate,
     TaskCompletion,
     RetainedData,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CertificateReference {
@@ -426,8 +565,11 @@ pub struct CertificateReference {
     pub store_location: Option<certificate_reference::StoreLocation>,
     #[serde(rename = "storeName", skip_serializing_if = "Option::is_none")]
     pub store_name: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub visibility: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod certificate_reference {
     use super::*;
@@ -435,6 +577,8 @@ pub mod certificate_reference {
     pub enum StoreLocation {
         CurrentUser,
         LocalMachine,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -442,20 +586,29 @@ pub struct ApplicationPackageReference {
     pub id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResizeError {
     pub code: String,
     pub message: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub details: Vec<ResizeError>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AutoScaleRunError {
     pub code: String,
     pub message: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub details: Vec<AutoScaleRunError>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AutoScaleRun {
@@ -465,6 +618,9 @@ pub struct AutoScaleRun {
     pub results: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<AutoScaleRunError>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VirtualMachineConfiguration {
@@ -476,15 +632,21 @@ pub struct VirtualMachineConfiguration {
     pub node_agent_sku_id: String,
     #[serde(rename = "windowsConfiguration", skip_serializing_if = "Option::is_none")]
     pub windows_configuration: Option<WindowsConfiguration>,
-    #[serde(rename = "dataDisks", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "dataDisks", default, skip_serializing_if = "Vec::is_empty")]
     pub data_disks: Vec<DataDisk>,
     #[serde(rename = "licenseType", skip_serializing_if = "Option::is_none")]
     pub license_type: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WindowsConfiguration {
     #[serde(rename = "enableAutomaticUpdates", skip_serializing_if = "Option::is_none")]
     pub enable_automatic_updates: Option<bool>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageReference {
@@ -498,6 +660,9 @@ pub struct ImageReference {
     pub version: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataDisk {
@@ -508,11 +673,17 @@ pub struct DataDisk {
     pub disk_size_gb: i32,
     #[serde(rename = "storageAccountType", skip_serializing_if = "Option::is_none")]
     pub storage_account_type: Option<StorageAccountType>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskSchedulingPolicy {
     #[serde(rename = "nodeFillType")]
     pub node_fill_type: task_scheduling_policy::NodeFillType,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod task_scheduling_policy {
     use super::*;
@@ -520,6 +691,8 @@ pub mod task_scheduling_policy {
     pub enum NodeFillType {
         Spread,
         Pack,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -530,6 +703,9 @@ pub struct LinuxUserConfiguration {
     pub gid: Option<i32>,
     #[serde(rename = "sshPrivateKey", skip_serializing_if = "Option::is_none")]
     pub ssh_private_key: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UserAccount {
@@ -539,14 +715,17 @@ pub struct UserAccount {
     pub elevation_level: Option<ElevationLevel>,
     #[serde(rename = "linuxUserConfiguration", skip_serializing_if = "Option::is_none")]
     pub linux_user_configuration: Option<LinuxUserConfiguration>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct StartTask {
     #[serde(rename = "commandLine", skip_serializing_if = "Option::is_none")]
     pub command_line: Option<String>,
-    #[serde(rename = "resourceFiles", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "resourceFiles", default, skip_serializing_if = "Vec::is_empty")]
     pub resource_files: Vec<ResourceFile>,
-    #[serde(rename = "environmentSettings", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "environmentSettings", default, skip_serializing_if = "Vec::is_empty")]
     pub environment_settings: Vec<EnvironmentSetting>,
     #[serde(rename = "userIdentity", skip_serializing_if = "Option::is_none")]
     pub user_identity: Option<UserIdentity>,
@@ -554,6 +733,9 @@ pub struct StartTask {
     pub max_task_retry_count: Option<i32>,
     #[serde(rename = "waitForSuccess", skip_serializing_if = "Option::is_none")]
     pub wait_for_success: Option<bool>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceFile {
@@ -563,12 +745,18 @@ pub struct ResourceFile {
     pub file_path: String,
     #[serde(rename = "fileMode", skip_serializing_if = "Option::is_none")]
     pub file_mode: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EnvironmentSetting {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UserIdentity {
@@ -576,6 +764,9 @@ pub struct UserIdentity {
     pub user_name: Option<String>,
     #[serde(rename = "autoUser", skip_serializing_if = "Option::is_none")]
     pub auto_user: Option<AutoUserSpecification>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AutoUserSpecification {
@@ -583,6 +774,9 @@ pub struct AutoUserSpecification {
     pub scope: Option<auto_user_specification::Scope>,
     #[serde(rename = "elevationLevel", skip_serializing_if = "Option::is_none")]
     pub elevation_level: Option<ElevationLevel>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod auto_user_specification {
     use super::*;
@@ -590,12 +784,16 @@ pub mod auto_user_specification {
     pub enum Scope {
         Task,
         Pool,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ElevationLevel {
     NonAdmin,
     Admin,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum StorageAccountType {
@@ -603,17 +801,24 @@ pub enum StorageAccountType {
     StandardLrs,
     #[serde(rename = "Premium_LRS")]
     PremiumLrs,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum CachingType {
     None,
     ReadOnly,
     ReadWrite,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OsDisk {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caching: Option<CachingType>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NetworkConfiguration {
@@ -621,6 +826,9 @@ pub struct NetworkConfiguration {
     pub subnet_id: Option<String>,
     #[serde(rename = "endpointConfiguration", skip_serializing_if = "Option::is_none")]
     pub endpoint_configuration: Option<PoolEndpointConfiguration>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudServiceConfiguration {
@@ -630,11 +838,17 @@ pub struct CloudServiceConfiguration {
     pub target_os_version: Option<String>,
     #[serde(rename = "currentOSVersion", skip_serializing_if = "Option::is_none")]
     pub current_os_version: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MetadataItem {
     pub name: String,
     pub value: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResizeOperationStatus {
@@ -648,13 +862,19 @@ pub struct ResizeOperationStatus {
     pub node_deallocation_option: Option<ComputeNodeDeallocationOption>,
     #[serde(rename = "startTime", skip_serializing_if = "Option::is_none")]
     pub start_time: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<ResizeError>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PoolEndpointConfiguration {
-    #[serde(rename = "inboundNatPools")]
+    #[serde(rename = "inboundNatPools", default)]
     pub inbound_nat_pools: Vec<InboundNatPool>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct InboundNatPool {
@@ -666,8 +886,11 @@ pub struct InboundNatPool {
     pub frontend_port_range_start: i32,
     #[serde(rename = "frontendPortRangeEnd")]
     pub frontend_port_range_end: i32,
-    #[serde(rename = "networkSecurityGroupRules", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "networkSecurityGroupRules", default, skip_serializing_if = "Vec::is_empty")]
     pub network_security_group_rules: Vec<NetworkSecurityGroupRule>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod inbound_nat_pool {
     use super::*;
@@ -677,6 +900,8 @@ pub mod inbound_nat_pool {
         Tcp,
         #[serde(rename = "UDP")]
         Udp,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -685,6 +910,9 @@ pub struct NetworkSecurityGroupRule {
     pub access: network_security_group_rule::Access,
     #[serde(rename = "sourceAddressPrefix")]
     pub source_address_prefix: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod network_security_group_rule {
     use super::*;
@@ -692,19 +920,36 @@ pub mod network_security_group_rule {
     pub enum Access {
         Allow,
         Deny,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListPoolsResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Pool>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ListPoolsResult {
+    type Item = Pool;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Pool> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudError {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudErrorBody {
@@ -714,8 +959,11 @@ pub struct CloudErrorBody {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub details: Vec<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
@@ -727,6 +975,9 @@ pub struct Operation {
     pub origin: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod operation {
     use super::*;
@@ -740,20 +991,38 @@ pub mod operation {
         pub resource: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Operation>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationListResult {
+    type Item = Operation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Operation> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CheckNameAvailabilityParameters {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: check_name_availability_parameters::Type,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod check_name_availability_parameters {
     use super::*;
@@ -761,6 +1030,8 @@ pub mod check_name_availability_parameters {
     pub enum Type {
         #[serde(rename = "Microsoft.Batch/batchAccounts")]
         MicrosoftBatchBatchAccounts,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -771,6 +1042,9 @@ pub struct CheckNameAvailabilityResult {
     pub reason: Option<check_name_availability_result::Reason>,
     #[serde(skip_serializing)]
     pub message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod check_name_availability_result {
     use super::*;
@@ -778,5 +1052,7 @@ pub mod check_name_availability_result {
     pub enum Reason {
         Invalid,
         AlreadyExists,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
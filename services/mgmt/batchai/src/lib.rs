@@ -1,39 +1,16 @@
 #![doc = "generated by AutoRust 0.1.0"]
 #[cfg(feature = "package-2017-09-preview")]
-mod package_2017_09_preview;
+pub mod package_2017_09_preview;
 #[cfg(feature = "package-2017-09-preview")]
-pub use package_2017_09_preview::{models, operations, API_VERSION};
+pub use package_2017_09_preview as v2017_09_preview;
 #[cfg(feature = "package-2018-03")]
-mod package_2018_03;
+pub mod package_2018_03;
+#[cfg(feature = "package-2018-03")]
+pub use package_2018_03 as v2018_03;
 #[cfg(feature = "package-2018-03")]
 pub use package_2018_03::{models, operations, API_VERSION};
 #[cfg(feature = "package-2018-05")]
-mod package_2018_05;
+pub mod package_2018_05;
+pub use azure_mgmt_core::OperationConfig;
 #[cfg(feature = "package-2018-05")]
-pub use package_2018_05::{models, operations, API_VERSION};
-pub struct OperationConfig {
-    pub api_version: String,
-    pub client: reqwest::Client,
-    pub base_path: String,
-    pub token_credential: Option<Box<dyn azure_core::TokenCredential>>,
-    pub token_credential_resource: String,
-}
-impl OperationConfig {
-    pub fn new(token_credential: Box<dyn azure_core::TokenCredential>) -> Self {
-        Self {
-            token_credential: Some(token_credential),
-            ..Default::default()
-        }
-    }
-}
-impl Default for OperationConfig {
-    fn default() -> Self {
-        Self {
-            api_version: API_VERSION.to_owned(),
-            client: reqwest::Client::new(),
-            base_path: "https://management.azure.com".to_owned(),
-            token_credential: None,
-            token_credential_resource: "https://management.azure.com/".to_owned(),
-        }
-    }
-}
+pub use package_2018_05 as v2018_05;
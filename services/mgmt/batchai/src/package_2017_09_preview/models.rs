@@ -12,13 +12,28 @@ pub struct FileServerBaseProperties {
     pub data_disks: DataDisks,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subnet: Option<ResourceId>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<FileServer>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for FileServerListResult {
+    type Item = FileServer;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<FileServer> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataDisks {
@@ -30,6 +45,9 @@ pub struct DataDisks {
     pub disk_count: i32,
     #[serde(rename = "storageAccountType")]
     pub storage_account_type: data_disks::StorageAccountType,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod data_disks {
     use super::*;
@@ -41,6 +59,8 @@ pub mod data_disks {
         Readonly,
         #[serde(rename = "readwrite")]
         Readwrite,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum StorageAccountType {
@@ -48,6 +68,8 @@ pub mod data_disks {
         StandardLrs,
         #[serde(rename = "Premium_LRS")]
         PremiumLrs,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -56,6 +78,9 @@ pub struct KeyVaultSecretReference {
     pub source_vault: ResourceId,
     #[serde(rename = "secretUrl")]
     pub secret_url: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct KeyVaultKeyReference {
@@ -63,6 +88,9 @@ pub struct KeyVaultKeyReference {
     pub source_vault: ResourceId,
     #[serde(rename = "keyUrl")]
     pub key_url: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MountSettings {
@@ -74,6 +102,9 @@ pub struct MountSettings {
     pub file_server_internal_ip: Option<String>,
     #[serde(rename = "fileServerType", skip_serializing_if = "Option::is_none")]
     pub file_server_type: Option<mount_settings::FileServerType>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod mount_settings {
     use super::*;
@@ -83,14 +114,19 @@ pub mod mount_settings {
         Nfs,
         #[serde(rename = "glusterfs")]
         Glusterfs,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SshConfiguration {
-    #[serde(rename = "publicIPsToAllow", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "publicIPsToAllow", default, skip_serializing_if = "Vec::is_empty")]
     pub public_i_ps_to_allow: Vec<String>,
     #[serde(rename = "userAccountSettings")]
     pub user_account_settings: UserAccountSettings,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerCreateParameters {
@@ -99,6 +135,9 @@ pub struct FileServerCreateParameters {
     pub tags: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<FileServerBaseProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerProperties {
@@ -118,6 +157,9 @@ pub struct FileServerProperties {
     pub creation_time: Option<String>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<file_server_properties::ProvisioningState>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod file_server_properties {
     use super::*;
@@ -133,6 +175,8 @@ pub mod file_server_properties {
         Succeeded,
         #[serde(rename = "failed")]
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -141,6 +185,9 @@ pub struct FileServer {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<FileServerProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterBaseProperties {
@@ -158,6 +205,9 @@ pub struct ClusterBaseProperties {
     pub user_account_settings: UserAccountSettings,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subnet: Option<ResourceId>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_base_properties {
     use super::*;
@@ -167,12 +217,17 @@ pub mod cluster_base_properties {
         Dedicated,
         #[serde(rename = "lowpriority")]
         Lowpriority,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterUpdateProperties {
     #[serde(rename = "scaleSettings", skip_serializing_if = "Option::is_none")]
     pub scale_settings: Option<ScaleSettings>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum DeallocationOption {
@@ -184,6 +239,8 @@ pub enum DeallocationOption {
     Waitforjobcompletion,
     #[serde(rename = "unknown")]
     Unknown,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ScaleSettings {
@@ -191,6 +248,9 @@ pub struct ScaleSettings {
     pub manual: Option<ManualScaleSettings>,
     #[serde(rename = "autoScale", skip_serializing_if = "Option::is_none")]
     pub auto_scale: Option<AutoScaleSettings>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AutoScaleSettings {
@@ -200,6 +260,9 @@ pub struct AutoScaleSettings {
     pub maximum_node_count: i32,
     #[serde(rename = "initialNodeCount", skip_serializing_if = "Option::is_none")]
     pub initial_node_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManualScaleSettings {
@@ -207,11 +270,17 @@ pub struct ManualScaleSettings {
     pub target_node_count: i32,
     #[serde(rename = "nodeDeallocationOption", skip_serializing_if = "Option::is_none")]
     pub node_deallocation_option: Option<DeallocationOption>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VirtualMachineConfiguration {
     #[serde(rename = "imageReference", skip_serializing_if = "Option::is_none")]
     pub image_reference: Option<ImageReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageReference {
@@ -220,6 +289,9 @@ pub struct ImageReference {
     pub sku: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NodeStateCounts {
@@ -233,6 +305,9 @@ pub struct NodeStateCounts {
     pub unusable_node_count: i32,
     #[serde(rename = "leavingNodeCount")]
     pub leaving_node_count: i32,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UserAccountSettings {
@@ -242,6 +317,9 @@ pub struct UserAccountSettings {
     pub admin_user_ssh_public_key: Option<String>,
     #[serde(rename = "adminUserPassword", skip_serializing_if = "Option::is_none")]
     pub admin_user_password: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NodeSetup {
@@ -249,17 +327,23 @@ pub struct NodeSetup {
     pub setup_task: Option<SetupTask>,
     #[serde(rename = "mountVolumes", skip_serializing_if = "Option::is_none")]
     pub mount_volumes: Option<MountVolumes>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SetupTask {
     #[serde(rename = "commandLine")]
     pub command_line: String,
-    #[serde(rename = "environmentVariables", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "environmentVariables", default, skip_serializing_if = "Vec::is_empty")]
     pub environment_variables: Vec<EnvironmentSetting>,
     #[serde(rename = "runElevated", skip_serializing_if = "Option::is_none")]
     pub run_elevated: Option<bool>,
     #[serde(rename = "stdOutErrPathPrefix")]
     pub std_out_err_path_prefix: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterCreateParameters {
@@ -268,6 +352,9 @@ pub struct ClusterCreateParameters {
     pub tags: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ClusterBaseProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterUpdateParameters {
@@ -275,6 +362,9 @@ pub struct ClusterUpdateParameters {
     pub tags: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ClusterUpdateProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterProperties {
@@ -302,12 +392,15 @@ pub struct ClusterProperties {
     pub allocation_state: Option<cluster_properties::AllocationState>,
     #[serde(rename = "allocationStateTransitionTime", skip_serializing)]
     pub allocation_state_transition_time: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<BatchAiError>,
     #[serde(rename = "currentNodeCount", skip_serializing)]
     pub current_node_count: Option<i32>,
     #[serde(rename = "nodeStateCounts", skip_serializing_if = "Option::is_none")]
     pub node_state_counts: Option<NodeStateCounts>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_properties {
     use super::*;
@@ -317,6 +410,8 @@ pub mod cluster_properties {
         Dedicated,
         #[serde(rename = "lowpriority")]
         Lowpriority,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProvisioningState {
@@ -328,6 +423,8 @@ pub mod cluster_properties {
         Failed,
         #[serde(rename = "deleting")]
         Deleting,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum AllocationState {
@@ -335,6 +432,8 @@ pub mod cluster_properties {
         Steady,
         #[serde(rename = "resizing")]
         Resizing,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -343,13 +442,28 @@ pub struct Cluster {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ClusterProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Cluster>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ClusterListResult {
+    type Item = Cluster;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Cluster> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobBaseProperties {
@@ -378,14 +492,17 @@ pub struct JobBaseProperties {
     pub job_preparation: Option<JobPreparation>,
     #[serde(rename = "stdOutErrPathPrefix")]
     pub std_out_err_path_prefix: String,
-    #[serde(rename = "inputDirectories", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "inputDirectories", default, skip_serializing_if = "Vec::is_empty")]
     pub input_directories: Vec<InputDirectory>,
-    #[serde(rename = "outputDirectories", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "outputDirectories", default, skip_serializing_if = "Vec::is_empty")]
     pub output_directories: Vec<OutputDirectory>,
-    #[serde(rename = "environmentVariables", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "environmentVariables", default, skip_serializing_if = "Vec::is_empty")]
     pub environment_variables: Vec<EnvironmentSetting>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constraints: Option<job_base_properties::Constraints>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod job_base_properties {
     use super::*;
@@ -402,6 +519,9 @@ pub struct JobCreateParameters {
     pub tags: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<JobBaseProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobProperties {
@@ -431,11 +551,11 @@ pub struct JobProperties {
     pub job_preparation: Option<JobPreparation>,
     #[serde(rename = "stdOutErrPathPrefix", skip_serializing_if = "Option::is_none")]
     pub std_out_err_path_prefix: Option<String>,
-    #[serde(rename = "inputDirectories", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "inputDirectories", default, skip_serializing_if = "Vec::is_empty")]
     pub input_directories: Vec<InputDirectory>,
-    #[serde(rename = "outputDirectories", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "outputDirectories", default, skip_serializing_if = "Vec::is_empty")]
     pub output_directories: Vec<OutputDirectory>,
-    #[serde(rename = "environmentVariables", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "environmentVariables", default, skip_serializing_if = "Vec::is_empty")]
     pub environment_variables: Vec<EnvironmentSetting>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constraints: Option<job_properties::Constraints>,
@@ -451,6 +571,9 @@ pub struct JobProperties {
     pub execution_state_transition_time: Option<String>,
     #[serde(rename = "executionInfo", skip_serializing_if = "Option::is_none")]
     pub execution_info: Option<job_properties::ExecutionInfo>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod job_properties {
     use super::*;
@@ -469,6 +592,8 @@ pub mod job_properties {
         Succeeded,
         #[serde(rename = "failed")]
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ExecutionState {
@@ -482,6 +607,8 @@ pub mod job_properties {
         Succeeded,
         #[serde(rename = "failed")]
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub struct ExecutionInfo {
@@ -491,8 +618,11 @@ pub mod job_properties {
         pub end_time: Option<String>,
         #[serde(rename = "exitCode", skip_serializing_if = "Option::is_none")]
         pub exit_code: Option<i32>,
-        #[serde(skip_serializing_if = "Vec::is_empty")]
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
         pub errors: Vec<BatchAiError>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -501,13 +631,28 @@ pub struct Job {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<JobProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Job>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for JobListResult {
+    type Item = Job;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Job> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RemoteLoginInformation {
@@ -516,13 +661,28 @@ pub struct RemoteLoginInformation {
     #[serde(rename = "ipAddress")]
     pub ip_address: String,
     pub port: f64,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RemoteLoginInformationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<RemoteLoginInformation>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for RemoteLoginInformationListResult {
+    type Item = RemoteLoginInformation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<RemoteLoginInformation> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileProperties {
@@ -530,6 +690,9 @@ pub struct FileProperties {
     pub last_modified: Option<String>,
     #[serde(rename = "contentLength", skip_serializing_if = "Option::is_none")]
     pub content_length: Option<i64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct File {
@@ -538,17 +701,35 @@ pub struct File {
     pub download_url: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<FileProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<File>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for FileListResult {
+    type Item = File;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<File> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceId {
     pub id: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Resource {
@@ -577,6 +758,8 @@ pub enum ToolType {
     Chainer,
     #[serde(rename = "custom")]
     Custom,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NameValuePair {
@@ -584,12 +767,18 @@ pub struct NameValuePair {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EnvironmentSetting {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LocalDataVolume {
@@ -597,6 +786,9 @@ pub struct LocalDataVolume {
     pub host_path: String,
     #[serde(rename = "localPath")]
     pub local_path: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageSourceRegistry {
@@ -605,6 +797,9 @@ pub struct ImageSourceRegistry {
     pub image: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credentials: Option<PrivateRegistryCredentials>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PrivateRegistryCredentials {
@@ -613,16 +808,25 @@ pub struct PrivateRegistryCredentials {
     pub password: Option<String>,
     #[serde(rename = "passwordSecretReference", skip_serializing_if = "Option::is_none")]
     pub password_secret_reference: Option<KeyVaultSecretReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerSettings {
     #[serde(rename = "imageSourceRegistry")]
     pub image_source_registry: ImageSourceRegistry,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct InputDirectory {
     pub id: String,
     pub path: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OutputDirectory {
@@ -635,6 +839,9 @@ pub struct OutputDirectory {
     pub type_: Option<output_directory::Type>,
     #[serde(rename = "createNew", skip_serializing_if = "Option::is_none")]
     pub create_new: Option<bool>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod output_directory {
     use super::*;
@@ -648,6 +855,8 @@ pub mod output_directory {
         Summary,
         #[serde(rename = "custom")]
         Custom,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -656,6 +865,9 @@ pub struct AzureStorageCredentialsInfo {
     pub account_key: Option<String>,
     #[serde(rename = "accountKeySecretReference", skip_serializing_if = "Option::is_none")]
     pub account_key_secret_reference: Option<KeyVaultSecretReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureFileShareReference {
@@ -670,6 +882,9 @@ pub struct AzureFileShareReference {
     pub file_mode: Option<String>,
     #[serde(rename = "directoryMode", skip_serializing_if = "Option::is_none")]
     pub directory_mode: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureBlobFileSystemReference {
@@ -682,6 +897,9 @@ pub struct AzureBlobFileSystemReference {
     pub relative_mount_path: String,
     #[serde(rename = "mountOptions", skip_serializing_if = "Option::is_none")]
     pub mount_options: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerReference {
@@ -693,6 +911,9 @@ pub struct FileServerReference {
     pub relative_mount_path: String,
     #[serde(rename = "mountOptions", skip_serializing_if = "Option::is_none")]
     pub mount_options: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UnmanagedFileSystemReference {
@@ -700,17 +921,23 @@ pub struct UnmanagedFileSystemReference {
     pub mount_command: String,
     #[serde(rename = "relativeMountPath")]
     pub relative_mount_path: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MountVolumes {
-    #[serde(rename = "azureFileShares", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "azureFileShares", default, skip_serializing_if = "Vec::is_empty")]
     pub azure_file_shares: Vec<AzureFileShareReference>,
-    #[serde(rename = "azureBlobFileSystems", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "azureBlobFileSystems", default, skip_serializing_if = "Vec::is_empty")]
     pub azure_blob_file_systems: Vec<AzureBlobFileSystemReference>,
-    #[serde(rename = "fileServers", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "fileServers", default, skip_serializing_if = "Vec::is_empty")]
     pub file_servers: Vec<FileServerReference>,
-    #[serde(rename = "unmanagedFileSystems", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "unmanagedFileSystems", default, skip_serializing_if = "Vec::is_empty")]
     pub unmanaged_file_systems: Vec<UnmanagedFileSystemReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CntKsettings {
@@ -726,6 +953,9 @@ pub struct CntKsettings {
     pub command_line_args: Option<String>,
     #[serde(rename = "processCount", skip_serializing_if = "Option::is_none")]
     pub process_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CaffeSettings {
@@ -739,6 +969,9 @@ pub struct CaffeSettings {
     pub command_line_args: Option<String>,
     #[serde(rename = "processCount", skip_serializing_if = "Option::is_none")]
     pub process_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Caffe2Settings {
@@ -748,6 +981,9 @@ pub struct Caffe2Settings {
     pub python_interpreter_path: Option<String>,
     #[serde(rename = "commandLineArgs", skip_serializing_if = "Option::is_none")]
     pub command_line_args: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ChainerSettings {
@@ -759,16 +995,25 @@ pub struct ChainerSettings {
     pub command_line_args: Option<String>,
     #[serde(rename = "processCount", skip_serializing_if = "Option::is_none")]
     pub process_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomToolkitSettings {
     #[serde(rename = "commandLine", skip_serializing_if = "Option::is_none")]
     pub command_line: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobPreparation {
     #[serde(rename = "commandLine")]
     pub command_line: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TensorFlowSettings {
@@ -786,11 +1031,17 @@ pub struct TensorFlowSettings {
     pub worker_count: Option<i32>,
     #[serde(rename = "parameterServerCount", skip_serializing_if = "Option::is_none")]
     pub parameter_server_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudError {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudErrorBody {
@@ -800,8 +1051,11 @@ pub struct CloudErrorBody {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub details: Vec<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchAiError {
@@ -809,8 +1063,11 @@ pub struct BatchAiError {
     pub code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub details: Vec<NameValuePair>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
@@ -822,6 +1079,9 @@ pub struct Operation {
     pub origin: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod operation {
     use super::*;
@@ -835,12 +1095,27 @@ pub mod operation {
         pub resource: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Operation>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationListResult {
+    type Item = Operation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Operation> {
+        self.value
+    }
 }
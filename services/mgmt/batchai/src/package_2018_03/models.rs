@@ -8,6 +8,9 @@ pub struct UsageName {
     pub value: Option<String>,
     #[serde(rename = "localizedValue", skip_serializing_if = "Option::is_none")]
     pub localized_value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Usage {
@@ -16,19 +19,37 @@ pub struct Usage {
     pub current_value: i32,
     pub limit: i64,
     pub name: UsageName,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod usage {
     use super::*;
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Unit {
         Count,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListUsagesResult {
+    #[serde(default)]
     pub value: Vec<Usage>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ListUsagesResult {
+    type Item = Usage;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Usage> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerBaseProperties {
@@ -40,13 +61,28 @@ pub struct FileServerBaseProperties {
     pub data_disks: DataDisks,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subnet: Option<ResourceId>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<FileServer>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for FileServerListResult {
+    type Item = FileServer;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<FileServer> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataDisks {
@@ -58,6 +94,9 @@ pub struct DataDisks {
     pub disk_count: i32,
     #[serde(rename = "storageAccountType")]
     pub storage_account_type: data_disks::StorageAccountType,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod data_disks {
     use super::*;
@@ -69,6 +108,8 @@ pub mod data_disks {
         Readonly,
         #[serde(rename = "readwrite")]
         Readwrite,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum StorageAccountType {
@@ -76,6 +117,8 @@ pub mod data_disks {
         StandardLrs,
         #[serde(rename = "Premium_LRS")]
         PremiumLrs,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -84,6 +127,9 @@ pub struct KeyVaultSecretReference {
     pub source_vault: ResourceId,
     #[serde(rename = "secretUrl")]
     pub secret_url: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct KeyVaultKeyReference {
@@ -91,6 +137,9 @@ pub struct KeyVaultKeyReference {
     pub source_vault: ResourceId,
     #[serde(rename = "keyUrl")]
     pub key_url: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MountSettings {
@@ -102,6 +151,9 @@ pub struct MountSettings {
     pub file_server_internal_ip: Option<String>,
     #[serde(rename = "fileServerType", skip_serializing_if = "Option::is_none")]
     pub file_server_type: Option<mount_settings::FileServerType>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod mount_settings {
     use super::*;
@@ -111,14 +163,19 @@ pub mod mount_settings {
         Nfs,
         #[serde(rename = "glusterfs")]
         Glusterfs,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SshConfiguration {
-    #[serde(rename = "publicIPsToAllow", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "publicIPsToAllow", default, skip_serializing_if = "Vec::is_empty")]
     pub public_i_ps_to_allow: Vec<String>,
     #[serde(rename = "userAccountSettings")]
     pub user_account_settings: UserAccountSettings,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerCreateParameters {
@@ -127,6 +184,9 @@ pub struct FileServerCreateParameters {
     pub tags: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<FileServerBaseProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerProperties {
@@ -146,6 +206,9 @@ pub struct FileServerProperties {
     pub creation_time: Option<String>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<file_server_properties::ProvisioningState>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod file_server_properties {
     use super::*;
@@ -161,6 +224,8 @@ pub mod file_server_properties {
         Succeeded,
         #[serde(rename = "failed")]
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -169,6 +234,9 @@ pub struct FileServer {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<FileServerProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterBaseProperties {
@@ -186,6 +254,9 @@ pub struct ClusterBaseProperties {
     pub user_account_settings: UserAccountSettings,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subnet: Option<ResourceId>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_base_properties {
     use super::*;
@@ -195,12 +266,17 @@ pub mod cluster_base_properties {
         Dedicated,
         #[serde(rename = "lowpriority")]
         Lowpriority,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterUpdateProperties {
     #[serde(rename = "scaleSettings", skip_serializing_if = "Option::is_none")]
     pub scale_settings: Option<ScaleSettings>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum DeallocationOption {
@@ -212,6 +288,8 @@ pub enum DeallocationOption {
     Waitforjobcompletion,
     #[serde(rename = "unknown")]
     Unknown,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ScaleSettings {
@@ -219,6 +297,9 @@ pub struct ScaleSettings {
     pub manual: Option<ManualScaleSettings>,
     #[serde(rename = "autoScale", skip_serializing_if = "Option::is_none")]
     pub auto_scale: Option<AutoScaleSettings>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AutoScaleSettings {
@@ -228,6 +309,9 @@ pub struct AutoScaleSettings {
     pub maximum_node_count: i32,
     #[serde(rename = "initialNodeCount", skip_serializing_if = "Option::is_none")]
     pub initial_node_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManualScaleSettings {
@@ -235,11 +319,17 @@ pub struct ManualScaleSettings {
     pub target_node_count: i32,
     #[serde(rename = "nodeDeallocationOption", skip_serializing_if = "Option::is_none")]
     pub node_deallocation_option: Option<DeallocationOption>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VirtualMachineConfiguration {
     #[serde(rename = "imageReference", skip_serializing_if = "Option::is_none")]
     pub image_reference: Option<ImageReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageReference {
@@ -250,6 +340,9 @@ pub struct ImageReference {
     pub version: Option<String>,
     #[serde(rename = "virtualMachineImageId", skip_serializing_if = "Option::is_none")]
     pub virtual_machine_image_id: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NodeStateCounts {
@@ -263,6 +356,9 @@ pub struct NodeStateCounts {
     pub unusable_node_count: i32,
     #[serde(rename = "leavingNodeCount")]
     pub leaving_node_count: i32,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UserAccountSettings {
@@ -272,11 +368,17 @@ pub struct UserAccountSettings {
     pub admin_user_ssh_public_key: Option<String>,
     #[serde(rename = "adminUserPassword", skip_serializing_if = "Option::is_none")]
     pub admin_user_password: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PerformanceCountersSettings {
     #[serde(rename = "appInsightsReference")]
     pub app_insights_reference: AppInsightsReference,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AppInsightsReference {
@@ -285,6 +387,9 @@ pub struct AppInsightsReference {
     pub instrumentation_key: Option<String>,
     #[serde(rename = "instrumentationKeySecretReference", skip_serializing_if = "Option::is_none")]
     pub instrumentation_key_secret_reference: Option<KeyVaultSecretReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NodeSetup {
@@ -294,14 +399,17 @@ pub struct NodeSetup {
     pub mount_volumes: Option<MountVolumes>,
     #[serde(rename = "performanceCountersSettings", skip_serializing_if = "Option::is_none")]
     pub performance_counters_settings: Option<PerformanceCountersSettings>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SetupTask {
     #[serde(rename = "commandLine")]
     pub command_line: String,
-    #[serde(rename = "environmentVariables", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "environmentVariables", default, skip_serializing_if = "Vec::is_empty")]
     pub environment_variables: Vec<EnvironmentVariable>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub secrets: Vec<EnvironmentVariableWithSecretValue>,
     #[serde(rename = "runElevated", skip_serializing_if = "Option::is_none")]
     pub run_elevated: Option<bool>,
@@ -309,6 +417,9 @@ pub struct SetupTask {
     pub std_out_err_path_prefix: String,
     #[serde(rename = "stdOutErrPathSuffix", skip_serializing)]
     pub std_out_err_path_suffix: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterCreateParameters {
@@ -317,6 +428,9 @@ pub struct ClusterCreateParameters {
     pub tags: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ClusterBaseProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterUpdateParameters {
@@ -324,6 +438,9 @@ pub struct ClusterUpdateParameters {
     pub tags: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ClusterUpdateProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterProperties {
@@ -351,12 +468,15 @@ pub struct ClusterProperties {
     pub allocation_state: Option<cluster_properties::AllocationState>,
     #[serde(rename = "allocationStateTransitionTime", skip_serializing)]
     pub allocation_state_transition_time: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<BatchAiError>,
     #[serde(rename = "currentNodeCount", skip_serializing)]
     pub current_node_count: Option<i32>,
     #[serde(rename = "nodeStateCounts", skip_serializing_if = "Option::is_none")]
     pub node_state_counts: Option<NodeStateCounts>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_properties {
     use super::*;
@@ -366,6 +486,8 @@ pub mod cluster_properties {
         Dedicated,
         #[serde(rename = "lowpriority")]
         Lowpriority,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProvisioningState {
@@ -377,6 +499,8 @@ pub mod cluster_properties {
         Failed,
         #[serde(rename = "deleting")]
         Deleting,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum AllocationState {
@@ -384,6 +508,8 @@ pub mod cluster_properties {
         Steady,
         #[serde(rename = "resizing")]
         Resizing,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -392,13 +518,28 @@ pub struct Cluster {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ClusterProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Cluster>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ClusterListResult {
+    type Item = Cluster;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Cluster> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobBaseProperties {
@@ -431,16 +572,19 @@ pub struct JobBaseProperties {
     pub job_preparation: Option<JobPreparation>,
     #[serde(rename = "stdOutErrPathPrefix")]
     pub std_out_err_path_prefix: String,
-    #[serde(rename = "inputDirectories", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "inputDirectories", default, skip_serializing_if = "Vec::is_empty")]
     pub input_directories: Vec<InputDirectory>,
-    #[serde(rename = "outputDirectories", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "outputDirectories", default, skip_serializing_if = "Vec::is_empty")]
     pub output_directories: Vec<OutputDirectory>,
-    #[serde(rename = "environmentVariables", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "environmentVariables", default, skip_serializing_if = "Vec::is_empty")]
     pub environment_variables: Vec<EnvironmentVariable>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub secrets: Vec<EnvironmentVariableWithSecretValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constraints: Option<job_base_properties::Constraints>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod job_base_properties {
     use super::*;
@@ -457,6 +601,9 @@ pub struct JobCreateParameters {
     pub tags: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<JobBaseProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobProperties {
@@ -492,13 +639,13 @@ pub struct JobProperties {
     pub job_preparation: Option<JobPreparation>,
     #[serde(rename = "stdOutErrPathPrefix", skip_serializing_if = "Option::is_none")]
     pub std_out_err_path_prefix: Option<String>,
-    #[serde(rename = "inputDirectories", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "inputDirectories", default, skip_serializing_if = "Vec::is_empty")]
     pub input_directories: Vec<InputDirectory>,
-    #[serde(rename = "outputDirectories", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "outputDirectories", default, skip_serializing_if = "Vec::is_empty")]
     pub output_directories: Vec<OutputDirectory>,
-    #[serde(rename = "environmentVariables", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "environmentVariables", default, skip_serializing_if = "Vec::is_empty")]
     pub environment_variables: Vec<EnvironmentVariable>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub secrets: Vec<EnvironmentVariableWithSecretValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constraints: Option<job_properties::Constraints>,
@@ -514,6 +661,9 @@ pub struct JobProperties {
     pub execution_state_transition_time: Option<String>,
     #[serde(rename = "executionInfo", skip_serializing_if = "Option::is_none")]
     pub execution_info: Option<job_properties::ExecutionInfo>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod job_properties {
     use super::*;
@@ -532,6 +682,8 @@ pub mod job_properties {
         Succeeded,
         #[serde(rename = "failed")]
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ExecutionState {
@@ -545,6 +697,8 @@ pub mod job_properties {
         Succeeded,
         #[serde(rename = "failed")]
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub struct ExecutionInfo {
@@ -554,8 +708,11 @@ pub mod job_properties {
         pub end_time: Option<String>,
         #[serde(rename = "exitCode", skip_serializing_if = "Option::is_none")]
         pub exit_code: Option<i32>,
-        #[serde(skip_serializing_if = "Vec::is_empty")]
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
         pub errors: Vec<BatchAiError>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -564,13 +721,28 @@ pub struct Job {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<JobProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Job>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for JobListResult {
+    type Item = Job;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Job> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RemoteLoginInformation {
@@ -579,13 +751,28 @@ pub struct RemoteLoginInformation {
     #[serde(rename = "ipAddress")]
     pub ip_address: String,
     pub port: f64,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RemoteLoginInformationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<RemoteLoginInformation>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for RemoteLoginInformationListResult {
+    type Item = RemoteLoginInformation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<RemoteLoginInformation> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileProperties {
@@ -593,6 +780,9 @@ pub struct FileProperties {
     pub last_modified: Option<String>,
     #[serde(rename = "contentLength", skip_serializing_if = "Option::is_none")]
     pub content_length: Option<i64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct File {
@@ -603,17 +793,35 @@ pub struct File {
     pub download_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<FileProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<File>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for FileListResult {
+    type Item = File;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<File> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceId {
     pub id: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Resource {
@@ -642,6 +850,8 @@ pub enum ToolType {
     Chainer,
     #[serde(rename = "custom")]
     Custom,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NameValuePair {
@@ -649,11 +859,17 @@ pub struct NameValuePair {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EnvironmentVariable {
     pub name: String,
     pub value: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EnvironmentVariableWithSecretValue {
@@ -662,6 +878,9 @@ pub struct EnvironmentVariableWithSecretValue {
     pub value: Option<String>,
     #[serde(rename = "valueSecretReference", skip_serializing_if = "Option::is_none")]
     pub value_secret_reference: Option<KeyVaultSecretReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LocalDataVolume {
@@ -669,6 +888,9 @@ pub struct LocalDataVolume {
     pub host_path: String,
     #[serde(rename = "localPath")]
     pub local_path: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageSourceRegistry {
@@ -677,6 +899,9 @@ pub struct ImageSourceRegistry {
     pub image: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credentials: Option<PrivateRegistryCredentials>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PrivateRegistryCredentials {
@@ -685,16 +910,25 @@ pub struct PrivateRegistryCredentials {
     pub password: Option<String>,
     #[serde(rename = "passwordSecretReference", skip_serializing_if = "Option::is_none")]
     pub password_secret_reference: Option<KeyVaultSecretReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerSettings {
     #[serde(rename = "imageSourceRegistry")]
     pub image_source_registry: ImageSourceRegistry,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct InputDirectory {
     pub id: String,
     pub path: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OutputDirectory {
@@ -707,6 +941,9 @@ pub struct OutputDirectory {
     pub type_: Option<output_directory::Type>,
     #[serde(rename = "createNew", skip_serializing_if = "Option::is_none")]
     pub create_new: Option<bool>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod output_directory {
     use super::*;
@@ -720,6 +957,8 @@ pub mod output_directory {
         Summary,
         #[serde(rename = "custom")]
         Custom,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -728,6 +967,9 @@ pub struct AzureStorageCredentialsInfo {
     pub account_key: Option<String>,
     #[serde(rename = "accountKeySecretReference", skip_serializing_if = "Option::is_none")]
     pub account_key_secret_reference: Option<KeyVaultSecretReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureFileShareReference {
@@ -742,6 +984,9 @@ pub struct AzureFileShareReference {
     pub file_mode: Option<String>,
     #[serde(rename = "directoryMode", skip_serializing_if = "Option::is_none")]
     pub directory_mode: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureBlobFileSystemReference {
@@ -754,6 +999,9 @@ pub struct AzureBlobFileSystemReference {
     pub relative_mount_path: String,
     #[serde(rename = "mountOptions", skip_serializing_if = "Option::is_none")]
     pub mount_options: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerReference {
@@ -765,6 +1013,9 @@ pub struct FileServerReference {
     pub relative_mount_path: String,
     #[serde(rename = "mountOptions", skip_serializing_if = "Option::is_none")]
     pub mount_options: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UnmanagedFileSystemReference {
@@ -772,17 +1023,23 @@ pub struct UnmanagedFileSystemReference {
     pub mount_command: String,
     #[serde(rename = "relativeMountPath")]
     pub relative_mount_path: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MountVolumes {
-    #[serde(rename = "azureFileShares", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "azureFileShares", default, skip_serializing_if = "Vec::is_empty")]
     pub azure_file_shares: Vec<AzureFileShareReference>,
-    #[serde(rename = "azureBlobFileSystems", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "azureBlobFileSystems", default, skip_serializing_if = "Vec::is_empty")]
     pub azure_blob_file_systems: Vec<AzureBlobFileSystemReference>,
-    #[serde(rename = "fileServers", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "fileServers", default, skip_serializing_if = "Vec::is_empty")]
     pub file_servers: Vec<FileServerReference>,
-    #[serde(rename = "unmanagedFileSystems", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "unmanagedFileSystems", default, skip_serializing_if = "Vec::is_empty")]
     pub unmanaged_file_systems: Vec<UnmanagedFileSystemReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CntKsettings {
@@ -798,6 +1055,9 @@ pub struct CntKsettings {
     pub command_line_args: Option<String>,
     #[serde(rename = "processCount", skip_serializing_if = "Option::is_none")]
     pub process_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CaffeSettings {
@@ -811,6 +1071,9 @@ pub struct CaffeSettings {
     pub command_line_args: Option<String>,
     #[serde(rename = "processCount", skip_serializing_if = "Option::is_none")]
     pub process_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Caffe2Settings {
@@ -820,6 +1083,9 @@ pub struct Caffe2Settings {
     pub python_interpreter_path: Option<String>,
     #[serde(rename = "commandLineArgs", skip_serializing_if = "Option::is_none")]
     pub command_line_args: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ChainerSettings {
@@ -831,16 +1097,25 @@ pub struct ChainerSettings {
     pub command_line_args: Option<String>,
     #[serde(rename = "processCount", skip_serializing_if = "Option::is_none")]
     pub process_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomToolkitSettings {
     #[serde(rename = "commandLine", skip_serializing_if = "Option::is_none")]
     pub command_line: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobPreparation {
     #[serde(rename = "commandLine")]
     pub command_line: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PyTorchSettings {
@@ -854,6 +1129,9 @@ pub struct PyTorchSettings {
     pub process_count: Option<i32>,
     #[serde(rename = "communicationBackend", skip_serializing_if = "Option::is_none")]
     pub communication_backend: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TensorFlowSettings {
@@ -871,11 +1149,17 @@ pub struct TensorFlowSettings {
     pub worker_count: Option<i32>,
     #[serde(rename = "parameterServerCount", skip_serializing_if = "Option::is_none")]
     pub parameter_server_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudError {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudErrorBody {
@@ -885,8 +1169,11 @@ pub struct CloudErrorBody {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub details: Vec<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchAiError {
@@ -894,8 +1181,11 @@ pub struct BatchAiError {
     pub code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub details: Vec<NameValuePair>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
@@ -907,6 +1197,9 @@ pub struct Operation {
     pub origin: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod operation {
     use super::*;
@@ -920,12 +1213,27 @@ pub mod operation {
         pub resource: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Operation>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationListResult {
+    type Item = Operation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Operation> {
+        self.value
+    }
 }
@@ -8,6 +8,9 @@ pub struct UsageName {
     pub value: Option<String>,
     #[serde(rename = "localizedValue", skip_serializing)]
     pub localized_value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Usage {
@@ -19,20 +22,37 @@ pub struct Usage {
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<UsageName>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod usage {
     use super::*;
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Unit {
         Count,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListUsagesResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Usage>,
     #[serde(rename = "nextLink", skip_serializing)]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ListUsagesResult {
+    type Item = Usage;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Usage> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerBaseProperties {
@@ -44,13 +64,28 @@ pub struct FileServerBaseProperties {
     pub data_disks: DataDisks,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subnet: Option<ResourceId>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<FileServer>,
     #[serde(rename = "nextLink", skip_serializing)]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for FileServerListResult {
+    type Item = FileServer;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<FileServer> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataDisks {
@@ -62,6 +97,9 @@ pub struct DataDisks {
     pub disk_count: i32,
     #[serde(rename = "storageAccountType")]
     pub storage_account_type: data_disks::StorageAccountType,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod data_disks {
     use super::*;
@@ -73,6 +111,8 @@ pub mod data_disks {
         Readonly,
         #[serde(rename = "readwrite")]
         Readwrite,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum StorageAccountType {
@@ -80,6 +120,8 @@ pub mod data_disks {
         StandardLrs,
         #[serde(rename = "Premium_LRS")]
         PremiumLrs,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -88,6 +130,9 @@ pub struct KeyVaultSecretReference {
     pub source_vault: ResourceId,
     #[serde(rename = "secretUrl")]
     pub secret_url: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MountSettings {
@@ -97,18 +142,27 @@ pub struct MountSettings {
     pub file_server_public_ip: Option<String>,
     #[serde(rename = "fileServerInternalIP", skip_serializing_if = "Option::is_none")]
     pub file_server_internal_ip: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SshConfiguration {
-    #[serde(rename = "publicIPsToAllow", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "publicIPsToAllow", default, skip_serializing_if = "Vec::is_empty")]
     pub public_i_ps_to_allow: Vec<String>,
     #[serde(rename = "userAccountSettings")]
     pub user_account_settings: UserAccountSettings,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerCreateParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<FileServerBaseProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerProperties {
@@ -128,6 +182,9 @@ pub struct FileServerProperties {
     pub creation_time: Option<String>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<file_server_properties::ProvisioningState>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod file_server_properties {
     use super::*;
@@ -143,6 +200,8 @@ pub mod file_server_properties {
         Succeeded,
         #[serde(rename = "failed")]
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -151,6 +210,9 @@ pub struct FileServer {
     pub proxy_resource: ProxyResource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<FileServerProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterBaseProperties {
@@ -168,6 +230,9 @@ pub struct ClusterBaseProperties {
     pub user_account_settings: UserAccountSettings,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subnet: Option<ResourceId>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_base_properties {
     use super::*;
@@ -177,12 +242,17 @@ pub mod cluster_base_properties {
         Dedicated,
         #[serde(rename = "lowpriority")]
         Lowpriority,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterUpdateProperties {
     #[serde(rename = "scaleSettings", skip_serializing_if = "Option::is_none")]
     pub scale_settings: Option<ScaleSettings>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum DeallocationOption {
@@ -192,6 +262,8 @@ pub enum DeallocationOption {
     Terminate,
     #[serde(rename = "waitforjobcompletion")]
     Waitforjobcompletion,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ScaleSettings {
@@ -199,6 +271,9 @@ pub struct ScaleSettings {
     pub manual: Option<ManualScaleSettings>,
     #[serde(rename = "autoScale", skip_serializing_if = "Option::is_none")]
     pub auto_scale: Option<AutoScaleSettings>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AutoScaleSettings {
@@ -208,6 +283,9 @@ pub struct AutoScaleSettings {
     pub maximum_node_count: i32,
     #[serde(rename = "initialNodeCount", skip_serializing_if = "Option::is_none")]
     pub initial_node_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManualScaleSettings {
@@ -215,11 +293,17 @@ pub struct ManualScaleSettings {
     pub target_node_count: i32,
     #[serde(rename = "nodeDeallocationOption", skip_serializing_if = "Option::is_none")]
     pub node_deallocation_option: Option<DeallocationOption>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VirtualMachineConfiguration {
     #[serde(rename = "imageReference", skip_serializing_if = "Option::is_none")]
     pub image_reference: Option<ImageReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageReference {
@@ -230,6 +314,9 @@ pub struct ImageReference {
     pub version: Option<String>,
     #[serde(rename = "virtualMachineImageId", skip_serializing_if = "Option::is_none")]
     pub virtual_machine_image_id: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NodeStateCounts {
@@ -243,6 +330,9 @@ pub struct NodeStateCounts {
     pub unusable_node_count: Option<i32>,
     #[serde(rename = "leavingNodeCount", skip_serializing)]
     pub leaving_node_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UserAccountSettings {
@@ -252,11 +342,17 @@ pub struct UserAccountSettings {
     pub admin_user_ssh_public_key: Option<String>,
     #[serde(rename = "adminUserPassword", skip_serializing_if = "Option::is_none")]
     pub admin_user_password: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PerformanceCountersSettings {
     #[serde(rename = "appInsightsReference")]
     pub app_insights_reference: AppInsightsReference,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AppInsightsReference {
@@ -265,6 +361,9 @@ pub struct AppInsightsReference {
     pub instrumentation_key: Option<String>,
     #[serde(rename = "instrumentationKeySecretReference", skip_serializing_if = "Option::is_none")]
     pub instrumentation_key_secret_reference: Option<KeyVaultSecretReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NodeSetup {
@@ -274,29 +373,41 @@ pub struct NodeSetup {
     pub mount_volumes: Option<MountVolumes>,
     #[serde(rename = "performanceCountersSettings", skip_serializing_if = "Option::is_none")]
     pub performance_counters_settings: Option<PerformanceCountersSettings>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SetupTask {
     #[serde(rename = "commandLine")]
     pub command_line: String,
-    #[serde(rename = "environmentVariables", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "environmentVariables", default, skip_serializing_if = "Vec::is_empty")]
     pub environment_variables: Vec<EnvironmentVariable>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub secrets: Vec<EnvironmentVariableWithSecretValue>,
     #[serde(rename = "stdOutErrPathPrefix")]
     pub std_out_err_path_prefix: String,
     #[serde(rename = "stdOutErrPathSuffix", skip_serializing)]
     pub std_out_err_path_suffix: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterCreateParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ClusterBaseProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterUpdateParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ClusterUpdateProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterProperties {
@@ -324,12 +435,15 @@ pub struct ClusterProperties {
     pub allocation_state: Option<cluster_properties::AllocationState>,
     #[serde(rename = "allocationStateTransitionTime", skip_serializing)]
     pub allocation_state_transition_time: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub errors: Vec<BatchAiError>,
     #[serde(rename = "currentNodeCount", skip_serializing)]
     pub current_node_count: Option<i32>,
     #[serde(rename = "nodeStateCounts", skip_serializing_if = "Option::is_none")]
     pub node_state_counts: Option<NodeStateCounts>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cluster_properties {
     use super::*;
@@ -339,6 +453,8 @@ pub mod cluster_properties {
         Dedicated,
         #[serde(rename = "lowpriority")]
         Lowpriority,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProvisioningState {
@@ -350,6 +466,8 @@ pub mod cluster_properties {
         Failed,
         #[serde(rename = "deleting")]
         Deleting,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum AllocationState {
@@ -357,6 +475,8 @@ pub mod cluster_properties {
         Steady,
         #[serde(rename = "resizing")]
         Resizing,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -365,13 +485,28 @@ pub struct Cluster {
     pub proxy_resource: ProxyResource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ClusterProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Cluster>,
     #[serde(rename = "nextLink", skip_serializing)]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ClusterListResult {
+    type Item = Cluster;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Cluster> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobBaseProperties {
@@ -406,16 +541,19 @@ pub struct JobBaseProperties {
     pub job_preparation: Option<JobPreparation>,
     #[serde(rename = "stdOutErrPathPrefix")]
     pub std_out_err_path_prefix: String,
-    #[serde(rename = "inputDirectories", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "inputDirectories", default, skip_serializing_if = "Vec::is_empty")]
     pub input_directories: Vec<InputDirectory>,
-    #[serde(rename = "outputDirectories", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "outputDirectories", default, skip_serializing_if = "Vec::is_empty")]
     pub output_directories: Vec<OutputDirectory>,
-    #[serde(rename = "environmentVariables", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "environmentVariables", default, skip_serializing_if = "Vec::is_empty")]
     pub environment_variables: Vec<EnvironmentVariable>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub secrets: Vec<EnvironmentVariableWithSecretValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constraints: Option<job_base_properties::Constraints>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod job_base_properties {
     use super::*;
@@ -427,6 +565,8 @@ pub mod job_base_properties {
         Normal,
         #[serde(rename = "high")]
         High,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub struct Constraints {
@@ -438,6 +578,9 @@ pub mod job_base_properties {
 pub struct JobCreateParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<JobBaseProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobProperties {
@@ -477,13 +620,13 @@ pub struct JobProperties {
     pub job_output_directory_path_segment: Option<String>,
     #[serde(rename = "stdOutErrPathPrefix", skip_serializing_if = "Option::is_none")]
     pub std_out_err_path_prefix: Option<String>,
-    #[serde(rename = "inputDirectories", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "inputDirectories", default, skip_serializing_if = "Vec::is_empty")]
     pub input_directories: Vec<InputDirectory>,
-    #[serde(rename = "outputDirectories", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "outputDirectories", default, skip_serializing_if = "Vec::is_empty")]
     pub output_directories: Vec<OutputDirectory>,
-    #[serde(rename = "environmentVariables", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "environmentVariables", default, skip_serializing_if = "Vec::is_empty")]
     pub environment_variables: Vec<EnvironmentVariable>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub secrets: Vec<EnvironmentVariableWithSecretValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constraints: Option<job_properties::Constraints>,
@@ -499,6 +642,9 @@ pub struct JobProperties {
     pub execution_state_transition_time: Option<String>,
     #[serde(rename = "executionInfo", skip_serializing_if = "Option::is_none")]
     pub execution_info: Option<job_properties::ExecutionInfo>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod job_properties {
     use super::*;
@@ -510,6 +656,8 @@ pub mod job_properties {
         Normal,
         #[serde(rename = "high")]
         High,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub struct Constraints {
@@ -526,6 +674,8 @@ pub mod job_properties {
         Succeeded,
         #[serde(rename = "failed")]
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ExecutionState {
@@ -539,6 +689,8 @@ pub mod job_properties {
         Succeeded,
         #[serde(rename = "failed")]
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub struct ExecutionInfo {
@@ -548,8 +700,11 @@ pub mod job_properties {
         pub end_time: Option<String>,
         #[serde(rename = "exitCode", skip_serializing)]
         pub exit_code: Option<i32>,
-        #[serde(skip_serializing)]
+        #[serde(default, skip_serializing)]
         pub errors: Vec<BatchAiError>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -558,13 +713,28 @@ pub struct Job {
     pub proxy_resource: ProxyResource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<JobProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Job>,
     #[serde(rename = "nextLink", skip_serializing)]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for JobListResult {
+    type Item = Job;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Job> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RemoteLoginInformation {
@@ -574,13 +744,28 @@ pub struct RemoteLoginInformation {
     pub ip_address: Option<String>,
     #[serde(skip_serializing)]
     pub port: Option<f64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RemoteLoginInformationListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<RemoteLoginInformation>,
     #[serde(rename = "nextLink", skip_serializing)]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for RemoteLoginInformationListResult {
+    type Item = RemoteLoginInformation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<RemoteLoginInformation> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileProperties {
@@ -588,6 +773,9 @@ pub struct FileProperties {
     pub last_modified: Option<String>,
     #[serde(rename = "contentLength", skip_serializing)]
     pub content_length: Option<i64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct File {
@@ -599,6 +787,9 @@ pub struct File {
     pub download_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<FileProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod file {
     use super::*;
@@ -608,18 +799,35 @@ pub mod file {
         File,
         #[serde(rename = "directory")]
         Directory,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<File>,
     #[serde(rename = "nextLink", skip_serializing)]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for FileListResult {
+    type Item = File;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<File> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceId {
     pub id: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Resource {
@@ -661,6 +869,8 @@ pub enum ToolType {
     Custommpi,
     #[serde(rename = "custom")]
     Custom,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NameValuePair {
@@ -668,11 +878,17 @@ pub struct NameValuePair {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EnvironmentVariable {
     pub name: String,
     pub value: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EnvironmentVariableWithSecretValue {
@@ -681,6 +897,9 @@ pub struct EnvironmentVariableWithSecretValue {
     pub value: Option<String>,
     #[serde(rename = "valueSecretReference", skip_serializing_if = "Option::is_none")]
     pub value_secret_reference: Option<KeyVaultSecretReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageSourceRegistry {
@@ -689,6 +908,9 @@ pub struct ImageSourceRegistry {
     pub image: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credentials: Option<PrivateRegistryCredentials>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PrivateRegistryCredentials {
@@ -697,6 +919,9 @@ pub struct PrivateRegistryCredentials {
     pub password: Option<String>,
     #[serde(rename = "passwordSecretReference", skip_serializing_if = "Option::is_none")]
     pub password_secret_reference: Option<KeyVaultSecretReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContainerSettings {
@@ -704,11 +929,17 @@ pub struct ContainerSettings {
     pub image_source_registry: ImageSourceRegistry,
     #[serde(rename = "shmSize", skip_serializing_if = "Option::is_none")]
     pub shm_size: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct InputDirectory {
     pub id: String,
     pub path: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OutputDirectory {
@@ -717,6 +948,9 @@ pub struct OutputDirectory {
     pub path_prefix: String,
     #[serde(rename = "pathSuffix", skip_serializing_if = "Option::is_none")]
     pub path_suffix: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureStorageCredentialsInfo {
@@ -724,6 +958,9 @@ pub struct AzureStorageCredentialsInfo {
     pub account_key: Option<String>,
     #[serde(rename = "accountKeySecretReference", skip_serializing_if = "Option::is_none")]
     pub account_key_secret_reference: Option<KeyVaultSecretReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureFileShareReference {
@@ -738,6 +975,9 @@ pub struct AzureFileShareReference {
     pub file_mode: Option<String>,
     #[serde(rename = "directoryMode", skip_serializing_if = "Option::is_none")]
     pub directory_mode: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AzureBlobFileSystemReference {
@@ -750,6 +990,9 @@ pub struct AzureBlobFileSystemReference {
     pub relative_mount_path: String,
     #[serde(rename = "mountOptions", skip_serializing_if = "Option::is_none")]
     pub mount_options: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServerReference {
@@ -761,6 +1004,9 @@ pub struct FileServerReference {
     pub relative_mount_path: String,
     #[serde(rename = "mountOptions", skip_serializing_if = "Option::is_none")]
     pub mount_options: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UnmanagedFileSystemReference {
@@ -768,17 +1014,23 @@ pub struct UnmanagedFileSystemReference {
     pub mount_command: String,
     #[serde(rename = "relativeMountPath")]
     pub relative_mount_path: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MountVolumes {
-    #[serde(rename = "azureFileShares", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "azureFileShares", default, skip_serializing_if = "Vec::is_empty")]
     pub azure_file_shares: Vec<AzureFileShareReference>,
-    #[serde(rename = "azureBlobFileSystems", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "azureBlobFileSystems", default, skip_serializing_if = "Vec::is_empty")]
     pub azure_blob_file_systems: Vec<AzureBlobFileSystemReference>,
-    #[serde(rename = "fileServers", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "fileServers", default, skip_serializing_if = "Vec::is_empty")]
     pub file_servers: Vec<FileServerReference>,
-    #[serde(rename = "unmanagedFileSystems", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "unmanagedFileSystems", default, skip_serializing_if = "Vec::is_empty")]
     pub unmanaged_file_systems: Vec<UnmanagedFileSystemReference>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CntKsettings {
@@ -794,6 +1046,9 @@ pub struct CntKsettings {
     pub command_line_args: Option<String>,
     #[serde(rename = "processCount", skip_serializing_if = "Option::is_none")]
     pub process_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CaffeSettings {
@@ -807,6 +1062,9 @@ pub struct CaffeSettings {
     pub command_line_args: Option<String>,
     #[serde(rename = "processCount", skip_serializing_if = "Option::is_none")]
     pub process_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Caffe2Settings {
@@ -816,6 +1074,9 @@ pub struct Caffe2Settings {
     pub python_interpreter_path: Option<String>,
     #[serde(rename = "commandLineArgs", skip_serializing_if = "Option::is_none")]
     pub command_line_args: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ChainerSettings {
@@ -827,11 +1088,17 @@ pub struct ChainerSettings {
     pub command_line_args: Option<String>,
     #[serde(rename = "processCount", skip_serializing_if = "Option::is_none")]
     pub process_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomToolkitSettings {
     #[serde(rename = "commandLine", skip_serializing_if = "Option::is_none")]
     pub command_line: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomMpiSettings {
@@ -839,11 +1106,17 @@ pub struct CustomMpiSettings {
     pub command_line: String,
     #[serde(rename = "processCount", skip_serializing_if = "Option::is_none")]
     pub process_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobPreparation {
     #[serde(rename = "commandLine")]
     pub command_line: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PyTorchSettings {
@@ -857,6 +1130,9 @@ pub struct PyTorchSettings {
     pub process_count: Option<i32>,
     #[serde(rename = "communicationBackend", skip_serializing_if = "Option::is_none")]
     pub communication_backend: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HorovodSettings {
@@ -868,6 +1144,9 @@ pub struct HorovodSettings {
     pub command_line_args: Option<String>,
     #[serde(rename = "processCount", skip_serializing_if = "Option::is_none")]
     pub process_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TensorFlowSettings {
@@ -885,11 +1164,17 @@ pub struct TensorFlowSettings {
     pub worker_count: Option<i32>,
     #[serde(rename = "parameterServerCount", skip_serializing_if = "Option::is_none")]
     pub parameter_server_count: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudError {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudErrorBody {
@@ -899,8 +1184,11 @@ pub struct CloudErrorBody {
     pub message: Option<String>,
     #[serde(skip_serializing)]
     pub target: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub details: Vec<CloudErrorBody>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchAiError {
@@ -908,8 +1196,11 @@ pub struct BatchAiError {
     pub code: Option<String>,
     #[serde(skip_serializing)]
     pub message: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub details: Vec<NameValuePair>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
@@ -921,6 +1212,9 @@ pub struct Operation {
     pub origin: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod operation {
     use super::*;
@@ -934,32 +1228,65 @@ pub mod operation {
         pub resource: Option<String>,
         #[serde(skip_serializing)]
         pub description: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Operation>,
     #[serde(rename = "nextLink", skip_serializing)]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationListResult {
+    type Item = Operation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Operation> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WorkspaceListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Workspace>,
     #[serde(rename = "nextLink", skip_serializing)]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for WorkspaceListResult {
+    type Item = Workspace;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Workspace> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WorkspaceCreateParameters {
     pub location: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WorkspaceUpdateParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WorkspaceProperties {
@@ -969,6 +1296,9 @@ pub struct WorkspaceProperties {
     pub provisioning_state: Option<workspace_properties::ProvisioningState>,
     #[serde(rename = "provisioningStateTransitionTime", skip_serializing)]
     pub provisioning_state_transition_time: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod workspace_properties {
     use super::*;
@@ -982,6 +1312,8 @@ pub mod workspace_properties {
         Succeeded,
         #[serde(rename = "failed")]
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -990,13 +1322,28 @@ pub struct Workspace {
     pub resource: Resource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<WorkspaceProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExperimentListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Experiment>,
     #[serde(rename = "nextLink", skip_serializing)]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ExperimentListResult {
+    type Item = Experiment;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Experiment> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExperimentProperties {
@@ -1006,6 +1353,9 @@ pub struct ExperimentProperties {
     pub provisioning_state: Option<experiment_properties::ProvisioningState>,
     #[serde(rename = "provisioningStateTransitionTime", skip_serializing)]
     pub provisioning_state_transition_time: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod experiment_properties {
     use super::*;
@@ -1019,6 +1369,8 @@ pub mod experiment_properties {
         Succeeded,
         #[serde(rename = "failed")]
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -1027,4 +1379,7 @@ pub struct Experiment {
     pub proxy_resource: ProxyResource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<ExperimentProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
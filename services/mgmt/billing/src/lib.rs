@@ -1,51 +1,28 @@
 #![doc = "generated by AutoRust 0.1.0"]
 #[cfg(feature = "package-2020-05")]
-mod package_2020_05;
+pub mod package_2020_05;
+#[cfg(feature = "package-2020-05")]
+pub use package_2020_05 as v2020_05;
 #[cfg(feature = "package-2020-05")]
 pub use package_2020_05::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-10-preview")]
-mod package_2019_10_preview;
-#[cfg(feature = "package-2019-10-preview")]
-pub use package_2019_10_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-11-preview")]
-mod package_2018_11_preview;
-#[cfg(feature = "package-2018-11-preview")]
-pub use package_2018_11_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-03-preview")]
-mod package_2018_03_preview;
-#[cfg(feature = "package-2018-03-preview")]
-pub use package_2018_03_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-2017-04-preview")]
-mod package_2017_04_preview;
-#[cfg(feature = "package-2017-04-preview")]
-pub use package_2017_04_preview::{models, operations, API_VERSION};
 #[cfg(feature = "package-2017-02-preview")]
-mod package_2017_02_preview;
+pub mod package_2017_02_preview;
 #[cfg(feature = "package-2017-02-preview")]
-pub use package_2017_02_preview::{models, operations, API_VERSION};
-pub struct OperationConfig {
-    pub api_version: String,
-    pub client: reqwest::Client,
-    pub base_path: String,
-    pub token_credential: Option<Box<dyn azure_core::TokenCredential>>,
-    pub token_credential_resource: String,
-}
-impl OperationConfig {
-    pub fn new(token_credential: Box<dyn azure_core::TokenCredential>) -> Self {
-        Self {
-            token_credential: Some(token_credential),
-            ..Default::default()
-        }
-    }
-}
-impl Default for OperationConfig {
-    fn default() -> Self {
-        Self {
-            api_version: API_VERSION.to_owned(),
-            client: reqwest::Client::new(),
-            base_path: "https://management.azure.com".to_owned(),
-            token_credential: None,
-            token_credential_resource: "https://management.azure.com/".to_owned(),
-        }
-    }
-}
+pub use package_2017_02_preview as v2017_02_preview;
+#[cfg(feature = "package-2017-04-preview")]
+pub mod package_2017_04_preview;
+#[cfg(feature = "package-2017-04-preview")]
+pub use package_2017_04_preview as v2017_04_preview;
+#[cfg(feature = "package-2018-03-preview")]
+pub mod package_2018_03_preview;
+#[cfg(feature = "package-2018-03-preview")]
+pub use package_2018_03_preview as v2018_03_preview;
+#[cfg(feature = "package-2018-11-preview")]
+pub mod package_2018_11_preview;
+#[cfg(feature = "package-2018-11-preview")]
+pub use package_2018_11_preview as v2018_11_preview;
+#[cfg(feature = "package-2019-10-preview")]
+pub mod package_2019_10_preview;
+pub use azure_mgmt_core::OperationConfig;
+#[cfg(feature = "package-2019-10-preview")]
+pub use package_2019_10_preview as v2019_10_preview;
@@ -16,6 +16,9 @@ pub struct ProfileProperties {
     pub resource_state: Option<profile_properties::ResourceState>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod profile_properties {
     use super::*;
@@ -25,29 +28,52 @@ pub mod profile_properties {
         Active,
         Deleting,
         Disabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ProfileListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Profile>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ProfileListResult {
+    type Item = Profile;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Profile> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ProfileUpdateParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SsoUri {
     #[serde(rename = "ssoUriValue", skip_serializing)]
     pub sso_uri_value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SupportedOptimizationTypesListResult {
-    #[serde(rename = "supportedOptimizationTypes", skip_serializing)]
+    #[serde(rename = "supportedOptimizationTypes", default, skip_serializing)]
     pub supported_optimization_types: Vec<OptimizationType>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Endpoint {
@@ -62,11 +88,15 @@ pub struct EndpointProperties {
     pub endpoint_properties_update_parameters: EndpointPropertiesUpdateParameters,
     #[serde(rename = "hostName", skip_serializing)]
     pub host_name: Option<String>,
+    #[serde(default)]
     pub origins: Vec<DeepCreatedOrigin>,
     #[serde(rename = "resourceState", skip_serializing)]
     pub resource_state: Option<endpoint_properties::ResourceState>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod endpoint_properties {
     use super::*;
@@ -78,14 +108,28 @@ pub mod endpoint_properties {
         Starting,
         Stopped,
         Stopping,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EndpointListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Endpoint>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for EndpointListResult {
+    type Item = Endpoint;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Endpoint> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EndpointUpdateParameters {
@@ -93,6 +137,9 @@ pub struct EndpointUpdateParameters {
     pub tags: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<EndpointPropertiesUpdateParameters>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EndpointPropertiesUpdateParameters {
@@ -100,7 +147,7 @@ pub struct EndpointPropertiesUpdateParameters {
     pub origin_host_header: Option<String>,
     #[serde(rename = "originPath", skip_serializing_if = "Option::is_none")]
     pub origin_path: Option<String>,
-    #[serde(rename = "contentTypesToCompress", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "contentTypesToCompress", default, skip_serializing_if = "Vec::is_empty")]
     pub content_types_to_compress: Vec<String>,
     #[serde(rename = "isCompressionEnabled", skip_serializing_if = "Option::is_none")]
     pub is_compression_enabled: Option<bool>,
@@ -114,7 +161,7 @@ pub struct EndpointPropertiesUpdateParameters {
     pub optimization_type: Option<OptimizationType>,
     #[serde(rename = "probePath", skip_serializing_if = "Option::is_none")]
     pub probe_path: Option<String>,
-    #[serde(rename = "geoFilters", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "geoFilters", default, skip_serializing_if = "Vec::is_empty")]
     pub geo_filters: Vec<GeoFilter>,
     #[serde(rename = "deliveryPolicy", skip_serializing_if = "Option::is_none")]
     pub delivery_policy: Option<endpoint_properties_update_parameters::DeliveryPolicy>,
@@ -125,7 +172,11 @@ pub mod endpoint_properties_update_parameters {
     pub struct DeliveryPolicy {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+        #[serde(default)]
         pub rules: Vec<DeliveryRule>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -133,9 +184,13 @@ pub struct DeliveryRule {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     pub order: i64,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub conditions: Vec<DeliveryRuleCondition>,
+    #[serde(default)]
     pub actions: Vec<DeliveryRuleAction>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleCondition {
@@ -159,6 +214,8 @@ pub mod delivery_rule_condition {
         HttpVersion,
         Cookies,
         IsDevice,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -166,84 +223,126 @@ pub struct DeliveryRuleRemoteAddressCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: RemoteAddressMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleRequestMethodCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: RequestMethodMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleQueryStringCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: QueryStringMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRulePostArgsCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: PostArgsMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleRequestUriCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: RequestUriMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleRequestHeaderCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: RequestHeaderMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleRequestBodyCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: RequestBodyMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleRequestSchemeCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: RequestSchemeMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleUrlPathCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: UrlPathMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleUrlFileExtensionCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: UrlFileExtensionMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleUrlFileNameCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: UrlFileNameMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleHttpVersionCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: HttpVersionMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleCookiesCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: CookiesMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleIsDeviceCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: IsDeviceMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RemoteAddressMatchConditionParameters {
@@ -252,10 +351,13 @@ pub struct RemoteAddressMatchConditionParameters {
     pub operator: remote_address_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod remote_address_match_condition_parameters {
     use super::*;
@@ -263,6 +365,8 @@ pub mod remote_address_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleRemoteAddressConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleRemoteAddressConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -270,6 +374,8 @@ pub mod remote_address_match_condition_parameters {
         #[serde(rename = "IPMatch")]
         IpMatch,
         GeoMatch,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -279,8 +385,11 @@ pub struct RequestMethodMatchConditionParameters {
     pub operator: request_method_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod request_method_match_condition_parameters {
     use super::*;
@@ -288,10 +397,14 @@ pub mod request_method_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleRequestMethodConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleRequestMethodConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
         Equal,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -301,10 +414,13 @@ pub struct QueryStringMatchConditionParameters {
     pub operator: query_string_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod query_string_match_condition_parameters {
     use super::*;
@@ -312,6 +428,8 @@ pub mod query_string_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleQueryStringConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleQueryStringConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -324,6 +442,8 @@ pub mod query_string_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -335,10 +455,13 @@ pub struct PostArgsMatchConditionParameters {
     pub operator: post_args_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod post_args_match_condition_parameters {
     use super::*;
@@ -346,6 +469,8 @@ pub mod post_args_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRulePostArgsConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRulePostArgsConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -358,6 +483,8 @@ pub mod post_args_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -367,10 +494,13 @@ pub struct RequestUriMatchConditionParameters {
     pub operator: request_uri_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod request_uri_match_condition_parameters {
     use super::*;
@@ -378,6 +508,8 @@ pub mod request_uri_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleRequestUriConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleRequestUriConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -390,6 +522,8 @@ pub mod request_uri_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -401,10 +535,13 @@ pub struct RequestHeaderMatchConditionParameters {
     pub operator: request_header_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod request_header_match_condition_parameters {
     use super::*;
@@ -412,6 +549,8 @@ pub mod request_header_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleRequestHeaderConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleRequestHeaderConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -424,6 +563,8 @@ pub mod request_header_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -433,10 +574,13 @@ pub struct RequestBodyMatchConditionParameters {
     pub operator: request_body_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod request_body_match_condition_parameters {
     use super::*;
@@ -444,6 +588,8 @@ pub mod request_body_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleRequestBodyConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleRequestBodyConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -456,6 +602,8 @@ pub mod request_body_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -465,8 +613,11 @@ pub struct RequestSchemeMatchConditionParameters {
     pub operator: request_scheme_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod request_scheme_match_condition_parameters {
     use super::*;
@@ -474,10 +625,14 @@ pub mod request_scheme_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleRequestSchemeConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleRequestSchemeConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
         Equal,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -487,10 +642,13 @@ pub struct UrlPathMatchConditionParameters {
     pub operator: url_path_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod url_path_match_condition_parameters {
     use super::*;
@@ -498,6 +656,8 @@ pub mod url_path_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleUrlPathMatchConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleUrlPathMatchConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -511,6 +671,8 @@ pub mod url_path_match_condition_parameters {
         GreaterThan,
         GreaterThanOrEqual,
         Wildcard,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -520,10 +682,13 @@ pub struct UrlFileExtensionMatchConditionParameters {
     pub operator: url_file_extension_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod url_file_extension_match_condition_parameters {
     use super::*;
@@ -531,6 +696,8 @@ pub mod url_file_extension_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleUrlFileExtensionMatchConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleUrlFileExtensionMatchConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -543,6 +710,8 @@ pub mod url_file_extension_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -552,10 +721,13 @@ pub struct UrlFileNameMatchConditionParameters {
     pub operator: url_file_name_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod url_file_name_match_condition_parameters {
     use super::*;
@@ -563,6 +735,8 @@ pub mod url_file_name_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleUrlFilenameConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleUrlFilenameConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -575,6 +749,8 @@ pub mod url_file_name_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -584,8 +760,11 @@ pub struct HttpVersionMatchConditionParameters {
     pub operator: http_version_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod http_version_match_condition_parameters {
     use super::*;
@@ -593,10 +772,14 @@ pub mod http_version_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleHttpVersionConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleHttpVersionConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
         Equal,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -608,10 +791,13 @@ pub struct CookiesMatchConditionParameters {
     pub operator: cookies_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cookies_match_condition_parameters {
     use super::*;
@@ -619,6 +805,8 @@ pub mod cookies_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleCookiesConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleCookiesConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -631,6 +819,8 @@ pub mod cookies_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -640,10 +830,13 @@ pub struct IsDeviceMatchConditionParameters {
     pub operator: is_device_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod is_device_match_condition_parameters {
     use super::*;
@@ -651,10 +844,14 @@ pub mod is_device_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleIsDeviceConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleIsDeviceConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
         Equal,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -671,6 +868,8 @@ pub mod delivery_rule_action {
         ModifyResponseHeader,
         UrlRedirect,
         UrlRewrite,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -678,6 +877,9 @@ pub struct UrlRedirectAction {
     #[serde(flatten)]
     pub delivery_rule_action: DeliveryRuleAction,
     pub parameters: UrlRedirectActionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UrlRedirectActionParameters {
@@ -695,6 +897,9 @@ pub struct UrlRedirectActionParameters {
     pub custom_query_string: Option<String>,
     #[serde(rename = "customFragment", skip_serializing_if = "Option::is_none")]
     pub custom_fragment: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod url_redirect_action_parameters {
     use super::*;
@@ -702,6 +907,8 @@ pub mod url_redirect_action_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleUrlRedirectActionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleUrlRedirectActionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum RedirectType {
@@ -709,12 +916,16 @@ pub mod url_redirect_action_parameters {
         Found,
         TemporaryRedirect,
         PermanentRedirect,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum DestinationProtocol {
         MatchRequest,
         Http,
         Https,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -722,6 +933,9 @@ pub struct UrlRewriteAction {
     #[serde(flatten)]
     pub delivery_rule_action: DeliveryRuleAction,
     pub parameters: UrlRewriteActionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UrlRewriteActionParameters {
@@ -732,6 +946,9 @@ pub struct UrlRewriteActionParameters {
     pub destination: String,
     #[serde(rename = "preserveUnmatchedPath", skip_serializing_if = "Option::is_none")]
     pub preserve_unmatched_path: Option<bool>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod url_rewrite_action_parameters {
     use super::*;
@@ -739,6 +956,8 @@ pub mod url_rewrite_action_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleUrlRewriteActionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleUrlRewriteActionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -746,12 +965,18 @@ pub struct DeliveryRuleRequestHeaderAction {
     #[serde(flatten)]
     pub delivery_rule_action: DeliveryRuleAction,
     pub parameters: HeaderActionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleResponseHeaderAction {
     #[serde(flatten)]
     pub delivery_rule_action: DeliveryRuleAction,
     pub parameters: HeaderActionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HeaderActionParameters {
@@ -763,6 +988,9 @@ pub struct HeaderActionParameters {
     pub header_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod header_action_parameters {
     use super::*;
@@ -770,12 +998,16 @@ pub mod header_action_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleHeaderActionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleHeaderActionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum HeaderAction {
         Append,
         Overwrite,
         Delete,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -783,6 +1015,9 @@ pub struct DeliveryRuleCacheExpirationAction {
     #[serde(flatten)]
     pub delivery_rule_action: DeliveryRuleAction,
     pub parameters: CacheExpirationActionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CacheExpirationActionParameters {
@@ -794,6 +1029,9 @@ pub struct CacheExpirationActionParameters {
     pub cache_type: cache_expiration_action_parameters::CacheType,
     #[serde(rename = "cacheDuration", skip_serializing_if = "Option::is_none")]
     pub cache_duration: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cache_expiration_action_parameters {
     use super::*;
@@ -801,16 +1039,22 @@ pub mod cache_expiration_action_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleCacheExpirationActionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleCacheExpirationActionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum CacheBehavior {
         BypassCache,
         Override,
         SetIfMissing,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum CacheType {
         All,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -818,6 +1062,9 @@ pub struct DeliveryRuleCacheKeyQueryStringAction {
     #[serde(flatten)]
     pub delivery_rule_action: DeliveryRuleAction,
     pub parameters: CacheKeyQueryStringActionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CacheKeyQueryStringActionParameters {
@@ -827,6 +1074,9 @@ pub struct CacheKeyQueryStringActionParameters {
     pub query_string_behavior: cache_key_query_string_action_parameters::QueryStringBehavior,
     #[serde(rename = "queryParameters", skip_serializing_if = "Option::is_none")]
     pub query_parameters: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cache_key_query_string_action_parameters {
     use super::*;
@@ -834,6 +1084,8 @@ pub mod cache_key_query_string_action_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleCacheKeyQueryStringBehaviorActionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleCacheKeyQueryStringBehaviorActionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum QueryStringBehavior {
@@ -841,18 +1093,25 @@ pub mod cache_key_query_string_action_parameters {
         IncludeAll,
         Exclude,
         ExcludeAll,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Transform {
     Lowercase,
     Uppercase,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeepCreatedOrigin {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<DeepCreatedOriginProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeepCreatedOriginProperties {
@@ -862,14 +1121,20 @@ pub struct DeepCreatedOriginProperties {
     pub http_port: Option<i64>,
     #[serde(rename = "httpsPort", skip_serializing_if = "Option::is_none")]
     pub https_port: Option<i64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GeoFilter {
     #[serde(rename = "relativePath")]
     pub relative_path: String,
     pub action: geo_filter::Action,
-    #[serde(rename = "countryCodes")]
+    #[serde(rename = "countryCodes", default)]
     pub country_codes: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod geo_filter {
     use super::*;
@@ -877,17 +1142,25 @@ pub mod geo_filter {
     pub enum Action {
         Block,
         Allow,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PurgeParameters {
-    #[serde(rename = "contentPaths")]
+    #[serde(rename = "contentPaths", default)]
     pub content_paths: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LoadParameters {
-    #[serde(rename = "contentPaths")]
+    #[serde(rename = "contentPaths", default)]
     pub content_paths: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Origin {
@@ -908,6 +1181,9 @@ pub struct OriginProperties {
     pub resource_state: Option<origin_properties::ResourceState>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod origin_properties {
     use super::*;
@@ -916,12 +1192,17 @@ pub mod origin_properties {
         Creating,
         Active,
         Deleting,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OriginUpdateParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<OriginPropertiesParameters>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OriginPropertiesParameters {
@@ -931,13 +1212,28 @@ pub struct OriginPropertiesParameters {
     pub http_port: Option<i64>,
     #[serde(rename = "httpsPort", skip_serializing_if = "Option::is_none")]
     pub https_port: Option<i64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OriginListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Origin>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OriginListResult {
+    type Item = Origin;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Origin> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomDomain {
@@ -962,6 +1258,9 @@ pub struct CustomDomainProperties {
     pub validation_data: Option<String>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod custom_domain_properties {
     use super::*;
@@ -970,6 +1269,8 @@ pub mod custom_domain_properties {
         Creating,
         Active,
         Deleting,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum CustomHttpsProvisioningState {
@@ -978,6 +1279,8 @@ pub mod custom_domain_properties {
         Disabling,
         Disabled,
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum CustomHttpsProvisioningSubstate {
@@ -991,17 +1294,25 @@ pub mod custom_domain_properties {
         CertificateDeployed,
         DeletingCertificate,
         CertificateDeleted,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomDomainParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<CustomDomainPropertiesParameters>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomDomainPropertiesParameters {
     #[serde(rename = "hostName")]
     pub host_name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomDomainHttpsParameters {
@@ -1018,12 +1329,16 @@ pub mod custom_domain_https_parameters {
     pub enum CertificateSource {
         AzureKeyVault,
         Cdn,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProtocolType {
         ServerNameIndication,
         #[serde(rename = "IPBased")]
         IpBased,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum MinimumTlsVersion {
@@ -1032,6 +1347,8 @@ pub mod custom_domain_https_parameters {
         Tls10,
         #[serde(rename = "TLS12")]
         Tls12,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -1040,6 +1357,9 @@ pub struct CdnManagedHttpsParameters {
     pub custom_domain_https_parameters: CustomDomainHttpsParameters,
     #[serde(rename = "certificateSourceParameters")]
     pub certificate_source_parameters: CdnCertificateSourceParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CdnCertificateSourceParameters {
@@ -1047,6 +1367,9 @@ pub struct CdnCertificateSourceParameters {
     pub odata_type: cdn_certificate_source_parameters::Odata_type,
     #[serde(rename = "certificateType")]
     pub certificate_type: cdn_certificate_source_parameters::CertificateType,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cdn_certificate_source_parameters {
     use super::*;
@@ -1054,11 +1377,15 @@ pub mod cdn_certificate_source_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.CdnCertificateSourceParameters")]
         MicrosoftAzureCdnModelsCdnCertificateSourceParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum CertificateType {
         Shared,
         Dedicated,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -1067,6 +1394,9 @@ pub struct UserManagedHttpsParameters {
     pub custom_domain_https_parameters: CustomDomainHttpsParameters,
     #[serde(rename = "certificateSourceParameters")]
     pub certificate_source_parameters: KeyVaultCertificateSourceParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct KeyVaultCertificateSourceParameters {
@@ -1086,6 +1416,9 @@ pub struct KeyVaultCertificateSourceParameters {
     pub update_rule: key_vault_certificate_source_parameters::UpdateRule,
     #[serde(rename = "deleteRule")]
     pub delete_rule: key_vault_certificate_source_parameters::DeleteRule,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod key_vault_certificate_source_parameters {
     use super::*;
@@ -1093,27 +1426,48 @@ pub mod key_vault_certificate_source_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.KeyVaultCertificateSourceParameters")]
         MicrosoftAzureCdnModelsKeyVaultCertificateSourceParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum UpdateRule {
         NoAction,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum DeleteRule {
         NoAction,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomDomainListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<CustomDomain>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for CustomDomainListResult {
+    type Item = CustomDomain;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<CustomDomain> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ValidateCustomDomainInput {
     #[serde(rename = "hostName")]
     pub host_name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ValidateCustomDomainOutput {
@@ -1123,12 +1477,18 @@ pub struct ValidateCustomDomainOutput {
     pub reason: Option<String>,
     #[serde(skip_serializing)]
     pub message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CheckNameAvailabilityInput {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: ResourceType,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CheckNameAvailabilityOutput {
@@ -1138,11 +1498,17 @@ pub struct CheckNameAvailabilityOutput {
     pub reason: Option<String>,
     #[serde(skip_serializing)]
     pub message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ValidateProbeInput {
     #[serde(rename = "probeURL")]
     pub probe_url: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ValidateProbeOutput {
@@ -1152,13 +1518,28 @@ pub struct ValidateProbeOutput {
     pub error_code: Option<String>,
     #[serde(skip_serializing)]
     pub message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceUsageListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<ResourceUsage>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ResourceUsageListResult {
+    type Item = ResourceUsage;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<ResourceUsage> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceUsage {
@@ -1170,11 +1551,16 @@ pub struct ResourceUsage {
     pub current_value: Option<i64>,
     #[serde(skip_serializing)]
     pub limit: Option<i64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ResourceType {
     #[serde(rename = "Microsoft.Cdn/Profiles/Endpoints")]
     MicrosoftCdnProfilesEndpoints,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
@@ -1182,6 +1568,9 @@ pub struct Operation {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display: Option<operation::Display>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod operation {
     use super::*;
@@ -1193,21 +1582,48 @@ pub mod operation {
         pub resource: Option<String>,
         #[serde(skip_serializing)]
         pub operation: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationsListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Operation>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationsListResult {
+    type Item = Operation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Operation> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EdgenodeResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<EdgeNode>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for EdgenodeResult {
+    type Item = EdgeNode;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<EdgeNode> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EdgeNode {
@@ -1218,17 +1634,23 @@ pub struct EdgeNode {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EdgeNodeProperties {
-    #[serde(rename = "ipAddressGroups")]
+    #[serde(rename = "ipAddressGroups", default)]
     pub ip_address_groups: Vec<IpAddressGroup>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct IpAddressGroup {
     #[serde(rename = "deliveryRegion", skip_serializing_if = "Option::is_none")]
     pub delivery_region: Option<String>,
-    #[serde(rename = "ipv4Addresses", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "ipv4Addresses", default, skip_serializing_if = "Vec::is_empty")]
     pub ipv4_addresses: Vec<CidrIpAddress>,
-    #[serde(rename = "ipv6Addresses", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "ipv6Addresses", default, skip_serializing_if = "Vec::is_empty")]
     pub ipv6_addresses: Vec<CidrIpAddress>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CidrIpAddress {
@@ -1236,6 +1658,9 @@ pub struct CidrIpAddress {
     pub base_ip_address: Option<String>,
     #[serde(rename = "prefixLength", skip_serializing_if = "Option::is_none")]
     pub prefix_length: Option<i64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Resource {
@@ -1265,11 +1690,16 @@ pub enum QueryStringCachingBehavior {
     BypassCaching,
     UseQueryString,
     NotSet,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Sku {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<sku::Name>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod sku {
     use super::*;
@@ -1289,6 +1719,8 @@ pub mod sku {
         StandardMicrosoft,
         #[serde(rename = "Premium_ChinaCdn")]
         PremiumChinaCdn,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -1298,6 +1730,8 @@ pub enum OptimizationType {
     VideoOnDemandMediaStreaming,
     LargeFileDownload,
     DynamicSiteAcceleration,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorResponse {
@@ -1305,4 +1739,7 @@ pub struct ErrorResponse {
     pub code: Option<String>,
     #[serde(skip_serializing)]
     pub message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
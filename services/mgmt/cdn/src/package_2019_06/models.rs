@@ -16,6 +16,9 @@ pub struct ProfileProperties {
     pub resource_state: Option<profile_properties::ResourceState>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod profile_properties {
     use super::*;
@@ -25,29 +28,52 @@ pub mod profile_properties {
         Active,
         Deleting,
         Disabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ProfileListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Profile>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ProfileListResult {
+    type Item = Profile;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Profile> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ProfileUpdateParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SsoUri {
     #[serde(rename = "ssoUriValue", skip_serializing)]
     pub sso_uri_value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SupportedOptimizationTypesListResult {
-    #[serde(rename = "supportedOptimizationTypes", skip_serializing)]
+    #[serde(rename = "supportedOptimizationTypes", default, skip_serializing)]
     pub supported_optimization_types: Vec<OptimizationType>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Endpoint {
@@ -62,11 +88,15 @@ pub struct EndpointProperties {
     pub endpoint_properties_update_parameters: EndpointPropertiesUpdateParameters,
     #[serde(rename = "hostName", skip_serializing)]
     pub host_name: Option<String>,
+    #[serde(default)]
     pub origins: Vec<DeepCreatedOrigin>,
     #[serde(rename = "resourceState", skip_serializing)]
     pub resource_state: Option<endpoint_properties::ResourceState>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod endpoint_properties {
     use super::*;
@@ -78,14 +108,28 @@ pub mod endpoint_properties {
         Starting,
         Stopped,
         Stopping,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EndpointListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Endpoint>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for EndpointListResult {
+    type Item = Endpoint;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Endpoint> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EndpointUpdateParameters {
@@ -93,6 +137,9 @@ pub struct EndpointUpdateParameters {
     pub tags: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<EndpointPropertiesUpdateParameters>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EndpointPropertiesUpdateParameters {
@@ -100,7 +147,7 @@ pub struct EndpointPropertiesUpdateParameters {
     pub origin_host_header: Option<String>,
     #[serde(rename = "originPath", skip_serializing_if = "Option::is_none")]
     pub origin_path: Option<String>,
-    #[serde(rename = "contentTypesToCompress", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "contentTypesToCompress", default, skip_serializing_if = "Vec::is_empty")]
     pub content_types_to_compress: Vec<String>,
     #[serde(rename = "isCompressionEnabled", skip_serializing_if = "Option::is_none")]
     pub is_compression_enabled: Option<bool>,
@@ -114,7 +161,7 @@ pub struct EndpointPropertiesUpdateParameters {
     pub optimization_type: Option<OptimizationType>,
     #[serde(rename = "probePath", skip_serializing_if = "Option::is_none")]
     pub probe_path: Option<String>,
-    #[serde(rename = "geoFilters", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "geoFilters", default, skip_serializing_if = "Vec::is_empty")]
     pub geo_filters: Vec<GeoFilter>,
     #[serde(rename = "deliveryPolicy", skip_serializing_if = "Option::is_none")]
     pub delivery_policy: Option<endpoint_properties_update_parameters::DeliveryPolicy>,
@@ -127,12 +174,19 @@ pub mod endpoint_properties_update_parameters {
     pub struct DeliveryPolicy {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+        #[serde(default)]
         pub rules: Vec<DeliveryRule>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub struct WebApplicationFirewallPolicyLink {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub id: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -140,9 +194,13 @@ pub struct DeliveryRule {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     pub order: i64,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub conditions: Vec<DeliveryRuleCondition>,
+    #[serde(default)]
     pub actions: Vec<DeliveryRuleAction>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleCondition {
@@ -166,6 +224,8 @@ pub mod delivery_rule_condition {
         HttpVersion,
         Cookies,
         IsDevice,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -173,84 +233,126 @@ pub struct DeliveryRuleRemoteAddressCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: RemoteAddressMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleRequestMethodCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: RequestMethodMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleQueryStringCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: QueryStringMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRulePostArgsCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: PostArgsMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleRequestUriCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: RequestUriMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleRequestHeaderCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: RequestHeaderMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleRequestBodyCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: RequestBodyMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleRequestSchemeCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: RequestSchemeMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleUrlPathCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: UrlPathMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleUrlFileExtensionCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: UrlFileExtensionMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleUrlFileNameCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: UrlFileNameMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleHttpVersionCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: HttpVersionMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleCookiesCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: CookiesMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleIsDeviceCondition {
     #[serde(flatten)]
     pub delivery_rule_condition: DeliveryRuleCondition,
     pub parameters: IsDeviceMatchConditionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RemoteAddressMatchConditionParameters {
@@ -259,10 +361,13 @@ pub struct RemoteAddressMatchConditionParameters {
     pub operator: remote_address_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod remote_address_match_condition_parameters {
     use super::*;
@@ -270,6 +375,8 @@ pub mod remote_address_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleRemoteAddressConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleRemoteAddressConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -277,6 +384,8 @@ pub mod remote_address_match_condition_parameters {
         #[serde(rename = "IPMatch")]
         IpMatch,
         GeoMatch,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -286,8 +395,11 @@ pub struct RequestMethodMatchConditionParameters {
     pub operator: request_method_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod request_method_match_condition_parameters {
     use super::*;
@@ -295,10 +407,14 @@ pub mod request_method_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleRequestMethodConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleRequestMethodConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
         Equal,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -308,10 +424,13 @@ pub struct QueryStringMatchConditionParameters {
     pub operator: query_string_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod query_string_match_condition_parameters {
     use super::*;
@@ -319,6 +438,8 @@ pub mod query_string_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleQueryStringConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleQueryStringConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -331,6 +452,8 @@ pub mod query_string_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -342,10 +465,13 @@ pub struct PostArgsMatchConditionParameters {
     pub operator: post_args_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod post_args_match_condition_parameters {
     use super::*;
@@ -353,6 +479,8 @@ pub mod post_args_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRulePostArgsConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRulePostArgsConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -365,6 +493,8 @@ pub mod post_args_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -374,10 +504,13 @@ pub struct RequestUriMatchConditionParameters {
     pub operator: request_uri_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod request_uri_match_condition_parameters {
     use super::*;
@@ -385,6 +518,8 @@ pub mod request_uri_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleRequestUriConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleRequestUriConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -397,6 +532,8 @@ pub mod request_uri_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -408,10 +545,13 @@ pub struct RequestHeaderMatchConditionParameters {
     pub operator: request_header_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod request_header_match_condition_parameters {
     use super::*;
@@ -419,6 +559,8 @@ pub mod request_header_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleRequestHeaderConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleRequestHeaderConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -431,6 +573,8 @@ pub mod request_header_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -440,10 +584,13 @@ pub struct RequestBodyMatchConditionParameters {
     pub operator: request_body_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod request_body_match_condition_parameters {
     use super::*;
@@ -451,6 +598,8 @@ pub mod request_body_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleRequestBodyConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleRequestBodyConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -463,6 +612,8 @@ pub mod request_body_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -472,8 +623,11 @@ pub struct RequestSchemeMatchConditionParameters {
     pub operator: request_scheme_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod request_scheme_match_condition_parameters {
     use super::*;
@@ -481,10 +635,14 @@ pub mod request_scheme_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleRequestSchemeConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleRequestSchemeConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
         Equal,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -494,10 +652,13 @@ pub struct UrlPathMatchConditionParameters {
     pub operator: url_path_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod url_path_match_condition_parameters {
     use super::*;
@@ -505,6 +666,8 @@ pub mod url_path_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleUrlPathMatchConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleUrlPathMatchConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -518,6 +681,8 @@ pub mod url_path_match_condition_parameters {
         GreaterThan,
         GreaterThanOrEqual,
         Wildcard,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -527,10 +692,13 @@ pub struct UrlFileExtensionMatchConditionParameters {
     pub operator: url_file_extension_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod url_file_extension_match_condition_parameters {
     use super::*;
@@ -538,6 +706,8 @@ pub mod url_file_extension_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleUrlFileExtensionMatchConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleUrlFileExtensionMatchConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -550,6 +720,8 @@ pub mod url_file_extension_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -559,10 +731,13 @@ pub struct UrlFileNameMatchConditionParameters {
     pub operator: url_file_name_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod url_file_name_match_condition_parameters {
     use super::*;
@@ -570,6 +745,8 @@ pub mod url_file_name_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleUrlFilenameConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleUrlFilenameConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -582,6 +759,8 @@ pub mod url_file_name_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -591,8 +770,11 @@ pub struct HttpVersionMatchConditionParameters {
     pub operator: http_version_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod http_version_match_condition_parameters {
     use super::*;
@@ -600,10 +782,14 @@ pub mod http_version_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleHttpVersionConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleHttpVersionConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
         Equal,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -615,10 +801,13 @@ pub struct CookiesMatchConditionParameters {
     pub operator: cookies_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cookies_match_condition_parameters {
     use super::*;
@@ -626,6 +815,8 @@ pub mod cookies_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleCookiesConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleCookiesConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -638,6 +829,8 @@ pub mod cookies_match_condition_parameters {
         LessThanOrEqual,
         GreaterThan,
         GreaterThanOrEqual,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -647,10 +840,13 @@ pub struct IsDeviceMatchConditionParameters {
     pub operator: is_device_match_condition_parameters::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValues", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "matchValues", default, skip_serializing_if = "Vec::is_empty")]
     pub match_values: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<Transform>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod is_device_match_condition_parameters {
     use super::*;
@@ -658,10 +854,14 @@ pub mod is_device_match_condition_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleIsDeviceConditionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleIsDeviceConditionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
         Equal,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -678,6 +878,8 @@ pub mod delivery_rule_action {
         ModifyResponseHeader,
         UrlRedirect,
         UrlRewrite,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -685,6 +887,9 @@ pub struct UrlRedirectAction {
     #[serde(flatten)]
     pub delivery_rule_action: DeliveryRuleAction,
     pub parameters: UrlRedirectActionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UrlRedirectActionParameters {
@@ -702,6 +907,9 @@ pub struct UrlRedirectActionParameters {
     pub custom_query_string: Option<String>,
     #[serde(rename = "customFragment", skip_serializing_if = "Option::is_none")]
     pub custom_fragment: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod url_redirect_action_parameters {
     use super::*;
@@ -709,6 +917,8 @@ pub mod url_redirect_action_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleUrlRedirectActionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleUrlRedirectActionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum RedirectType {
@@ -716,12 +926,16 @@ pub mod url_redirect_action_parameters {
         Found,
         TemporaryRedirect,
         PermanentRedirect,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum DestinationProtocol {
         MatchRequest,
         Http,
         Https,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -729,6 +943,9 @@ pub struct UrlRewriteAction {
     #[serde(flatten)]
     pub delivery_rule_action: DeliveryRuleAction,
     pub parameters: UrlRewriteActionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UrlRewriteActionParameters {
@@ -739,6 +956,9 @@ pub struct UrlRewriteActionParameters {
     pub destination: String,
     #[serde(rename = "preserveUnmatchedPath", skip_serializing_if = "Option::is_none")]
     pub preserve_unmatched_path: Option<bool>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod url_rewrite_action_parameters {
     use super::*;
@@ -746,6 +966,8 @@ pub mod url_rewrite_action_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleUrlRewriteActionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleUrlRewriteActionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -753,12 +975,18 @@ pub struct DeliveryRuleRequestHeaderAction {
     #[serde(flatten)]
     pub delivery_rule_action: DeliveryRuleAction,
     pub parameters: HeaderActionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeliveryRuleResponseHeaderAction {
     #[serde(flatten)]
     pub delivery_rule_action: DeliveryRuleAction,
     pub parameters: HeaderActionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HeaderActionParameters {
@@ -770,6 +998,9 @@ pub struct HeaderActionParameters {
     pub header_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod header_action_parameters {
     use super::*;
@@ -777,12 +1008,16 @@ pub mod header_action_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleHeaderActionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleHeaderActionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum HeaderAction {
         Append,
         Overwrite,
         Delete,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -790,6 +1025,9 @@ pub struct DeliveryRuleCacheExpirationAction {
     #[serde(flatten)]
     pub delivery_rule_action: DeliveryRuleAction,
     pub parameters: CacheExpirationActionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CacheExpirationActionParameters {
@@ -801,6 +1039,9 @@ pub struct CacheExpirationActionParameters {
     pub cache_type: cache_expiration_action_parameters::CacheType,
     #[serde(rename = "cacheDuration", skip_serializing_if = "Option::is_none")]
     pub cache_duration: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cache_expiration_action_parameters {
     use super::*;
@@ -808,16 +1049,22 @@ pub mod cache_expiration_action_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleCacheExpirationActionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleCacheExpirationActionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum CacheBehavior {
         BypassCache,
         Override,
         SetIfMissing,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum CacheType {
         All,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -825,6 +1072,9 @@ pub struct DeliveryRuleCacheKeyQueryStringAction {
     #[serde(flatten)]
     pub delivery_rule_action: DeliveryRuleAction,
     pub parameters: CacheKeyQueryStringActionParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CacheKeyQueryStringActionParameters {
@@ -834,6 +1084,9 @@ pub struct CacheKeyQueryStringActionParameters {
     pub query_string_behavior: cache_key_query_string_action_parameters::QueryStringBehavior,
     #[serde(rename = "queryParameters", skip_serializing_if = "Option::is_none")]
     pub query_parameters: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cache_key_query_string_action_parameters {
     use super::*;
@@ -841,6 +1094,8 @@ pub mod cache_key_query_string_action_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.DeliveryRuleCacheKeyQueryStringBehaviorActionParameters")]
         MicrosoftAzureCdnModelsDeliveryRuleCacheKeyQueryStringBehaviorActionParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum QueryStringBehavior {
@@ -848,18 +1103,25 @@ pub mod cache_key_query_string_action_parameters {
         IncludeAll,
         Exclude,
         ExcludeAll,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Transform {
     Lowercase,
     Uppercase,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeepCreatedOrigin {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<DeepCreatedOriginProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeepCreatedOriginProperties {
@@ -869,14 +1131,20 @@ pub struct DeepCreatedOriginProperties {
     pub http_port: Option<i64>,
     #[serde(rename = "httpsPort", skip_serializing_if = "Option::is_none")]
     pub https_port: Option<i64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GeoFilter {
     #[serde(rename = "relativePath")]
     pub relative_path: String,
     pub action: geo_filter::Action,
-    #[serde(rename = "countryCodes")]
+    #[serde(rename = "countryCodes", default)]
     pub country_codes: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod geo_filter {
     use super::*;
@@ -884,17 +1152,25 @@ pub mod geo_filter {
     pub enum Action {
         Block,
         Allow,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PurgeParameters {
-    #[serde(rename = "contentPaths")]
+    #[serde(rename = "contentPaths", default)]
     pub content_paths: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LoadParameters {
-    #[serde(rename = "contentPaths")]
+    #[serde(rename = "contentPaths", default)]
     pub content_paths: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Origin {
@@ -915,6 +1191,9 @@ pub struct OriginProperties {
     pub resource_state: Option<origin_properties::ResourceState>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod origin_properties {
     use super::*;
@@ -923,12 +1202,17 @@ pub mod origin_properties {
         Creating,
         Active,
         Deleting,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OriginUpdateParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<OriginPropertiesParameters>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OriginPropertiesParameters {
@@ -938,13 +1222,28 @@ pub struct OriginPropertiesParameters {
     pub http_port: Option<i64>,
     #[serde(rename = "httpsPort", skip_serializing_if = "Option::is_none")]
     pub https_port: Option<i64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OriginListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Origin>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OriginListResult {
+    type Item = Origin;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Origin> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomDomain {
@@ -969,6 +1268,9 @@ pub struct CustomDomainProperties {
     pub validation_data: Option<String>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod custom_domain_properties {
     use super::*;
@@ -977,6 +1279,8 @@ pub mod custom_domain_properties {
         Creating,
         Active,
         Deleting,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum CustomHttpsProvisioningState {
@@ -985,6 +1289,8 @@ pub mod custom_domain_properties {
         Disabling,
         Disabled,
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum CustomHttpsProvisioningSubstate {
@@ -998,17 +1304,25 @@ pub mod custom_domain_properties {
         CertificateDeployed,
         DeletingCertificate,
         CertificateDeleted,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomDomainParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<CustomDomainPropertiesParameters>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomDomainPropertiesParameters {
     #[serde(rename = "hostName")]
     pub host_name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomDomainHttpsParameters {
@@ -1025,12 +1339,16 @@ pub mod custom_domain_https_parameters {
     pub enum CertificateSource {
         AzureKeyVault,
         Cdn,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProtocolType {
         ServerNameIndication,
         #[serde(rename = "IPBased")]
         IpBased,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum MinimumTlsVersion {
@@ -1039,6 +1357,8 @@ pub mod custom_domain_https_parameters {
         Tls10,
         #[serde(rename = "TLS12")]
         Tls12,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -1047,6 +1367,9 @@ pub struct CdnManagedHttpsParameters {
     pub custom_domain_https_parameters: CustomDomainHttpsParameters,
     #[serde(rename = "certificateSourceParameters")]
     pub certificate_source_parameters: CdnCertificateSourceParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CdnCertificateSourceParameters {
@@ -1054,6 +1377,9 @@ pub struct CdnCertificateSourceParameters {
     pub odata_type: cdn_certificate_source_parameters::Odata_type,
     #[serde(rename = "certificateType")]
     pub certificate_type: cdn_certificate_source_parameters::CertificateType,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cdn_certificate_source_parameters {
     use super::*;
@@ -1061,11 +1387,15 @@ pub mod cdn_certificate_source_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.CdnCertificateSourceParameters")]
         MicrosoftAzureCdnModelsCdnCertificateSourceParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum CertificateType {
         Shared,
         Dedicated,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -1074,6 +1404,9 @@ pub struct UserManagedHttpsParameters {
     pub custom_domain_https_parameters: CustomDomainHttpsParameters,
     #[serde(rename = "certificateSourceParameters")]
     pub certificate_source_parameters: KeyVaultCertificateSourceParameters,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct KeyVaultCertificateSourceParameters {
@@ -1093,6 +1426,9 @@ pub struct KeyVaultCertificateSourceParameters {
     pub update_rule: key_vault_certificate_source_parameters::UpdateRule,
     #[serde(rename = "deleteRule")]
     pub delete_rule: key_vault_certificate_source_parameters::DeleteRule,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod key_vault_certificate_source_parameters {
     use super::*;
@@ -1100,27 +1436,48 @@ pub mod key_vault_certificate_source_parameters {
     pub enum Odata_type {
         #[serde(rename = "#Microsoft.Azure.Cdn.Models.KeyVaultCertificateSourceParameters")]
         MicrosoftAzureCdnModelsKeyVaultCertificateSourceParameters,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum UpdateRule {
         NoAction,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum DeleteRule {
         NoAction,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomDomainListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<CustomDomain>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for CustomDomainListResult {
+    type Item = CustomDomain;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<CustomDomain> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ValidateCustomDomainInput {
     #[serde(rename = "hostName")]
     pub host_name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ValidateCustomDomainOutput {
@@ -1130,12 +1487,18 @@ pub struct ValidateCustomDomainOutput {
     pub reason: Option<String>,
     #[serde(skip_serializing)]
     pub message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CheckNameAvailabilityInput {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: ResourceType,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CheckNameAvailabilityOutput {
@@ -1145,11 +1508,17 @@ pub struct CheckNameAvailabilityOutput {
     pub reason: Option<String>,
     #[serde(skip_serializing)]
     pub message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ValidateProbeInput {
     #[serde(rename = "probeURL")]
     pub probe_url: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ValidateProbeOutput {
@@ -1159,13 +1528,28 @@ pub struct ValidateProbeOutput {
     pub error_code: Option<String>,
     #[serde(skip_serializing)]
     pub message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceUsageListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<ResourceUsage>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ResourceUsageListResult {
+    type Item = ResourceUsage;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<ResourceUsage> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceUsage {
@@ -1177,11 +1561,16 @@ pub struct ResourceUsage {
     pub current_value: Option<i64>,
     #[serde(skip_serializing)]
     pub limit: Option<i64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ResourceType {
     #[serde(rename = "Microsoft.Cdn/Profiles/Endpoints")]
     MicrosoftCdnProfilesEndpoints,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
@@ -1189,6 +1578,9 @@ pub struct Operation {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display: Option<operation::Display>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod operation {
     use super::*;
@@ -1200,21 +1592,48 @@ pub mod operation {
         pub resource: Option<String>,
         #[serde(skip_serializing)]
         pub operation: Option<String>,
+        #[cfg(feature = "additional-properties")]
+        #[serde(flatten)]
+        pub additional_properties: serde_json::Map<String, serde_json::Value>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationsListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Operation>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for OperationsListResult {
+    type Item = Operation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Operation> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EdgenodeResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<EdgeNode>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for EdgenodeResult {
+    type Item = EdgeNode;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<EdgeNode> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EdgeNode {
@@ -1225,17 +1644,23 @@ pub struct EdgeNode {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EdgeNodeProperties {
-    #[serde(rename = "ipAddressGroups")]
+    #[serde(rename = "ipAddressGroups", default)]
     pub ip_address_groups: Vec<IpAddressGroup>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct IpAddressGroup {
     #[serde(rename = "deliveryRegion", skip_serializing_if = "Option::is_none")]
     pub delivery_region: Option<String>,
-    #[serde(rename = "ipv4Addresses", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "ipv4Addresses", default, skip_serializing_if = "Vec::is_empty")]
     pub ipv4_addresses: Vec<CidrIpAddress>,
-    #[serde(rename = "ipv6Addresses", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "ipv6Addresses", default, skip_serializing_if = "Vec::is_empty")]
     pub ipv6_addresses: Vec<CidrIpAddress>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CidrIpAddress {
@@ -1243,6 +1668,9 @@ pub struct CidrIpAddress {
     pub base_ip_address: Option<String>,
     #[serde(rename = "prefixLength", skip_serializing_if = "Option::is_none")]
     pub prefix_length: Option<i64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Resource {
@@ -1272,11 +1700,16 @@ pub enum QueryStringCachingBehavior {
     BypassCaching,
     UseQueryString,
     NotSet,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Sku {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<sku::Name>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod sku {
     use super::*;
@@ -1296,6 +1729,8 @@ pub mod sku {
         StandardMicrosoft,
         #[serde(rename = "Premium_ChinaCdn")]
         PremiumChinaCdn,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -1305,6 +1740,8 @@ pub enum OptimizationType {
     VideoOnDemandMediaStreaming,
     LargeFileDownload,
     DynamicSiteAcceleration,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorResponse {
@@ -1312,13 +1749,28 @@ pub struct ErrorResponse {
     pub code: Option<String>,
     #[serde(skip_serializing)]
     pub message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CdnWebApplicationFirewallPolicyList {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<CdnWebApplicationFirewallPolicy>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for CdnWebApplicationFirewallPolicyList {
+    type Item = CdnWebApplicationFirewallPolicy;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<CdnWebApplicationFirewallPolicy> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CdnWebApplicationFirewallPolicy {
@@ -1334,6 +1786,9 @@ pub struct CdnWebApplicationFirewallPolicy {
 pub struct CdnWebApplicationFirewallPolicyPatchParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CdnWebApplicationFirewallPolicyProperties {
@@ -1345,12 +1800,15 @@ pub struct CdnWebApplicationFirewallPolicyProperties {
     pub custom_rules: Option<CustomRuleList>,
     #[serde(rename = "managedRules", skip_serializing_if = "Option::is_none")]
     pub managed_rules: Option<ManagedRuleSetList>,
-    #[serde(rename = "endpointLinks", skip_serializing)]
+    #[serde(rename = "endpointLinks", default, skip_serializing)]
     pub endpoint_links: Vec<CdnEndpoint>,
     #[serde(rename = "provisioningState", skip_serializing)]
     pub provisioning_state: Option<cdn_web_application_firewall_policy_properties::ProvisioningState>,
     #[serde(rename = "resourceState", skip_serializing)]
     pub resource_state: Option<cdn_web_application_firewall_policy_properties::ResourceState>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod cdn_web_application_firewall_policy_properties {
     use super::*;
@@ -1359,6 +1817,8 @@ pub mod cdn_web_application_firewall_policy_properties {
         Creating,
         Succeeded,
         Failed,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ResourceState {
@@ -1368,6 +1828,8 @@ pub mod cdn_web_application_firewall_policy_properties {
         Disabling,
         Disabled,
         Deleting,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -1382,6 +1844,9 @@ pub struct PolicySettings {
     pub default_custom_block_response_status_code: Option<policy_settings::DefaultCustomBlockResponseStatusCode>,
     #[serde(rename = "defaultCustomBlockResponseBody", skip_serializing_if = "Option::is_none")]
     pub default_custom_block_response_body: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod policy_settings {
     use super::*;
@@ -1389,11 +1854,15 @@ pub mod policy_settings {
     pub enum EnabledState {
         Disabled,
         Enabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Mode {
         Prevention,
         Detection,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum DefaultCustomBlockResponseStatusCode {}
@@ -1402,11 +1871,17 @@ pub mod policy_settings {
 pub struct CdnEndpoint {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomRuleList {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub rules: Vec<CustomRule>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CustomRule {
@@ -1414,7 +1889,7 @@ pub struct CustomRule {
     #[serde(rename = "enabledState", skip_serializing_if = "Option::is_none")]
     pub enabled_state: Option<custom_rule::EnabledState>,
     pub priority: i64,
-    #[serde(rename = "matchConditions")]
+    #[serde(rename = "matchConditions", default)]
     pub match_conditions: Vec<MatchCondition>,
     pub action: ActionType,
 }
@@ -1424,12 +1899,17 @@ pub mod custom_rule {
     pub enum EnabledState {
         Disabled,
         Enabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RateLimitRuleList {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub rules: Vec<RateLimitRule>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RateLimitRule {
@@ -1439,6 +1919,9 @@ pub struct RateLimitRule {
     pub rate_limit_threshold: i64,
     #[serde(rename = "rateLimitDurationInMinutes")]
     pub rate_limit_duration_in_minutes: i64,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MatchCondition {
@@ -1449,10 +1932,13 @@ pub struct MatchCondition {
     pub operator: match_condition::Operator,
     #[serde(rename = "negateCondition", skip_serializing_if = "Option::is_none")]
     pub negate_condition: Option<bool>,
-    #[serde(rename = "matchValue")]
+    #[serde(rename = "matchValue", default)]
     pub match_value: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<TransformType>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod match_condition {
     use super::*;
@@ -1467,6 +1953,8 @@ pub mod match_condition {
         RequestBody,
         Cookies,
         PostArgs,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Operator {
@@ -1483,6 +1971,8 @@ pub mod match_condition {
         BeginsWith,
         EndsWith,
         RegEx,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -1493,11 +1983,16 @@ pub enum TransformType {
     UrlDecode,
     UrlEncode,
     RemoveNulls,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManagedRuleSetList {
-    #[serde(rename = "managedRuleSets", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "managedRuleSets", default, skip_serializing_if = "Vec::is_empty")]
     pub managed_rule_sets: Vec<ManagedRuleSet>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManagedRuleSet {
@@ -1507,15 +2002,21 @@ pub struct ManagedRuleSet {
     pub rule_set_version: String,
     #[serde(rename = "anomalyScore", skip_serializing_if = "Option::is_none")]
     pub anomaly_score: Option<i64>,
-    #[serde(rename = "ruleGroupOverrides", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "ruleGroupOverrides", default, skip_serializing_if = "Vec::is_empty")]
     pub rule_group_overrides: Vec<ManagedRuleGroupOverride>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManagedRuleGroupOverride {
     #[serde(rename = "ruleGroupName")]
     pub rule_group_name: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub rules: Vec<ManagedRuleOverride>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManagedRuleOverride {
@@ -1525,6 +2026,9 @@ pub struct ManagedRuleOverride {
     pub enabled_state: Option<managed_rule_override::EnabledState>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub action: Option<ActionType>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod managed_rule_override {
     use super::*;
@@ -1532,14 +2036,28 @@ pub mod managed_rule_override {
     pub enum EnabledState {
         Disabled,
         Enabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManagedRuleSetDefinitionList {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<ManagedRuleSetDefinition>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for ManagedRuleSetDefinitionList {
+    type Item = ManagedRuleSetDefinition;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<ManagedRuleSetDefinition> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManagedRuleSetDefinition {
@@ -1549,6 +2067,9 @@ pub struct ManagedRuleSetDefinition {
     pub properties: Option<ManagedRuleSetDefinitionProperties>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sku: Option<Sku>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManagedRuleSetDefinitionProperties {
@@ -1558,8 +2079,11 @@ pub struct ManagedRuleSetDefinitionProperties {
     pub rule_set_type: Option<String>,
     #[serde(rename = "ruleSetVersion", skip_serializing)]
     pub rule_set_version: Option<String>,
-    #[serde(rename = "ruleGroups", skip_serializing)]
+    #[serde(rename = "ruleGroups", default, skip_serializing)]
     pub rule_groups: Vec<ManagedRuleGroupDefinition>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManagedRuleGroupDefinition {
@@ -1567,8 +2091,11 @@ pub struct ManagedRuleGroupDefinition {
     pub rule_group_name: Option<String>,
     #[serde(skip_serializing)]
     pub description: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub rules: Vec<ManagedRuleDefinition>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManagedRuleDefinition {
@@ -1576,6 +2103,9 @@ pub struct ManagedRuleDefinition {
     pub rule_id: Option<String>,
     #[serde(skip_serializing)]
     pub description: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ActionType {
@@ -1583,4 +2113,6 @@ pub enum ActionType {
     Block,
     Log,
     Redirect,
+    #[serde(untagged)]
+    UnknownValue(String),
 }
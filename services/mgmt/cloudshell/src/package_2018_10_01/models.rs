@@ -7,6 +7,9 @@ pub struct CloudShellConsole {
     #[serde(flatten)]
     pub resource: Resource,
     pub properties: ConsoleProperties,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConsoleProperties {
@@ -15,6 +18,9 @@ pub struct ConsoleProperties {
     #[serde(rename = "provisioningState")]
     pub provisioning_state: console_properties::ProvisioningState,
     pub uri: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod console_properties {
     use super::*;
@@ -22,6 +28,8 @@ pub mod console_properties {
     pub enum OsType {
         Linux,
         Windows,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProvisioningState {
@@ -34,6 +42,8 @@ pub mod console_properties {
         Failed,
         Canceled,
         Succeeded,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -44,6 +54,9 @@ pub struct ConsoleCreateProperties {
     pub provisioning_state: Option<console_create_properties::ProvisioningState>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub uri: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod console_create_properties {
     use super::*;
@@ -51,6 +64,8 @@ pub mod console_create_properties {
     pub enum OsType {
         Linux,
         Windows,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProvisioningState {
@@ -63,6 +78,8 @@ pub mod console_create_properties {
         Failed,
         Canceled,
         Succeeded,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -70,6 +87,9 @@ pub struct ConsoleDefinition {
     #[serde(flatten)]
     pub resource: Resource,
     pub properties: ConsoleCreateProperties,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Resource {}
@@ -78,17 +98,26 @@ pub struct UserSettingsResponse {
     #[serde(flatten)]
     pub resource: Resource,
     pub properties: UserProperties,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudShellUserSettings {
     #[serde(flatten)]
     pub resource: Resource,
     pub properties: UserProperties,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudShellPatchUserSettings {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<UserProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UserProperties {
@@ -102,6 +131,9 @@ pub struct UserProperties {
     pub terminal_settings: TerminalSettings,
     #[serde(rename = "preferredShellType")]
     pub preferred_shell_type: user_properties::PreferredShellType,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod user_properties {
     use super::*;
@@ -109,6 +141,8 @@ pub mod user_properties {
     pub enum PreferredOsType {
         Windows,
         Linux,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum PreferredShellType {
@@ -118,6 +152,8 @@ pub mod user_properties {
         Pwsh,
         #[serde(rename = "powershell")]
         Powershell,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -128,6 +164,9 @@ pub struct StorageProfile {
     pub file_share_name: Option<String>,
     #[serde(rename = "diskSizeInGB", skip_serializing_if = "Option::is_none")]
     pub disk_size_in_gb: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TerminalSettings {
@@ -135,6 +174,9 @@ pub struct TerminalSettings {
     pub font_size: Option<terminal_settings::FontSize>,
     #[serde(rename = "fontStyle", skip_serializing_if = "Option::is_none")]
     pub font_style: Option<terminal_settings::FontStyle>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod terminal_settings {
     use super::*;
@@ -144,20 +186,30 @@ pub mod terminal_settings {
         Small,
         Medium,
         Large,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum FontStyle {
         NotSpecified,
         Monospace,
         Courier,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorResponse {
     pub error: ErrorDetail,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorDetail {
     pub code: String,
     pub message: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
@@ -2,80 +2,28 @@
 #![allow(unused_mut)]
 #![allow(unused_variables)]
 #![allow(unused_imports)]
-use crate::models::*;
+use crate::package_2018_10_01::models::*;
 use reqwest::StatusCode;
-use snafu::{ResultExt, Snafu};
 pub async fn get_user_settings_with_location(
     operation_config: &crate::OperationConfig,
     user_settings_name: &str,
     location: &str,
 ) -> std::result::Result<UserSettingsResponse, get_user_settings_with_location::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/locations/{}/userSettings/{}",
         &operation_config.base_path, location, user_settings_name
     );
-    let mut req_builder = client.get(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(get_user_settings_with_location::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-    let req = req_builder.build().context(get_user_settings_with_location::BuildRequestError)?;
-    let rsp = client
-        .execute(req)
-        .await
-        .context(get_user_settings_with_location::ExecuteRequestError)?;
+    let mut req_builder = operation_config.client.get(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
-        StatusCode::OK => {
-            let body: bytes::Bytes = rsp.bytes().await.context(get_user_settings_with_location::ResponseBytesError)?;
-            let rsp_value: UserSettingsResponse =
-                serde_json::from_slice(&body).context(get_user_settings_with_location::DeserializeError { body })?;
-            Ok(rsp_value)
-        }
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(get_user_settings_with_location::ResponseBytesError)?;
-            let rsp_value: ErrorResponse =
-                serde_json::from_slice(&body).context(get_user_settings_with_location::DeserializeError { body })?;
-            get_user_settings_with_location::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        StatusCode::OK => Ok(rsp.json().await?),
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod get_user_settings_with_location {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    use crate::package_2018_10_01::{models, models::*};
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn put_user_settings_with_location(
     operation_config: &crate::OperationConfig,
@@ -83,73 +31,22 @@ pub async fn put_user_settings_with_location(
     location: &str,
     parameters: &CloudShellUserSettings,
 ) -> std::result::Result<UserSettingsResponse, put_user_settings_with_location::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/locations/{}/userSettings/{}",
         &operation_config.base_path, location, user_settings_name
     );
-    let mut req_builder = client.put(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(put_user_settings_with_location::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+    let mut req_builder = operation_config.client.put(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
     req_builder = req_builder.json(parameters);
-    let req = req_builder.build().context(put_user_settings_with_location::BuildRequestError)?;
-    let rsp = client
-        .execute(req)
-        .await
-        .context(put_user_settings_with_location::ExecuteRequestError)?;
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
-        StatusCode::OK => {
-            let body: bytes::Bytes = rsp.bytes().await.context(put_user_settings_with_location::ResponseBytesError)?;
-            let rsp_value: UserSettingsResponse =
-                serde_json::from_slice(&body).context(put_user_settings_with_location::DeserializeError { body })?;
-            Ok(rsp_value)
-        }
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(put_user_settings_with_location::ResponseBytesError)?;
-            let rsp_value: ErrorResponse =
-                serde_json::from_slice(&body).context(put_user_settings_with_location::DeserializeError { body })?;
-            put_user_settings_with_location::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        StatusCode::OK => Ok(rsp.json().await?),
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod put_user_settings_with_location {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    use crate::package_2018_10_01::{models, models::*};
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn patch_user_settings_with_location(
     operation_config: &crate::OperationConfig,
@@ -157,968 +54,324 @@ pub async fn patch_user_settings_with_location(
     location: &str,
     parameters: &CloudShellPatchUserSettings,
 ) -> std::result::Result<UserSettingsResponse, patch_user_settings_with_location::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/locations/{}/userSettings/{}",
         &operation_config.base_path, location, user_settings_name
     );
-    let mut req_builder = client.patch(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(patch_user_settings_with_location::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+    let mut req_builder = operation_config.client.patch(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
     req_builder = req_builder.json(parameters);
-    let req = req_builder.build().context(patch_user_settings_with_location::BuildRequestError)?;
-    let rsp = client
-        .execute(req)
-        .await
-        .context(patch_user_settings_with_location::ExecuteRequestError)?;
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
-        StatusCode::OK => {
-            let body: bytes::Bytes = rsp.bytes().await.context(patch_user_settings_with_location::ResponseBytesError)?;
-            let rsp_value: UserSettingsResponse =
-                serde_json::from_slice(&body).context(patch_user_settings_with_location::DeserializeError { body })?;
-            Ok(rsp_value)
-        }
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(patch_user_settings_with_location::ResponseBytesError)?;
-            let rsp_value: ErrorResponse =
-                serde_json::from_slice(&body).context(patch_user_settings_with_location::DeserializeError { body })?;
-            patch_user_settings_with_location::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        StatusCode::OK => Ok(rsp.json().await?),
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod patch_user_settings_with_location {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    use crate::package_2018_10_01::{models, models::*};
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn delete_user_settings_with_location(
     operation_config: &crate::OperationConfig,
     user_settings_name: &str,
     location: &str,
 ) -> std::result::Result<delete_user_settings_with_location::Response, delete_user_settings_with_location::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/locations/{}/userSettings/{}",
         &operation_config.base_path, location, user_settings_name
     );
-    let mut req_builder = client.delete(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(delete_user_settings_with_location::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-    let req = req_builder.build().context(delete_user_settings_with_location::BuildRequestError)?;
-    let rsp = client
-        .execute(req)
-        .await
-        .context(delete_user_settings_with_location::ExecuteRequestError)?;
+    let mut req_builder = operation_config.client.delete(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
         StatusCode::OK => Ok(delete_user_settings_with_location::Response::Ok200),
         StatusCode::NO_CONTENT => Ok(delete_user_settings_with_location::Response::NoContent204),
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(delete_user_settings_with_location::ResponseBytesError)?;
-            let rsp_value: ErrorResponse =
-                serde_json::from_slice(&body).context(delete_user_settings_with_location::DeserializeError { body })?;
-            delete_user_settings_with_location::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod delete_user_settings_with_location {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
+    use crate::package_2018_10_01::{models, models::*};
     #[derive(Debug)]
     pub enum Response {
         Ok200,
         NoContent204,
     }
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn get_console_with_location(
     operation_config: &crate::OperationConfig,
     console_name: &str,
     location: &str,
 ) -> std::result::Result<CloudShellConsole, get_console_with_location::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/locations/{}/consoles/{}",
         &operation_config.base_path, location, console_name
     );
-    let mut req_builder = client.get(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(get_console_with_location::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-    let req = req_builder.build().context(get_console_with_location::BuildRequestError)?;
-    let rsp = client.execute(req).await.context(get_console_with_location::ExecuteRequestError)?;
+    let mut req_builder = operation_config.client.get(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
-        StatusCode::OK => {
-            let body: bytes::Bytes = rsp.bytes().await.context(get_console_with_location::ResponseBytesError)?;
-            let rsp_value: CloudShellConsole =
-                serde_json::from_slice(&body).context(get_console_with_location::DeserializeError { body })?;
-            Ok(rsp_value)
-        }
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(get_console_with_location::ResponseBytesError)?;
-            let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(get_console_with_location::DeserializeError { body })?;
-            get_console_with_location::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        StatusCode::OK => Ok(rsp.json().await?),
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod get_console_with_location {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    use crate::package_2018_10_01::{models, models::*};
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn put_console_with_location(
     operation_config: &crate::OperationConfig,
     console_name: &str,
     location: &str,
 ) -> std::result::Result<put_console_with_location::Response, put_console_with_location::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/locations/{}/consoles/{}",
         &operation_config.base_path, location, console_name
     );
-    let mut req_builder = client.put(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(put_console_with_location::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-    let req = req_builder.build().context(put_console_with_location::BuildRequestError)?;
-    let rsp = client.execute(req).await.context(put_console_with_location::ExecuteRequestError)?;
+    let mut req_builder = operation_config.client.put(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
-        StatusCode::OK => {
-            let body: bytes::Bytes = rsp.bytes().await.context(put_console_with_location::ResponseBytesError)?;
-            let rsp_value: CloudShellConsole =
-                serde_json::from_slice(&body).context(put_console_with_location::DeserializeError { body })?;
-            Ok(put_console_with_location::Response::Ok200(rsp_value))
-        }
-        StatusCode::CREATED => {
-            let body: bytes::Bytes = rsp.bytes().await.context(put_console_with_location::ResponseBytesError)?;
-            let rsp_value: CloudShellConsole =
-                serde_json::from_slice(&body).context(put_console_with_location::DeserializeError { body })?;
-            Ok(put_console_with_location::Response::Created201(rsp_value))
-        }
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(put_console_with_location::ResponseBytesError)?;
-            let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(put_console_with_location::DeserializeError { body })?;
-            put_console_with_location::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        StatusCode::OK => Ok(put_console_with_location::Response::Ok200(rsp.json().await?)),
+        StatusCode::CREATED => Ok(put_console_with_location::Response::Created201(rsp.json().await?)),
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod put_console_with_location {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
+    use crate::package_2018_10_01::{models, models::*};
     #[derive(Debug)]
     pub enum Response {
         Ok200(CloudShellConsole),
         Created201(CloudShellConsole),
     }
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn delete_console_with_location(
     operation_config: &crate::OperationConfig,
     console_name: &str,
     location: &str,
 ) -> std::result::Result<delete_console_with_location::Response, delete_console_with_location::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/locations/{}/consoles/{}",
         &operation_config.base_path, location, console_name
     );
-    let mut req_builder = client.delete(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(delete_console_with_location::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-    let req = req_builder.build().context(delete_console_with_location::BuildRequestError)?;
-    let rsp = client
-        .execute(req)
-        .await
-        .context(delete_console_with_location::ExecuteRequestError)?;
+    let mut req_builder = operation_config.client.delete(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
         StatusCode::OK => Ok(delete_console_with_location::Response::Ok200),
         StatusCode::NO_CONTENT => Ok(delete_console_with_location::Response::NoContent204),
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(delete_console_with_location::ResponseBytesError)?;
-            let rsp_value: ErrorResponse =
-                serde_json::from_slice(&body).context(delete_console_with_location::DeserializeError { body })?;
-            delete_console_with_location::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod delete_console_with_location {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
+    use crate::package_2018_10_01::{models, models::*};
     #[derive(Debug)]
     pub enum Response {
         Ok200,
         NoContent204,
     }
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn keep_alive_with_location(
     operation_config: &crate::OperationConfig,
     console_name: &str,
     location: &str,
 ) -> std::result::Result<(), keep_alive_with_location::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/locations/{}/consoles/{}/keepAlive",
         &operation_config.base_path, location, console_name
     );
-    let mut req_builder = client.post(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(keep_alive_with_location::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
+    let mut req_builder = operation_config.client.post(uri_str);
     req_builder = req_builder.header(reqwest::header::CONTENT_LENGTH, 0);
-    let req = req_builder.build().context(keep_alive_with_location::BuildRequestError)?;
-    let rsp = client.execute(req).await.context(keep_alive_with_location::ExecuteRequestError)?;
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
         StatusCode::OK => Ok(()),
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(keep_alive_with_location::ResponseBytesError)?;
-            let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(keep_alive_with_location::DeserializeError { body })?;
-            keep_alive_with_location::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod keep_alive_with_location {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    use crate::package_2018_10_01::{models, models::*};
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn get_user_settings(
     operation_config: &crate::OperationConfig,
     user_settings_name: &str,
 ) -> std::result::Result<UserSettingsResponse, get_user_settings::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/userSettings/{}",
         &operation_config.base_path, user_settings_name
     );
-    let mut req_builder = client.get(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(get_user_settings::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-    let req = req_builder.build().context(get_user_settings::BuildRequestError)?;
-    let rsp = client.execute(req).await.context(get_user_settings::ExecuteRequestError)?;
+    let mut req_builder = operation_config.client.get(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
-        StatusCode::OK => {
-            let body: bytes::Bytes = rsp.bytes().await.context(get_user_settings::ResponseBytesError)?;
-            let rsp_value: UserSettingsResponse = serde_json::from_slice(&body).context(get_user_settings::DeserializeError { body })?;
-            Ok(rsp_value)
-        }
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(get_user_settings::ResponseBytesError)?;
-            let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(get_user_settings::DeserializeError { body })?;
-            get_user_settings::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        StatusCode::OK => Ok(rsp.json().await?),
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod get_user_settings {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    use crate::package_2018_10_01::{models, models::*};
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn put_user_settings(
     operation_config: &crate::OperationConfig,
     user_settings_name: &str,
     parameters: &CloudShellUserSettings,
 ) -> std::result::Result<UserSettingsResponse, put_user_settings::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/userSettings/{}",
         &operation_config.base_path, user_settings_name
     );
-    let mut req_builder = client.put(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(put_user_settings::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+    let mut req_builder = operation_config.client.put(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
     req_builder = req_builder.json(parameters);
-    let req = req_builder.build().context(put_user_settings::BuildRequestError)?;
-    let rsp = client.execute(req).await.context(put_user_settings::ExecuteRequestError)?;
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
-        StatusCode::OK => {
-            let body: bytes::Bytes = rsp.bytes().await.context(put_user_settings::ResponseBytesError)?;
-            let rsp_value: UserSettingsResponse = serde_json::from_slice(&body).context(put_user_settings::DeserializeError { body })?;
-            Ok(rsp_value)
-        }
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(put_user_settings::ResponseBytesError)?;
-            let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(put_user_settings::DeserializeError { body })?;
-            put_user_settings::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        StatusCode::OK => Ok(rsp.json().await?),
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod put_user_settings {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    use crate::package_2018_10_01::{models, models::*};
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn patch_user_settings(
     operation_config: &crate::OperationConfig,
     user_settings_name: &str,
     parameters: &CloudShellPatchUserSettings,
 ) -> std::result::Result<UserSettingsResponse, patch_user_settings::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/userSettings/{}",
         &operation_config.base_path, user_settings_name
     );
-    let mut req_builder = client.patch(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(patch_user_settings::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+    let mut req_builder = operation_config.client.patch(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
     req_builder = req_builder.json(parameters);
-    let req = req_builder.build().context(patch_user_settings::BuildRequestError)?;
-    let rsp = client.execute(req).await.context(patch_user_settings::ExecuteRequestError)?;
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
-        StatusCode::OK => {
-            let body: bytes::Bytes = rsp.bytes().await.context(patch_user_settings::ResponseBytesError)?;
-            let rsp_value: UserSettingsResponse = serde_json::from_slice(&body).context(patch_user_settings::DeserializeError { body })?;
-            Ok(rsp_value)
-        }
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(patch_user_settings::ResponseBytesError)?;
-            let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(patch_user_settings::DeserializeError { body })?;
-            patch_user_settings::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        StatusCode::OK => Ok(rsp.json().await?),
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod patch_user_settings {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    use crate::package_2018_10_01::{models, models::*};
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn delete_user_settings(
     operation_config: &crate::OperationConfig,
     user_settings_name: &str,
 ) -> std::result::Result<delete_user_settings::Response, delete_user_settings::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/userSettings/{}",
         &operation_config.base_path, user_settings_name
     );
-    let mut req_builder = client.delete(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(delete_user_settings::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-    let req = req_builder.build().context(delete_user_settings::BuildRequestError)?;
-    let rsp = client.execute(req).await.context(delete_user_settings::ExecuteRequestError)?;
+    let mut req_builder = operation_config.client.delete(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
         StatusCode::OK => Ok(delete_user_settings::Response::Ok200),
         StatusCode::NO_CONTENT => Ok(delete_user_settings::Response::NoContent204),
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(delete_user_settings::ResponseBytesError)?;
-            let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(delete_user_settings::DeserializeError { body })?;
-            delete_user_settings::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod delete_user_settings {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
+    use crate::package_2018_10_01::{models, models::*};
     #[derive(Debug)]
     pub enum Response {
         Ok200,
         NoContent204,
     }
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn get_console(
     operation_config: &crate::OperationConfig,
     console_name: &str,
 ) -> std::result::Result<CloudShellConsole, get_console::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/consoles/{}",
         &operation_config.base_path, console_name
     );
-    let mut req_builder = client.get(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(get_console::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-    let req = req_builder.build().context(get_console::BuildRequestError)?;
-    let rsp = client.execute(req).await.context(get_console::ExecuteRequestError)?;
+    let mut req_builder = operation_config.client.get(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
-        StatusCode::OK => {
-            let body: bytes::Bytes = rsp.bytes().await.context(get_console::ResponseBytesError)?;
-            let rsp_value: CloudShellConsole = serde_json::from_slice(&body).context(get_console::DeserializeError { body })?;
-            Ok(rsp_value)
-        }
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(get_console::ResponseBytesError)?;
-            let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(get_console::DeserializeError { body })?;
-            get_console::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        StatusCode::OK => Ok(rsp.json().await?),
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod get_console {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    use crate::package_2018_10_01::{models, models::*};
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn put_console(
     operation_config: &crate::OperationConfig,
     console_name: &str,
     parameters: &ConsoleDefinition,
 ) -> std::result::Result<put_console::Response, put_console::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/consoles/{}",
         &operation_config.base_path, console_name
     );
-    let mut req_builder = client.put(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(put_console::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
+    let mut req_builder = operation_config.client.put(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
     req_builder = req_builder.json(parameters);
-    let req = req_builder.build().context(put_console::BuildRequestError)?;
-    let rsp = client.execute(req).await.context(put_console::ExecuteRequestError)?;
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
-        StatusCode::OK => {
-            let body: bytes::Bytes = rsp.bytes().await.context(put_console::ResponseBytesError)?;
-            let rsp_value: CloudShellConsole = serde_json::from_slice(&body).context(put_console::DeserializeError { body })?;
-            Ok(put_console::Response::Ok200(rsp_value))
-        }
-        StatusCode::CREATED => {
-            let body: bytes::Bytes = rsp.bytes().await.context(put_console::ResponseBytesError)?;
-            let rsp_value: CloudShellConsole = serde_json::from_slice(&body).context(put_console::DeserializeError { body })?;
-            Ok(put_console::Response::Created201(rsp_value))
-        }
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(put_console::ResponseBytesError)?;
-            let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(put_console::DeserializeError { body })?;
-            put_console::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        StatusCode::OK => Ok(put_console::Response::Ok200(rsp.json().await?)),
+        StatusCode::CREATED => Ok(put_console::Response::Created201(rsp.json().await?)),
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod put_console {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
+    use crate::package_2018_10_01::{models, models::*};
     #[derive(Debug)]
     pub enum Response {
         Ok200(CloudShellConsole),
         Created201(CloudShellConsole),
     }
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn delete_console(
     operation_config: &crate::OperationConfig,
     console_name: &str,
 ) -> std::result::Result<delete_console::Response, delete_console::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/consoles/{}",
         &operation_config.base_path, console_name
     );
-    let mut req_builder = client.delete(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(delete_console::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
-    req_builder = req_builder.query(&[("api-version", &operation_config.api_version)]);
-    let req = req_builder.build().context(delete_console::BuildRequestError)?;
-    let rsp = client.execute(req).await.context(delete_console::ExecuteRequestError)?;
+    let mut req_builder = operation_config.client.delete(uri_str);
+    req_builder = req_builder.query(&[("api-version", crate::package_2018_10_01::API_VERSION)]);
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
         StatusCode::OK => Ok(delete_console::Response::Ok200),
         StatusCode::NO_CONTENT => Ok(delete_console::Response::NoContent204),
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(delete_console::ResponseBytesError)?;
-            let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(delete_console::DeserializeError { body })?;
-            delete_console::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod delete_console {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
+    use crate::package_2018_10_01::{models, models::*};
     #[derive(Debug)]
     pub enum Response {
         Ok200,
         NoContent204,
     }
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
 pub async fn keep_alive(operation_config: &crate::OperationConfig, console_name: &str) -> std::result::Result<(), keep_alive::Error> {
-    let client = &operation_config.client;
     let uri_str = &format!(
         "{}/providers/Microsoft.Portal/consoles/{}/keepAlive",
         &operation_config.base_path, console_name
     );
-    let mut req_builder = client.post(uri_str);
-    if let Some(token_credential) = &operation_config.token_credential {
-        let token_response = token_credential
-            .get_token(&operation_config.token_credential_resource)
-            .await
-            .context(keep_alive::GetTokenError)?;
-        req_builder = req_builder.bearer_auth(token_response.token.secret());
-    }
+    let mut req_builder = operation_config.client.post(uri_str);
     req_builder = req_builder.header(reqwest::header::CONTENT_LENGTH, 0);
-    let req = req_builder.build().context(keep_alive::BuildRequestError)?;
-    let rsp = client.execute(req).await.context(keep_alive::ExecuteRequestError)?;
+    let rsp = azure_mgmt_core::send(operation_config, req_builder).await?;
     match rsp.status() {
         StatusCode::OK => Ok(()),
-        status_code => {
-            let body: bytes::Bytes = rsp.bytes().await.context(keep_alive::ResponseBytesError)?;
-            let rsp_value: ErrorResponse = serde_json::from_slice(&body).context(keep_alive::DeserializeError { body })?;
-            keep_alive::DefaultResponse {
-                status_code,
-                value: rsp_value,
-            }
-            .fail()
-        }
+        _ => Err(rsp.default_response().await),
     }
 }
 pub mod keep_alive {
-    use crate::{models, models::*};
-    use reqwest::StatusCode;
-    use snafu::Snafu;
-    #[derive(Debug, Snafu)]
-    #[snafu(visibility(pub(crate)))]
-    pub enum Error {
-        DefaultResponse {
-            status_code: StatusCode,
-            value: models::ErrorResponse,
-        },
-        BuildRequestError {
-            source: reqwest::Error,
-        },
-        ExecuteRequestError {
-            source: reqwest::Error,
-        },
-        ResponseBytesError {
-            source: reqwest::Error,
-        },
-        DeserializeError {
-            source: serde_json::Error,
-            body: bytes::Bytes,
-        },
-        GetTokenError {
-            source: azure_core::errors::AzureError,
-        },
-    }
+    use crate::package_2018_10_01::{models, models::*};
+    pub type Error = azure_mgmt_core::Error<models::ErrorResponse>;
 }
@@ -1,35 +1,12 @@
 #![doc = "generated by AutoRust 0.1.0"]
 #[cfg(feature = "package-2015-06-preview")]
-mod package_2015_06_preview;
+pub mod package_2015_06_preview;
 #[cfg(feature = "package-2015-06-preview")]
-pub use package_2015_06_preview::{models, operations, API_VERSION};
+pub use package_2015_06_preview as v2015_06_preview;
 #[cfg(feature = "profile-hybrid-2020-09-01")]
-mod profile_hybrid_2020_09_01;
+pub mod profile_hybrid_2020_09_01;
+pub use azure_mgmt_core::OperationConfig;
+#[cfg(feature = "profile-hybrid-2020-09-01")]
+pub use profile_hybrid_2020_09_01 as profile_hybrid_v2020_09_01;
 #[cfg(feature = "profile-hybrid-2020-09-01")]
 pub use profile_hybrid_2020_09_01::{models, operations, API_VERSION};
-pub struct OperationConfig {
-    pub api_version: String,
-    pub client: reqwest::Client,
-    pub base_path: String,
-    pub token_credential: Option<Box<dyn azure_core::TokenCredential>>,
-    pub token_credential_resource: String,
-}
-impl OperationConfig {
-    pub fn new(token_credential: Box<dyn azure_core::TokenCredential>) -> Self {
-        Self {
-            token_credential: Some(token_credential),
-            ..Default::default()
-        }
-    }
-}
-impl Default for OperationConfig {
-    fn default() -> Self {
-        Self {
-            api_version: API_VERSION.to_owned(),
-            client: reqwest::Client::new(),
-            base_path: "https://management.azure.com".to_owned(),
-            token_credential: None,
-            token_credential_resource: "https://management.azure.com/".to_owned(),
-        }
-    }
-}
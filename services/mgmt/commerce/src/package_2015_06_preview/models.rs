@@ -6,6 +6,9 @@ use serde::{Deserialize, Serialize};
 pub struct InfoField {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UsageSample {
@@ -33,6 +36,9 @@ pub struct UsageSample {
     pub info_fields: Option<InfoField>,
     #[serde(rename = "instanceData", skip_serializing_if = "Option::is_none")]
     pub instance_data: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UsageAggregation {
@@ -44,13 +50,28 @@ pub struct UsageAggregation {
     pub type_: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<UsageSample>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UsageAggregationListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<UsageAggregation>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for UsageAggregationListResult {
+    type Item = UsageAggregation;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<UsageAggregation> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RateCardQueryParameters {
@@ -62,6 +83,9 @@ pub struct RateCardQueryParameters {
     pub locale: String,
     #[serde(rename = "RegionInfo")]
     pub region_info: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceRateCardInfo {
@@ -71,10 +95,13 @@ pub struct ResourceRateCardInfo {
     pub locale: Option<String>,
     #[serde(rename = "IsTaxIncluded", skip_serializing_if = "Option::is_none")]
     pub is_tax_included: Option<bool>,
-    #[serde(rename = "OfferTerms", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "OfferTerms", default, skip_serializing_if = "Vec::is_empty")]
     pub offer_terms: Vec<OfferTermInfo>,
-    #[serde(rename = "Meters", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "Meters", default, skip_serializing_if = "Vec::is_empty")]
     pub meters: Vec<MeterInfo>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MeterInfo {
@@ -88,7 +115,7 @@ pub struct MeterInfo {
     pub meter_sub_category: Option<String>,
     #[serde(rename = "Unit", skip_serializing_if = "Option::is_none")]
     pub unit: Option<String>,
-    #[serde(rename = "MeterTags", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "MeterTags", default, skip_serializing_if = "Vec::is_empty")]
     pub meter_tags: Vec<String>,
     #[serde(rename = "MeterRegion", skip_serializing_if = "Option::is_none")]
     pub meter_region: Option<String>,
@@ -98,6 +125,9 @@ pub struct MeterInfo {
     pub effective_date: Option<String>,
     #[serde(rename = "IncludedQuantity", skip_serializing_if = "Option::is_none")]
     pub included_quantity: Option<f32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OfferTermInfo {
@@ -116,6 +146,8 @@ pub mod offer_term_info {
         MonetaryCommitment,
         #[serde(rename = "Monetary Credit")]
         MonetaryCredit,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -124,8 +156,11 @@ pub struct MonetaryCredit {
     pub offer_term_info: OfferTermInfo,
     #[serde(rename = "Credit", skip_serializing_if = "Option::is_none")]
     pub credit: Option<f64>,
-    #[serde(rename = "ExcludedMeterIds", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "ExcludedMeterIds", default, skip_serializing_if = "Vec::is_empty")]
     pub excluded_meter_ids: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MonetaryCommitment {
@@ -133,8 +168,11 @@ pub struct MonetaryCommitment {
     pub offer_term_info: OfferTermInfo,
     #[serde(rename = "TieredDiscount", skip_serializing_if = "Option::is_none")]
     pub tiered_discount: Option<serde_json::Value>,
-    #[serde(rename = "ExcludedMeterIds", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "ExcludedMeterIds", default, skip_serializing_if = "Vec::is_empty")]
     pub excluded_meter_ids: Vec<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RecurringCharge {
@@ -142,6 +180,9 @@ pub struct RecurringCharge {
     pub offer_term_info: OfferTermInfo,
     #[serde(rename = "RecurringCharge", skip_serializing_if = "Option::is_none")]
     pub recurring_charge: Option<i64>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorResponse {
@@ -149,4 +190,7 @@ pub struct ErrorResponse {
     pub code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
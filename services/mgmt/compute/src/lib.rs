@@ -1,211 +1,188 @@
 #![doc = "generated by AutoRust 0.1.0"]
 #[cfg(feature = "package-2020-09-30")]
-mod package_2020_09_30;
+pub mod package_2020_09_30;
+#[cfg(feature = "package-2020-09-30")]
+pub use package_2020_09_30 as v2020_09_30;
 #[cfg(feature = "package-2020-09-30")]
 pub use package_2020_09_30::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-09-30-only")]
-mod package_2020_09_30_only;
-#[cfg(feature = "package-2020-09-30-only")]
-pub use package_2020_09_30_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-06-30")]
-mod package_2020_06_30;
-#[cfg(feature = "package-2020-06-30")]
-pub use package_2020_06_30::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-06-30-only")]
-mod package_2020_06_30_only;
-#[cfg(feature = "package-2020-06-30-only")]
-pub use package_2020_06_30_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-06-01")]
-mod package_2020_06_01;
-#[cfg(feature = "package-2020-06-01")]
-pub use package_2020_06_01::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-06-01-only")]
-mod package_2020_06_01_only;
-#[cfg(feature = "package-2020-06-01-only")]
-pub use package_2020_06_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-05-01")]
-mod package_2020_05_01;
-#[cfg(feature = "package-2020-05-01")]
-pub use package_2020_05_01::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-05-01-only")]
-mod package_2020_05_01_only;
-#[cfg(feature = "package-2020-05-01-only")]
-pub use package_2020_05_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-12-01")]
-mod package_2019_12_01;
-#[cfg(feature = "package-2019-12-01")]
-pub use package_2019_12_01::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-12-01-only")]
-mod package_2019_12_01_only;
-#[cfg(feature = "package-2019-12-01-only")]
-pub use package_2019_12_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-11-01")]
-mod package_2019_11_01;
-#[cfg(feature = "package-2019-11-01")]
-pub use package_2019_11_01::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-11-01-only")]
-mod package_2019_11_01_only;
-#[cfg(feature = "package-2019-11-01-only")]
-pub use package_2019_11_01_only::{models, operations, API_VERSION};
+#[cfg(feature = "package-2015-06-preview")]
+pub mod package_2015_06_preview;
+#[cfg(feature = "package-2015-06-preview")]
+pub use package_2015_06_preview as v2015_06_preview;
+#[cfg(feature = "package-2016-03")]
+pub mod package_2016_03;
+#[cfg(feature = "package-2016-03")]
+pub use package_2016_03 as v2016_03;
+#[cfg(feature = "package-2016-04-preview")]
+pub mod package_2016_04_preview;
+#[cfg(feature = "package-2016-04-preview")]
+pub use package_2016_04_preview as v2016_04_preview;
+#[cfg(feature = "package-2017-03")]
+pub mod package_2017_03;
+#[cfg(feature = "package-2017-03")]
+pub use package_2017_03 as v2017_03;
+#[cfg(feature = "package-2017-12")]
+pub mod package_2017_12;
+#[cfg(feature = "package-2017-12")]
+pub use package_2017_12 as v2017_12;
+#[cfg(feature = "package-2018-04")]
+pub mod package_2018_04;
+#[cfg(feature = "package-2018-04")]
+pub use package_2018_04 as v2018_04;
+#[cfg(feature = "package-2018-04-01")]
+pub mod package_2018_04_01;
+#[cfg(feature = "package-2018-04-01")]
+pub use package_2018_04_01 as v2018_04_01;
+#[cfg(feature = "package-2018-06")]
+pub mod package_2018_06;
+#[cfg(feature = "package-2018-06")]
+pub use package_2018_06 as v2018_06;
+#[cfg(feature = "package-2018-06-01")]
+pub mod package_2018_06_01;
+#[cfg(feature = "package-2018-06-01")]
+pub use package_2018_06_01 as v2018_06_01;
+#[cfg(feature = "package-2018-06-exclude-gallery")]
+pub mod package_2018_06_exclude_gallery;
+#[cfg(feature = "package-2018-06-exclude-gallery")]
+pub use package_2018_06_exclude_gallery as v2018_06_exclude_gallery;
+#[cfg(feature = "package-2018-09-30-only")]
+pub mod package_2018_09_30_only;
+#[cfg(feature = "package-2018-09-30-only")]
+pub use package_2018_09_30_only as v2018_09_30_only;
+#[cfg(feature = "package-2018-10-01")]
+pub mod package_2018_10_01;
+#[cfg(feature = "package-2018-10-01")]
+pub use package_2018_10_01 as v2018_10_01;
+#[cfg(feature = "package-2018-10-01-Disks")]
+pub mod package_2018_10_01_disks;
+#[cfg(feature = "package-2018-10-01-Disks")]
+pub use package_2018_10_01_disks as v2018_10_01_disks;
+#[cfg(feature = "package-2018-10-01-only")]
+pub mod package_2018_10_01_only;
+#[cfg(feature = "package-2018-10-01-only")]
+pub use package_2018_10_01_only as v2018_10_01_only;
+#[cfg(feature = "package-2019-03-01")]
+pub mod package_2019_03_01;
+#[cfg(feature = "package-2019-03-01")]
+pub use package_2019_03_01 as v2019_03_01;
+#[cfg(feature = "package-2019-03-01-only")]
+pub mod package_2019_03_01_only;
+#[cfg(feature = "package-2019-03-01-only")]
+pub use package_2019_03_01_only as v2019_03_01_only;
+#[cfg(feature = "package-2019-04-01-only")]
+pub mod package_2019_04_01_only;
+#[cfg(feature = "package-2019-04-01-only")]
+pub use package_2019_04_01_only as v2019_04_01_only;
 #[cfg(feature = "package-2019-07")]
-mod package_2019_07;
+pub mod package_2019_07;
 #[cfg(feature = "package-2019-07")]
-pub use package_2019_07::{models, operations, API_VERSION};
+pub use package_2019_07 as v2019_07;
 #[cfg(feature = "package-2019-07-01")]
-mod package_2019_07_01;
+pub mod package_2019_07_01;
 #[cfg(feature = "package-2019-07-01")]
-pub use package_2019_07_01::{models, operations, API_VERSION};
+pub use package_2019_07_01 as v2019_07_01;
 #[cfg(feature = "package-2019-07-01-only")]
-mod package_2019_07_01_only;
+pub mod package_2019_07_01_only;
 #[cfg(feature = "package-2019-07-01-only")]
-pub use package_2019_07_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-03-01")]
-mod package_2019_03_01;
-#[cfg(feature = "package-2019-03-01")]
-pub use package_2019_03_01::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-04-01-only")]
-mod package_2019_04_01_only;
-#[cfg(feature = "package-2019-04-01-only")]
-pub use package_2019_04_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-03-01-only")]
-mod package_2019_03_01_only;
-#[cfg(feature = "package-2019-03-01-only")]
-pub use package_2019_03_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-10-01-Disks")]
-mod package_2018_10_01_disks;
-#[cfg(feature = "package-2018-10-01-Disks")]
-pub use package_2018_10_01_disks::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-10-01")]
-mod package_2018_10_01;
-#[cfg(feature = "package-2018-10-01")]
-pub use package_2018_10_01::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-10-01-only")]
-mod package_2018_10_01_only;
-#[cfg(feature = "package-2018-10-01-only")]
-pub use package_2018_10_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-09-30-only")]
-mod package_2018_09_30_only;
-#[cfg(feature = "package-2018-09-30-only")]
-pub use package_2018_09_30_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-06-exclude-gallery")]
-mod package_2018_06_exclude_gallery;
-#[cfg(feature = "package-2018-06-exclude-gallery")]
-pub use package_2018_06_exclude_gallery::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-06")]
-mod package_2018_06;
-#[cfg(feature = "package-2018-06")]
-pub use package_2018_06::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-06-01")]
-mod package_2018_06_01;
-#[cfg(feature = "package-2018-06-01")]
-pub use package_2018_06_01::{models, operations, API_VERSION};
-#[cfg(feature = "package-compute-only-2018-06")]
-mod package_compute_only_2018_06;
-#[cfg(feature = "package-compute-only-2018-06")]
-pub use package_compute_only_2018_06::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-04-01")]
-mod package_2018_04_01;
-#[cfg(feature = "package-2018-04-01")]
-pub use package_2018_04_01::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-04")]
-mod package_2018_04;
-#[cfg(feature = "package-2018-04")]
-pub use package_2018_04::{models, operations, API_VERSION};
-#[cfg(feature = "package-compute-2018-04")]
-mod package_compute_2018_04;
-#[cfg(feature = "package-compute-2018-04")]
-pub use package_compute_2018_04::{models, operations, API_VERSION};
-#[cfg(feature = "package-disks-2018-04")]
-mod package_disks_2018_04;
-#[cfg(feature = "package-disks-2018-04")]
-pub use package_disks_2018_04::{models, operations, API_VERSION};
-#[cfg(feature = "package-2017-12")]
-mod package_2017_12;
-#[cfg(feature = "package-2017-12")]
-pub use package_2017_12::{models, operations, API_VERSION};
+pub use package_2019_07_01_only as v2019_07_01_only;
+#[cfg(feature = "package-2019-11-01")]
+pub mod package_2019_11_01;
+#[cfg(feature = "package-2019-11-01")]
+pub use package_2019_11_01 as v2019_11_01;
+#[cfg(feature = "package-2019-11-01-only")]
+pub mod package_2019_11_01_only;
+#[cfg(feature = "package-2019-11-01-only")]
+pub use package_2019_11_01_only as v2019_11_01_only;
+#[cfg(feature = "package-2019-12-01")]
+pub mod package_2019_12_01;
+#[cfg(feature = "package-2019-12-01")]
+pub use package_2019_12_01 as v2019_12_01;
+#[cfg(feature = "package-2019-12-01-only")]
+pub mod package_2019_12_01_only;
+#[cfg(feature = "package-2019-12-01-only")]
+pub use package_2019_12_01_only as v2019_12_01_only;
+#[cfg(feature = "package-2020-05-01")]
+pub mod package_2020_05_01;
+#[cfg(feature = "package-2020-05-01")]
+pub use package_2020_05_01 as v2020_05_01;
+#[cfg(feature = "package-2020-05-01-only")]
+pub mod package_2020_05_01_only;
+#[cfg(feature = "package-2020-05-01-only")]
+pub use package_2020_05_01_only as v2020_05_01_only;
+#[cfg(feature = "package-2020-06-01")]
+pub mod package_2020_06_01;
+#[cfg(feature = "package-2020-06-01")]
+pub use package_2020_06_01 as v2020_06_01;
+#[cfg(feature = "package-2020-06-01-only")]
+pub mod package_2020_06_01_only;
+#[cfg(feature = "package-2020-06-01-only")]
+pub use package_2020_06_01_only as v2020_06_01_only;
+#[cfg(feature = "package-2020-06-30")]
+pub mod package_2020_06_30;
+#[cfg(feature = "package-2020-06-30")]
+pub use package_2020_06_30 as v2020_06_30;
+#[cfg(feature = "package-2020-06-30-only")]
+pub mod package_2020_06_30_only;
+#[cfg(feature = "package-2020-06-30-only")]
+pub use package_2020_06_30_only as v2020_06_30_only;
+#[cfg(feature = "package-2020-09-30-only")]
+pub mod package_2020_09_30_only;
+#[cfg(feature = "package-2020-09-30-only")]
+pub use package_2020_09_30_only as v2020_09_30_only;
+#[cfg(feature = "package-compute-2015-06")]
+pub mod package_compute_2015_06;
+#[cfg(feature = "package-compute-2015-06")]
+pub use package_compute_2015_06 as compute_v2015_06;
+#[cfg(feature = "package-compute-2016-03")]
+pub mod package_compute_2016_03;
+#[cfg(feature = "package-compute-2016-03")]
+pub use package_compute_2016_03 as compute_v2016_03;
+#[cfg(feature = "package-compute-2016-04-preview")]
+pub mod package_compute_2016_04_preview;
+#[cfg(feature = "package-compute-2016-04-preview")]
+pub use package_compute_2016_04_preview as compute_v2016_04_preview;
+#[cfg(feature = "package-compute-2017-03")]
+pub mod package_compute_2017_03;
+#[cfg(feature = "package-compute-2017-03")]
+pub use package_compute_2017_03 as compute_v2017_03;
 #[cfg(feature = "package-compute-2017-12")]
-mod package_compute_2017_12;
+pub mod package_compute_2017_12;
 #[cfg(feature = "package-compute-2017-12")]
-pub use package_compute_2017_12::{models, operations, API_VERSION};
+pub use package_compute_2017_12 as compute_v2017_12;
+#[cfg(feature = "package-compute-2018-04")]
+pub mod package_compute_2018_04;
+#[cfg(feature = "package-compute-2018-04")]
+pub use package_compute_2018_04 as compute_v2018_04;
 #[cfg(feature = "package-compute-only-2017-12")]
-mod package_compute_only_2017_12;
+pub mod package_compute_only_2017_12;
 #[cfg(feature = "package-compute-only-2017-12")]
-pub use package_compute_only_2017_12::{models, operations, API_VERSION};
-#[cfg(feature = "package-skus-2017-09")]
-mod package_skus_2017_09;
-#[cfg(feature = "package-skus-2017-09")]
-pub use package_skus_2017_09::{models, operations, API_VERSION};
-#[cfg(feature = "package-2017-03")]
-mod package_2017_03;
-#[cfg(feature = "package-2017-03")]
-pub use package_2017_03::{models, operations, API_VERSION};
-#[cfg(feature = "package-compute-2017-03")]
-mod package_compute_2017_03;
-#[cfg(feature = "package-compute-2017-03")]
-pub use package_compute_2017_03::{models, operations, API_VERSION};
-#[cfg(feature = "package-container-service-2017-01")]
-mod package_container_service_2017_01;
-#[cfg(feature = "package-container-service-2017-01")]
-pub use package_container_service_2017_01::{models, operations, API_VERSION};
-#[cfg(feature = "package-container-service-2016-09")]
-mod package_container_service_2016_09;
-#[cfg(feature = "package-container-service-2016-09")]
-pub use package_container_service_2016_09::{models, operations, API_VERSION};
-#[cfg(feature = "package-2016-04-preview")]
-mod package_2016_04_preview;
-#[cfg(feature = "package-2016-04-preview")]
-pub use package_2016_04_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-compute-2016-04-preview")]
-mod package_compute_2016_04_preview;
-#[cfg(feature = "package-compute-2016-04-preview")]
-pub use package_compute_2016_04_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-2016-03")]
-mod package_2016_03;
-#[cfg(feature = "package-2016-03")]
-pub use package_2016_03::{models, operations, API_VERSION};
-#[cfg(feature = "package-compute-2016-03")]
-mod package_compute_2016_03;
-#[cfg(feature = "package-compute-2016-03")]
-pub use package_compute_2016_03::{models, operations, API_VERSION};
-#[cfg(feature = "package-container-service-2016-03")]
-mod package_container_service_2016_03;
-#[cfg(feature = "package-container-service-2016-03")]
-pub use package_container_service_2016_03::{models, operations, API_VERSION};
+pub use package_compute_only_2017_12 as compute_only_v2017_12;
+#[cfg(feature = "package-compute-only-2018-06")]
+pub mod package_compute_only_2018_06;
+#[cfg(feature = "package-compute-only-2018-06")]
+pub use package_compute_only_2018_06 as compute_only_v2018_06;
 #[cfg(feature = "package-container-service-2015-11-preview")]
-mod package_container_service_2015_11_preview;
+pub mod package_container_service_2015_11_preview;
 #[cfg(feature = "package-container-service-2015-11-preview")]
-pub use package_container_service_2015_11_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-compute-2015-06")]
-mod package_compute_2015_06;
-#[cfg(feature = "package-compute-2015-06")]
-pub use package_compute_2015_06::{models, operations, API_VERSION};
-#[cfg(feature = "package-2015-06-preview")]
-mod package_2015_06_preview;
-#[cfg(feature = "package-2015-06-preview")]
-pub use package_2015_06_preview::{models, operations, API_VERSION};
-pub struct OperationConfig {
-    pub api_version: String,
-    pub client: reqwest::Client,
-    pub base_path: String,
-    pub token_credential: Option<Box<dyn azure_core::TokenCredential>>,
-    pub token_credential_resource: String,
-}
-impl OperationConfig {
-    pub fn new(token_credential: Box<dyn azure_core::TokenCredential>) -> Self {
-        Self {
-            token_credential: Some(token_credential),
-            ..Default::default()
-        }
-    }
-}
-impl Default for OperationConfig {
-    fn default() -> Self {
-        Self {
-            api_version: API_VERSION.to_owned(),
-            client: reqwest::Client::new(),
-            base_path: "https://management.azure.com".to_owned(),
-            token_credential: None,
-            token_credential_resource: "https://management.azure.com/".to_owned(),
-        }
-    }
-}
+pub use package_container_service_2015_11_preview as container_service_v2015_11_preview;
+#[cfg(feature = "package-container-service-2016-03")]
+pub mod package_container_service_2016_03;
+#[cfg(feature = "package-container-service-2016-03")]
+pub use package_container_service_2016_03 as container_service_v2016_03;
+#[cfg(feature = "package-container-service-2016-09")]
+pub mod package_container_service_2016_09;
+#[cfg(feature = "package-container-service-2016-09")]
+pub use package_container_service_2016_09 as container_service_v2016_09;
+#[cfg(feature = "package-container-service-2017-01")]
+pub mod package_container_service_2017_01;
+#[cfg(feature = "package-container-service-2017-01")]
+pub use package_container_service_2017_01 as container_service_v2017_01;
+#[cfg(feature = "package-disks-2018-04")]
+pub mod package_disks_2018_04;
+#[cfg(feature = "package-disks-2018-04")]
+pub use package_disks_2018_04 as disks_v2018_04;
+#[cfg(feature = "package-skus-2017-09")]
+pub mod package_skus_2017_09;
+pub use azure_mgmt_core::OperationConfig;
+#[cfg(feature = "package-skus-2017-09")]
+pub use package_skus_2017_09 as skus_v2017_09;
@@ -1,83 +1,60 @@
 #![doc = "generated by AutoRust 0.1.0"]
 #[cfg(feature = "package-2019-10")]
-mod package_2019_10;
+pub mod package_2019_10;
+#[cfg(feature = "package-2019-10")]
+pub use package_2019_10 as v2019_10;
 #[cfg(feature = "package-2019-10")]
 pub use package_2019_10::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-06")]
-mod package_2019_06;
-#[cfg(feature = "package-2019-06")]
-pub use package_2019_06::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-05")]
-mod package_2019_05;
-#[cfg(feature = "package-2019-05")]
-pub use package_2019_05::{models, operations, API_VERSION};
-#[cfg(feature = "package-preview-2019-05")]
-mod package_preview_2019_05;
-#[cfg(feature = "package-preview-2019-05")]
-pub use package_preview_2019_05::{models, operations, API_VERSION};
-#[cfg(feature = "package-preview-2019-04")]
-mod package_preview_2019_04;
-#[cfg(feature = "package-preview-2019-04")]
-pub use package_preview_2019_04::{models, operations, API_VERSION};
-#[cfg(feature = "package-preview-2018-11")]
-mod package_preview_2018_11;
-#[cfg(feature = "package-preview-2018-11")]
-pub use package_preview_2018_11::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-01")]
-mod package_2019_01;
-#[cfg(feature = "package-2019-01")]
-pub use package_2019_01::{models, operations, API_VERSION};
 #[cfg(feature = "package-2017-11")]
-mod package_2017_11;
+pub mod package_2017_11;
 #[cfg(feature = "package-2017-11")]
-pub use package_2017_11::{models, operations, API_VERSION};
+pub use package_2017_11 as v2017_11;
 #[cfg(feature = "package-2018-01")]
-mod package_2018_01;
+pub mod package_2018_01;
 #[cfg(feature = "package-2018-01")]
-pub use package_2018_01::{models, operations, API_VERSION};
+pub use package_2018_01 as v2018_01;
 #[cfg(feature = "package-2018-03")]
-mod package_2018_03;
+pub mod package_2018_03;
 #[cfg(feature = "package-2018-03")]
-pub use package_2018_03::{models, operations, API_VERSION};
+pub use package_2018_03 as v2018_03;
 #[cfg(feature = "package-2018-05")]
-mod package_2018_05;
+pub mod package_2018_05;
 #[cfg(feature = "package-2018-05")]
-pub use package_2018_05::{models, operations, API_VERSION};
+pub use package_2018_05 as v2018_05;
 #[cfg(feature = "package-2018-06")]
-mod package_2018_06;
+pub mod package_2018_06;
 #[cfg(feature = "package-2018-06")]
-pub use package_2018_06::{models, operations, API_VERSION};
+pub use package_2018_06 as v2018_06;
 #[cfg(feature = "package-2018-08")]
-mod package_2018_08;
+pub mod package_2018_08;
 #[cfg(feature = "package-2018-08")]
-pub use package_2018_08::{models, operations, API_VERSION};
+pub use package_2018_08 as v2018_08;
 #[cfg(feature = "package-2018-10")]
-mod package_2018_10;
+pub mod package_2018_10;
 #[cfg(feature = "package-2018-10")]
-pub use package_2018_10::{models, operations, API_VERSION};
-pub struct OperationConfig {
-    pub api_version: String,
-    pub client: reqwest::Client,
-    pub base_path: String,
-    pub token_credential: Option<Box<dyn azure_core::TokenCredential>>,
-    pub token_credential_resource: String,
-}
-impl OperationConfig {
-    pub fn new(token_credential: Box<dyn azure_core::TokenCredential>) -> Self {
-        Self {
-            token_credential: Some(token_credential),
-            ..Default::default()
-        }
-    }
-}
-impl Default for OperationConfig {
-    fn default() -> Self {
-        Self {
-            api_version: API_VERSION.to_owned(),
-            client: reqwest::Client::new(),
-            base_path: "https://management.azure.com".to_owned(),
-            token_credential: None,
-            token_credential_resource: "https://management.azure.com/".to_owned(),
-        }
-    }
-}
+pub use package_2018_10 as v2018_10;
+#[cfg(feature = "package-2019-01")]
+pub mod package_2019_01;
+#[cfg(feature = "package-2019-01")]
+pub use package_2019_01 as v2019_01;
+#[cfg(feature = "package-2019-05")]
+pub mod package_2019_05;
+#[cfg(feature = "package-2019-05")]
+pub use package_2019_05 as v2019_05;
+#[cfg(feature = "package-2019-06")]
+pub mod package_2019_06;
+#[cfg(feature = "package-2019-06")]
+pub use package_2019_06 as v2019_06;
+#[cfg(feature = "package-preview-2018-11")]
+pub mod package_preview_2018_11;
+#[cfg(feature = "package-preview-2018-11")]
+pub use package_preview_2018_11 as preview_v2018_11;
+#[cfg(feature = "package-preview-2019-04")]
+pub mod package_preview_2019_04;
+#[cfg(feature = "package-preview-2019-04")]
+pub use package_preview_2019_04 as preview_v2019_04;
+#[cfg(feature = "package-preview-2019-05")]
+pub mod package_preview_2019_05;
+pub use azure_mgmt_core::OperationConfig;
+#[cfg(feature = "package-preview-2019-05")]
+pub use package_preview_2019_05 as preview_v2019_05;
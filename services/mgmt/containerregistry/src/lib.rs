@@ -1,79 +1,56 @@
 #![doc = "generated by AutoRust 0.1.0"]
-#[cfg(feature = "package-2019-12-preview")]
-mod package_2019_12_preview;
-#[cfg(feature = "package-2019-12-preview")]
-pub use package_2019_12_preview::{models, operations, API_VERSION};
+#[cfg(feature = "package-2019-05")]
+pub mod package_2019_05;
+#[cfg(feature = "package-2019-05")]
+pub use package_2019_05 as v2019_05;
 #[cfg(feature = "package-2019-06-preview")]
-mod package_2019_06_preview;
+pub mod package_2019_06_preview;
 #[cfg(feature = "package-2019-06-preview")]
-pub use package_2019_06_preview::{models, operations, API_VERSION};
+pub use package_2019_06_preview as v2019_06_preview;
 #[cfg(feature = "package-2019-06-preview-only")]
-mod package_2019_06_preview_only;
+pub mod package_2019_06_preview_only;
 #[cfg(feature = "package-2019-06-preview-only")]
-pub use package_2019_06_preview_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-05")]
-mod package_2019_05;
+pub use package_2019_06_preview_only as v2019_06_preview_only;
+#[cfg(feature = "package-2019-12-preview")]
+pub mod package_2019_12_preview;
 #[cfg(feature = "package-2019-05")]
 pub use package_2019_05::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-05-preview")]
-mod package_2019_05_preview;
-#[cfg(feature = "package-2019-05-preview")]
-pub use package_2019_05_preview::{models, operations, API_VERSION};
+#[cfg(feature = "package-2019-12-preview")]
+pub use package_2019_12_preview as v2019_12_preview;
+#[cfg(feature = "package-2016-06-preview")]
+pub mod package_2016_06_preview;
+#[cfg(feature = "package-2016-06-preview")]
+pub use package_2016_06_preview as v2016_06_preview;
+#[cfg(feature = "package-2017-03")]
+pub mod package_2017_03;
+#[cfg(feature = "package-2017-03")]
+pub use package_2017_03 as v2017_03;
+#[cfg(feature = "package-2017-06-preview")]
+pub mod package_2017_06_preview;
+#[cfg(feature = "package-2017-06-preview")]
+pub use package_2017_06_preview as v2017_06_preview;
+#[cfg(feature = "package-2017-10")]
+pub mod package_2017_10;
+#[cfg(feature = "package-2017-10")]
+pub use package_2017_10 as v2017_10;
+#[cfg(feature = "package-2018-02-preview")]
+pub mod package_2018_02_preview;
+#[cfg(feature = "package-2018-02-preview")]
+pub use package_2018_02_preview as v2018_02_preview;
+#[cfg(feature = "package-2018-09")]
+pub mod package_2018_09;
+#[cfg(feature = "package-2018-09")]
+pub use package_2018_09 as v2018_09;
 #[cfg(feature = "package-2019-04")]
-mod package_2019_04;
+pub mod package_2019_04;
 #[cfg(feature = "package-2019-04")]
-pub use package_2019_04::{models, operations, API_VERSION};
+pub use package_2019_04 as v2019_04;
 #[cfg(feature = "package-2019-04-only")]
-mod package_2019_04_only;
+pub mod package_2019_04_only;
 #[cfg(feature = "package-2019-04-only")]
-pub use package_2019_04_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-09")]
-mod package_2018_09;
-#[cfg(feature = "package-2018-09")]
-pub use package_2018_09::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-02-preview")]
-mod package_2018_02_preview;
-#[cfg(feature = "package-2018-02-preview")]
-pub use package_2018_02_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-2017-10")]
-mod package_2017_10;
-#[cfg(feature = "package-2017-10")]
-pub use package_2017_10::{models, operations, API_VERSION};
-#[cfg(feature = "package-2017-06-preview")]
-mod package_2017_06_preview;
-#[cfg(feature = "package-2017-06-preview")]
-pub use package_2017_06_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-2017-03")]
-mod package_2017_03;
-#[cfg(feature = "package-2017-03")]
-pub use package_2017_03::{models, operations, API_VERSION};
-#[cfg(feature = "package-2016-06-preview")]
-mod package_2016_06_preview;
-#[cfg(feature = "package-2016-06-preview")]
-pub use package_2016_06_preview::{models, operations, API_VERSION};
-pub struct OperationConfig {
-    pub api_version: String,
-    pub client: reqwest::Client,
-    pub base_path: String,
-    pub token_credential: Option<Box<dyn azure_core::TokenCredential>>,
-    pub token_credential_resource: String,
-}
-impl OperationConfig {
-    pub fn new(token_credential: Box<dyn azure_core::TokenCredential>) -> Self {
-        Self {
-            token_credential: Some(token_credential),
-            ..Default::default()
-        }
-    }
-}
-impl Default for OperationConfig {
-    fn default() -> Self {
-        Self {
-            api_version: API_VERSION.to_owned(),
-            client: reqwest::Client::new(),
-            base_path: "https://management.azure.com".to_owned(),
-            token_credential: None,
-            token_credential_resource: "https://management.azure.com/".to_owned(),
-        }
-    }
-}
+pub use package_2019_04_only as v2019_04_only;
+#[cfg(feature = "package-2019-05-preview")]
+pub mod package_2019_05_preview;
+pub use azure_mgmt_core::OperationConfig;
+#[cfg(feature = "package-2019-05-preview")]
+pub use package_2019_05_preview as v2019_05_preview;
@@ -15,6 +15,9 @@ pub struct Run {
     pub proxy_resource: ProxyResource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<RunProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RunProperties {
@@ -32,7 +35,7 @@ pub struct RunProperties {
     pub start_time: Option<String>,
     #[serde(rename = "finishTime", skip_serializing_if = "Option::is_none")]
     pub finish_time: Option<String>,
-    #[serde(rename = "outputImages", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "outputImages", default, skip_serializing_if = "Vec::is_empty")]
     pub output_images: Vec<ImageDescriptor>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub task: Option<String>,
@@ -46,7 +49,7 @@ pub struct RunProperties {
     pub agent_configuration: Option<AgentProperties>,
     #[serde(rename = "sourceRegistryAuth", skip_serializing_if = "Option::is_none")]
     pub source_registry_auth: Option<String>,
-    #[serde(rename = "customRegistries", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "customRegistries", default, skip_serializing_if = "Vec::is_empty")]
     pub custom_registries: Vec<String>,
     #[serde(rename = "runErrorMessage", skip_serializing)]
     pub run_error_message: Option<String>,
@@ -56,6 +59,9 @@ pub struct RunProperties {
     pub is_archive_enabled: Option<bool>,
     #[serde(rename = "timerTrigger", skip_serializing_if = "Option::is_none")]
     pub timer_trigger: Option<TimerTriggerDescriptor>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod run_properties {
     use super::*;
@@ -69,6 +75,8 @@ pub mod run_properties {
         Canceled,
         Error,
         Timeout,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum RunType {
@@ -76,6 +84,8 @@ pub mod run_properties {
         QuickRun,
         AutoBuild,
         AutoRun,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ProvisioningState {
@@ -85,6 +95,8 @@ pub mod run_properties {
         Succeeded,
         Failed,
         Canceled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -97,6 +109,9 @@ pub struct ImageDescriptor {
     pub tag: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub digest: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageUpdateTrigger {
@@ -104,8 +119,11 @@ pub struct ImageUpdateTrigger {
     pub id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub images: Vec<ImageDescriptor>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SourceTriggerDescriptor {
@@ -123,6 +141,9 @@ pub struct SourceTriggerDescriptor {
     pub branch_name: Option<String>,
     #[serde(rename = "providerType", skip_serializing_if = "Option::is_none")]
     pub provider_type: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TimerTriggerDescriptor {
@@ -130,6 +151,9 @@ pub struct TimerTriggerDescriptor {
     pub timer_trigger_name: Option<String>,
     #[serde(rename = "scheduleOccurrence", skip_serializing_if = "Option::is_none")]
     pub schedule_occurrence: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PlatformProperties {
@@ -138,6 +162,9 @@ pub struct PlatformProperties {
     pub architecture: Option<platform_properties::Architecture>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub variant: Option<platform_properties::Variant>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod platform_properties {
     use super::*;
@@ -145,6 +172,8 @@ pub mod platform_properties {
     pub enum Os {
         Windows,
         Linux,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Architecture {
@@ -154,6 +183,8 @@ pub mod platform_properties {
         X86,
         #[serde(rename = "arm")]
         Arm,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Variant {
@@ -163,12 +194,17 @@ pub mod platform_properties {
         V7,
         #[serde(rename = "v8")]
         V8,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AgentProperties {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cpu: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SourceUploadDefinition {
@@ -176,13 +212,28 @@ pub struct SourceUploadDefinition {
     pub upload_url: Option<String>,
     #[serde(rename = "relativePath", skip_serializing_if = "Option::is_none")]
     pub relative_path: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RunListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Run>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for RunListResult {
+    type Item = Run;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Run> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RunFilter {
@@ -202,6 +253,9 @@ pub struct RunFilter {
     pub is_archive_enabled: Option<bool>,
     #[serde(rename = "taskName", skip_serializing_if = "Option::is_none")]
     pub task_name: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod run_filter {
     use super::*;
@@ -211,6 +265,8 @@ pub mod run_filter {
         QuickRun,
         AutoBuild,
         AutoRun,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Status {
@@ -222,24 +278,44 @@ pub mod run_filter {
         Canceled,
         Error,
         Timeout,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RunUpdateParameters {
     #[serde(rename = "isArchiveEnabled", skip_serializing_if = "Option::is_none")]
     pub is_archive_enabled: Option<bool>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RunGetLogResult {
     #[serde(rename = "logLink", skip_serializing_if = "Option::is_none")]
     pub log_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskListResult {
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Task>,
     #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
+}
+impl azure_core::Page for TaskListResult {
+    type Item = Task;
+    fn continuation(&self) -> Option<String> {
+        self.next_link.clone().filter(|next_link| !next_link.is_empty())
+    }
+    fn into_items(self) -> Vec<Task> {
+        self.value
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Task {
@@ -249,6 +325,9 @@ pub struct Task {
     pub identity: Option<IdentityProperties>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<TaskProperties>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct IdentityProperties {
@@ -260,6 +339,9 @@ pub struct IdentityProperties {
     pub type_: Option<identity_properties::Type>,
     #[serde(rename = "userAssignedIdentities", skip_serializing_if = "Option::is_none")]
     pub user_assigned_identities: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod identity_properties {
     use super::*;
@@ -270,6 +352,8 @@ pub mod identity_properties {
         #[serde(rename = "SystemAssigned, UserAssigned")]
         SystemAssignedUserAssigned,
         None,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -290,6 +374,9 @@ pub struct TaskProperties {
     pub trigger: Option<TriggerProperties>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credentials: Option<Credentials>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod task_properties {
     use super::*;
@@ -301,11 +388,15 @@ pub mod task_properties {
         Succeeded,
         Failed,
         Canceled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Status {
         Disabled,
         Enabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -314,12 +405,15 @@ pub struct UserIdentityProperties {
     pub principal_id: Option<String>,
     #[serde(rename = "clientId", skip_serializing_if = "Option::is_none")]
     pub client_id: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskStepProperties {
     #[serde(rename = "type", skip_serializing)]
     pub type_: Option<task_step_properties::Type>,
-    #[serde(rename = "baseImageDependencies", skip_serializing)]
+    #[serde(rename = "baseImageDependencies", default, skip_serializing)]
     pub base_image_dependencies: Vec<BaseImageDependency>,
     #[serde(rename = "contextPath", skip_serializing_if = "Option::is_none")]
     pub context_path: Option<String>,
@@ -333,16 +427,21 @@ pub mod task_step_properties {
         Docker,
         FileTask,
         EncodedTask,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TriggerProperties {
-    #[serde(rename = "timerTriggers", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "timerTriggers", default, skip_serializing_if = "Vec::is_empty")]
     pub timer_triggers: Vec<TimerTrigger>,
-    #[serde(rename = "sourceTriggers", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "sourceTriggers", default, skip_serializing_if = "Vec::is_empty")]
     pub source_triggers: Vec<SourceTrigger>,
     #[serde(rename = "baseImageTrigger", skip_serializing_if = "Option::is_none")]
     pub base_image_trigger: Option<BaseImageTrigger>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Credentials {
@@ -350,6 +449,9 @@ pub struct Credentials {
     pub source_registry: Option<SourceRegistryCredentials>,
     #[serde(rename = "customRegistries", skip_serializing_if = "Option::is_none")]
     pub custom_registries: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BaseImageDependency {
@@ -363,6 +465,9 @@ pub struct BaseImageDependency {
     pub tag: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub digest: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod base_image_dependency {
     use super::*;
@@ -370,6 +475,8 @@ pub mod base_image_dependency {
     pub enum Type {
         BuildTime,
         RunTime,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -378,6 +485,9 @@ pub struct TimerTrigger {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<timer_trigger::Status>,
     pub name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod timer_trigger {
     use super::*;
@@ -385,17 +495,22 @@ pub mod timer_trigger {
     pub enum Status {
         Disabled,
         Enabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SourceTrigger {
     #[serde(rename = "sourceRepository")]
     pub source_repository: SourceProperties,
-    #[serde(rename = "sourceTriggerEvents")]
+    #[serde(rename = "sourceTriggerEvents", default)]
     pub source_trigger_events: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<source_trigger::Status>,
     pub name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod source_trigger {
     use super::*;
@@ -403,6 +518,8 @@ pub mod source_trigger {
     pub enum Status {
         Disabled,
         Enabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -412,6 +529,9 @@ pub struct BaseImageTrigger {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<base_image_trigger::Status>,
     pub name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod base_image_trigger {
     use super::*;
@@ -419,17 +539,24 @@ pub mod base_image_trigger {
     pub enum BaseImageTriggerType {
         All,
         Runtime,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Status {
         Disabled,
         Enabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SourceRegistryCredentials {
     #[serde(rename = "loginMode", skip_serializing_if = "Option::is_none")]
     pub login_mode: Option<source_registry_credentials::LoginMode>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod source_registry_credentials {
     use super::*;
@@ -437,6 +564,8 @@ pub mod source_registry_credentials {
     pub enum LoginMode {
         None,
         Default,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -447,6 +576,9 @@ pub struct CustomRegistryCredentials {
     pub password: Option<SecretObject>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub identity: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SourceProperties {
@@ -458,6 +590,9 @@ pub struct SourceProperties {
     pub branch: Option<String>,
     #[serde(rename = "sourceControlAuthProperties", skip_serializing_if = "Option::is_none")]
     pub source_control_auth_properties: Option<AuthInfo>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod source_properties {
     use super::*;
@@ -465,6 +600,8 @@ pub mod source_properties {
     pub enum SourceControlType {
         Github,
         VisualStudioTeamService,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -473,6 +610,9 @@ pub struct SecretObject {
     pub value: Option<String>,
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub type_: Option<secret_object::Type>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod secret_object {
     use super::*;
@@ -480,6 +620,8 @@ pub mod secret_object {
     pub enum Type {
         Opaque,
         Vaultsecret,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -493,6 +635,9 @@ pub struct AuthInfo {
     pub scope: Option<String>,
     #[serde(rename = "expiresIn", skip_serializing_if = "Option::is_none")]
     pub expires_in: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod auth_info {
     use super::*;
@@ -501,6 +646,8 @@ pub mod auth_info {
         #[serde(rename = "PAT")]
         Pat,
         OAuth,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -511,6 +658,9 @@ pub struct TaskUpdateParameters {
     pub properties: Option<TaskPropertiesUpdateParameters>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<serde_json::Value>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskPropertiesUpdateParameters {
@@ -528,6 +678,9 @@ pub struct TaskPropertiesUpdateParameters {
     pub trigger: Option<TriggerUpdateParameters>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credentials: Option<Credentials>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod task_properties_update_parameters {
     use super::*;
@@ -535,6 +688,8 @@ pub mod task_properties_update_parameters {
     pub enum Status {
         Disabled,
         Enabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -545,6 +700,9 @@ pub struct PlatformUpdateParameters {
     pub architecture: Option<platform_update_parameters::Architecture>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub variant: Option<platform_update_parameters::Variant>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod platform_update_parameters {
     use super::*;
@@ -552,6 +710,8 @@ pub mod platform_update_parameters {
     pub enum Os {
         Windows,
         Linux,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Architecture {
@@ -561,6 +721,8 @@ pub mod platform_update_parameters {
         X86,
         #[serde(rename = "arm")]
         Arm,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Variant {
@@ -570,6 +732,8 @@ pub mod platform_update_parameters {
         V7,
         #[serde(rename = "v8")]
         V8,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -588,16 +752,21 @@ pub mod task_step_update_parameters {
         Docker,
         FileTask,
         EncodedTask,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TriggerUpdateParameters {
-    #[serde(rename = "timerTriggers", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "timerTriggers", default, skip_serializing_if = "Vec::is_empty")]
     pub timer_triggers: Vec<TimerTriggerUpdateParameters>,
-    #[serde(rename = "sourceTriggers", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "sourceTriggers", default, skip_serializing_if = "Vec::is_empty")]
     pub source_triggers: Vec<SourceTriggerUpdateParameters>,
     #[serde(rename = "baseImageTrigger", skip_serializing_if = "Option::is_none")]
     pub base_image_trigger: Option<BaseImageTriggerUpdateParameters>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TimerTriggerUpdateParameters {
@@ -606,6 +775,9 @@ pub struct TimerTriggerUpdateParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<timer_trigger_update_parameters::Status>,
     pub name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod timer_trigger_update_parameters {
     use super::*;
@@ -613,17 +785,22 @@ pub mod timer_trigger_update_parameters {
     pub enum Status {
         Disabled,
         Enabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SourceTriggerUpdateParameters {
     #[serde(rename = "sourceRepository", skip_serializing_if = "Option::is_none")]
     pub source_repository: Option<SourceUpdateParameters>,
-    #[serde(rename = "sourceTriggerEvents", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "sourceTriggerEvents", default, skip_serializing_if = "Vec::is_empty")]
     pub source_trigger_events: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<source_trigger_update_parameters::Status>,
     pub name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod source_trigger_update_parameters {
     use super::*;
@@ -631,6 +808,8 @@ pub mod source_trigger_update_parameters {
     pub enum Status {
         Disabled,
         Enabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -640,6 +819,9 @@ pub struct BaseImageTriggerUpdateParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<base_image_trigger_update_parameters::Status>,
     pub name: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod base_image_trigger_update_parameters {
     use super::*;
@@ -647,11 +829,15 @@ pub mod base_image_trigger_update_parameters {
     pub enum BaseImageTriggerType {
         All,
         Runtime,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Status {
         Disabled,
         Enabled,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -664,6 +850,9 @@ pub struct SourceUpdateParameters {
     pub branch: Option<String>,
     #[serde(rename = "sourceControlAuthProperties", skip_serializing_if = "Option::is_none")]
     pub source_control_auth_properties: Option<AuthInfoUpdateParameters>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod source_update_parameters {
     use super::*;
@@ -671,6 +860,8 @@ pub mod source_update_parameters {
     pub enum SourceControlType {
         Github,
         VisualStudioTeamService,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -685,6 +876,9 @@ pub struct AuthInfoUpdateParameters {
     pub scope: Option<String>,
     #[serde(rename = "expiresIn", skip_serializing_if = "Option::is_none")]
     pub expires_in: Option<i32>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 pub mod auth_info_update_parameters {
     use super::*;
@@ -693,6 +887,8 @@ pub mod auth_info_update_parameters {
         #[serde(rename = "PAT")]
         Pat,
         OAuth,
+        #[serde(untagged)]
+        UnknownValue(String),
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -720,7 +916,7 @@ pub struct ProxyResource {
 pub struct DockerBuildRequest {
     #[serde(flatten)]
     pub run_request: RunRequest,
-    #[serde(rename = "imageNames", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "imageNames", default, skip_serializing_if = "Vec::is_empty")]
     pub image_names: Vec<String>,
     #[serde(rename = "isPushEnabled", skip_serializing_if = "Option::is_none")]
     pub is_push_enabled: Option<bool>,
@@ -730,7 +926,7 @@ pub struct DockerBuildRequest {
     pub docker_file_path: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub arguments: Vec<Argument>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timeout: Option<i32>,
@@ -741,6 +937,9 @@ pub struct DockerBuildRequest {
     pub source_location: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credentials: Option<Credentials>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Argument {
@@ -748,6 +947,9 @@ pub struct Argument {
     pub value: String,
     #[serde(rename = "isSecret", skip_serializing_if = "Option::is_none")]
     pub is_secret: Option<bool>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileTaskRunRequest {
@@ -757,7 +959,7 @@ pub struct FileTaskRunRequest {
     pub task_file_path: String,
     #[serde(rename = "valuesFilePath", skip_serializing_if = "Option::is_none")]
     pub values_file_path: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub values: Vec<SetValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timeout: Option<i32>,
@@ -768,6 +970,9 @@ pub struct FileTaskRunRequest {
     pub source_location: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credentials: Option<Credentials>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SetValue {
@@ -775,6 +980,9 @@ pub struct SetValue {
     pub value: String,
     #[serde(rename = "isSecret", skip_serializing_if = "Option::is_none")]
     pub is_secret: Option<bool>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TaskRunRequest {
@@ -782,8 +990,11 @@ pub struct TaskRunRequest {
     pub run_request: RunRequest,
     #[serde(rename = "taskName")]
     pub task_name: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub values: Vec<SetValue>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EncodedTaskRunRequest {
@@ -793,7 +1004,7 @@ pub struct EncodedTaskRunRequest {
     pub encoded_task_content: String,
     #[serde(rename = "encodedValuesContent", skip_serializing_if = "Option::is_none")]
     pub encoded_values_content: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub values: Vec<SetValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timeout: Option<i32>,
@@ -804,12 +1015,15 @@ pub struct EncodedTaskRunRequest {
     pub source_location: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credentials: Option<Credentials>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DockerBuildStep {
     #[serde(flatten)]
     pub task_step_properties: TaskStepProperties,
-    #[serde(rename = "imageNames", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "imageNames", default, skip_serializing_if = "Vec::is_empty")]
     pub image_names: Vec<String>,
     #[serde(rename = "isPushEnabled", skip_serializing_if = "Option::is_none")]
     pub is_push_enabled: Option<bool>,
@@ -819,8 +1033,11 @@ pub struct DockerBuildStep {
     pub docker_file_path: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub arguments: Vec<Argument>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileTaskStep {
@@ -830,8 +1047,11 @@ pub struct FileTaskStep {
     pub task_file_path: String,
     #[serde(rename = "valuesFilePath", skip_serializing_if = "Option::is_none")]
     pub values_file_path: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub values: Vec<SetValue>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EncodedTaskStep {
@@ -841,14 +1061,17 @@ pub struct EncodedTaskStep {
     pub encoded_task_content: String,
     #[serde(rename = "encodedValuesContent", skip_serializing_if = "Option::is_none")]
     pub encoded_values_content: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub values: Vec<SetValue>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DockerBuildStepUpdateParameters {
     #[serde(flatten)]
     pub task_step_update_parameters: TaskStepUpdateParameters,
-    #[serde(rename = "imageNames", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "imageNames", default, skip_serializing_if = "Vec::is_empty")]
     pub image_names: Vec<String>,
     #[serde(rename = "isPushEnabled", skip_serializing_if = "Option::is_none")]
     pub is_push_enabled: Option<bool>,
@@ -856,10 +1079,13 @@ pub struct DockerBuildStepUpdateParameters {
     pub no_cache: Option<bool>,
     #[serde(rename = "dockerFilePath", skip_serializing_if = "Option::is_none")]
     pub docker_file_path: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub arguments: Vec<Argument>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<String>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileTaskStepUpdateParameters {
@@ -869,8 +1095,11 @@ pub struct FileTaskStepUpdateParameters {
     pub task_file_path: Option<String>,
     #[serde(rename = "valuesFilePath", skip_serializing_if = "Option::is_none")]
     pub values_file_path: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub values: Vec<SetValue>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EncodedTaskStepUpdateParameters {
@@ -880,6 +1109,9 @@ pub struct EncodedTaskStepUpdateParameters {
     pub encoded_task_content: Option<String>,
     #[serde(rename = "encodedValuesContent", skip_serializing_if = "Option::is_none")]
     pub encoded_values_content: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub values: Vec<SetValue>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
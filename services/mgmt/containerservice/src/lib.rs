@@ -1,211 +1,188 @@
 #![doc = "generated by AutoRust 0.1.0"]
 #[cfg(feature = "package-2020-09")]
-mod package_2020_09;
+pub mod package_2020_09;
+#[cfg(feature = "package-2020-09")]
+pub use package_2020_09 as v2020_09;
 #[cfg(feature = "package-2020-09")]
 pub use package_2020_09::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-07")]
-mod package_2020_07;
-#[cfg(feature = "package-2020-07")]
-pub use package_2020_07::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-06")]
-mod package_2020_06;
-#[cfg(feature = "package-2020-06")]
-pub use package_2020_06::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-04")]
-mod package_2020_04;
-#[cfg(feature = "package-2020-04")]
-pub use package_2020_04::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-03")]
-mod package_2020_03;
-#[cfg(feature = "package-2020-03")]
-pub use package_2020_03::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-02")]
-mod package_2020_02;
-#[cfg(feature = "package-2020-02")]
-pub use package_2020_02::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-01")]
-mod package_2020_01;
-#[cfg(feature = "package-2020-01")]
-pub use package_2020_01::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-11")]
-mod package_2019_11;
-#[cfg(feature = "package-2019-11")]
-pub use package_2019_11::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-10-27-preview")]
-mod package_2019_10_27_preview;
-#[cfg(feature = "package-2019-10-27-preview")]
-pub use package_2019_10_27_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-09-30-preview")]
-mod package_2019_09_30_preview;
-#[cfg(feature = "package-2019-09-30-preview")]
-pub use package_2019_09_30_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-10")]
-mod package_2019_10;
-#[cfg(feature = "package-2019-10")]
-pub use package_2019_10::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-08")]
-mod package_2019_08;
-#[cfg(feature = "package-2019-08")]
-pub use package_2019_08::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-06")]
-mod package_2019_06;
-#[cfg(feature = "package-2019-06")]
-pub use package_2019_06::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-04")]
-mod package_2019_04;
-#[cfg(feature = "package-2019-04")]
-pub use package_2019_04::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-02")]
-mod package_2019_02;
-#[cfg(feature = "package-2019-02")]
-pub use package_2019_02::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-08-preview")]
-mod package_2018_08_preview;
-#[cfg(feature = "package-2018-08-preview")]
-pub use package_2018_08_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-09-30-preview")]
-mod package_2018_09_30_preview;
-#[cfg(feature = "package-2018-09-30-preview")]
-pub use package_2018_09_30_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-03")]
-mod package_2018_03;
-#[cfg(feature = "package-2018-03")]
-pub use package_2018_03::{models, operations, API_VERSION};
-#[cfg(feature = "package-2017-09")]
-mod package_2017_09;
-#[cfg(feature = "package-2017-09")]
-pub use package_2017_09::{models, operations, API_VERSION};
-#[cfg(feature = "package-2017-08")]
-mod package_2017_08;
-#[cfg(feature = "package-2017-08")]
-pub use package_2017_08::{models, operations, API_VERSION};
+#[cfg(feature = "package-2016-03-only")]
+pub mod package_2016_03_only;
+#[cfg(feature = "package-2016-03-only")]
+pub use package_2016_03_only as v2016_03_only;
+#[cfg(feature = "package-2016-09-only")]
+pub mod package_2016_09_only;
+#[cfg(feature = "package-2016-09-only")]
+pub use package_2016_09_only as v2016_09_only;
+#[cfg(feature = "package-2017-01-only")]
+pub mod package_2017_01_only;
+#[cfg(feature = "package-2017-01-only")]
+pub use package_2017_01_only as v2017_01_only;
 #[cfg(feature = "package-2017-07")]
-mod package_2017_07;
+pub mod package_2017_07;
 #[cfg(feature = "package-2017-07")]
-pub use package_2017_07::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-09-01-only")]
-mod package_2020_09_01_only;
-#[cfg(feature = "package-2020-09-01-only")]
-pub use package_2020_09_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-07-01-only")]
-mod package_2020_07_01_only;
-#[cfg(feature = "package-2020-07-01-only")]
-pub use package_2020_07_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-06-01-only")]
-mod package_2020_06_01_only;
-#[cfg(feature = "package-2020-06-01-only")]
-pub use package_2020_06_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-04-01-only")]
-mod package_2020_04_01_only;
-#[cfg(feature = "package-2020-04-01-only")]
-pub use package_2020_04_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-03-01-only")]
-mod package_2020_03_01_only;
-#[cfg(feature = "package-2020-03-01-only")]
-pub use package_2020_03_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-02-01-only")]
-mod package_2020_02_01_only;
-#[cfg(feature = "package-2020-02-01-only")]
-pub use package_2020_02_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-01-01-only")]
-mod package_2020_01_01_only;
-#[cfg(feature = "package-2020-01-01-only")]
-pub use package_2020_01_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-11-01-only")]
-mod package_2019_11_01_only;
-#[cfg(feature = "package-2019-11-01-only")]
-pub use package_2019_11_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-10-27-preview-only")]
-mod package_2019_10_27_preview_only;
-#[cfg(feature = "package-2019-10-27-preview-only")]
-pub use package_2019_10_27_preview_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-10-01-only")]
-mod package_2019_10_01_only;
-#[cfg(feature = "package-2019-10-01-only")]
-pub use package_2019_10_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-08-01-only")]
-mod package_2019_08_01_only;
-#[cfg(feature = "package-2019-08-01-only")]
-pub use package_2019_08_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-06-01-only")]
-mod package_2019_06_01_only;
-#[cfg(feature = "package-2019-06-01-only")]
-pub use package_2019_06_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-04-30-only")]
-mod package_2019_04_30_only;
-#[cfg(feature = "package-2019-04-30-only")]
-pub use package_2019_04_30_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-04-01-only")]
-mod package_2019_04_01_only;
-#[cfg(feature = "package-2019-04-01-only")]
-pub use package_2019_04_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-02-only")]
-mod package_2019_02_only;
-#[cfg(feature = "package-2019-02-only")]
-pub use package_2019_02_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-08-preview-only")]
-mod package_2018_08_preview_only;
-#[cfg(feature = "package-2018-08-preview-only")]
-pub use package_2018_08_preview_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-09-preview-only")]
-mod package_2018_09_preview_only;
-#[cfg(feature = "package-2018-09-preview-only")]
-pub use package_2018_09_preview_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-09-preview-only")]
-mod package_2019_09_preview_only;
-#[cfg(feature = "package-2019-09-preview-only")]
-pub use package_2019_09_preview_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2018-03-only")]
-mod package_2018_03_only;
-#[cfg(feature = "package-2018-03-only")]
-pub use package_2018_03_only::{models, operations, API_VERSION};
+pub use package_2017_07 as v2017_07;
 #[cfg(feature = "package-2017-07-only-extended")]
-mod package_2017_07_only_extended;
+pub mod package_2017_07_only_extended;
 #[cfg(feature = "package-2017-07-only-extended")]
-pub use package_2017_07_only_extended::{models, operations, API_VERSION};
+pub use package_2017_07_only_extended as v2017_07_only_extended;
+#[cfg(feature = "package-2017-08")]
+pub mod package_2017_08;
+#[cfg(feature = "package-2017-08")]
+pub use package_2017_08 as v2017_08;
 #[cfg(feature = "package-2017-08-only")]
-mod package_2017_08_only;
+pub mod package_2017_08_only;
 #[cfg(feature = "package-2017-08-only")]
-pub use package_2017_08_only::{models, operations, API_VERSION};
+pub use package_2017_08_only as v2017_08_only;
+#[cfg(feature = "package-2017-09")]
+pub mod package_2017_09;
+#[cfg(feature = "package-2017-09")]
+pub use package_2017_09 as v2017_09;
 #[cfg(feature = "package-2017-09-only")]
-mod package_2017_09_only;
+pub mod package_2017_09_only;
 #[cfg(feature = "package-2017-09-only")]
-pub use package_2017_09_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2017-01-only")]
-mod package_2017_01_only;
-#[cfg(feature = "package-2017-01-only")]
-pub use package_2017_01_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2016-09-only")]
-mod package_2016_09_only;
-#[cfg(feature = "package-2016-09-only")]
-pub use package_2016_09_only::{models, operations, API_VERSION};
-#[cfg(feature = "package-2016-03-only")]
-mod package_2016_03_only;
-#[cfg(feature = "package-2016-03-only")]
-pub use package_2016_03_only::{models, operations, API_VERSION};
-pub struct OperationConfig {
-    pub api_version: String,
-    pub client: reqwest::Client,
-    pub base_path: String,
-    pub token_credential: Option<Box<dyn azure_core::TokenCredential>>,
-    pub token_credential_resource: String,
-}
-impl OperationConfig {
-    pub fn new(token_credential: Box<dyn azure_core::TokenCredential>) -> Self {
-        Self {
-            token_credential: Some(token_credential),
-            ..Default::default()
-        }
-    }
-}
-impl Default for OperationConfig {
-    fn default() -> Self {
-        Self {
-            api_version: API_VERSION.to_owned(),
-            client: reqwest::Client::new(),
-            base_path: "https://management.azure.com".to_owned(),
-            token_credential: None,
-            token_credential_resource: "https://management.azure.com/".to_owned(),
-        }
-    }
-}
+pub use package_2017_09_only as v2017_09_only;
+#[cfg(feature = "package-2018-03")]
+pub mod package_2018_03;
+#[cfg(feature = "package-2018-03")]
+pub use package_2018_03 as v2018_03;
+#[cfg(feature = "package-2018-03-only")]
+pub mod package_2018_03_only;
+#[cfg(feature = "package-2018-03-only")]
+pub use package_2018_03_only as v2018_03_only;
+#[cfg(feature = "package-2018-08-preview")]
+pub mod package_2018_08_preview;
+#[cfg(feature = "package-2018-08-preview")]
+pub use package_2018_08_preview as v2018_08_preview;
+#[cfg(feature = "package-2018-08-preview-only")]
+pub mod package_2018_08_preview_only;
+#[cfg(feature = "package-2018-08-preview-only")]
+pub use package_2018_08_preview_only as v2018_08_preview_only;
+#[cfg(feature = "package-2018-09-30-preview")]
+pub mod package_2018_09_30_preview;
+#[cfg(feature = "package-2018-09-30-preview")]
+pub use package_2018_09_30_preview as v2018_09_30_preview;
+#[cfg(feature = "package-2018-09-preview-only")]
+pub mod package_2018_09_preview_only;
+#[cfg(feature = "package-2018-09-preview-only")]
+pub use package_2018_09_preview_only as v2018_09_preview_only;
+#[cfg(feature = "package-2019-02")]
+pub mod package_2019_02;
+#[cfg(feature = "package-2019-02")]
+pub use package_2019_02 as v2019_02;
+#[cfg(feature = "package-2019-02-only")]
+pub mod package_2019_02_only;
+#[cfg(feature = "package-2019-02-only")]
+pub use package_2019_02_only as v2019_02_only;
+#[cfg(feature = "package-2019-04")]
+pub mod package_2019_04;
+#[cfg(feature = "package-2019-04")]
+pub use package_2019_04 as v2019_04;
+#[cfg(feature = "package-2019-04-01-only")]
+pub mod package_2019_04_01_only;
+#[cfg(feature = "package-2019-04-01-only")]
+pub use package_2019_04_01_only as v2019_04_01_only;
+#[cfg(feature = "package-2019-04-30-only")]
+pub mod package_2019_04_30_only;
+#[cfg(feature = "package-2019-04-30-only")]
+pub use package_2019_04_30_only as v2019_04_30_only;
+#[cfg(feature = "package-2019-06")]
+pub mod package_2019_06;
+#[cfg(feature = "package-2019-06")]
+pub use package_2019_06 as v2019_06;
+#[cfg(feature = "package-2019-06-01-only")]
+pub mod package_2019_06_01_only;
+#[cfg(feature = "package-2019-06-01-only")]
+pub use package_2019_06_01_only as v2019_06_01_only;
+#[cfg(feature = "package-2019-08")]
+pub mod package_2019_08;
+#[cfg(feature = "package-2019-08")]
+pub use package_2019_08 as v2019_08;
+#[cfg(feature = "package-2019-08-01-only")]
+pub mod package_2019_08_01_only;
+#[cfg(feature = "package-2019-08-01-only")]
+pub use package_2019_08_01_only as v2019_08_01_only;
+#[cfg(feature = "package-2019-09-30-preview")]
+pub mod package_2019_09_30_preview;
+#[cfg(feature = "package-2019-09-30-preview")]
+pub use package_2019_09_30_preview as v2019_09_30_preview;
+#[cfg(feature = "package-2019-09-preview-only")]
+pub mod package_2019_09_preview_only;
+#[cfg(feature = "package-2019-09-preview-only")]
+pub use package_2019_09_preview_only as v2019_09_preview_only;
+#[cfg(feature = "package-2019-10")]
+pub mod package_2019_10;
+#[cfg(feature = "package-2019-10")]
+pub use package_2019_10 as v2019_10;
+#[cfg(feature = "package-2019-10-01-only")]
+pub mod package_2019_10_01_only;
+#[cfg(feature = "package-2019-10-01-only")]
+pub use package_2019_10_01_only as v2019_10_01_only;
+#[cfg(feature = "package-2019-10-27-preview")]
+pub mod package_2019_10_27_preview;
+#[cfg(feature = "package-2019-10-27-preview")]
+pub use package_2019_10_27_preview as v2019_10_27_preview;
+#[cfg(feature = "package-2019-10-27-preview-only")]
+pub mod package_2019_10_27_preview_only;
+#[cfg(feature = "package-2019-10-27-preview-only")]
+pub use package_2019_10_27_preview_only as v2019_10_27_preview_only;
+#[cfg(feature = "package-2019-11")]
+pub mod package_2019_11;
+#[cfg(feature = "package-2019-11")]
+pub use package_2019_11 as v2019_11;
+#[cfg(feature = "package-2019-11-01-only")]
+pub mod package_2019_11_01_only;
+#[cfg(feature = "package-2019-11-01-only")]
+pub use package_2019_11_01_only as v2019_11_01_only;
+#[cfg(feature = "package-2020-01")]
+pub mod package_2020_01;
+#[cfg(feature = "package-2020-01")]
+pub use package_2020_01 as v2020_01;
+#[cfg(feature = "package-2020-01-01-only")]
+pub mod package_2020_01_01_only;
+#[cfg(feature = "package-2020-01-01-only")]
+pub use package_2020_01_01_only as v2020_01_01_only;
+#[cfg(feature = "package-2020-02")]
+pub mod package_2020_02;
+#[cfg(feature = "package-2020-02")]
+pub use package_2020_02 as v2020_02;
+#[cfg(feature = "package-2020-02-01-only")]
+pub mod package_2020_02_01_only;
+#[cfg(feature = "package-2020-02-01-only")]
+pub use package_2020_02_01_only as v2020_02_01_only;
+#[cfg(feature = "package-2020-03")]
+pub mod package_2020_03;
+#[cfg(feature = "package-2020-03")]
+pub use package_2020_03 as v2020_03;
+#[cfg(feature = "package-2020-03-01-only")]
+pub mod package_2020_03_01_only;
+#[cfg(feature = "package-2020-03-01-only")]
+pub use package_2020_03_01_only as v2020_03_01_only;
+#[cfg(feature = "package-2020-04")]
+pub mod package_2020_04;
+#[cfg(feature = "package-2020-04")]
+pub use package_2020_04 as v2020_04;
+#[cfg(feature = "package-2020-04-01-only")]
+pub mod package_2020_04_01_only;
+#[cfg(feature = "package-2020-04-01-only")]
+pub use package_2020_04_01_only as v2020_04_01_only;
+#[cfg(feature = "package-2020-06")]
+pub mod package_2020_06;
+#[cfg(feature = "package-2020-06")]
+pub use package_2020_06 as v2020_06;
+#[cfg(feature = "package-2020-06-01-only")]
+pub mod package_2020_06_01_only;
+#[cfg(feature = "package-2020-06-01-only")]
+pub use package_2020_06_01_only as v2020_06_01_only;
+#[cfg(feature = "package-2020-07")]
+pub mod package_2020_07;
+#[cfg(feature = "package-2020-07")]
+pub use package_2020_07 as v2020_07;
+#[cfg(feature = "package-2020-07-01-only")]
+pub mod package_2020_07_01_only;
+#[cfg(feature = "package-2020-07-01-only")]
+pub use package_2020_07_01_only as v2020_07_01_only;
+#[cfg(feature = "package-2020-09-01-only")]
+pub mod package_2020_09_01_only;
+pub use azure_mgmt_core::OperationConfig;
+#[cfg(feature = "package-2020-09-01-only")]
+pub use package_2020_09_01_only as v2020_09_01_only;
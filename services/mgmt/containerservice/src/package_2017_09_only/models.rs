@@ -8,6 +8,9 @@ pub struct OrchestratorProfile {
     pub orchestrator_type: String,
     #[serde(rename = "orchestratorVersion")]
     pub orchestrator_version: String,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OrchestratorVersionProfile {
@@ -16,11 +19,19 @@ pub struct OrchestratorVersionProfile {
     #[serde(rename = "orchestratorVersion")]
     pub orchestrator_version: String,
     pub default: bool,
+    #[serde(default)]
     pub upgrades: Vec<OrchestratorProfile>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OrchestratorVersionProfileProperties {
+    #[serde(default)]
     pub orchestrators: Vec<OrchestratorVersionProfile>,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OrchestratorVersionProfileListResult {
@@ -31,4 +42,7 @@ pub struct OrchestratorVersionProfileListResult {
     #[serde(rename = "type", skip_serializing)]
     pub type_: Option<String>,
     pub properties: OrchestratorVersionProfileProperties,
+    #[cfg(feature = "additional-properties")]
+    #[serde(flatten)]
+    pub additional_properties: serde_json::Map<String, serde_json::Value>,
 }
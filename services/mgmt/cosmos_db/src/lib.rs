@@ -1,75 +1,52 @@
 #![doc = "generated by AutoRust 0.1.0"]
 #[cfg(feature = "package-2020-09")]
-mod package_2020_09;
+pub mod package_2020_09;
+#[cfg(feature = "package-2020-09")]
+pub use package_2020_09 as v2020_09;
 #[cfg(feature = "package-2020-09")]
 pub use package_2020_09::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-06-preview")]
-mod package_2020_06_preview;
-#[cfg(feature = "package-2020-06-preview")]
-pub use package_2020_06_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-04")]
-mod package_2020_04;
-#[cfg(feature = "package-2020-04")]
-pub use package_2020_04::{models, operations, API_VERSION};
-#[cfg(feature = "package-2020-03")]
-mod package_2020_03;
-#[cfg(feature = "package-2020-03")]
-pub use package_2020_03::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-12")]
-mod package_2019_12;
-#[cfg(feature = "package-2019-12")]
-pub use package_2019_12::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-08")]
-mod package_2019_08;
-#[cfg(feature = "package-2019-08")]
-pub use package_2019_08::{models, operations, API_VERSION};
-#[cfg(feature = "package-2019-08-preview")]
-mod package_2019_08_preview;
-#[cfg(feature = "package-2019-08-preview")]
-pub use package_2019_08_preview::{models, operations, API_VERSION};
-#[cfg(feature = "package-2015-04")]
-mod package_2015_04;
-#[cfg(feature = "package-2015-04")]
-pub use package_2015_04::{models, operations, API_VERSION};
 #[cfg(feature = "package-2014-04")]
-mod package_2014_04;
+pub mod package_2014_04;
 #[cfg(feature = "package-2014-04")]
-pub use package_2014_04::{models, operations, API_VERSION};
+pub use package_2014_04 as v2014_04;
+#[cfg(feature = "package-2015-04")]
+pub mod package_2015_04;
+#[cfg(feature = "package-2015-04")]
+pub use package_2015_04 as v2015_04;
 #[cfg(feature = "package-2015-11")]
-mod package_2015_11;
+pub mod package_2015_11;
 #[cfg(feature = "package-2015-11")]
-pub use package_2015_11::{models, operations, API_VERSION};
+pub use package_2015_11 as v2015_11;
 #[cfg(feature = "package-2016-03-19")]
-mod package_2016_03_19;
+pub mod package_2016_03_19;
 #[cfg(feature = "package-2016-03-19")]
-pub use package_2016_03_19::{models, operations, API_VERSION};
+pub use package_2016_03_19 as v2016_03_19;
 #[cfg(feature = "package-2016-03-31")]
-mod package_2016_03_31;
+pub mod package_2016_03_31;
 #[cfg(feature = "package-2016-03-31")]
-pub use package_2016_03_31::{models, operations, API_VERSION};
-pub struct OperationConfig {
-    pub api_version: String,
-    pub client: reqwest::Client,
-    pub base_path: String,
-    pub token_credential: Option<Box<dyn azure_core::TokenCredential>>,
-    pub token_credential_resource: String,
-}
-impl OperationConfig {
-    pub fn new(token_credential: Box<dyn azure_core::TokenCredential>) -> Self {
-        Self {
-            token_credential: Some(token_credential),
-            ..Default::default()
-        }
-    }
-}
-impl Default for OperationConfig {
-    fn default() -> Self {
-        Self {
-            api_version: API_VERSION.to_owned(),
-            client: reqwest::Client::new(),
-            base_path: "https://management.azure.com".to_owned(),
-            token_credential: None,
-            token_credential_resource: "https://management.azure.com/".to_owned(),
-        }
-    }
-}
+pub use package_2016_03_31 as v2016_03_31;
+#[cfg(feature = "package-2019-08")]
+pub mod package_2019_08;
+#[cfg(feature = "package-2019-08")]
+pub use package_2019_08 as v2019_08;
+#[cfg(feature = "package-2019-08-preview")]
+pub mod package_2019_08_preview;
+#[cfg(feature = "package-2019-08-preview")]
+pub use package_2019_08_preview as v2019_08_preview;
+#[cfg(feature = "package-2019-12")]
+pub mod package_2019_12;
+#[cfg(feature = "package-2019-12")]
+pub use package_2019_12 as v2019_12;
+#[cfg(feature = "package-2020-03")]
+pub mod package_2020_03;
+#[cfg(feature = "package-2020-03")]
+pub use package_2020_03 as v2020_03;
+#[cfg(feature = "package-2020-04")]
+pub mod package_2020_04;
+#[cfg(feature = "package-2020-04")]
+pub use package_2020_04 as v2020_04;
+#[cfg(feature = "package-2020-06-preview")]
+pub mod package_2020_06_preview;
+pub use azure_mgmt_core::OperationConfig;
+#[cfg(feature = "package-2020-06-preview")]
+pub use package_2020_06_preview as v2020_06_preview;